@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Compares reading a syntax tree from JSON (`json::from_slice`) with reading
+// it from the binary format (`binary::from_slice`).
+//
+// Run with `cargo bench --features json,binary --bench binary`.
+
+mod common;
+
+use syn_serde::{binary, json};
+
+fn main() {
+    for (path, file) in common::large_files() {
+        let s = json::to_vec(&file);
+        let bytes = binary::to_vec(&file);
+
+        println!(
+            "{} ({} bytes, {} bytes in the binary format):",
+            path.file_name().unwrap().to_string_lossy(),
+            s.len(),
+            bytes.len()
+        );
+        common::bench("json", || json::from_slice::<syn::File>(&s).unwrap());
+        common::bench("binary", || binary::from_slice::<syn::File>(&bytes).unwrap());
+    }
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Compares serializing a syntax tree through its adapter with serializing it
+// through `Serializable`, which does not build the adapter tree.
+//
+// Run with `cargo bench --bench serialize`.
+
+mod common;
+
+use syn_serde::{Serializable, Syn as _};
+
+fn main() {
+    for (path, file) in common::large_files() {
+        assert_eq!(
+            serde_json::to_vec(&Serializable::new(&file)).unwrap(),
+            serde_json::to_vec(&file.to_adapter()).unwrap()
+        );
+
+        println!("{}:", path.file_name().unwrap().to_string_lossy());
+        common::bench("adapter", || serde_json::to_vec(&file.to_adapter()).unwrap());
+        common::bench("serializable", || serde_json::to_vec(&Serializable::new(&file)).unwrap());
+    }
+}
@@ -1,19 +1,23 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-mod pickle;
-
 use std::{
     env, fs,
     io::{self, Write as _},
 };
 
+use syn_serde::{SerializeOptions, pickle};
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args: Vec<_> = env::args_os().skip(1).collect();
+    let mut args: Vec<_> = env::args_os().skip(1).collect();
+    // Emit every field, including those with default values, so Python code
+    // does not need to know the defaults.
+    let explicit_fields = args.iter().any(|arg| arg == "--explicit-fields");
+    args.retain(|arg| arg != "--explicit-fields");
     let (input_path, output_path) = match &*args {
         [input] => (input, None),
         [input, output] => (input, Some(output)),
         _ => {
-            println!("Usage: rust2pickle <input_path> [output_path]");
+            println!("Usage: rust2pickle [--explicit-fields] <input_path> [output_path]");
             std::process::exit(1);
         }
     };
@@ -21,7 +25,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let code = fs::read_to_string(input_path)?;
     let syntax = syn::parse_file(&code)?;
 
-    let buf = pickle::to_vec(&syntax);
+    let options = SerializeOptions::new().explicit_fields(explicit_fields);
+    let buf = pickle::to_vec_with_options(&syntax, options);
     if let Some(output_path) = output_path {
         fs::write(output_path, buf)?;
     } else {
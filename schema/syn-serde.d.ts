@@ -0,0 +1,1739 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// This file is @generated by syn-serde-internal-codegen
+// (generate function at tools/codegen/src/typescript.rs).
+// It is not intended for manual editing.
+
+/** An adapter for `syn::Abi`. */
+export interface Abi {
+  name?: LitStr | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::AngleBracketedGenericArguments`. */
+export interface AngleBracketedGenericArguments {
+  colon2_token?: boolean;
+  args: GenericArgument[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::Arm`. */
+export interface Arm {
+  attrs?: Attribute[];
+  pat: Pat;
+  guard?: Expr | null;
+  body: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::AssocConst`. */
+export interface AssocConst {
+  ident: Ident;
+  generics?: AngleBracketedGenericArguments | null;
+  value: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::AssocType`. */
+export interface AssocType {
+  ident: Ident;
+  generics?: AngleBracketedGenericArguments | null;
+  ty: Type;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::AttrStyle`. */
+export type AttrStyle =
+  | "outer"
+  | "inner";
+
+/** An adapter for `syn::Attribute`. */
+export interface Attribute {
+  style: AttrStyle;
+  meta: Meta;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::BareFnArg`. */
+export interface BareFnArg {
+  attrs?: Attribute[];
+  name?: Ident | null;
+  ty: Type;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::BareVariadic`. */
+export interface BareVariadic {
+  attrs?: Attribute[];
+  name?: Ident | null;
+  comma?: boolean;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::BinOp`. */
+export type BinOp =
+  | "+"
+  | "-"
+  | "*"
+  | "/"
+  | "%"
+  | "&&"
+  | "||"
+  | "^"
+  | "&"
+  | "|"
+  | "<<"
+  | ">>"
+  | "=="
+  | "<"
+  | "<="
+  | "!="
+  | ">="
+  | ">"
+  | "+="
+  | "-="
+  | "*="
+  | "/="
+  | "%="
+  | "^="
+  | "&="
+  | "|="
+  | "<<="
+  | ">>=";
+
+/** An adapter for `syn::Block`. */
+export type Block = Stmt[];
+
+/** An adapter for `syn::BoundLifetimes`. */
+export type BoundLifetimes = GenericParam[];
+
+/** An adapter for `syn::CapturedParam`. */
+export type CapturedParam =
+  | { lifetime: Lifetime }
+  | { ident: Ident }
+  | { kind: "CapturedParamLifetime"; value: Lifetime }
+  | { kind: "CapturedParamIdent"; value: Ident };
+
+/** An adapter for `syn::ConstParam`. */
+export interface ConstParam {
+  attrs?: Attribute[];
+  ident: Ident;
+  ty: Type;
+  eq_token?: boolean;
+  default?: Expr | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::Constraint`. */
+export interface Constraint {
+  ident: Ident;
+  generics?: AngleBracketedGenericArguments | null;
+  bounds: TypeParamBound[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::Data`. */
+export type Data =
+  | { struct: DataStruct }
+  | { enum: DataEnum }
+  | { union: DataUnion }
+  | { kind: "DataStruct"; value: DataStruct }
+  | { kind: "DataEnum"; value: DataEnum }
+  | { kind: "DataUnion"; value: DataUnion };
+
+/** An adapter for `syn::DataEnum`. */
+export type DataEnum = Variant[];
+
+/** An adapter for `syn::DataStruct`. */
+export type DataStruct = Fields;
+
+/** An adapter for `syn::DataUnion`. */
+export type DataUnion = FieldsNamed;
+
+/** An adapter for `proc_macro2::Delimiter`. */
+export type Delimiter = "parenthesis" | "brace" | "bracket" | "none";
+
+/** An adapter for `syn::DeriveInput`. */
+export interface DeriveInput {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  ident: Ident;
+  generics?: Generics;
+  data: Data;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::Expr`. */
+export type Expr =
+  | { array: ExprArray }
+  | { assign: ExprAssign }
+  | { async: ExprAsync }
+  | { await: ExprAwait }
+  | { binary: ExprBinary }
+  | { block: ExprBlock }
+  | { break: ExprBreak }
+  | { call: ExprCall }
+  | { cast: ExprCast }
+  | { closure: ExprClosure }
+  | { const: ExprConst }
+  | { continue: ExprContinue }
+  | { field: ExprField }
+  | { for_loop: ExprForLoop }
+  | { group: ExprGroup }
+  | { if: ExprIf }
+  | { index: ExprIndex }
+  | { infer: ExprInfer }
+  | { let: ExprLet }
+  | { lit: ExprLit }
+  | { loop: ExprLoop }
+  | { macro: ExprMacro }
+  | { match: ExprMatch }
+  | { method_call: ExprMethodCall }
+  | { paren: ExprParen }
+  | { path: ExprPath }
+  | { range: ExprRange }
+  | { raw_addr: ExprRawAddr }
+  | { reference: ExprReference }
+  | { repeat: ExprRepeat }
+  | { return: ExprReturn }
+  | { struct: ExprStruct }
+  | { try: ExprTry }
+  | { try_block: ExprTryBlock }
+  | { tuple: ExprTuple }
+  | { unary: ExprUnary }
+  | { unsafe: ExprUnsafe }
+  | { verbatim: TokenStream }
+  | { while: ExprWhile }
+  | { yield: ExprYield }
+  | { kind: "ExprArray" } & ExprArray
+  | { kind: "ExprAssign" } & ExprAssign
+  | { kind: "ExprAsync" } & ExprAsync
+  | { kind: "ExprAwait" } & ExprAwait
+  | { kind: "ExprBinary" } & ExprBinary
+  | { kind: "ExprBlock" } & ExprBlock
+  | { kind: "ExprBreak" } & ExprBreak
+  | { kind: "ExprCall" } & ExprCall
+  | { kind: "ExprCast" } & ExprCast
+  | { kind: "ExprClosure" } & ExprClosure
+  | { kind: "ExprConst" } & ExprConst
+  | { kind: "ExprContinue" } & ExprContinue
+  | { kind: "ExprField" } & ExprField
+  | { kind: "ExprForLoop" } & ExprForLoop
+  | { kind: "ExprGroup" } & ExprGroup
+  | { kind: "ExprIf" } & ExprIf
+  | { kind: "ExprIndex" } & ExprIndex
+  | { kind: "ExprInfer" } & ExprInfer
+  | { kind: "ExprLet" } & ExprLet
+  | { kind: "ExprLit" } & ExprLit
+  | { kind: "ExprLoop" } & ExprLoop
+  | { kind: "ExprMacro" } & ExprMacro
+  | { kind: "ExprMatch" } & ExprMatch
+  | { kind: "ExprMethodCall" } & ExprMethodCall
+  | { kind: "ExprParen" } & ExprParen
+  | { kind: "ExprPath" } & ExprPath
+  | { kind: "ExprRange" } & ExprRange
+  | { kind: "ExprRawAddr" } & ExprRawAddr
+  | { kind: "ExprReference" } & ExprReference
+  | { kind: "ExprRepeat" } & ExprRepeat
+  | { kind: "ExprReturn" } & ExprReturn
+  | { kind: "ExprStruct" } & ExprStruct
+  | { kind: "ExprTry" } & ExprTry
+  | { kind: "ExprTryBlock" } & ExprTryBlock
+  | { kind: "ExprTuple" } & ExprTuple
+  | { kind: "ExprUnary" } & ExprUnary
+  | { kind: "ExprUnsafe" } & ExprUnsafe
+  | { kind: "ExprVerbatim"; value: TokenStream }
+  | { kind: "ExprWhile" } & ExprWhile
+  | { kind: "ExprYield" } & ExprYield;
+
+/** An adapter for `syn::ExprArray`. */
+export interface ExprArray {
+  attrs?: Attribute[];
+  elems: Expr[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprAssign`. */
+export interface ExprAssign {
+  attrs?: Attribute[];
+  left: Expr;
+  right: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprAsync`. */
+export interface ExprAsync {
+  attrs?: Attribute[];
+  move?: boolean;
+  stmts: Block;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprAwait`. */
+export interface ExprAwait {
+  attrs?: Attribute[];
+  base: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprBinary`. */
+export interface ExprBinary {
+  attrs?: Attribute[];
+  left: Expr;
+  op: BinOp;
+  right: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprBlock`. */
+export interface ExprBlock {
+  attrs?: Attribute[];
+  label?: Label | null;
+  stmts: Block;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprBreak`. */
+export interface ExprBreak {
+  attrs?: Attribute[];
+  label?: Lifetime | null;
+  expr?: Expr | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprCall`. */
+export interface ExprCall {
+  attrs?: Attribute[];
+  func: Expr;
+  args: Expr[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprCast`. */
+export interface ExprCast {
+  attrs?: Attribute[];
+  expr: Expr;
+  ty: Type;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprClosure`. */
+export interface ExprClosure {
+  attrs?: Attribute[];
+  lifetimes?: BoundLifetimes | null;
+  const?: boolean;
+  static?: boolean;
+  async?: boolean;
+  move?: boolean;
+  inputs: Pat[];
+  output?: ReturnType;
+  body: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprConst`. */
+export interface ExprConst {
+  attrs?: Attribute[];
+  stmts: Block;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprContinue`. */
+export interface ExprContinue {
+  attrs?: Attribute[];
+  label?: Lifetime | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprField`. */
+export type ExprField = {
+  attrs?: Attribute[];
+  base: Expr;
+  span?: Location | null;
+} & ({ ident: Ident } | { index: Index });
+
+/** An adapter for `syn::ExprForLoop`. */
+export interface ExprForLoop {
+  attrs?: Attribute[];
+  label?: Label | null;
+  pat: Pat;
+  expr: Expr;
+  body: Block;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprGroup`. */
+export interface ExprGroup {
+  attrs?: Attribute[];
+  expr: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprIf`. */
+export interface ExprIf {
+  attrs?: Attribute[];
+  cond: Expr;
+  then_branch: Block;
+  else_branch?: Expr | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprIndex`. */
+export interface ExprIndex {
+  attrs?: Attribute[];
+  expr: Expr;
+  index: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprInfer`. */
+export interface ExprInfer {
+  attrs?: Attribute[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprLet`. */
+export interface ExprLet {
+  attrs?: Attribute[];
+  pat: Pat;
+  expr: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprLit`. */
+export type ExprLit = {
+  attrs?: Attribute[];
+  span?: Location | null;
+} & ({ str: LitStr } | { byte_str: LitByteStr } | { c_str: LitCStr } | { byte: LitByte } | { char: LitChar } | { int: LitInt } | { float: LitFloat } | { bool: LitBool } | { verbatim: Literal });
+
+/** An adapter for `syn::ExprLoop`. */
+export interface ExprLoop {
+  attrs?: Attribute[];
+  label?: Label | null;
+  body: Block;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprMacro`. */
+export interface ExprMacro {
+  attrs?: Attribute[];
+  path: Path;
+  delimiter: MacroDelimiter;
+  tokens: TokenStream;
+  args?: MacroArgs;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprMatch`. */
+export interface ExprMatch {
+  attrs?: Attribute[];
+  expr: Expr;
+  arms: Arm[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprMethodCall`. */
+export interface ExprMethodCall {
+  attrs?: Attribute[];
+  receiver: Expr;
+  method: Ident;
+  turbofish?: AngleBracketedGenericArguments | null;
+  args: Expr[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprParen`. */
+export interface ExprParen {
+  attrs?: Attribute[];
+  expr: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprPath`. */
+export interface ExprPath {
+  attrs?: Attribute[];
+  qself?: QSelf | null;
+  leading_colon?: boolean;
+  segments: PathSegment[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprRange`. */
+export interface ExprRange {
+  attrs?: Attribute[];
+  start?: Expr | null;
+  limits: RangeLimits;
+  end?: Expr | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprRawAddr`. */
+export interface ExprRawAddr {
+  attrs?: Attribute[];
+  mutability: PointerMutability;
+  expr: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprReference`. */
+export interface ExprReference {
+  attrs?: Attribute[];
+  mut?: boolean;
+  expr: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprRepeat`. */
+export interface ExprRepeat {
+  attrs?: Attribute[];
+  expr: Expr;
+  len: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprReturn`. */
+export interface ExprReturn {
+  attrs?: Attribute[];
+  expr?: Expr | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprStruct`. */
+export interface ExprStruct {
+  attrs?: Attribute[];
+  qself?: QSelf | null;
+  path: Path;
+  fields: FieldValue[];
+  dot2_token?: boolean;
+  rest?: Expr | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprTry`. */
+export interface ExprTry {
+  attrs?: Attribute[];
+  expr: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprTryBlock`. */
+export interface ExprTryBlock {
+  attrs?: Attribute[];
+  stmts: Block;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprTuple`. */
+export interface ExprTuple {
+  attrs?: Attribute[];
+  elems: Expr[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprUnary`. */
+export interface ExprUnary {
+  attrs?: Attribute[];
+  op: UnOp;
+  expr: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprUnsafe`. */
+export interface ExprUnsafe {
+  attrs?: Attribute[];
+  stmts: Block;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprWhile`. */
+export interface ExprWhile {
+  attrs?: Attribute[];
+  label?: Label | null;
+  cond: Expr;
+  body: Block;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ExprYield`. */
+export interface ExprYield {
+  attrs?: Attribute[];
+  expr?: Expr | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::Field`. */
+export interface Field {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  mut?: FieldMutability;
+  ident?: Ident | null;
+  colon_token?: boolean;
+  ty: Type;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::FieldMutability`. */
+export type FieldMutability = "none";
+
+/** An adapter for `syn::FieldPat`. */
+export type FieldPat = {
+  attrs?: Attribute[];
+  colon_token?: boolean;
+  pat: Pat;
+  span?: Location | null;
+} & ({ ident: Ident } | { index: Index });
+
+/** An adapter for `syn::FieldValue`. */
+export type FieldValue = {
+  attrs?: Attribute[];
+  colon_token?: boolean;
+  expr: Expr;
+  span?: Location | null;
+} & ({ ident: Ident } | { index: Index });
+
+/** An adapter for `syn::Fields`. */
+export type Fields =
+  | "unit"
+  | { named: FieldsNamed }
+  | { unnamed: FieldsUnnamed }
+  | { kind: "FieldsNamed"; value: FieldsNamed }
+  | { kind: "FieldsUnnamed"; value: FieldsUnnamed };
+
+/** An adapter for `syn::FieldsNamed`. */
+export type FieldsNamed = Field[];
+
+/** An adapter for `syn::FieldsUnnamed`. */
+export type FieldsUnnamed = Field[];
+
+/** An adapter for `syn::File`. */
+export interface File {
+  shebang?: string | null;
+  attrs?: Attribute[];
+  items: Item[];
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::FnArg`. */
+export type FnArg =
+  | { receiver: Receiver }
+  | { typed: PatType }
+  | { kind: "FnArgReceiver" } & Receiver
+  | { kind: "FnArgTyped" } & PatType;
+
+/** An adapter for `syn::ForeignItem`. */
+export type ForeignItem =
+  | { fn: ForeignItemFn }
+  | { static: ForeignItemStatic }
+  | { type: ForeignItemType }
+  | { macro: ForeignItemMacro }
+  | { verbatim: TokenStream }
+  | { kind: "ForeignItemFn" } & ForeignItemFn
+  | { kind: "ForeignItemStatic" } & ForeignItemStatic
+  | { kind: "ForeignItemType" } & ForeignItemType
+  | { kind: "ForeignItemMacro" } & ForeignItemMacro
+  | { kind: "ForeignItemVerbatim"; value: TokenStream };
+
+/** An adapter for `syn::ForeignItemFn`. */
+export interface ForeignItemFn {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  const?: boolean;
+  async?: boolean;
+  unsafe?: boolean;
+  abi?: Abi | null;
+  ident: Ident;
+  generics?: Generics;
+  inputs: FnArg[];
+  variadic?: Variadic | null;
+  output?: ReturnType;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ForeignItemMacro`. */
+export interface ForeignItemMacro {
+  attrs?: Attribute[];
+  path: Path;
+  delimiter: MacroDelimiter;
+  tokens: TokenStream;
+  args?: MacroArgs;
+  semi_token?: boolean;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ForeignItemStatic`. */
+export interface ForeignItemStatic {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  mut?: StaticMutability;
+  ident: Ident;
+  ty: Type;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ForeignItemType`. */
+export interface ForeignItemType {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  ident: Ident;
+  generics?: Generics;
+  span?: Location | null;
+}
+
+/** An argument after the format string. */
+export interface FormatArg {
+  name?: Ident;
+  expr: Expr;
+}
+
+/** The arguments of a macro that takes a format string. */
+export interface FormatArgs {
+  exprs?: Expr[];
+  string?: LitStr;
+  args?: FormatArg[];
+}
+
+/** An adapter for `syn::GenericArgument`. */
+export type GenericArgument =
+  | { lifetime: Lifetime }
+  | { type: Type }
+  | { const: Expr }
+  | { assoc_type: AssocType }
+  | { assoc_const: AssocConst }
+  | { constraint: Constraint }
+  | { kind: "GenericArgumentLifetime"; value: Lifetime }
+  | { kind: "GenericArgumentType"; value: Type }
+  | { kind: "GenericArgumentConst"; value: Expr }
+  | { kind: "GenericArgumentAssocType" } & AssocType
+  | { kind: "GenericArgumentAssocConst" } & AssocConst
+  | { kind: "GenericArgumentConstraint" } & Constraint;
+
+/** An adapter for `syn::GenericParam`. */
+export type GenericParam =
+  | { lifetime: LifetimeParam }
+  | { type: TypeParam }
+  | { const: ConstParam }
+  | { kind: "GenericParamLifetime" } & LifetimeParam
+  | { kind: "GenericParamType" } & TypeParam
+  | { kind: "GenericParamConst" } & ConstParam;
+
+/** An adapter for `syn::Generics`. */
+export interface Generics {
+  params?: GenericParam[];
+  where_clause?: WhereClause | null;
+  span?: Location | null;
+}
+
+/** An adapter for `proc_macro2::Group`. */
+export interface Group {
+  delimiter: Delimiter;
+  stream: TokenStream;
+  span?: Location | null;
+}
+
+/** An adapter for `proc_macro2::Ident`. */
+export type Ident = string | { name: string; span?: Location | null };
+
+/** An adapter for `syn::ImplItem`. */
+export type ImplItem =
+  | { const: ImplItemConst }
+  | { fn: ImplItemFn }
+  | { type: ImplItemType }
+  | { macro: ImplItemMacro }
+  | { verbatim: TokenStream }
+  | { kind: "ImplItemConst" } & ImplItemConst
+  | { kind: "ImplItemFn" } & ImplItemFn
+  | { kind: "ImplItemType" } & ImplItemType
+  | { kind: "ImplItemMacro" } & ImplItemMacro
+  | { kind: "ImplItemVerbatim"; value: TokenStream };
+
+/** An adapter for `syn::ImplItemConst`. */
+export interface ImplItemConst {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  default?: boolean;
+  ident: Ident;
+  generics?: Generics;
+  ty: Type;
+  expr: Expr;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ImplItemFn`. */
+export interface ImplItemFn {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  default?: boolean;
+  const?: boolean;
+  async?: boolean;
+  unsafe?: boolean;
+  abi?: Abi | null;
+  ident: Ident;
+  generics?: Generics;
+  inputs: FnArg[];
+  variadic?: Variadic | null;
+  output?: ReturnType;
+  stmts: Block;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ImplItemMacro`. */
+export interface ImplItemMacro {
+  attrs?: Attribute[];
+  path: Path;
+  delimiter: MacroDelimiter;
+  tokens: TokenStream;
+  args?: MacroArgs;
+  semi_token?: boolean;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ImplItemType`. */
+export interface ImplItemType {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  default?: boolean;
+  ident: Ident;
+  generics?: Generics;
+  ty: Type;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ImplRestriction`. */
+export type ImplRestriction = never;
+
+/** An adapter for `syn::Index`. */
+export type Index = number;
+
+/** An adapter for `syn::Item`. */
+export type Item =
+  | { const: ItemConst }
+  | { enum: ItemEnum }
+  | { extern_crate: ItemExternCrate }
+  | { fn: ItemFn }
+  | { foreign_mod: ItemForeignMod }
+  | { impl: ItemImpl }
+  | { macro: ItemMacro }
+  | { mod: ItemMod }
+  | { static: ItemStatic }
+  | { struct: ItemStruct }
+  | { trait: ItemTrait }
+  | { trait_alias: ItemTraitAlias }
+  | { type: ItemType }
+  | { union: ItemUnion }
+  | { use: ItemUse }
+  | { verbatim: TokenStream }
+  | { kind: "ItemConst" } & ItemConst
+  | { kind: "ItemEnum" } & ItemEnum
+  | { kind: "ItemExternCrate" } & ItemExternCrate
+  | { kind: "ItemFn" } & ItemFn
+  | { kind: "ItemForeignMod" } & ItemForeignMod
+  | { kind: "ItemImpl" } & ItemImpl
+  | { kind: "ItemMacro" } & ItemMacro
+  | { kind: "ItemMod" } & ItemMod
+  | { kind: "ItemStatic" } & ItemStatic
+  | { kind: "ItemStruct" } & ItemStruct
+  | { kind: "ItemTrait" } & ItemTrait
+  | { kind: "ItemTraitAlias" } & ItemTraitAlias
+  | { kind: "ItemType" } & ItemType
+  | { kind: "ItemUnion" } & ItemUnion
+  | { kind: "ItemUse" } & ItemUse
+  | { kind: "ItemVerbatim"; value: TokenStream };
+
+/** An adapter for `syn::ItemConst`. */
+export interface ItemConst {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  ident: Ident;
+  generics?: Generics;
+  ty: Type;
+  expr: Expr;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemEnum`. */
+export interface ItemEnum {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  ident: Ident;
+  generics?: Generics;
+  variants: Variant[];
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemExternCrate`. */
+export interface ItemExternCrate {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  ident: Ident;
+  rename?: Ident | null;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemFn`. */
+export interface ItemFn {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  const?: boolean;
+  async?: boolean;
+  unsafe?: boolean;
+  abi?: Abi | null;
+  ident: Ident;
+  generics?: Generics;
+  inputs: FnArg[];
+  variadic?: Variadic | null;
+  output?: ReturnType;
+  stmts: Block;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemForeignMod`. */
+export interface ItemForeignMod {
+  attrs?: Attribute[];
+  unsafe?: boolean;
+  abi: Abi;
+  items: ForeignItem[];
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemImpl`. */
+export interface ItemImpl {
+  attrs?: Attribute[];
+  default?: boolean;
+  unsafe?: boolean;
+  generics?: Generics;
+  trait?: [boolean, Path] | null;
+  self_ty: Type;
+  items: ImplItem[];
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemMacro`. */
+export interface ItemMacro {
+  attrs?: Attribute[];
+  ident?: Ident | null;
+  path: Path;
+  delimiter: MacroDelimiter;
+  tokens: TokenStream;
+  args?: MacroArgs;
+  semi_token?: boolean;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemMod`. */
+export interface ItemMod {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  unsafe?: boolean;
+  ident: Ident;
+  content?: Item[] | null;
+  semi?: boolean;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemStatic`. */
+export interface ItemStatic {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  mut?: StaticMutability;
+  ident: Ident;
+  ty: Type;
+  expr: Expr;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemStruct`. */
+export interface ItemStruct {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  ident: Ident;
+  generics?: Generics;
+  fields: Fields;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemTrait`. */
+export interface ItemTrait {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  unsafe?: boolean;
+  auto?: boolean;
+  restriction?: ImplRestriction | null;
+  ident: Ident;
+  generics?: Generics;
+  colon_token?: boolean;
+  supertraits?: TypeParamBound[];
+  items: TraitItem[];
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemTraitAlias`. */
+export interface ItemTraitAlias {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  ident: Ident;
+  generics?: Generics;
+  bounds: TypeParamBound[];
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemType`. */
+export interface ItemType {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  ident: Ident;
+  generics?: Generics;
+  ty: Type;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemUnion`. */
+export interface ItemUnion {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  ident: Ident;
+  generics?: Generics;
+  fields: FieldsNamed;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::ItemUse`. */
+export interface ItemUse {
+  attrs?: Attribute[];
+  vis?: Visibility;
+  leading_colon?: boolean;
+  tree: UseTree;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::Label`. */
+export type Label = Lifetime;
+
+/** An adapter for `syn::Lifetime`. */
+export type Lifetime = Ident;
+
+/** An adapter for `syn::LifetimeParam`. */
+export interface LifetimeParam {
+  attrs?: Attribute[];
+  lifetime: Lifetime;
+  colon_token?: boolean;
+  bounds: Lifetime[];
+  span?: Location | null;
+}
+
+/** An adapter for `proc_macro2::LineColumn`. */
+export interface LineColumn {
+  line: number;
+  column: number;
+}
+
+/** An adapter for `syn::Lit`. */
+export type Lit =
+  | { str: LitStr }
+  | { byte_str: LitByteStr }
+  | { c_str: LitCStr }
+  | { byte: LitByte }
+  | { char: LitChar }
+  | { int: LitInt }
+  | { float: LitFloat }
+  | { bool: LitBool }
+  | { verbatim: Literal };
+
+/** An adapter for `syn::LitBool`. */
+export type LitBool = boolean;
+
+/** An adapter for `syn::LitByte`. */
+export type LitByte = string | { value: number; suffix?: string };
+
+/** An adapter for `syn::LitByteStr`. */
+export type LitByteStr = string | { value: number[]; suffix?: string; style?: StrStyle };
+
+/** An adapter for `syn::LitCStr`. */
+export type LitCStr = string | { value: number[]; suffix?: string; style?: StrStyle };
+
+/** An adapter for `syn::LitChar`. */
+export type LitChar = string | { value: string; suffix?: string };
+
+/** An adapter for `syn::LitFloat`. */
+export type LitFloat = string | { value: string; suffix?: string };
+
+/** An adapter for `syn::LitInt`. */
+export type LitInt = string | { value: string; suffix?: string };
+
+/** An adapter for `syn::LitStr`. */
+export type LitStr = string | { value: string; suffix?: string; style?: StrStyle };
+
+/** An adapter for `proc_macro2::Literal`. */
+export type Literal = string | { text: string; span?: Location | null };
+
+/** An adapter for `syn::Local`. */
+export interface Local {
+  attrs?: Attribute[];
+  pat: Pat;
+  init?: LocalInit | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::LocalInit`. */
+export interface LocalInit {
+  expr: Expr;
+  diverge?: Expr | null;
+}
+
+/** The location of a node in the source code. */
+export interface Location {
+  start: LineColumn;
+  end: LineColumn;
+}
+
+/** An adapter for `syn::Macro`. */
+export interface Macro {
+  path: Path;
+  delimiter: MacroDelimiter;
+  tokens: TokenStream;
+  args?: MacroArgs;
+}
+
+/** An adapter for the arguments of a well-known macro of the standard library. */
+export type MacroArgs =
+  | { format: FormatArgs }
+  | { list: Expr[] }
+  | { repeat: RepeatArgs };
+
+/** An adapter for `syn::MacroDelimiter`. */
+export type MacroDelimiter =
+  | "paren"
+  | "brace"
+  | "bracket";
+
+/** An adapter for `syn::Member`. */
+export type Member =
+  | { ident: Ident }
+  | { index: Index };
+
+/** An adapter for `syn::Meta`. */
+export type Meta =
+  | { path: Path }
+  | { list: MetaList }
+  | { name_value: MetaNameValue }
+  | { kind: "MetaPath" } & Path
+  | { kind: "MetaList" } & MetaList
+  | { kind: "MetaNameValue" } & MetaNameValue;
+
+/** An adapter for `syn::MetaList`. */
+export interface MetaList {
+  path: Path;
+  delimiter: MacroDelimiter;
+  tokens: TokenStream;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::MetaNameValue`. */
+export interface MetaNameValue {
+  path: Path;
+  value: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::ParenthesizedGenericArguments`. */
+export interface ParenthesizedGenericArguments {
+  inputs: Type[];
+  output?: ReturnType;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::Pat`. */
+export type Pat =
+  | { const: ExprConst }
+  | { ident: PatIdent }
+  | { lit: ExprLit }
+  | { macro: ExprMacro }
+  | { or: PatOr }
+  | { paren: PatParen }
+  | { path: ExprPath }
+  | { range: ExprRange }
+  | { reference: PatReference }
+  | { rest: PatRest }
+  | { slice: PatSlice }
+  | { struct: PatStruct }
+  | { tuple: PatTuple }
+  | { tuple_struct: PatTupleStruct }
+  | { type: PatType }
+  | { verbatim: TokenStream }
+  | { _: PatWild }
+  | { kind: "PatConst" } & ExprConst
+  | { kind: "PatIdent" } & PatIdent
+  | { kind: "PatLit" } & ExprLit
+  | { kind: "PatMacro" } & ExprMacro
+  | { kind: "PatOr" } & PatOr
+  | { kind: "PatParen" } & PatParen
+  | { kind: "PatPath" } & ExprPath
+  | { kind: "PatRange" } & ExprRange
+  | { kind: "PatReference" } & PatReference
+  | { kind: "PatRest" } & PatRest
+  | { kind: "PatSlice" } & PatSlice
+  | { kind: "PatStruct" } & PatStruct
+  | { kind: "PatTuple" } & PatTuple
+  | { kind: "PatTupleStruct" } & PatTupleStruct
+  | { kind: "PatType" } & PatType
+  | { kind: "PatVerbatim"; value: TokenStream }
+  | { kind: "PatWild" } & PatWild;
+
+/** An adapter for `syn::PatIdent`. */
+export interface PatIdent {
+  attrs?: Attribute[];
+  ref?: boolean;
+  mut?: boolean;
+  ident: Ident;
+  subpat?: Pat | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PatOr`. */
+export interface PatOr {
+  attrs?: Attribute[];
+  leading_vert?: boolean;
+  cases: Pat[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PatParen`. */
+export interface PatParen {
+  attrs?: Attribute[];
+  pat: Pat;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PatReference`. */
+export interface PatReference {
+  attrs?: Attribute[];
+  mut?: boolean;
+  pat: Pat;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PatRest`. */
+export interface PatRest {
+  attrs?: Attribute[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PatSlice`. */
+export interface PatSlice {
+  attrs?: Attribute[];
+  elems: Pat[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PatStruct`. */
+export interface PatStruct {
+  attrs?: Attribute[];
+  qself?: QSelf | null;
+  path: Path;
+  fields: FieldPat[];
+  rest?: PatRest | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PatTuple`. */
+export interface PatTuple {
+  attrs?: Attribute[];
+  elems: Pat[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PatTupleStruct`. */
+export interface PatTupleStruct {
+  attrs?: Attribute[];
+  qself?: QSelf | null;
+  path: Path;
+  elems: Pat[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PatType`. */
+export interface PatType {
+  attrs?: Attribute[];
+  pat: Pat;
+  ty: Type;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PatWild`. */
+export interface PatWild {
+  attrs?: Attribute[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::Path`. */
+export interface Path {
+  leading_colon?: boolean;
+  segments: PathSegment[];
+}
+
+/** An adapter for `syn::PathArguments`. */
+export type PathArguments =
+  | "none"
+  | { angle_bracketed: AngleBracketedGenericArguments }
+  | { parenthesized: ParenthesizedGenericArguments }
+  | { kind: "PathArgumentsAngleBracketed" } & AngleBracketedGenericArguments
+  | { kind: "PathArgumentsParenthesized" } & ParenthesizedGenericArguments;
+
+/** An adapter for `syn::PathSegment`. */
+export interface PathSegment {
+  ident: Ident;
+  arguments?: PathArguments;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PointerMutability`. */
+export type PointerMutability =
+  | "const"
+  | "mut";
+
+/** An adapter for `syn::PreciseCapture`. */
+export type PreciseCapture = CapturedParam[];
+
+/** An adapter for `syn::PredicateLifetime`. */
+export interface PredicateLifetime {
+  lifetime: Lifetime;
+  bounds: Lifetime[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::PredicateType`. */
+export interface PredicateType {
+  lifetimes?: BoundLifetimes | null;
+  bounded_ty: Type;
+  bounds: TypeParamBound[];
+  span?: Location | null;
+}
+
+/** An adapter for `proc_macro2::Punct`. */
+export interface Punct {
+  op: "!" | "#" | "$" | "%" | "&" | "'" | "*" | "+" | "," | "-" | "." | "/" | ":" | ";" | "<" | "=" | ">" | "?" | "@" | "^" | "|" | "~";
+  spacing: Spacing;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::QSelf`. */
+export interface QSelf {
+  ty: Type;
+  position: number;
+  as_token?: boolean;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::RangeLimits`. */
+export type RangeLimits =
+  | ".."
+  | "..=";
+
+/** An adapter for `syn::Receiver`. */
+export interface Receiver {
+  attrs?: Attribute[];
+  ref?: boolean;
+  lifetime?: Lifetime | null;
+  mut?: boolean;
+  colon_token?: boolean;
+  ty: Type;
+  span?: Location | null;
+}
+
+/** The arguments of `vec![x; n]`. */
+export interface RepeatArgs {
+  expr: Expr;
+  len: Expr;
+}
+
+/** An adapter for `syn::ReturnType`. */
+export type ReturnType = Type | null;
+
+/** An adapter for `syn::Signature`. */
+export interface Signature {
+  const?: boolean;
+  async?: boolean;
+  unsafe?: boolean;
+  abi?: Abi | null;
+  ident: Ident;
+  generics?: Generics;
+  inputs: FnArg[];
+  variadic?: Variadic | null;
+  output?: ReturnType;
+}
+
+/** An adapter for `proc_macro2::Spacing`. */
+export type Spacing = "alone" | "joint";
+
+/** Tokens that are spelled differently in the source code than they are printed. */
+export interface Spelling {
+  index: number;
+  tokens: string[];
+  text: string;
+}
+
+/** An adapter for `syn::StaticMutability`. */
+export type StaticMutability =
+  | "mut"
+  | "none";
+
+/** An adapter for `syn::Stmt`. */
+export type Stmt =
+  | { let: Local }
+  | { item: Item }
+  | { expr: [Expr, boolean] }
+  | { macro: StmtMacro }
+  | { kind: "StmtLocal" } & Local
+  | { kind: "StmtItem"; value: Item }
+  | { kind: "StmtExpr"; value: [Expr, boolean] }
+  | { kind: "StmtMacro" } & StmtMacro;
+
+/** An adapter for `syn::StmtMacro`. */
+export interface StmtMacro {
+  attrs?: Attribute[];
+  path: Path;
+  delimiter: MacroDelimiter;
+  tokens: TokenStream;
+  args?: MacroArgs;
+  semi_token?: boolean;
+  span?: Location | null;
+}
+
+/** An adapter for `proc_macro2::TokenStream`. */
+export type TokenStream = TokenTree[] | string;
+
+/** An adapter for `proc_macro2::TokenTree`. */
+export type TokenTree =
+  | { group: Group }
+  | { ident: Ident }
+  | { punct: Punct }
+  | { lit: Literal }
+  | { kind: "TokenTreeGroup" } & Group
+  | { kind: "TokenTreeIdent"; value: Ident }
+  | { kind: "TokenTreePunct" } & Punct
+  | { kind: "TokenTreeLiteral"; value: Literal };
+
+/** An adapter for `syn::TraitBound`. */
+export interface TraitBound {
+  paren_token?: boolean;
+  modifier?: TraitBoundModifier;
+  lifetimes?: BoundLifetimes | null;
+  path: Path;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TraitBoundModifier`. */
+export type TraitBoundModifier =
+  | "none"
+  | "maybe";
+
+/** An adapter for `syn::TraitItem`. */
+export type TraitItem =
+  | { const: TraitItemConst }
+  | { fn: TraitItemFn }
+  | { type: TraitItemType }
+  | { macro: TraitItemMacro }
+  | { verbatim: TokenStream }
+  | { kind: "TraitItemConst" } & TraitItemConst
+  | { kind: "TraitItemFn" } & TraitItemFn
+  | { kind: "TraitItemType" } & TraitItemType
+  | { kind: "TraitItemMacro" } & TraitItemMacro
+  | { kind: "TraitItemVerbatim"; value: TokenStream };
+
+/** An adapter for `syn::TraitItemConst`. */
+export interface TraitItemConst {
+  attrs?: Attribute[];
+  ident: Ident;
+  generics?: Generics;
+  ty: Type;
+  default?: Expr | null;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::TraitItemFn`. */
+export interface TraitItemFn {
+  attrs?: Attribute[];
+  const?: boolean;
+  async?: boolean;
+  unsafe?: boolean;
+  abi?: Abi | null;
+  ident: Ident;
+  generics?: Generics;
+  inputs: FnArg[];
+  variadic?: Variadic | null;
+  output?: ReturnType;
+  default?: Block | null;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::TraitItemMacro`. */
+export interface TraitItemMacro {
+  attrs?: Attribute[];
+  path: Path;
+  delimiter: MacroDelimiter;
+  tokens: TokenStream;
+  args?: MacroArgs;
+  semi_token?: boolean;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** An adapter for `syn::TraitItemType`. */
+export interface TraitItemType {
+  attrs?: Attribute[];
+  ident: Ident;
+  generics?: Generics;
+  colon_token?: boolean;
+  bounds?: TypeParamBound[];
+  default?: Type | null;
+  span?: Location | null;
+  trivia?: Trivia | null;
+}
+
+/** The comments and whitespace of a file or an item, recorded in lossless mode. */
+export interface Trivia {
+  leading: string[];
+  spellings?: Spelling[];
+  trailing?: string;
+  bom?: boolean;
+}
+
+/** An adapter for `syn::Type`. */
+export type Type =
+  | "_"
+  | "!"
+  | { array: TypeArray }
+  | { bare_fn: TypeBareFn }
+  | { group: TypeGroup }
+  | { impl_trait: TypeImplTrait }
+  | { macro: TypeMacro }
+  | { paren: TypeParen }
+  | { path: TypePath }
+  | { ptr: TypePtr }
+  | { reference: TypeReference }
+  | { slice: TypeSlice }
+  | { trait_object: TypeTraitObject }
+  | { tuple: TypeTuple }
+  | { verbatim: TokenStream }
+  | { kind: "TypeArray" } & TypeArray
+  | { kind: "TypeBareFn" } & TypeBareFn
+  | { kind: "TypeGroup" } & TypeGroup
+  | { kind: "TypeImplTrait" } & TypeImplTrait
+  | { kind: "TypeMacro" } & TypeMacro
+  | { kind: "TypeParen" } & TypeParen
+  | { kind: "TypePath" } & TypePath
+  | { kind: "TypePtr" } & TypePtr
+  | { kind: "TypeReference" } & TypeReference
+  | { kind: "TypeSlice" } & TypeSlice
+  | { kind: "TypeTraitObject" } & TypeTraitObject
+  | { kind: "TypeTuple" } & TypeTuple
+  | { kind: "TypeVerbatim"; value: TokenStream };
+
+/** An adapter for `syn::TypeArray`. */
+export interface TypeArray {
+  elem: Type;
+  len: Expr;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypeBareFn`. */
+export interface TypeBareFn {
+  lifetimes?: BoundLifetimes | null;
+  unsafe?: boolean;
+  abi?: Abi | null;
+  inputs: BareFnArg[];
+  variadic?: BareVariadic | null;
+  output?: ReturnType;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypeGroup`. */
+export interface TypeGroup {
+  elem: Type;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypeImplTrait`. */
+export interface TypeImplTrait {
+  bounds: TypeParamBound[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypeMacro`. */
+export interface TypeMacro {
+  path: Path;
+  delimiter: MacroDelimiter;
+  tokens: TokenStream;
+  args?: MacroArgs;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypeParam`. */
+export interface TypeParam {
+  attrs?: Attribute[];
+  ident: Ident;
+  colon_token?: boolean;
+  bounds?: TypeParamBound[];
+  eq_token?: boolean;
+  default?: Type | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypeParamBound`. */
+export type TypeParamBound =
+  | { trait: TraitBound }
+  | { lifetime: Lifetime }
+  | { precise_capture: PreciseCapture }
+  | { verbatim: TokenStream }
+  | { kind: "TypeParamBoundTrait" } & TraitBound
+  | { kind: "TypeParamBoundLifetime"; value: Lifetime }
+  | { kind: "TypeParamBoundPreciseCapture"; value: PreciseCapture }
+  | { kind: "TypeParamBoundVerbatim"; value: TokenStream };
+
+/** An adapter for `syn::TypeParen`. */
+export interface TypeParen {
+  elem: Type;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypePath`. */
+export interface TypePath {
+  qself?: QSelf | null;
+  leading_colon?: boolean;
+  segments: PathSegment[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypePtr`. */
+export interface TypePtr {
+  const?: boolean;
+  mut?: boolean;
+  elem: Type;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypeReference`. */
+export interface TypeReference {
+  lifetime?: Lifetime | null;
+  mut?: boolean;
+  elem: Type;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypeSlice`. */
+export interface TypeSlice {
+  elem: Type;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypeTraitObject`. */
+export interface TypeTraitObject {
+  dyn?: boolean;
+  bounds: TypeParamBound[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::TypeTuple`. */
+export interface TypeTuple {
+  elems: Type[];
+  span?: Location | null;
+}
+
+/** An adapter for `syn::UnOp`. */
+export type UnOp =
+  | "*"
+  | "!"
+  | "-";
+
+/** An adapter for `syn::UseGroup`. */
+export type UseGroup = UseTree[];
+
+/** An adapter for `syn::UseName`. */
+export type UseName = Ident;
+
+/** An adapter for `syn::UsePath`. */
+export interface UsePath {
+  ident: Ident;
+  tree: UseTree;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::UseRename`. */
+export interface UseRename {
+  ident: Ident;
+  rename: Ident;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::UseTree`. */
+export type UseTree =
+  | "*"
+  | { path: UsePath }
+  | { ident: UseName }
+  | { rename: UseRename }
+  | { group: UseGroup }
+  | { kind: "UseTreePath" } & UsePath
+  | { kind: "UseTreeName"; value: UseName }
+  | { kind: "UseTreeRename" } & UseRename
+  | { kind: "UseTreeGroup"; value: UseGroup };
+
+/** An adapter for `syn::Variadic`. */
+export interface Variadic {
+  attrs?: Attribute[];
+  pat?: Pat | null;
+  comma?: boolean;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::Variant`. */
+export interface Variant {
+  attrs?: Attribute[];
+  ident: Ident;
+  fields: Fields;
+  discriminant?: Expr | null;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::VisRestricted`. */
+export interface VisRestricted {
+  in_token?: boolean;
+  path: Path;
+  span?: Location | null;
+}
+
+/** An adapter for `syn::Visibility`. */
+export type Visibility =
+  | "pub"
+  | "inherited"
+  | { restricted: VisRestricted }
+  | { kind: "VisibilityRestricted" } & VisRestricted;
+
+/** An adapter for `syn::WhereClause`. */
+export type WhereClause = WherePredicate[];
+
+/** An adapter for `syn::WherePredicate`. */
+export type WherePredicate =
+  | { lifetime: PredicateLifetime }
+  | { type: PredicateType }
+  | { kind: "WherePredicateLifetime" } & PredicateLifetime
+  | { kind: "WherePredicateType" } & PredicateType;
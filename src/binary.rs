@@ -0,0 +1,461 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A module to provide functions for a compact binary format <-> Rust
+//! serialize and deserialize.
+//!
+//! The binary format is intended for caching parsed syntax trees: it is
+//! several times smaller than JSON and faster to read. It is not intended as
+//! an exchange format; its layout may change between versions of syn-serde.
+//!
+//! The adapters are written directly with [postcard], which is not
+//! self-describing. Unlike in the other formats, every field of the adapters
+//! is written in order, including those that have default values, and fields
+//! that other formats flatten into their parent (e.g., the signature of a
+//! [`TraitItemFn`]) are written as nested values. The layout also depends on
+//! the `macro-args` feature of syn-serde, which adds the arguments of macros.
+//!
+//! The data starts with the version of the layout and the features that it
+//! depends on, so [`from_slice`] returns an error instead of misreading data
+//! written with another layout.
+//!
+//! [postcard]: https://docs.rs/postcard
+
+use alloc::{
+    string::{String, ToString as _},
+    vec::Vec,
+};
+use core::fmt;
+
+use serde::de::{self, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor};
+
+use super::*;
+use crate::hint::Hint;
+
+/// The version of the layout of the binary format, which is written before
+/// the syntax tree.
+const VERSION: u32 = 1;
+
+/// The bit of [`FEATURES`] that is set if the `macro-args` feature is enabled.
+const MACRO_ARGS: u8 = 1 << 0;
+
+/// The features of syn-serde that change the layout of the binary format, as
+/// a bitmask that is written after [`VERSION`].
+const FEATURES: u8 = if cfg!(feature = "macro-args") { MACRO_ARGS } else { 0 };
+
+/// An error that occurred while deserializing the binary format.
+#[derive(Debug)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    fn new<T: fmt::Display>(message: T) -> Self {
+        Self { message: message.to_string() }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+// -----------------------------------------------------------------------------
+// Serialize
+
+/// Serialize the given [`Syn`] type as a byte vector of the binary format.
+///
+/// # Examples
+///
+/// ```
+/// let syn_file: syn::File = syn::parse_quote! {
+///     fn main() {}
+/// };
+///
+/// let bytes = syn_serde::binary::to_vec(&syn_file);
+/// let file: syn::File = syn_serde::binary::from_slice(&bytes).unwrap();
+/// assert_eq!(file, syn_file);
+/// ```
+// All of the data structures in syn-serde are compatible with postcard when
+// all of their fields are serialized, so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec<S>(syn: &S) -> Vec<u8>
+where
+    S: Syn,
+{
+    let syn = Serializable::new(syn).with_options(SerializeOptions::new().binary(true));
+    postcard::to_allocvec(&(VERSION, FEATURES, syn)).unwrap()
+}
+
+// -----------------------------------------------------------------------------
+// Deserialize
+
+/// Deserialize an instance of [`Syn`] type from bytes of the binary format.
+///
+/// # Errors
+///
+/// Returns an error if the bytes were not written by [`to_vec`] of this
+/// version of syn-serde with the same `macro-args` feature, or if they do not
+/// describe a valid syntax tree.
+pub fn from_slice<S>(v: &[u8]) -> Result<S, Error>
+where
+    S: Syn,
+{
+    let mut deserializer = postcard::Deserializer::from_bytes(v);
+    let version = u32::deserialize(&mut deserializer).map_err(Error::new)?;
+    if version != VERSION {
+        return Err(Error::new(format_args!(
+            "unsupported binary format version {version} (expected {VERSION})"
+        )));
+    }
+    let features = u8::deserialize(&mut deserializer).map_err(Error::new)?;
+    if features & !MACRO_ARGS != 0 {
+        return Err(Error::new(format_args!("unsupported binary format features {features:#04x}")));
+    }
+    if features != FEATURES {
+        let with = |features: u8| if features & MACRO_ARGS == 0 { "without" } else { "with" };
+        return Err(Error::new(format_args!(
+            "the data was written {} the `macro-args` feature, but is read {} it",
+            with(features),
+            with(FEATURES)
+        )));
+    }
+    let adapter = S::Adapter::deserialize(Wrap(&mut deserializer)).map_err(Error::new)?;
+    if !deserializer.finalize().map_err(Error::new)?.is_empty() {
+        return Err(Error::new("trailing bytes after the syntax tree"));
+    }
+    S::try_from_adapter(&adapter).map_err(Error::new)
+}
+
+/// Wraps a deserializer, a visitor, or an access so the hints passed by the
+/// adapters (see hint.rs) are read as the types that `to_vec` writes for them.
+struct Wrap<T>(T);
+
+macro_rules! forward_deserialize {
+    ($($method:ident)*) => {$(
+        fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.0.$method(Wrap(visitor))
+        }
+    )*};
+}
+
+impl<'de, D> Deserializer<'de> for Wrap<D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let visitor = Wrap(visitor);
+        match Hint::from_name(name) {
+            Some(Hint::Enum) => self.0.deserialize_enum(name, &[], visitor),
+            Some(Hint::Str) => self.0.deserialize_str(visitor),
+            Some(Hint::Seq) => self.0.deserialize_seq(visitor),
+            Some(Hint::Pair) => self.0.deserialize_tuple(2, visitor),
+            // The visitor reads as many fields as the struct has.
+            Some(Hint::Struct) => self.0.deserialize_tuple(usize::MAX, visitor),
+            None => self.0.deserialize_newtype_struct(name, visitor),
+        }
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_tuple(len, Wrap(visitor))
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_unit_struct(name, Wrap(visitor))
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_tuple_struct(name, len, Wrap(visitor))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_struct(name, fields, Wrap(visitor))
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_enum(name, variants, Wrap(visitor))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+
+    forward_deserialize! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32
+        deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char
+        deserialize_str deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_option deserialize_unit deserialize_seq deserialize_map
+        deserialize_identifier deserialize_ignored_any
+    }
+}
+
+impl<'de, T> DeserializeSeed<'de> for Wrap<T>
+where
+    T: DeserializeSeed<'de>,
+{
+    type Value = T::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.deserialize(Wrap(deserializer))
+    }
+}
+
+impl<'de, V> Visitor<'de> for Wrap<V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.expecting(f)
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        self.0.visit_bool(v)
+    }
+    fn visit_i8<E: de::Error>(self, v: i8) -> Result<Self::Value, E> {
+        self.0.visit_i8(v)
+    }
+    fn visit_i16<E: de::Error>(self, v: i16) -> Result<Self::Value, E> {
+        self.0.visit_i16(v)
+    }
+    fn visit_i32<E: de::Error>(self, v: i32) -> Result<Self::Value, E> {
+        self.0.visit_i32(v)
+    }
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.0.visit_i64(v)
+    }
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        self.0.visit_i128(v)
+    }
+    fn visit_u8<E: de::Error>(self, v: u8) -> Result<Self::Value, E> {
+        self.0.visit_u8(v)
+    }
+    fn visit_u16<E: de::Error>(self, v: u16) -> Result<Self::Value, E> {
+        self.0.visit_u16(v)
+    }
+    fn visit_u32<E: de::Error>(self, v: u32) -> Result<Self::Value, E> {
+        self.0.visit_u32(v)
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.0.visit_u64(v)
+    }
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        self.0.visit_u128(v)
+    }
+    fn visit_f32<E: de::Error>(self, v: f32) -> Result<Self::Value, E> {
+        self.0.visit_f32(v)
+    }
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        self.0.visit_f64(v)
+    }
+    fn visit_char<E: de::Error>(self, v: char) -> Result<Self::Value, E> {
+        self.0.visit_char(v)
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.0.visit_str(v)
+    }
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        self.0.visit_borrowed_str(v)
+    }
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        self.0.visit_string(v)
+    }
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        self.0.visit_bytes(v)
+    }
+    fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        self.0.visit_borrowed_bytes(v)
+    }
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        self.0.visit_byte_buf(v)
+    }
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.0.visit_none()
+    }
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.0.visit_unit()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.visit_some(Wrap(deserializer))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.visit_newtype_struct(Wrap(deserializer))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.0.visit_seq(Wrap(seq))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.0.visit_map(Wrap(map))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.0.visit_enum(Wrap(data))
+    }
+}
+
+impl<'de, A> SeqAccess<'de> for Wrap<A>
+where
+    A: SeqAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.0.next_element_seed(Wrap(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+impl<'de, A> MapAccess<'de> for Wrap<A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        self.0.next_key_seed(Wrap(seed))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.0.next_value_seed(Wrap(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+impl<'de, A> EnumAccess<'de> for Wrap<A>
+where
+    A: EnumAccess<'de>,
+{
+    type Error = A::Error;
+    type Variant = Wrap<A::Variant>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let (value, variant) = self.0.variant_seed(Wrap(seed))?;
+        Ok((value, Wrap(variant)))
+    }
+}
+
+impl<'de, A> VariantAccess<'de> for Wrap<A>
+where
+    A: VariantAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.0.unit_variant()
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.0.newtype_variant_seed(Wrap(seed))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.tuple_variant(len, Wrap(visitor))
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.struct_variant(fields, Wrap(visitor))
+    }
+}
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A module to provide functions for CBOR <-> Rust serialize and deserialize.
+//!
+//! The data has the same shape as the JSON representation: structs and enum
+//! variants with data are written as maps keyed by field and variant names,
+//! and variants without data as strings.
+
+use alloc::vec::Vec;
+use std::io;
+
+use ciborium::{de, ser};
+use serde::de::Error as _;
+
+use super::*;
+
+// Serialize [`Syn`] type into CBOR data.
+
+/// Serialize the given [`Syn`] type as CBOR into the I/O stream.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn to_writer<W>(writer: W, syn_file: &syn::File) -> Result<(), ciborium::ser::Error<io::Error>>
+/// # where
+/// #     W: io::Write,
+/// # {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file);
+/// ciborium::into_writer(&serializable, writer)
+/// # }
+/// ```
+pub fn to_writer<S, W>(writer: W, syn: &S) -> Result<(), ser::Error<io::Error>>
+where
+    S: Syn,
+    W: io::Write,
+{
+    let serializable = Serializable::new(syn);
+    ciborium::into_writer(&serializable, writer)
+}
+
+/// Serialize the given [`Syn`] type as a CBOR byte vector.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # fn to_vec(syn_file: &syn::File) -> Vec<u8> {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file);
+/// let mut buf = vec![];
+/// ciborium::into_writer(&serializable, &mut buf).unwrap();
+/// buf
+/// # }
+/// ```
+// All of the data structures in syn-serde are compatible with CBOR and writing to a vector
+// cannot fail, so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec<S>(syn: &S) -> Vec<u8>
+where
+    S: Syn,
+{
+    let serializable = Serializable::new(syn);
+    let mut buf = Vec::new();
+    ciborium::into_writer(&serializable, &mut buf).unwrap();
+    buf
+}
+
+/// Serialize the given [`Syn`] type as CBOR into the I/O stream, with the
+/// given options.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn to_writer_with_options<W>(
+/// #     writer: W,
+/// #     syn_file: &syn::File,
+/// #     options: syn_serde::SerializeOptions,
+/// # ) -> Result<(), ciborium::ser::Error<io::Error>>
+/// # where
+/// #     W: io::Write,
+/// # {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file).with_options(options);
+/// ciborium::into_writer(&serializable, writer)
+/// # }
+/// ```
+pub fn to_writer_with_options<S, W>(
+    writer: W,
+    syn: &S,
+    options: SerializeOptions,
+) -> Result<(), ser::Error<io::Error>>
+where
+    S: Syn,
+    W: io::Write,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    ciborium::into_writer(&serializable, writer)
+}
+
+/// Serialize the given [`Syn`] type as a CBOR byte vector, with the given
+/// options.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # fn to_vec_with_options(syn_file: &syn::File, options: syn_serde::SerializeOptions) -> Vec<u8> {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file).with_options(options);
+/// let mut buf = vec![];
+/// ciborium::into_writer(&serializable, &mut buf).unwrap();
+/// buf
+/// # }
+/// ```
+// All of the data structures in syn-serde are compatible with CBOR and writing to a vector
+// cannot fail, so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec_with_options<S>(syn: &S, options: SerializeOptions) -> Vec<u8>
+where
+    S: Syn,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    let mut buf = Vec::new();
+    ciborium::into_writer(&serializable, &mut buf).unwrap();
+    buf
+}
+
+// Deserialize CBOR data to [`Syn`] type.
+
+/// Deserialize an instance of [`Syn`] type from an I/O stream of CBOR.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn from_reader<R: io::Read>(reader: R) -> Result<syn::File, ciborium::de::Error<io::Error>> {
+/// use serde::de::Error as _;
+/// use syn_serde::Syn;
+///
+/// let adapter: <syn::File as Syn>::Adapter = ciborium::from_reader(reader)?;
+/// let syn_file = syn::File::try_from_adapter(&adapter).map_err(ciborium::de::Error::custom)?;
+/// Ok(syn_file)
+/// # }
+/// ```
+pub fn from_reader<S, R>(reader: R) -> Result<S, de::Error<io::Error>>
+where
+    S: Syn,
+    R: io::Read,
+{
+    let adapter: S::Adapter = ciborium::from_reader(reader)?;
+    S::try_from_adapter(&adapter).map_err(de::Error::custom)
+}
+
+/// Deserialize an instance of [`Syn`] type from bytes of CBOR.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn from_slice(v: &[u8]) -> Result<syn::File, ciborium::de::Error<io::Error>> {
+/// use serde::de::Error as _;
+/// use syn_serde::Syn;
+///
+/// let adapter: <syn::File as Syn>::Adapter = ciborium::from_reader(v)?;
+/// let syn_file = syn::File::try_from_adapter(&adapter).map_err(ciborium::de::Error::custom)?;
+/// Ok(syn_file)
+/// # }
+/// ```
+pub fn from_slice<S>(v: &[u8]) -> Result<S, de::Error<io::Error>>
+where
+    S: Syn,
+{
+    from_reader(v)
+}
@@ -38,19 +38,46 @@ pub(crate) fn assert_struct_semi(fields: &Fields, semi_token: bool) {
 
 ast_struct! {
     /// An adapter for [`struct@syn::Field`].
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
     pub struct Field {
+        /// Corresponds to [`syn::Field::attrs`].
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
-        pub(crate) attrs: Vec<Attribute>,
+        pub attrs: Vec<Attribute>,
+        /// Corresponds to [`syn::Field::vis`].
         #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-        pub(crate) vis: Visibility,
+        pub vis: Visibility,
+        /// Corresponds to [`syn::Field::mutability`]. Serialized as `mut`.
         #[serde(rename = "mut")]
         #[serde(default, skip_serializing_if = "FieldMutability::is_none")]
-        pub(crate) mutability: FieldMutability,
+        pub mutability: FieldMutability,
+        /// Corresponds to [`syn::Field::ident`].
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub(crate) ident: Option<Ident>,
+        pub ident: Option<Ident>,
         // TODO: can remove?
+        /// Whether [`syn::Field::colon_token`] is present.
         #[serde(default, skip_serializing_if = "not")]
-        pub(crate) colon_token: bool,
-        pub(crate) ty: Type,
+        pub colon_token: bool,
+        /// Corresponds to [`syn::Field::ty`].
+        pub ty: Type,
+        /// The location of this node, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub span: Option<Location>,
+    }
+}
+
+impl Field {
+    /// Creates an adapter from the fields that are required in the serialized
+    /// form, with the other fields set to their default values.
+    #[must_use]
+    pub fn new(ty: Type) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            mutability: default(),
+            ident: default(),
+            colon_token: default(),
+            ty,
+            span: None,
+        }
     }
 }
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use super::*;
+pub use crate::{
+    ast_enum::Data,
+    ast_struct::{DataEnum, DataUnion, DeriveInput},
+};
+
+ast_struct! {
+    /// An adapter for [`struct@syn::DataStruct`].
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+    #[serde(transparent)]
+    pub struct DataStruct {
+        /// Corresponds to [`syn::DataStruct::fields`].
+        pub fields: Fields,
+        // #[serde(default, skip_serializing_if = "not")]
+        // pub semi_token: bool,
+    }
+}
+
+impl DataStruct {
+    /// Creates an adapter from the fields that are required in the serialized
+    /// form, with the other fields set to their default values.
+    #[must_use]
+    pub fn new(fields: Fields) -> Self {
+        Self { fields }
+    }
+}
+
+mod convert {
+    use super::*;
+
+    // DataStruct
+    syn_trait_impl!(syn::DataStruct);
+    impl From<&syn::DataStruct> for DataStruct {
+        fn from(other: &syn::DataStruct) -> Self {
+            let fields: Fields = other.fields.ref_into();
+            assert_struct_semi(&fields, other.semi_token.is_some());
+
+            Self { fields }
+        }
+    }
+    impl TryFromRef<DataStruct> for syn::DataStruct {
+        fn try_from_ref(other: &DataStruct) -> Result<Self, Error> {
+            Ok(Self {
+                struct_token: default(),
+                fields: other.fields.try_ref_into().context("DataStruct", "fields")?,
+                semi_token: default_or_none(!other.fields.is_named()),
+            })
+        }
+    }
+    impl Serialize for Ser<'_, syn::DataStruct> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            Ser(&self.0.fields, self.1).serialize(serializer)
+        }
+    }
+}
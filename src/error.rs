@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::string::{String, ToString as _};
+use core::fmt;
+
+/// An error that occurred while converting an adapter into a [`Syn`] type.
+///
+/// This is returned by [`Syn::try_from_adapter`] when the adapter does not
+/// describe a valid syntax tree, e.g., an identifier that is not a valid Rust
+/// identifier or a literal that cannot be lexed.
+///
+/// [`Syn`]: crate::Syn
+/// [`Syn::try_from_adapter`]: crate::Syn::try_from_adapter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    node: &'static str,
+    field: Option<&'static str>,
+    message: String,
+}
+
+impl Error {
+    pub(crate) fn new<T>(node: &'static str, message: T) -> Self
+    where
+        T: fmt::Display,
+    {
+        Self { node, field: None, message: message.to_string() }
+    }
+
+    /// Returns the name of the innermost node that failed to convert, e.g.,
+    /// `"Signature"`.
+    #[must_use]
+    pub fn node(&self) -> &str {
+        self.node
+    }
+
+    /// Returns the name of the field of [`node`](Self::node) that failed to
+    /// convert, e.g., `"ident"`. For enums, this is the name of the variant.
+    ///
+    /// Returns `None` if the node itself is invalid.
+    #[must_use]
+    pub fn field(&self) -> Option<&str> {
+        self.field
+    }
+
+    /// Returns the reason why the conversion failed.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.field {
+            Some(field) => write!(f, "{}.{}: {}", self.node, field, self.message),
+            None => write!(f, "{}: {}", self.node, self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub(crate) trait ResultExt {
+    /// Records the field that was being converted, unless the error already
+    /// knows which field of a more deeply nested node failed.
+    fn context(self, node: &'static str, field: &'static str) -> Self;
+}
+
+impl<T> ResultExt for Result<T, Error> {
+    fn context(self, node: &'static str, field: &'static str) -> Self {
+        self.map_err(|mut e| {
+            if e.field.is_none() {
+                e.node = node;
+                e.field = Some(field);
+            }
+            e
+        })
+    }
+}
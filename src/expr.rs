@@ -4,28 +4,45 @@ use alloc::{boxed::Box, vec::Vec};
 
 use super::*;
 pub use crate::{
-    ast_enum::{Expr, Member, RangeLimits},
+    ast_enum::{Expr, Member, PointerMutability, RangeLimits},
     ast_struct::{
         ExprArray, ExprAssign, ExprAsync, ExprAwait, ExprBinary, ExprBlock, ExprBreak, ExprCall,
         ExprCast, ExprClosure, ExprConst, ExprContinue, ExprField, ExprForLoop, ExprGroup, ExprIf,
         ExprIndex, ExprInfer, ExprLet, ExprLit, ExprLoop, ExprMacro, ExprMatch, ExprMethodCall,
-        ExprParen, ExprPath, ExprRange, ExprReference, ExprRepeat, ExprReturn, ExprStruct, ExprTry,
-        ExprTryBlock, ExprTuple, ExprUnary, ExprUnsafe, ExprWhile, ExprYield, FieldValue, Index,
-        Label,
+        ExprParen, ExprPath, ExprRange, ExprRawAddr, ExprReference, ExprRepeat, ExprReturn,
+        ExprStruct, ExprTry, ExprTryBlock, ExprTuple, ExprUnary, ExprUnsafe, ExprWhile, ExprYield,
+        FieldValue, Index, Label,
     },
 };
 
 ast_struct! {
     /// An adapter for [`struct@syn::Arm`].
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
     pub struct Arm {
+        /// Corresponds to [`syn::Arm::attrs`].
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
-        pub(crate) attrs: Vec<Attribute>,
-        pub(crate) pat: Pat,
+        pub attrs: Vec<Attribute>,
+        /// Corresponds to [`syn::Arm::pat`].
+        pub pat: Pat,
+        /// Corresponds to [`syn::Arm::guard`].
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub(crate) guard: Option<Box<Expr>>,
-        pub(crate) body: Box<Expr>,
+        pub guard: Option<Box<Expr>>,
+        /// Corresponds to [`syn::Arm::body`].
+        pub body: Box<Expr>,
         // #[serde(default, skip_serializing_if = "not")]
-        // pub(crate) comma: bool,
+        // pub comma: bool,
+        /// The location of this node, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub span: Option<Location>,
+    }
+}
+
+impl Arm {
+    /// Creates an adapter from the fields that are required in the serialized
+    /// form, with the other fields set to their default values.
+    #[must_use]
+    pub fn new(pat: Pat, body: Box<Expr>) -> Self {
+        Self { attrs: default(), pat, guard: default(), body, span: None }
     }
 }
 
@@ -62,6 +79,7 @@ pub(crate) fn requires_terminator(expr: &Expr) -> bool {
         | Expr::Paren(_)
         | Expr::Path(_)
         | Expr::Range(_)
+        | Expr::RawAddr(_)
         | Expr::Reference(_)
         | Expr::Repeat(_)
         | Expr::Return(_)
@@ -95,6 +113,7 @@ mod convert {
                     pat: other.pat.ref_into(),
                     guard: other.guard.ref_map(|(_, x)| x.map_into()),
                     body,
+                    span: None,
                 }
             })
             .collect()
@@ -105,18 +124,19 @@ mod convert {
                 attrs: other.attrs.map_into(),
                 expr: other.expr.map_into(),
                 arms: from_syn_arms(&other.arms),
+                span: None,
             }
         }
     }
-    impl From<&ExprMatch> for syn::ExprMatch {
-        fn from(other: &ExprMatch) -> Self {
-            Self {
-                attrs: other.attrs.map_into(),
+    impl TryFromRef<ExprMatch> for syn::ExprMatch {
+        fn try_from_ref(other: &ExprMatch) -> Result<Self, Error> {
+            Ok(Self {
+                attrs: other.attrs.try_map_into().context("ExprMatch", "attrs")?,
                 match_token: default(),
-                expr: other.expr.map_into(),
+                expr: other.expr.try_map_into().context("ExprMatch", "expr")?,
                 brace_token: default(),
-                arms: other.arms.map_into(),
-            }
+                arms: other.arms.try_map_into().context("ExprMatch", "arms")?,
+            })
         }
     }
 
@@ -134,19 +154,61 @@ mod convert {
                 pat: other.pat.ref_into(),
                 guard: other.guard.ref_map(|(_, x)| x.map_into()),
                 body,
+                span: None,
             }
         }
     }
-    impl From<&Arm> for syn::Arm {
-        fn from(other: &Arm) -> Self {
-            Self {
-                attrs: other.attrs.map_into(),
-                pat: other.pat.ref_into(),
-                guard: other.guard.ref_map(|x| (default(), x.map_into())),
+    impl TryFromRef<Arm> for syn::Arm {
+        fn try_from_ref(other: &Arm) -> Result<Self, Error> {
+            Ok(Self {
+                attrs: other.attrs.try_map_into().context("Arm", "attrs")?,
+                pat: other.pat.try_ref_into().context("Arm", "pat")?,
+                guard: other
+                    .guard
+                    .try_ref_map(|x| Ok((default(), x.try_map_into()?)))
+                    .context("Arm", "guard")?,
                 fat_arrow_token: default(),
-                body: other.body.map_into(),
+                body: other.body.try_map_into().context("Arm", "body")?,
                 comma: default_or_none(requires_terminator(&other.body)),
+            })
+        }
+    }
+    impl Serialize for Ser<'_, syn::Arm> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let node = self.0;
+            let explicit = self.1.has_explicit_fields();
+            let span = Location::of(node, self.1);
+            let keep_span = self.1.is_binary() || span.is_some();
+            let mut state = serializer.serialize_struct(
+                "Arm",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + 1
+                    + usize::from(explicit || node.guard.is_some())
+                    + 1
+                    + usize::from(keep_span),
+            )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            state.serialize_field("pat", &Ser(&node.pat, self.1))?;
+            if explicit || node.guard.is_some() {
+                let guard = node.guard.as_ref().map(|(_, guard)| Ser(guard, self.1));
+                state.serialize_field("guard", &guard)?;
+            } else {
+                state.skip_field("guard")?;
+            }
+            state.serialize_field("body", &Ser(&node.body, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
             }
+            state.end()
         }
     }
 }
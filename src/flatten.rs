@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// The deserialization of adapters with flattened fields (see
+// `ast_struct::flatten` in codegen).
+//
+// serde deserializes flattened fields by buffering the map they are read from,
+// which requires a self-describing format. The binary format writes these
+// fields as nested values instead, so these adapters are deserialized from
+// either a map, with the fields flattened, or a sequence of all of their
+// fields in order.
+
+use core::{fmt, marker::PhantomData};
+
+use serde::de::{
+    MapAccess, SeqAccess, Visitor,
+    value::{MapAccessDeserializer, SeqAccessDeserializer},
+};
+
+use super::*;
+
+/// An adapter struct with flattened fields.
+pub(crate) trait Flattened: Sized {
+    /// Deserializes the adapter from a map, in which the fields of the
+    /// flattened fields are written in place of them.
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+
+    /// Deserializes the adapter from a sequence of all of its fields in order.
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// Deserializes `T` from either a map or, in the binary format, a sequence of
+/// its fields.
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Flattened,
+    D: Deserializer<'de>,
+{
+    hint::deserialize(deserializer, hint::Hint::Struct, FlattenedVisitor(PhantomData))
+}
+
+struct FlattenedVisitor<T>(PhantomData<fn() -> T>);
+
+impl<'de, T> Visitor<'de> for FlattenedVisitor<T>
+where
+    T: Flattened,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map")
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        T::deserialize_map(MapAccessDeserializer::new(map))
+    }
+
+    // The binary format, which writes every field in order.
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        T::deserialize_seq(SeqAccessDeserializer::new(seq))
+    }
+}
@@ -4,9 +4,11 @@
 // It is not intended for manual editing.
 
 #![cfg_attr(rustfmt, rustfmt::skip)]
+#![allow(clippy::large_enum_variant)]
 use crate::*;
 /// An adapter for [`enum@syn::AttrStyle`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum AttrStyle {
     Outer,
@@ -14,6 +16,7 @@ pub enum AttrStyle {
 }
 /// An adapter for [`enum@syn::BinOp`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum BinOp {
@@ -74,8 +77,85 @@ pub enum BinOp {
     #[serde(rename = ">>=")]
     ShrAssign,
 }
+/// An adapter for [`enum@syn::CapturedParam`].
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CapturedParam {
+    Lifetime(Lifetime),
+    Ident(Ident),
+}
+impl<'de> Deserialize<'de> for CapturedParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for CapturedParam {
+    const VARIANTS: &'static [&'static str] = &["lifetime", "ident"];
+    const KINDS: &'static [&'static str] = &[
+        "CapturedParamLifetime",
+        "CapturedParamIdent",
+    ];
+    const INLINE: &'static [bool] = &[false, false];
+    const AMBIGUOUS: &'static [bool] = &[false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => Lifetime::deserialize(deserializer).map(Self::Lifetime),
+            1 => Ident::deserialize(deserializer).map(Self::Ident),
+            _ => unreachable!(),
+        }
+    }
+}
+/// An adapter for [`enum@syn::Data`].
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[serde(rename_all = "snake_case")]
+pub enum Data {
+    Struct(DataStruct),
+    Enum(DataEnum),
+    Union(DataUnion),
+}
+impl<'de> Deserialize<'de> for Data {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for Data {
+    const VARIANTS: &'static [&'static str] = &["struct", "enum", "union"];
+    const KINDS: &'static [&'static str] = &["DataStruct", "DataEnum", "DataUnion"];
+    const INLINE: &'static [bool] = &[false, false, false];
+    const AMBIGUOUS: &'static [bool] = &[false, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => DataStruct::deserialize(deserializer).map(Self::Struct),
+            1 => DataEnum::deserialize(deserializer).map(Self::Enum),
+            2 => DataUnion::deserialize(deserializer).map(Self::Union),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::Expr`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Expr {
@@ -106,6 +186,7 @@ pub enum Expr {
     Paren(ExprParen),
     Path(ExprPath),
     Range(ExprRange),
+    RawAddr(ExprRawAddr),
     Reference(ExprReference),
     Repeat(ExprRepeat),
     Return(ExprReturn),
@@ -119,30 +200,250 @@ pub enum Expr {
     While(ExprWhile),
     Yield(ExprYield),
 }
+impl<'de> Deserialize<'de> for Expr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for Expr {
+    const VARIANTS: &'static [&'static str] = &[
+        "array",
+        "assign",
+        "async",
+        "await",
+        "binary",
+        "block",
+        "break",
+        "call",
+        "cast",
+        "closure",
+        "const",
+        "continue",
+        "field",
+        "for_loop",
+        "group",
+        "if",
+        "index",
+        "infer",
+        "let",
+        "lit",
+        "loop",
+        "macro",
+        "match",
+        "method_call",
+        "paren",
+        "path",
+        "range",
+        "raw_addr",
+        "reference",
+        "repeat",
+        "return",
+        "struct",
+        "try",
+        "try_block",
+        "tuple",
+        "unary",
+        "unsafe",
+        "verbatim",
+        "while",
+        "yield",
+    ];
+    const KINDS: &'static [&'static str] = &[
+        "ExprArray",
+        "ExprAssign",
+        "ExprAsync",
+        "ExprAwait",
+        "ExprBinary",
+        "ExprBlock",
+        "ExprBreak",
+        "ExprCall",
+        "ExprCast",
+        "ExprClosure",
+        "ExprConst",
+        "ExprContinue",
+        "ExprField",
+        "ExprForLoop",
+        "ExprGroup",
+        "ExprIf",
+        "ExprIndex",
+        "ExprInfer",
+        "ExprLet",
+        "ExprLit",
+        "ExprLoop",
+        "ExprMacro",
+        "ExprMatch",
+        "ExprMethodCall",
+        "ExprParen",
+        "ExprPath",
+        "ExprRange",
+        "ExprRawAddr",
+        "ExprReference",
+        "ExprRepeat",
+        "ExprReturn",
+        "ExprStruct",
+        "ExprTry",
+        "ExprTryBlock",
+        "ExprTuple",
+        "ExprUnary",
+        "ExprUnsafe",
+        "ExprVerbatim",
+        "ExprWhile",
+        "ExprYield",
+    ];
+    const INLINE: &'static [bool] = &[
+        true, true, true, true, true, true, true, true, true, true, true, true, true,
+        true, true, true, true, true, true, true, true, true, true, true, true, true,
+        true, true, true, true, true, true, true, true, true, true, true, false, true,
+        true,
+    ];
+    const AMBIGUOUS: &'static [bool] = &[
+        false, false, true, false, false, false, false, false, false, false, true, false,
+        false, false, false, false, true, false, false, false, false, false, false,
+        false, false, true, false, false, false, false, false, false, false, false,
+        false, false, false, true, false, false,
+    ];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => ExprArray::deserialize(deserializer).map(Self::Array),
+            1 => ExprAssign::deserialize(deserializer).map(Self::Assign),
+            2 => ExprAsync::deserialize(deserializer).map(Self::Async),
+            3 => ExprAwait::deserialize(deserializer).map(Self::Await),
+            4 => ExprBinary::deserialize(deserializer).map(Self::Binary),
+            5 => ExprBlock::deserialize(deserializer).map(Self::Block),
+            6 => ExprBreak::deserialize(deserializer).map(Self::Break),
+            7 => ExprCall::deserialize(deserializer).map(Self::Call),
+            8 => ExprCast::deserialize(deserializer).map(Self::Cast),
+            9 => ExprClosure::deserialize(deserializer).map(Self::Closure),
+            10 => ExprConst::deserialize(deserializer).map(Self::Const),
+            11 => ExprContinue::deserialize(deserializer).map(Self::Continue),
+            12 => ExprField::deserialize(deserializer).map(Self::Field),
+            13 => ExprForLoop::deserialize(deserializer).map(Self::ForLoop),
+            14 => ExprGroup::deserialize(deserializer).map(Self::Group),
+            15 => ExprIf::deserialize(deserializer).map(Self::If),
+            16 => ExprIndex::deserialize(deserializer).map(Self::Index),
+            17 => ExprInfer::deserialize(deserializer).map(Self::Infer),
+            18 => ExprLet::deserialize(deserializer).map(Self::Let),
+            19 => ExprLit::deserialize(deserializer).map(Self::Lit),
+            20 => ExprLoop::deserialize(deserializer).map(Self::Loop),
+            21 => ExprMacro::deserialize(deserializer).map(Self::Macro),
+            22 => ExprMatch::deserialize(deserializer).map(Self::Match),
+            23 => ExprMethodCall::deserialize(deserializer).map(Self::MethodCall),
+            24 => ExprParen::deserialize(deserializer).map(Self::Paren),
+            25 => ExprPath::deserialize(deserializer).map(Self::Path),
+            26 => ExprRange::deserialize(deserializer).map(Self::Range),
+            27 => ExprRawAddr::deserialize(deserializer).map(Self::RawAddr),
+            28 => ExprReference::deserialize(deserializer).map(Self::Reference),
+            29 => ExprRepeat::deserialize(deserializer).map(Self::Repeat),
+            30 => ExprReturn::deserialize(deserializer).map(Self::Return),
+            31 => ExprStruct::deserialize(deserializer).map(Self::Struct),
+            32 => ExprTry::deserialize(deserializer).map(Self::Try),
+            33 => ExprTryBlock::deserialize(deserializer).map(Self::TryBlock),
+            34 => ExprTuple::deserialize(deserializer).map(Self::Tuple),
+            35 => ExprUnary::deserialize(deserializer).map(Self::Unary),
+            36 => ExprUnsafe::deserialize(deserializer).map(Self::Unsafe),
+            37 => TokenStream::deserialize(deserializer).map(Self::Verbatim),
+            38 => ExprWhile::deserialize(deserializer).map(Self::While),
+            39 => ExprYield::deserialize(deserializer).map(Self::Yield),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::FieldMutability`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum FieldMutability {
     None,
 }
 /// An adapter for [`enum@syn::Fields`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum Fields {
     Named(FieldsNamed),
     Unnamed(FieldsUnnamed),
     Unit,
 }
+impl<'de> Deserialize<'de> for Fields {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for Fields {
+    const VARIANTS: &'static [&'static str] = &["named", "unnamed", "unit"];
+    const KINDS: &'static [&'static str] = &[
+        "FieldsNamed",
+        "FieldsUnnamed",
+        "FieldsUnit",
+    ];
+    const INLINE: &'static [bool] = &[false, false, false];
+    const AMBIGUOUS: &'static [bool] = &[false, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => FieldsNamed::deserialize(deserializer).map(Self::Named),
+            1 => FieldsUnnamed::deserialize(deserializer).map(Self::Unnamed),
+            2 => <()>::deserialize(deserializer).map(|()| Self::Unit),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::FnArg`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum FnArg {
     Receiver(Receiver),
     Typed(PatType),
 }
+impl<'de> Deserialize<'de> for FnArg {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for FnArg {
+    const VARIANTS: &'static [&'static str] = &["receiver", "typed"];
+    const KINDS: &'static [&'static str] = &["FnArgReceiver", "FnArgTyped"];
+    const INLINE: &'static [bool] = &[true, true];
+    const AMBIGUOUS: &'static [bool] = &[false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => Receiver::deserialize(deserializer).map(Self::Receiver),
+            1 => PatType::deserialize(deserializer).map(Self::Typed),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::ForeignItem`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum ForeignItem {
@@ -152,8 +453,51 @@ pub enum ForeignItem {
     Macro(ForeignItemMacro),
     Verbatim(TokenStream),
 }
+impl<'de> Deserialize<'de> for ForeignItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for ForeignItem {
+    const VARIANTS: &'static [&'static str] = &[
+        "fn",
+        "static",
+        "type",
+        "macro",
+        "verbatim",
+    ];
+    const KINDS: &'static [&'static str] = &[
+        "ForeignItemFn",
+        "ForeignItemStatic",
+        "ForeignItemType",
+        "ForeignItemMacro",
+        "ForeignItemVerbatim",
+    ];
+    const INLINE: &'static [bool] = &[true, true, true, true, false];
+    const AMBIGUOUS: &'static [bool] = &[false, false, false, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => ForeignItemFn::deserialize(deserializer).map(Self::Fn),
+            1 => ForeignItemStatic::deserialize(deserializer).map(Self::Static),
+            2 => ForeignItemType::deserialize(deserializer).map(Self::Type),
+            3 => ForeignItemMacro::deserialize(deserializer).map(Self::Macro),
+            4 => TokenStream::deserialize(deserializer).map(Self::Verbatim),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::GenericArgument`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum GenericArgument {
@@ -164,16 +508,95 @@ pub enum GenericArgument {
     AssocConst(AssocConst),
     Constraint(Constraint),
 }
+impl<'de> Deserialize<'de> for GenericArgument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for GenericArgument {
+    const VARIANTS: &'static [&'static str] = &[
+        "lifetime",
+        "type",
+        "const",
+        "assoc_type",
+        "assoc_const",
+        "constraint",
+    ];
+    const KINDS: &'static [&'static str] = &[
+        "GenericArgumentLifetime",
+        "GenericArgumentType",
+        "GenericArgumentConst",
+        "GenericArgumentAssocType",
+        "GenericArgumentAssocConst",
+        "GenericArgumentConstraint",
+    ];
+    const INLINE: &'static [bool] = &[false, false, false, true, true, true];
+    const AMBIGUOUS: &'static [bool] = &[false, false, false, false, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => Lifetime::deserialize(deserializer).map(Self::Lifetime),
+            1 => Type::deserialize(deserializer).map(Self::Type),
+            2 => Expr::deserialize(deserializer).map(Self::Const),
+            3 => AssocType::deserialize(deserializer).map(Self::AssocType),
+            4 => AssocConst::deserialize(deserializer).map(Self::AssocConst),
+            5 => Constraint::deserialize(deserializer).map(Self::Constraint),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::GenericParam`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum GenericParam {
     Lifetime(LifetimeParam),
     Type(TypeParam),
     Const(ConstParam),
 }
+impl<'de> Deserialize<'de> for GenericParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for GenericParam {
+    const VARIANTS: &'static [&'static str] = &["lifetime", "type", "const"];
+    const KINDS: &'static [&'static str] = &[
+        "GenericParamLifetime",
+        "GenericParamType",
+        "GenericParamConst",
+    ];
+    const INLINE: &'static [bool] = &[true, true, true];
+    const AMBIGUOUS: &'static [bool] = &[true, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => LifetimeParam::deserialize(deserializer).map(Self::Lifetime),
+            1 => TypeParam::deserialize(deserializer).map(Self::Type),
+            2 => ConstParam::deserialize(deserializer).map(Self::Const),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::ImplItem`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum ImplItem {
@@ -183,13 +606,57 @@ pub enum ImplItem {
     Macro(ImplItemMacro),
     Verbatim(TokenStream),
 }
+impl<'de> Deserialize<'de> for ImplItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for ImplItem {
+    const VARIANTS: &'static [&'static str] = &[
+        "const",
+        "fn",
+        "type",
+        "macro",
+        "verbatim",
+    ];
+    const KINDS: &'static [&'static str] = &[
+        "ImplItemConst",
+        "ImplItemFn",
+        "ImplItemType",
+        "ImplItemMacro",
+        "ImplItemVerbatim",
+    ];
+    const INLINE: &'static [bool] = &[true, true, true, true, false];
+    const AMBIGUOUS: &'static [bool] = &[true, false, false, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => ImplItemConst::deserialize(deserializer).map(Self::Const),
+            1 => ImplItemFn::deserialize(deserializer).map(Self::Fn),
+            2 => ImplItemType::deserialize(deserializer).map(Self::Type),
+            3 => ImplItemMacro::deserialize(deserializer).map(Self::Macro),
+            4 => TokenStream::deserialize(deserializer).map(Self::Verbatim),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::ImplRestriction`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum ImplRestriction {}
 /// An adapter for [`enum@syn::Item`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Item {
@@ -210,13 +677,96 @@ pub enum Item {
     Use(ItemUse),
     Verbatim(TokenStream),
 }
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for Item {
+    const VARIANTS: &'static [&'static str] = &[
+        "const",
+        "enum",
+        "extern_crate",
+        "fn",
+        "foreign_mod",
+        "impl",
+        "macro",
+        "mod",
+        "static",
+        "struct",
+        "trait",
+        "trait_alias",
+        "type",
+        "union",
+        "use",
+        "verbatim",
+    ];
+    const KINDS: &'static [&'static str] = &[
+        "ItemConst",
+        "ItemEnum",
+        "ItemExternCrate",
+        "ItemFn",
+        "ItemForeignMod",
+        "ItemImpl",
+        "ItemMacro",
+        "ItemMod",
+        "ItemStatic",
+        "ItemStruct",
+        "ItemTrait",
+        "ItemTraitAlias",
+        "ItemType",
+        "ItemUnion",
+        "ItemUse",
+        "ItemVerbatim",
+    ];
+    const INLINE: &'static [bool] = &[
+        true, true, true, true, true, true, true, true, true, true, true, true, true,
+        true, true, false,
+    ];
+    const AMBIGUOUS: &'static [bool] = &[
+        true, false, false, false, false, false, false, false, false, false, true, false,
+        false, false, false, false,
+    ];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => ItemConst::deserialize(deserializer).map(Self::Const),
+            1 => ItemEnum::deserialize(deserializer).map(Self::Enum),
+            2 => ItemExternCrate::deserialize(deserializer).map(Self::ExternCrate),
+            3 => ItemFn::deserialize(deserializer).map(Self::Fn),
+            4 => ItemForeignMod::deserialize(deserializer).map(Self::ForeignMod),
+            5 => ItemImpl::deserialize(deserializer).map(Self::Impl),
+            6 => ItemMacro::deserialize(deserializer).map(Self::Macro),
+            7 => ItemMod::deserialize(deserializer).map(Self::Mod),
+            8 => ItemStatic::deserialize(deserializer).map(Self::Static),
+            9 => ItemStruct::deserialize(deserializer).map(Self::Struct),
+            10 => ItemTrait::deserialize(deserializer).map(Self::Trait),
+            11 => ItemTraitAlias::deserialize(deserializer).map(Self::TraitAlias),
+            12 => ItemType::deserialize(deserializer).map(Self::Type),
+            13 => ItemUnion::deserialize(deserializer).map(Self::Union),
+            14 => ItemUse::deserialize(deserializer).map(Self::Use),
+            15 => TokenStream::deserialize(deserializer).map(Self::Verbatim),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::Lit`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Lit {
     Str(LitStr),
     ByteStr(LitByteStr),
+    CStr(LitCStr),
     Byte(LitByte),
     Char(LitChar),
     Int(LitInt),
@@ -226,6 +776,7 @@ pub enum Lit {
 }
 /// An adapter for [`enum@syn::MacroDelimiter`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum MacroDelimiter {
     Paren,
@@ -234,6 +785,7 @@ pub enum MacroDelimiter {
 }
 /// An adapter for [`enum@syn::Member`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum Member {
     #[serde(rename = "ident")]
@@ -242,15 +794,45 @@ pub enum Member {
     Unnamed(Index),
 }
 /// An adapter for [`enum@syn::Meta`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum Meta {
     Path(Path),
     List(MetaList),
     NameValue(MetaNameValue),
 }
+impl<'de> Deserialize<'de> for Meta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for Meta {
+    const VARIANTS: &'static [&'static str] = &["path", "list", "name_value"];
+    const KINDS: &'static [&'static str] = &["MetaPath", "MetaList", "MetaNameValue"];
+    const INLINE: &'static [bool] = &[true, true, true];
+    const AMBIGUOUS: &'static [bool] = &[true, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => Path::deserialize(deserializer).map(Self::Path),
+            1 => MetaList::deserialize(deserializer).map(Self::List),
+            2 => MetaNameValue::deserialize(deserializer).map(Self::NameValue),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::Pat`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Pat {
@@ -273,16 +855,152 @@ pub enum Pat {
     #[serde(rename = "_")]
     Wild(PatWild),
 }
+impl<'de> Deserialize<'de> for Pat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for Pat {
+    const VARIANTS: &'static [&'static str] = &[
+        "const",
+        "ident",
+        "lit",
+        "macro",
+        "or",
+        "paren",
+        "path",
+        "range",
+        "reference",
+        "rest",
+        "slice",
+        "struct",
+        "tuple",
+        "tuple_struct",
+        "type",
+        "verbatim",
+        "_",
+    ];
+    const KINDS: &'static [&'static str] = &[
+        "PatConst",
+        "PatIdent",
+        "PatLit",
+        "PatMacro",
+        "PatOr",
+        "PatParen",
+        "PatPath",
+        "PatRange",
+        "PatReference",
+        "PatRest",
+        "PatSlice",
+        "PatStruct",
+        "PatTuple",
+        "PatTupleStruct",
+        "PatType",
+        "PatVerbatim",
+        "PatWild",
+    ];
+    const INLINE: &'static [bool] = &[
+        true, true, true, true, true, true, true, true, true, true, true, true, true,
+        true, true, false, true,
+    ];
+    const AMBIGUOUS: &'static [bool] = &[
+        false, true, false, false, false, false, true, false, false, true, false, false,
+        false, false, false, true, false,
+    ];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => ExprConst::deserialize(deserializer).map(Self::Const),
+            1 => PatIdent::deserialize(deserializer).map(Self::Ident),
+            2 => ExprLit::deserialize(deserializer).map(Self::Lit),
+            3 => ExprMacro::deserialize(deserializer).map(Self::Macro),
+            4 => PatOr::deserialize(deserializer).map(Self::Or),
+            5 => PatParen::deserialize(deserializer).map(Self::Paren),
+            6 => ExprPath::deserialize(deserializer).map(Self::Path),
+            7 => ExprRange::deserialize(deserializer).map(Self::Range),
+            8 => PatReference::deserialize(deserializer).map(Self::Reference),
+            9 => PatRest::deserialize(deserializer).map(Self::Rest),
+            10 => PatSlice::deserialize(deserializer).map(Self::Slice),
+            11 => PatStruct::deserialize(deserializer).map(Self::Struct),
+            12 => PatTuple::deserialize(deserializer).map(Self::Tuple),
+            13 => PatTupleStruct::deserialize(deserializer).map(Self::TupleStruct),
+            14 => PatType::deserialize(deserializer).map(Self::Type),
+            15 => TokenStream::deserialize(deserializer).map(Self::Verbatim),
+            16 => PatWild::deserialize(deserializer).map(Self::Wild),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::PathArguments`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum PathArguments {
     None,
     AngleBracketed(AngleBracketedGenericArguments),
     Parenthesized(ParenthesizedGenericArguments),
 }
+impl<'de> Deserialize<'de> for PathArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for PathArguments {
+    const VARIANTS: &'static [&'static str] = &[
+        "none",
+        "angle_bracketed",
+        "parenthesized",
+    ];
+    const KINDS: &'static [&'static str] = &[
+        "PathArgumentsNone",
+        "PathArgumentsAngleBracketed",
+        "PathArgumentsParenthesized",
+    ];
+    const INLINE: &'static [bool] = &[false, true, true];
+    const AMBIGUOUS: &'static [bool] = &[false, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => <()>::deserialize(deserializer).map(|()| Self::None),
+            1 => {
+                AngleBracketedGenericArguments::deserialize(deserializer)
+                    .map(Self::AngleBracketed)
+            }
+            2 => {
+                ParenthesizedGenericArguments::deserialize(deserializer)
+                    .map(Self::Parenthesized)
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+/// An adapter for [`enum@syn::PointerMutability`].
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[serde(rename_all = "snake_case")]
+pub enum PointerMutability {
+    Const,
+    Mut,
+}
 /// An adapter for [`enum@syn::RangeLimits`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum RangeLimits {
     #[serde(rename = "..")]
@@ -292,6 +1010,7 @@ pub enum RangeLimits {
 }
 /// An adapter for [`enum@syn::StaticMutability`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum StaticMutability {
@@ -301,13 +1020,15 @@ pub enum StaticMutability {
 }
 /// An adapter for [`enum@syn::TraitBoundModifier`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum TraitBoundModifier {
     None,
     Maybe,
 }
 /// An adapter for [`enum@syn::TraitItem`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum TraitItem {
@@ -317,8 +1038,51 @@ pub enum TraitItem {
     Macro(TraitItemMacro),
     Verbatim(TokenStream),
 }
+impl<'de> Deserialize<'de> for TraitItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for TraitItem {
+    const VARIANTS: &'static [&'static str] = &[
+        "const",
+        "fn",
+        "type",
+        "macro",
+        "verbatim",
+    ];
+    const KINDS: &'static [&'static str] = &[
+        "TraitItemConst",
+        "TraitItemFn",
+        "TraitItemType",
+        "TraitItemMacro",
+        "TraitItemVerbatim",
+    ];
+    const INLINE: &'static [bool] = &[true, true, true, true, false];
+    const AMBIGUOUS: &'static [bool] = &[true, false, false, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => TraitItemConst::deserialize(deserializer).map(Self::Const),
+            1 => TraitItemFn::deserialize(deserializer).map(Self::Fn),
+            2 => TraitItemType::deserialize(deserializer).map(Self::Type),
+            3 => TraitItemMacro::deserialize(deserializer).map(Self::Macro),
+            4 => TokenStream::deserialize(deserializer).map(Self::Verbatim),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::Type`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Type {
@@ -340,17 +1104,137 @@ pub enum Type {
     Tuple(TypeTuple),
     Verbatim(TokenStream),
 }
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for Type {
+    const VARIANTS: &'static [&'static str] = &[
+        "array",
+        "bare_fn",
+        "group",
+        "impl_trait",
+        "_",
+        "macro",
+        "!",
+        "paren",
+        "path",
+        "ptr",
+        "reference",
+        "slice",
+        "trait_object",
+        "tuple",
+        "verbatim",
+    ];
+    const KINDS: &'static [&'static str] = &[
+        "TypeArray",
+        "TypeBareFn",
+        "TypeGroup",
+        "TypeImplTrait",
+        "TypeInfer",
+        "TypeMacro",
+        "TypeNever",
+        "TypeParen",
+        "TypePath",
+        "TypePtr",
+        "TypeReference",
+        "TypeSlice",
+        "TypeTraitObject",
+        "TypeTuple",
+        "TypeVerbatim",
+    ];
+    const INLINE: &'static [bool] = &[
+        true, true, true, true, false, true, false, true, true, true, true, true, true,
+        true, false,
+    ];
+    const AMBIGUOUS: &'static [bool] = &[
+        false, false, false, false, false, false, false, false, true, false, false,
+        false, false, false, false,
+    ];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => TypeArray::deserialize(deserializer).map(Self::Array),
+            1 => TypeBareFn::deserialize(deserializer).map(Self::BareFn),
+            2 => TypeGroup::deserialize(deserializer).map(Self::Group),
+            3 => TypeImplTrait::deserialize(deserializer).map(Self::ImplTrait),
+            4 => <()>::deserialize(deserializer).map(|()| Self::Infer),
+            5 => TypeMacro::deserialize(deserializer).map(Self::Macro),
+            6 => <()>::deserialize(deserializer).map(|()| Self::Never),
+            7 => TypeParen::deserialize(deserializer).map(Self::Paren),
+            8 => TypePath::deserialize(deserializer).map(Self::Path),
+            9 => TypePtr::deserialize(deserializer).map(Self::Ptr),
+            10 => TypeReference::deserialize(deserializer).map(Self::Reference),
+            11 => TypeSlice::deserialize(deserializer).map(Self::Slice),
+            12 => TypeTraitObject::deserialize(deserializer).map(Self::TraitObject),
+            13 => TypeTuple::deserialize(deserializer).map(Self::Tuple),
+            14 => TokenStream::deserialize(deserializer).map(Self::Verbatim),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::TypeParamBound`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum TypeParamBound {
     Trait(TraitBound),
     Lifetime(Lifetime),
+    PreciseCapture(PreciseCapture),
     Verbatim(TokenStream),
 }
+impl<'de> Deserialize<'de> for TypeParamBound {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for TypeParamBound {
+    const VARIANTS: &'static [&'static str] = &[
+        "trait",
+        "lifetime",
+        "precise_capture",
+        "verbatim",
+    ];
+    const KINDS: &'static [&'static str] = &[
+        "TypeParamBoundTrait",
+        "TypeParamBoundLifetime",
+        "TypeParamBoundPreciseCapture",
+        "TypeParamBoundVerbatim",
+    ];
+    const INLINE: &'static [bool] = &[true, false, false, false];
+    const AMBIGUOUS: &'static [bool] = &[false, false, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => TraitBound::deserialize(deserializer).map(Self::Trait),
+            1 => Lifetime::deserialize(deserializer).map(Self::Lifetime),
+            2 => PreciseCapture::deserialize(deserializer).map(Self::PreciseCapture),
+            3 => TokenStream::deserialize(deserializer).map(Self::Verbatim),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::UnOp`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum UnOp {
@@ -362,7 +1246,8 @@ pub enum UnOp {
     Neg,
 }
 /// An adapter for [`enum@syn::UseTree`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum UseTree {
     Path(UsePath),
@@ -373,8 +1258,45 @@ pub enum UseTree {
     Glob,
     Group(UseGroup),
 }
+impl<'de> Deserialize<'de> for UseTree {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for UseTree {
+    const VARIANTS: &'static [&'static str] = &["path", "ident", "rename", "*", "group"];
+    const KINDS: &'static [&'static str] = &[
+        "UseTreePath",
+        "UseTreeName",
+        "UseTreeRename",
+        "UseTreeGlob",
+        "UseTreeGroup",
+    ];
+    const INLINE: &'static [bool] = &[true, false, true, false, false];
+    const AMBIGUOUS: &'static [bool] = &[false, true, true, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => UsePath::deserialize(deserializer).map(Self::Path),
+            1 => UseName::deserialize(deserializer).map(Self::Name),
+            2 => UseRename::deserialize(deserializer).map(Self::Rename),
+            3 => <()>::deserialize(deserializer).map(|()| Self::Glob),
+            4 => UseGroup::deserialize(deserializer).map(Self::Group),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::Visibility`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 pub enum Visibility {
     #[serde(rename = "pub")]
@@ -382,11 +1304,74 @@ pub enum Visibility {
     Restricted(VisRestricted),
     Inherited,
 }
+impl<'de> Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for Visibility {
+    const VARIANTS: &'static [&'static str] = &["pub", "restricted", "inherited"];
+    const KINDS: &'static [&'static str] = &[
+        "VisibilityPublic",
+        "VisibilityRestricted",
+        "VisibilityInherited",
+    ];
+    const INLINE: &'static [bool] = &[false, true, false];
+    const AMBIGUOUS: &'static [bool] = &[false, false, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => <()>::deserialize(deserializer).map(|()| Self::Public),
+            1 => VisRestricted::deserialize(deserializer).map(Self::Restricted),
+            2 => <()>::deserialize(deserializer).map(|()| Self::Inherited),
+            _ => unreachable!(),
+        }
+    }
+}
 /// An adapter for [`enum@syn::WherePredicate`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum WherePredicate {
     Lifetime(PredicateLifetime),
     Type(PredicateType),
 }
+impl<'de> Deserialize<'de> for WherePredicate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+impl tagged::TaggedEnum for WherePredicate {
+    const VARIANTS: &'static [&'static str] = &["lifetime", "type"];
+    const KINDS: &'static [&'static str] = &[
+        "WherePredicateLifetime",
+        "WherePredicateType",
+    ];
+    const INLINE: &'static [bool] = &[true, true];
+    const AMBIGUOUS: &'static [bool] = &[true, false];
+    fn deserialize_variant<'de, D>(
+        index: usize,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => PredicateLifetime::deserialize(deserializer).map(Self::Lifetime),
+            1 => PredicateType::deserialize(deserializer).map(Self::Type),
+            _ => unreachable!(),
+        }
+    }
+}
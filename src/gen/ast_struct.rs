@@ -7,1188 +7,4682 @@
 use crate::*;
 /// An adapter for [`struct@syn::Abi`].
 #[derive(Serialize, Deserialize)]
+#[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct Abi {
+    /// Corresponds to [`syn::Abi::name`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<LitStr>,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) name: Option<LitStr>,
+    pub span: Option<Location>,
 }
 /// An adapter for [`struct@syn::AngleBracketedGenericArguments`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct AngleBracketedGenericArguments {
+    /// Whether [`syn::AngleBracketedGenericArguments::colon2_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) colon2_token: bool,
-    pub(crate) args: Punctuated<GenericArgument>,
+    pub colon2_token: bool,
+    /// Corresponds to [`syn::AngleBracketedGenericArguments::args`].
+    pub args: Punctuated<GenericArgument>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl AngleBracketedGenericArguments {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(args: Punctuated<GenericArgument>) -> Self {
+        Self {
+            colon2_token: default(),
+            args,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::AssocConst`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct AssocConst {
-    pub(crate) ident: Ident,
+    /// Corresponds to [`syn::AssocConst::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::AssocConst::generics`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generics: Option<AngleBracketedGenericArguments>,
+    /// Corresponds to [`syn::AssocConst::value`].
+    pub value: Expr,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) generics: Option<AngleBracketedGenericArguments>,
-    pub(crate) value: Expr,
+    pub span: Option<Location>,
+}
+impl AssocConst {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, value: Expr) -> Self {
+        Self {
+            ident,
+            generics: default(),
+            value,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::AssocType`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct AssocType {
-    pub(crate) ident: Ident,
+    /// Corresponds to [`syn::AssocType::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::AssocType::generics`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) generics: Option<AngleBracketedGenericArguments>,
-    pub(crate) ty: Type,
+    pub generics: Option<AngleBracketedGenericArguments>,
+    /// Corresponds to [`syn::AssocType::ty`].
+    pub ty: Type,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl AssocType {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, ty: Type) -> Self {
+        Self {
+            ident,
+            generics: default(),
+            ty,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::Attribute`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct Attribute {
-    pub(crate) style: AttrStyle,
-    pub(crate) meta: Meta,
+    /// Corresponds to [`syn::Attribute::style`].
+    pub style: AttrStyle,
+    /// Corresponds to [`syn::Attribute::meta`].
+    pub meta: Meta,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl Attribute {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(style: AttrStyle, meta: Meta) -> Self {
+        Self { style, meta, span: None }
+    }
 }
 /// An adapter for [`struct@syn::BareFnArg`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct BareFnArg {
+    /// Corresponds to [`syn::BareFnArg::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::BareFnArg::name`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Ident>,
+    /// Corresponds to [`syn::BareFnArg::ty`].
+    pub ty: Type,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) name: Option<Ident>,
-    pub(crate) ty: Type,
+    pub span: Option<Location>,
+}
+impl BareFnArg {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ty: Type) -> Self {
+        Self {
+            attrs: default(),
+            name: default(),
+            ty,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::BareVariadic`].
 #[derive(Serialize, Deserialize)]
+#[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct BareVariadic {
+    /// Corresponds to [`syn::BareVariadic::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::BareVariadic::name`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) name: Option<Ident>,
+    pub name: Option<Ident>,
+    /// Whether [`syn::BareVariadic::comma`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) comma: bool,
+    pub comma: bool,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
 }
 /// An adapter for [`struct@syn::Block`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(transparent)]
+#[non_exhaustive]
 pub struct Block {
-    pub(crate) stmts: Vec<Stmt>,
+    /// Corresponds to [`syn::Block::stmts`].
+    pub stmts: Vec<Stmt>,
+}
+impl Block {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(stmts: Vec<Stmt>) -> Self {
+        Self { stmts }
+    }
 }
 /// An adapter for [`struct@syn::BoundLifetimes`].
 #[derive(Serialize, Deserialize)]
 #[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(transparent)]
+#[non_exhaustive]
 pub struct BoundLifetimes {
-    pub(crate) lifetimes: Punctuated<GenericParam>,
+    /// Corresponds to [`syn::BoundLifetimes::lifetimes`].
+    pub lifetimes: Punctuated<GenericParam>,
+}
+impl BoundLifetimes {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(lifetimes: Punctuated<GenericParam>) -> Self {
+        Self { lifetimes }
+    }
 }
 /// An adapter for [`struct@syn::ConstParam`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ConstParam {
+    /// Corresponds to [`syn::ConstParam::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) ident: Ident,
-    pub(crate) ty: Type,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ConstParam::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ConstParam::ty`].
+    pub ty: Type,
+    /// Whether [`syn::ConstParam::eq_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) eq_token: bool,
+    pub eq_token: bool,
+    /// Corresponds to [`syn::ConstParam::default`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) default: Option<Expr>,
+    pub default: Option<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ConstParam {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, ty: Type) -> Self {
+        Self {
+            attrs: default(),
+            ident,
+            ty,
+            eq_token: default(),
+            default: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::Constraint`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct Constraint {
-    pub(crate) ident: Ident,
+    /// Corresponds to [`syn::Constraint::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::Constraint::generics`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generics: Option<AngleBracketedGenericArguments>,
+    /// Corresponds to [`syn::Constraint::bounds`].
+    pub bounds: Punctuated<TypeParamBound>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl Constraint {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, bounds: Punctuated<TypeParamBound>) -> Self {
+        Self {
+            ident,
+            generics: default(),
+            bounds,
+            span: None,
+        }
+    }
+}
+/// An adapter for [`struct@syn::DataEnum`].
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct DataEnum {
+    /// Corresponds to [`syn::DataEnum::variants`].
+    pub variants: Punctuated<Variant>,
+}
+impl DataEnum {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(variants: Punctuated<Variant>) -> Self {
+        Self { variants }
+    }
+}
+/// An adapter for [`struct@syn::DataUnion`].
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct DataUnion {
+    /// Corresponds to [`syn::DataUnion::fields`].
+    pub fields: FieldsNamed,
+}
+impl DataUnion {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(fields: FieldsNamed) -> Self {
+        Self { fields }
+    }
+}
+/// An adapter for [`struct@syn::DeriveInput`].
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
+pub struct DeriveInput {
+    /// Corresponds to [`syn::DeriveInput::attrs`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::DeriveInput::vis`].
+    #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
+    pub vis: Visibility,
+    /// Corresponds to [`syn::DeriveInput::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::DeriveInput::generics`].
+    #[serde(default, skip_serializing_if = "Generics::is_none")]
+    pub generics: Generics,
+    /// Corresponds to [`syn::DeriveInput::data`].
+    pub data: Data,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) generics: Option<AngleBracketedGenericArguments>,
-    pub(crate) bounds: Punctuated<TypeParamBound>,
+    pub span: Option<Location>,
+}
+impl DeriveInput {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, data: Data) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            ident,
+            generics: default(),
+            data,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprArray`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprArray {
+    /// Corresponds to [`syn::ExprArray::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) elems: Punctuated<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprArray::elems`].
+    pub elems: Punctuated<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprArray {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(elems: Punctuated<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            elems,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprAssign`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprAssign {
+    /// Corresponds to [`syn::ExprAssign::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) left: Box<Expr>,
-    pub(crate) right: Box<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprAssign::left`].
+    pub left: Box<Expr>,
+    /// Corresponds to [`syn::ExprAssign::right`].
+    pub right: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprAssign {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(left: Box<Expr>, right: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            left,
+            right,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprAsync`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprAsync {
+    /// Corresponds to [`syn::ExprAsync::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Whether [`syn::ExprAsync::capture`] is present. Serialized as `move`.
     #[serde(rename = "move")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) capture: bool,
+    pub capture: bool,
+    /// Corresponds to [`syn::ExprAsync::block`]. Serialized as `stmts`.
     #[serde(rename = "stmts")]
-    pub(crate) block: Block,
+    pub block: Block,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprAsync {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(block: Block) -> Self {
+        Self {
+            attrs: default(),
+            capture: default(),
+            block,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprAwait`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprAwait {
+    /// Corresponds to [`syn::ExprAwait::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) base: Box<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprAwait::base`].
+    pub base: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprAwait {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(base: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            base,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprBinary`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprBinary {
+    /// Corresponds to [`syn::ExprBinary::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) left: Box<Expr>,
-    pub(crate) op: BinOp,
-    pub(crate) right: Box<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprBinary::left`].
+    pub left: Box<Expr>,
+    /// Corresponds to [`syn::ExprBinary::op`].
+    pub op: BinOp,
+    /// Corresponds to [`syn::ExprBinary::right`].
+    pub right: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprBinary {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(left: Box<Expr>, op: BinOp, right: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            left,
+            op,
+            right,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprBlock`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprBlock {
+    /// Corresponds to [`syn::ExprBlock::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprBlock::label`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) label: Option<Label>,
+    pub label: Option<Label>,
+    /// Corresponds to [`syn::ExprBlock::block`]. Serialized as `stmts`.
     #[serde(rename = "stmts")]
-    pub(crate) block: Block,
+    pub block: Block,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprBlock {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(block: Block) -> Self {
+        Self {
+            attrs: default(),
+            label: default(),
+            block,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprBreak`].
 #[derive(Serialize, Deserialize)]
+#[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprBreak {
+    /// Corresponds to [`syn::ExprBreak::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprBreak::label`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) label: Option<Lifetime>,
+    pub label: Option<Lifetime>,
+    /// Corresponds to [`syn::ExprBreak::expr`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) expr: Option<Box<Expr>>,
+    pub expr: Option<Box<Expr>>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
 }
 /// An adapter for [`struct@syn::ExprCall`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprCall {
+    /// Corresponds to [`syn::ExprCall::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) func: Box<Expr>,
-    pub(crate) args: Punctuated<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprCall::func`].
+    pub func: Box<Expr>,
+    /// Corresponds to [`syn::ExprCall::args`].
+    pub args: Punctuated<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprCall {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(func: Box<Expr>, args: Punctuated<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            func,
+            args,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprCast`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprCast {
+    /// Corresponds to [`syn::ExprCast::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) expr: Box<Expr>,
-    pub(crate) ty: Box<Type>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprCast::expr`].
+    pub expr: Box<Expr>,
+    /// Corresponds to [`syn::ExprCast::ty`].
+    pub ty: Box<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprCast {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(expr: Box<Expr>, ty: Box<Type>) -> Self {
+        Self {
+            attrs: default(),
+            expr,
+            ty,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprClosure`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprClosure {
+    /// Corresponds to [`syn::ExprClosure::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprClosure::lifetimes`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) lifetimes: Option<BoundLifetimes>,
+    pub lifetimes: Option<BoundLifetimes>,
+    /// Whether [`syn::ExprClosure::constness`] is present. Serialized as `const`.
     #[serde(rename = "const")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) constness: bool,
+    pub constness: bool,
+    /// Whether [`syn::ExprClosure::movability`] is present. Serialized as `static`.
     #[serde(rename = "static")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) movability: bool,
+    pub movability: bool,
+    /// Whether [`syn::ExprClosure::asyncness`] is present. Serialized as `async`.
     #[serde(rename = "async")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) asyncness: bool,
+    pub asyncness: bool,
+    /// Whether [`syn::ExprClosure::capture`] is present. Serialized as `move`.
     #[serde(rename = "move")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) capture: bool,
-    pub(crate) inputs: Punctuated<Pat>,
+    pub capture: bool,
+    /// Corresponds to [`syn::ExprClosure::inputs`].
+    pub inputs: Punctuated<Pat>,
+    /// Corresponds to [`syn::ExprClosure::output`].
     #[serde(default)]
-    pub(crate) output: ReturnType,
-    pub(crate) body: Box<Expr>,
+    pub output: ReturnType,
+    /// Corresponds to [`syn::ExprClosure::body`].
+    pub body: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprClosure {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(inputs: Punctuated<Pat>, body: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            lifetimes: default(),
+            constness: default(),
+            movability: default(),
+            asyncness: default(),
+            capture: default(),
+            inputs,
+            output: default(),
+            body,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprConst`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprConst {
+    /// Corresponds to [`syn::ExprConst::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprConst::block`]. Serialized as `stmts`.
     #[serde(rename = "stmts")]
-    pub(crate) block: Block,
+    pub block: Block,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprConst {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(block: Block) -> Self {
+        Self {
+            attrs: default(),
+            block,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprContinue`].
 #[derive(Serialize, Deserialize)]
+#[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprContinue {
+    /// Corresponds to [`syn::ExprContinue::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprContinue::label`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) label: Option<Lifetime>,
+    pub label: Option<Lifetime>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
 }
 /// An adapter for [`struct@syn::ExprField`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprField {
+    /// Corresponds to [`syn::ExprField::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) base: Box<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprField::base`].
+    pub base: Box<Expr>,
+    /// Corresponds to [`syn::ExprField::member`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) member: Member,
+    pub member: Member,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprField {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(base: Box<Expr>, member: Member) -> Self {
+        Self {
+            attrs: default(),
+            base,
+            member,
+            span: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for ExprField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for ExprField {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ExprField")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            base: Box<Expr>,
+            #[serde(flatten)]
+            member: Member,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ExprField")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            base: Box<Expr>,
+            member: Member,
+            span: Option<Location>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::ExprForLoop`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprForLoop {
+    /// Corresponds to [`syn::ExprForLoop::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprForLoop::label`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Label>,
+    /// Corresponds to [`syn::ExprForLoop::pat`].
+    pub pat: Box<Pat>,
+    /// Corresponds to [`syn::ExprForLoop::expr`].
+    pub expr: Box<Expr>,
+    /// Corresponds to [`syn::ExprForLoop::body`].
+    pub body: Block,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) label: Option<Label>,
-    pub(crate) pat: Box<Pat>,
-    pub(crate) expr: Box<Expr>,
-    pub(crate) body: Block,
+    pub span: Option<Location>,
+}
+impl ExprForLoop {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(pat: Box<Pat>, expr: Box<Expr>, body: Block) -> Self {
+        Self {
+            attrs: default(),
+            label: default(),
+            pat,
+            expr,
+            body,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprGroup`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprGroup {
+    /// Corresponds to [`syn::ExprGroup::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) expr: Box<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprGroup::expr`].
+    pub expr: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprGroup {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(expr: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            expr,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprIf`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprIf {
+    /// Corresponds to [`syn::ExprIf::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) cond: Box<Expr>,
-    pub(crate) then_branch: Block,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprIf::cond`].
+    pub cond: Box<Expr>,
+    /// Corresponds to [`syn::ExprIf::then_branch`].
+    pub then_branch: Block,
+    /// Corresponds to [`syn::ExprIf::else_branch`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) else_branch: Option<Box<Expr>>,
+    pub else_branch: Option<Box<Expr>>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprIf {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(cond: Box<Expr>, then_branch: Block) -> Self {
+        Self {
+            attrs: default(),
+            cond,
+            then_branch,
+            else_branch: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprIndex`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprIndex {
+    /// Corresponds to [`syn::ExprIndex::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) expr: Box<Expr>,
-    pub(crate) index: Box<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprIndex::expr`].
+    pub expr: Box<Expr>,
+    /// Corresponds to [`syn::ExprIndex::index`].
+    pub index: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprIndex {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(expr: Box<Expr>, index: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            expr,
+            index,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprInfer`].
 #[derive(Serialize, Deserialize)]
+#[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprInfer {
+    /// Corresponds to [`syn::ExprInfer::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
 }
 /// An adapter for [`struct@syn::ExprLet`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprLet {
+    /// Corresponds to [`syn::ExprLet::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) pat: Box<Pat>,
-    pub(crate) expr: Box<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprLet::pat`].
+    pub pat: Box<Pat>,
+    /// Corresponds to [`syn::ExprLet::expr`].
+    pub expr: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprLet {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(pat: Box<Pat>, expr: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            pat,
+            expr,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprLit`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprLit {
+    /// Corresponds to [`syn::ExprLit::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprLit::lit`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) lit: Lit,
+    pub lit: Lit,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprLit {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(lit: Lit) -> Self {
+        Self {
+            attrs: default(),
+            lit,
+            span: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for ExprLit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for ExprLit {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ExprLit")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(flatten)]
+            lit: Lit,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ExprLit")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            lit: Lit,
+            span: Option<Location>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::ExprLoop`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprLoop {
+    /// Corresponds to [`syn::ExprLoop::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprLoop::label`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) label: Option<Label>,
-    pub(crate) body: Block,
+    pub label: Option<Label>,
+    /// Corresponds to [`syn::ExprLoop::body`].
+    pub body: Block,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprLoop {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(body: Block) -> Self {
+        Self {
+            attrs: default(),
+            label: default(),
+            body,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprMacro`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprMacro {
+    /// Corresponds to [`syn::ExprMacro::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprMacro::mac`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) mac: Macro,
+    pub mac: Macro,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprMacro {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(mac: Macro) -> Self {
+        Self {
+            attrs: default(),
+            mac,
+            span: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for ExprMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for ExprMacro {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ExprMacro")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(flatten)]
+            mac: Macro,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ExprMacro")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            mac: Macro,
+            span: Option<Location>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::ExprMatch`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprMatch {
+    /// Corresponds to [`syn::ExprMatch::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) expr: Box<Expr>,
-    pub(crate) arms: Vec<Arm>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprMatch::expr`].
+    pub expr: Box<Expr>,
+    /// Corresponds to [`syn::ExprMatch::arms`].
+    pub arms: Vec<Arm>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprMatch {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(expr: Box<Expr>, arms: Vec<Arm>) -> Self {
+        Self {
+            attrs: default(),
+            expr,
+            arms,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprMethodCall`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprMethodCall {
+    /// Corresponds to [`syn::ExprMethodCall::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) receiver: Box<Expr>,
-    pub(crate) method: Ident,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprMethodCall::receiver`].
+    pub receiver: Box<Expr>,
+    /// Corresponds to [`syn::ExprMethodCall::method`].
+    pub method: Ident,
+    /// Corresponds to [`syn::ExprMethodCall::turbofish`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub turbofish: Option<AngleBracketedGenericArguments>,
+    /// Corresponds to [`syn::ExprMethodCall::args`].
+    pub args: Punctuated<Expr>,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) turbofish: Option<AngleBracketedGenericArguments>,
-    pub(crate) args: Punctuated<Expr>,
+    pub span: Option<Location>,
+}
+impl ExprMethodCall {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(receiver: Box<Expr>, method: Ident, args: Punctuated<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            receiver,
+            method,
+            turbofish: default(),
+            args,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprParen`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprParen {
+    /// Corresponds to [`syn::ExprParen::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) expr: Box<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprParen::expr`].
+    pub expr: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprParen {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(expr: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            expr,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprPath`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprPath {
+    /// Corresponds to [`syn::ExprPath::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprPath::qself`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) qself: Option<QSelf>,
+    pub qself: Option<QSelf>,
+    /// Corresponds to [`syn::ExprPath::path`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) path: Path,
+    pub path: Path,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprPath {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(path: Path) -> Self {
+        Self {
+            attrs: default(),
+            qself: default(),
+            path,
+            span: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for ExprPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for ExprPath {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ExprPath")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            qself: Option<QSelf>,
+            #[serde(flatten)]
+            path: Path,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ExprPath")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            qself: Option<QSelf>,
+            path: Path,
+            span: Option<Location>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::ExprRange`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprRange {
+    /// Corresponds to [`syn::ExprRange::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprRange::start`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) start: Option<Box<Expr>>,
-    pub(crate) limits: RangeLimits,
+    pub start: Option<Box<Expr>>,
+    /// Corresponds to [`syn::ExprRange::limits`].
+    pub limits: RangeLimits,
+    /// Corresponds to [`syn::ExprRange::end`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<Box<Expr>>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprRange {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(limits: RangeLimits) -> Self {
+        Self {
+            attrs: default(),
+            start: default(),
+            limits,
+            end: default(),
+            span: None,
+        }
+    }
+}
+/// An adapter for [`struct@syn::ExprRawAddr`].
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
+pub struct ExprRawAddr {
+    /// Corresponds to [`syn::ExprRawAddr::attrs`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprRawAddr::mutability`].
+    pub mutability: PointerMutability,
+    /// Corresponds to [`syn::ExprRawAddr::expr`].
+    pub expr: Box<Expr>,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) end: Option<Box<Expr>>,
+    pub span: Option<Location>,
+}
+impl ExprRawAddr {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(mutability: PointerMutability, expr: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            mutability,
+            expr,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprReference`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprReference {
+    /// Corresponds to [`syn::ExprReference::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Whether [`syn::ExprReference::mutability`] is present. Serialized as `mut`.
     #[serde(rename = "mut")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) mutability: bool,
-    pub(crate) expr: Box<Expr>,
+    pub mutability: bool,
+    /// Corresponds to [`syn::ExprReference::expr`].
+    pub expr: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprReference {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(expr: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            mutability: default(),
+            expr,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprRepeat`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprRepeat {
+    /// Corresponds to [`syn::ExprRepeat::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) expr: Box<Expr>,
-    pub(crate) len: Box<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprRepeat::expr`].
+    pub expr: Box<Expr>,
+    /// Corresponds to [`syn::ExprRepeat::len`].
+    pub len: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprRepeat {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(expr: Box<Expr>, len: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            expr,
+            len,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprReturn`].
 #[derive(Serialize, Deserialize)]
+#[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprReturn {
+    /// Corresponds to [`syn::ExprReturn::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprReturn::expr`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expr: Option<Box<Expr>>,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) expr: Option<Box<Expr>>,
+    pub span: Option<Location>,
 }
 /// An adapter for [`struct@syn::ExprStruct`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprStruct {
+    /// Corresponds to [`syn::ExprStruct::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprStruct::qself`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) qself: Option<QSelf>,
-    pub(crate) path: Path,
-    pub(crate) fields: Punctuated<FieldValue>,
+    pub qself: Option<QSelf>,
+    /// Corresponds to [`syn::ExprStruct::path`].
+    pub path: Path,
+    /// Corresponds to [`syn::ExprStruct::fields`].
+    pub fields: Punctuated<FieldValue>,
+    /// Whether [`syn::ExprStruct::dot2_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) dot2_token: bool,
+    pub dot2_token: bool,
+    /// Corresponds to [`syn::ExprStruct::rest`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rest: Option<Box<Expr>>,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) rest: Option<Box<Expr>>,
+    pub span: Option<Location>,
+}
+impl ExprStruct {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(path: Path, fields: Punctuated<FieldValue>) -> Self {
+        Self {
+            attrs: default(),
+            qself: default(),
+            path,
+            fields,
+            dot2_token: default(),
+            rest: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprTry`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprTry {
+    /// Corresponds to [`syn::ExprTry::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) expr: Box<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprTry::expr`].
+    pub expr: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprTry {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(expr: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            expr,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprTryBlock`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprTryBlock {
+    /// Corresponds to [`syn::ExprTryBlock::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprTryBlock::block`]. Serialized as `stmts`.
     #[serde(rename = "stmts")]
-    pub(crate) block: Block,
+    pub block: Block,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprTryBlock {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(block: Block) -> Self {
+        Self {
+            attrs: default(),
+            block,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprTuple`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprTuple {
+    /// Corresponds to [`syn::ExprTuple::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) elems: Punctuated<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprTuple::elems`].
+    pub elems: Punctuated<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprTuple {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(elems: Punctuated<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            elems,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprUnary`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprUnary {
+    /// Corresponds to [`syn::ExprUnary::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) op: UnOp,
-    pub(crate) expr: Box<Expr>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprUnary::op`].
+    pub op: UnOp,
+    /// Corresponds to [`syn::ExprUnary::expr`].
+    pub expr: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprUnary {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(op: UnOp, expr: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            op,
+            expr,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprUnsafe`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprUnsafe {
+    /// Corresponds to [`syn::ExprUnsafe::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprUnsafe::block`]. Serialized as `stmts`.
     #[serde(rename = "stmts")]
-    pub(crate) block: Block,
+    pub block: Block,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ExprUnsafe {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(block: Block) -> Self {
+        Self {
+            attrs: default(),
+            block,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprWhile`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprWhile {
+    /// Corresponds to [`syn::ExprWhile::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprWhile::label`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Label>,
+    /// Corresponds to [`syn::ExprWhile::cond`].
+    pub cond: Box<Expr>,
+    /// Corresponds to [`syn::ExprWhile::body`].
+    pub body: Block,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) label: Option<Label>,
-    pub(crate) cond: Box<Expr>,
-    pub(crate) body: Block,
+    pub span: Option<Location>,
+}
+impl ExprWhile {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(cond: Box<Expr>, body: Block) -> Self {
+        Self {
+            attrs: default(),
+            label: default(),
+            cond,
+            body,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ExprYield`].
 #[derive(Serialize, Deserialize)]
+#[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ExprYield {
+    /// Corresponds to [`syn::ExprYield::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ExprYield::expr`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expr: Option<Box<Expr>>,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) expr: Option<Box<Expr>>,
+    pub span: Option<Location>,
 }
 /// An adapter for [`struct@syn::FieldPat`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct FieldPat {
+    /// Corresponds to [`syn::FieldPat::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::FieldPat::member`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) member: Member,
+    pub member: Member,
+    /// Whether [`syn::FieldPat::colon_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) colon_token: bool,
-    pub(crate) pat: Box<Pat>,
+    pub colon_token: bool,
+    /// Corresponds to [`syn::FieldPat::pat`].
+    pub pat: Box<Pat>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl FieldPat {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(member: Member, pat: Box<Pat>) -> Self {
+        Self {
+            attrs: default(),
+            member,
+            colon_token: default(),
+            pat,
+            span: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for FieldPat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for FieldPat {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "FieldPat")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(flatten)]
+            member: Member,
+            #[serde(default, skip_serializing_if = "not")]
+            colon_token: bool,
+            pat: Box<Pat>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "FieldPat")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            member: Member,
+            colon_token: bool,
+            pat: Box<Pat>,
+            span: Option<Location>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::FieldValue`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct FieldValue {
+    /// Corresponds to [`syn::FieldValue::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::FieldValue::member`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) member: Member,
+    pub member: Member,
+    /// Whether [`syn::FieldValue::colon_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) colon_token: bool,
-    pub(crate) expr: Expr,
+    pub colon_token: bool,
+    /// Corresponds to [`syn::FieldValue::expr`].
+    pub expr: Expr,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl FieldValue {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(member: Member, expr: Expr) -> Self {
+        Self {
+            attrs: default(),
+            member,
+            colon_token: default(),
+            expr,
+            span: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for FieldValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for FieldValue {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "FieldValue")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(flatten)]
+            member: Member,
+            #[serde(default, skip_serializing_if = "not")]
+            colon_token: bool,
+            expr: Expr,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "FieldValue")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            member: Member,
+            colon_token: bool,
+            expr: Expr,
+            span: Option<Location>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::FieldsNamed`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(transparent)]
+#[non_exhaustive]
 pub struct FieldsNamed {
-    pub(crate) named: Punctuated<Field>,
+    /// Corresponds to [`syn::FieldsNamed::named`].
+    pub named: Punctuated<Field>,
+}
+impl FieldsNamed {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(named: Punctuated<Field>) -> Self {
+        Self { named }
+    }
 }
 /// An adapter for [`struct@syn::FieldsUnnamed`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(transparent)]
+#[non_exhaustive]
 pub struct FieldsUnnamed {
-    pub(crate) unnamed: Punctuated<Field>,
+    /// Corresponds to [`syn::FieldsUnnamed::unnamed`].
+    pub unnamed: Punctuated<Field>,
+}
+impl FieldsUnnamed {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(unnamed: Punctuated<Field>) -> Self {
+        Self { unnamed }
+    }
 }
 /// An adapter for [`struct@syn::File`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct File {
+    /// Corresponds to [`syn::File::shebang`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) shebang: Option<String>,
+    pub shebang: Option<String>,
+    /// Corresponds to [`syn::File::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) items: Vec<Item>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::File::items`].
+    pub items: Vec<Item>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl File {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(items: Vec<Item>) -> Self {
+        Self {
+            shebang: default(),
+            attrs: default(),
+            items,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ForeignItemFn`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ForeignItemFn {
+    /// Corresponds to [`syn::ForeignItemFn::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ForeignItemFn::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
+    pub vis: Visibility,
+    /// Corresponds to [`syn::ForeignItemFn::sig`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) sig: Signature,
+    pub sig: Signature,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ForeignItemFn {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(sig: Signature) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            sig,
+            span: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for ForeignItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for ForeignItemFn {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ForeignItemFn")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
+            vis: Visibility,
+            #[serde(flatten)]
+            sig: Signature,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ForeignItemFn")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            vis: Visibility,
+            sig: Signature,
+            span: Option<Location>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::ForeignItemMacro`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ForeignItemMacro {
+    /// Corresponds to [`syn::ForeignItemMacro::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ForeignItemMacro::mac`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) mac: Macro,
+    pub mac: Macro,
+    /// Whether [`syn::ForeignItemMacro::semi_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) semi_token: bool,
+    pub semi_token: bool,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ForeignItemMacro {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(mac: Macro) -> Self {
+        Self {
+            attrs: default(),
+            mac,
+            semi_token: default(),
+            span: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for ForeignItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for ForeignItemMacro {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ForeignItemMacro")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(flatten)]
+            mac: Macro,
+            #[serde(default, skip_serializing_if = "not")]
+            semi_token: bool,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ForeignItemMacro")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            mac: Macro,
+            semi_token: bool,
+            span: Option<Location>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::ForeignItemStatic`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ForeignItemStatic {
+    /// Corresponds to [`syn::ForeignItemStatic::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ForeignItemStatic::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
+    pub vis: Visibility,
+    /// Corresponds to [`syn::ForeignItemStatic::mutability`]. Serialized as `mut`.
     #[serde(rename = "mut")]
     #[serde(default, skip_serializing_if = "StaticMutability::is_none")]
-    pub(crate) mutability: StaticMutability,
-    pub(crate) ident: Ident,
-    pub(crate) ty: Box<Type>,
+    pub mutability: StaticMutability,
+    /// Corresponds to [`syn::ForeignItemStatic::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ForeignItemStatic::ty`].
+    pub ty: Box<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ForeignItemStatic {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, ty: Box<Type>) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            mutability: default(),
+            ident,
+            ty,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ForeignItemType`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ForeignItemType {
+    /// Corresponds to [`syn::ForeignItemType::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ForeignItemType::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
-    pub(crate) ident: Ident,
+    pub vis: Visibility,
+    /// Corresponds to [`syn::ForeignItemType::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ForeignItemType::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
+    pub generics: Generics,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ForeignItemType {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            ident,
+            generics: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ImplItemConst`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ImplItemConst {
+    /// Corresponds to [`syn::ImplItemConst::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ImplItemConst::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
+    pub vis: Visibility,
+    /// Whether [`syn::ImplItemConst::defaultness`] is present. Serialized as `default`.
     #[serde(rename = "default")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) defaultness: bool,
-    pub(crate) ident: Ident,
+    pub defaultness: bool,
+    /// Corresponds to [`syn::ImplItemConst::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ImplItemConst::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
-    pub(crate) ty: Type,
-    pub(crate) expr: Expr,
+    pub generics: Generics,
+    /// Corresponds to [`syn::ImplItemConst::ty`].
+    pub ty: Type,
+    /// Corresponds to [`syn::ImplItemConst::expr`].
+    pub expr: Expr,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ImplItemConst {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, ty: Type, expr: Expr) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            defaultness: default(),
+            ident,
+            generics: default(),
+            ty,
+            expr,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ImplItemFn`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ImplItemFn {
+    /// Corresponds to [`syn::ImplItemFn::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ImplItemFn::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
+    pub vis: Visibility,
+    /// Whether [`syn::ImplItemFn::defaultness`] is present. Serialized as `default`.
     #[serde(rename = "default")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) defaultness: bool,
+    pub defaultness: bool,
+    /// Corresponds to [`syn::ImplItemFn::sig`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) sig: Signature,
+    pub sig: Signature,
+    /// Corresponds to [`syn::ImplItemFn::block`]. Serialized as `stmts`.
     #[serde(rename = "stmts")]
-    pub(crate) block: Block,
+    pub block: Block,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ImplItemFn {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(sig: Signature, block: Block) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            defaultness: default(),
+            sig,
+            block,
+            span: None,
+            trivia: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for ImplItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for ImplItemFn {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ImplItemFn")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
+            vis: Visibility,
+            #[serde(rename = "default")]
+            #[serde(default, skip_serializing_if = "not")]
+            defaultness: bool,
+            #[serde(flatten)]
+            sig: Signature,
+            #[serde(rename = "stmts")]
+            block: Block,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            trivia: Option<Trivia>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ImplItemFn")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            vis: Visibility,
+            defaultness: bool,
+            sig: Signature,
+            block: Block,
+            span: Option<Location>,
+            trivia: Option<Trivia>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::ImplItemMacro`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ImplItemMacro {
+    /// Corresponds to [`syn::ImplItemMacro::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ImplItemMacro::mac`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) mac: Macro,
+    pub mac: Macro,
+    /// Whether [`syn::ImplItemMacro::semi_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) semi_token: bool,
+    pub semi_token: bool,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ImplItemMacro {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(mac: Macro) -> Self {
+        Self {
+            attrs: default(),
+            mac,
+            semi_token: default(),
+            span: None,
+            trivia: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for ImplItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for ImplItemMacro {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ImplItemMacro")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(flatten)]
+            mac: Macro,
+            #[serde(default, skip_serializing_if = "not")]
+            semi_token: bool,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            trivia: Option<Trivia>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ImplItemMacro")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            mac: Macro,
+            semi_token: bool,
+            span: Option<Location>,
+            trivia: Option<Trivia>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::ImplItemType`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ImplItemType {
+    /// Corresponds to [`syn::ImplItemType::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ImplItemType::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
+    pub vis: Visibility,
+    /// Whether [`syn::ImplItemType::defaultness`] is present. Serialized as `default`.
     #[serde(rename = "default")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) defaultness: bool,
-    pub(crate) ident: Ident,
+    pub defaultness: bool,
+    /// Corresponds to [`syn::ImplItemType::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ImplItemType::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
-    pub(crate) ty: Type,
+    pub generics: Generics,
+    /// Corresponds to [`syn::ImplItemType::ty`].
+    pub ty: Type,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ImplItemType {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, ty: Type) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            defaultness: default(),
+            ident,
+            generics: default(),
+            ty,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::Index`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(transparent)]
+#[non_exhaustive]
 pub struct Index {
-    pub(crate) index: u32,
+    /// Corresponds to [`syn::Index::index`].
+    pub index: u32,
+}
+impl Index {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(index: u32) -> Self {
+        Self { index }
+    }
 }
 /// An adapter for [`struct@syn::ItemConst`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemConst {
+    /// Corresponds to [`syn::ItemConst::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ItemConst::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
-    pub(crate) ident: Ident,
+    pub vis: Visibility,
+    /// Corresponds to [`syn::ItemConst::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ItemConst::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
-    pub(crate) ty: Box<Type>,
-    pub(crate) expr: Box<Expr>,
+    pub generics: Generics,
+    /// Corresponds to [`syn::ItemConst::ty`].
+    pub ty: Box<Type>,
+    /// Corresponds to [`syn::ItemConst::expr`].
+    pub expr: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemConst {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, ty: Box<Type>, expr: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            ident,
+            generics: default(),
+            ty,
+            expr,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ItemEnum`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemEnum {
+    /// Corresponds to [`syn::ItemEnum::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ItemEnum::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
-    pub(crate) ident: Ident,
+    pub vis: Visibility,
+    /// Corresponds to [`syn::ItemEnum::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ItemEnum::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
-    pub(crate) variants: Punctuated<Variant>,
+    pub generics: Generics,
+    /// Corresponds to [`syn::ItemEnum::variants`].
+    pub variants: Punctuated<Variant>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemEnum {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, variants: Punctuated<Variant>) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            ident,
+            generics: default(),
+            variants,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ItemExternCrate`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemExternCrate {
+    /// Corresponds to [`syn::ItemExternCrate::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ItemExternCrate::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
-    pub(crate) ident: Ident,
+    pub vis: Visibility,
+    /// Corresponds to [`syn::ItemExternCrate::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ItemExternCrate::rename`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) rename: Option<Ident>,
+    pub rename: Option<Ident>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemExternCrate {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            ident,
+            rename: default(),
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ItemFn`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemFn {
+    /// Corresponds to [`syn::ItemFn::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ItemFn::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
+    pub vis: Visibility,
+    /// Corresponds to [`syn::ItemFn::sig`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) sig: Signature,
+    pub sig: Signature,
+    /// Corresponds to [`syn::ItemFn::block`]. Serialized as `stmts`.
     #[serde(rename = "stmts")]
-    pub(crate) block: Box<Block>,
+    pub block: Box<Block>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemFn {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(sig: Signature, block: Box<Block>) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            sig,
+            block,
+            span: None,
+            trivia: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for ItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for ItemFn {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ItemFn")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
+            vis: Visibility,
+            #[serde(flatten)]
+            sig: Signature,
+            #[serde(rename = "stmts")]
+            block: Box<Block>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            trivia: Option<Trivia>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ItemFn")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            vis: Visibility,
+            sig: Signature,
+            block: Box<Block>,
+            span: Option<Location>,
+            trivia: Option<Trivia>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::ItemForeignMod`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemForeignMod {
+    /// Corresponds to [`syn::ItemForeignMod::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Whether [`syn::ItemForeignMod::unsafety`] is present. Serialized as `unsafe`.
     #[serde(rename = "unsafe")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) unsafety: bool,
-    pub(crate) abi: Abi,
-    pub(crate) items: Vec<ForeignItem>,
+    pub unsafety: bool,
+    /// Corresponds to [`syn::ItemForeignMod::abi`].
+    pub abi: Abi,
+    /// Corresponds to [`syn::ItemForeignMod::items`].
+    pub items: Vec<ForeignItem>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemForeignMod {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(abi: Abi, items: Vec<ForeignItem>) -> Self {
+        Self {
+            attrs: default(),
+            unsafety: default(),
+            abi,
+            items,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ItemImpl`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemImpl {
+    /// Corresponds to [`syn::ItemImpl::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Whether [`syn::ItemImpl::defaultness`] is present. Serialized as `default`.
     #[serde(rename = "default")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) defaultness: bool,
+    pub defaultness: bool,
+    /// Whether [`syn::ItemImpl::unsafety`] is present. Serialized as `unsafe`.
     #[serde(rename = "unsafe")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) unsafety: bool,
+    pub unsafety: bool,
+    /// Corresponds to [`syn::ItemImpl::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
+    pub generics: Generics,
+    /// Corresponds to [`syn::ItemImpl::trait_`]. Serialized as `trait`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "trait")]
-    pub(crate) trait_: Option<(bool, Path)>,
-    pub(crate) self_ty: Box<Type>,
-    pub(crate) items: Vec<ImplItem>,
+    pub trait_: Option<(bool, Path)>,
+    /// Corresponds to [`syn::ItemImpl::self_ty`].
+    pub self_ty: Box<Type>,
+    /// Corresponds to [`syn::ItemImpl::items`].
+    pub items: Vec<ImplItem>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemImpl {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(self_ty: Box<Type>, items: Vec<ImplItem>) -> Self {
+        Self {
+            attrs: default(),
+            defaultness: default(),
+            unsafety: default(),
+            generics: default(),
+            trait_: default(),
+            self_ty,
+            items,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ItemMacro`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemMacro {
+    /// Corresponds to [`syn::ItemMacro::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ItemMacro::ident`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) ident: Option<Ident>,
+    pub ident: Option<Ident>,
+    /// Corresponds to [`syn::ItemMacro::mac`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) mac: Macro,
+    pub mac: Macro,
+    /// Whether [`syn::ItemMacro::semi_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) semi_token: bool,
+    pub semi_token: bool,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemMacro {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(mac: Macro) -> Self {
+        Self {
+            attrs: default(),
+            ident: default(),
+            mac,
+            semi_token: default(),
+            span: None,
+            trivia: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for ItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for ItemMacro {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ItemMacro")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            ident: Option<Ident>,
+            #[serde(flatten)]
+            mac: Macro,
+            #[serde(default, skip_serializing_if = "not")]
+            semi_token: bool,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            trivia: Option<Trivia>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "ItemMacro")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            ident: Option<Ident>,
+            mac: Macro,
+            semi_token: bool,
+            span: Option<Location>,
+            trivia: Option<Trivia>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::ItemStatic`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemStatic {
+    /// Corresponds to [`syn::ItemStatic::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ItemStatic::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
+    pub vis: Visibility,
+    /// Corresponds to [`syn::ItemStatic::mutability`]. Serialized as `mut`.
     #[serde(rename = "mut")]
     #[serde(default, skip_serializing_if = "StaticMutability::is_none")]
-    pub(crate) mutability: StaticMutability,
-    pub(crate) ident: Ident,
-    pub(crate) ty: Box<Type>,
-    pub(crate) expr: Box<Expr>,
+    pub mutability: StaticMutability,
+    /// Corresponds to [`syn::ItemStatic::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ItemStatic::ty`].
+    pub ty: Box<Type>,
+    /// Corresponds to [`syn::ItemStatic::expr`].
+    pub expr: Box<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemStatic {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, ty: Box<Type>, expr: Box<Expr>) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            mutability: default(),
+            ident,
+            ty,
+            expr,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ItemTrait`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemTrait {
+    /// Corresponds to [`syn::ItemTrait::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ItemTrait::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
+    pub vis: Visibility,
+    /// Whether [`syn::ItemTrait::unsafety`] is present. Serialized as `unsafe`.
     #[serde(rename = "unsafe")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) unsafety: bool,
+    pub unsafety: bool,
+    /// Whether [`syn::ItemTrait::auto_token`] is present. Serialized as `auto`.
     #[serde(rename = "auto")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) auto_token: bool,
+    pub auto_token: bool,
+    /// Corresponds to [`syn::ItemTrait::restriction`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) restriction: Option<ImplRestriction>,
-    pub(crate) ident: Ident,
+    pub restriction: Option<ImplRestriction>,
+    /// Corresponds to [`syn::ItemTrait::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ItemTrait::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
+    pub generics: Generics,
+    /// Whether [`syn::ItemTrait::colon_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) colon_token: bool,
+    pub colon_token: bool,
+    /// Corresponds to [`syn::ItemTrait::supertraits`].
     #[serde(default, skip_serializing_if = "Punctuated::is_empty")]
-    pub(crate) supertraits: Punctuated<TypeParamBound>,
-    pub(crate) items: Vec<TraitItem>,
+    pub supertraits: Punctuated<TypeParamBound>,
+    /// Corresponds to [`syn::ItemTrait::items`].
+    pub items: Vec<TraitItem>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemTrait {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, items: Vec<TraitItem>) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            unsafety: default(),
+            auto_token: default(),
+            restriction: default(),
+            ident,
+            generics: default(),
+            colon_token: default(),
+            supertraits: default(),
+            items,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ItemTraitAlias`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemTraitAlias {
+    /// Corresponds to [`syn::ItemTraitAlias::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ItemTraitAlias::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
-    pub(crate) ident: Ident,
+    pub vis: Visibility,
+    /// Corresponds to [`syn::ItemTraitAlias::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ItemTraitAlias::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
-    pub(crate) bounds: Punctuated<TypeParamBound>,
+    pub generics: Generics,
+    /// Corresponds to [`syn::ItemTraitAlias::bounds`].
+    pub bounds: Punctuated<TypeParamBound>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemTraitAlias {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, bounds: Punctuated<TypeParamBound>) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            ident,
+            generics: default(),
+            bounds,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ItemType`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemType {
+    /// Corresponds to [`syn::ItemType::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ItemType::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
-    pub(crate) ident: Ident,
+    pub vis: Visibility,
+    /// Corresponds to [`syn::ItemType::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ItemType::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
-    pub(crate) ty: Box<Type>,
+    pub generics: Generics,
+    /// Corresponds to [`syn::ItemType::ty`].
+    pub ty: Box<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemType {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, ty: Box<Type>) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            ident,
+            generics: default(),
+            ty,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ItemUnion`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemUnion {
+    /// Corresponds to [`syn::ItemUnion::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ItemUnion::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
-    pub(crate) ident: Ident,
+    pub vis: Visibility,
+    /// Corresponds to [`syn::ItemUnion::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::ItemUnion::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
-    pub(crate) fields: FieldsNamed,
+    pub generics: Generics,
+    /// Corresponds to [`syn::ItemUnion::fields`].
+    pub fields: FieldsNamed,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemUnion {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, fields: FieldsNamed) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            ident,
+            generics: default(),
+            fields,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::ItemUse`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ItemUse {
+    /// Corresponds to [`syn::ItemUse::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::ItemUse::vis`].
     #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-    pub(crate) vis: Visibility,
+    pub vis: Visibility,
+    /// Whether [`syn::ItemUse::leading_colon`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) leading_colon: bool,
-    pub(crate) tree: UseTree,
+    pub leading_colon: bool,
+    /// Corresponds to [`syn::ItemUse::tree`].
+    pub tree: UseTree,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl ItemUse {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(tree: UseTree) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            leading_colon: default(),
+            tree,
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::Label`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(transparent)]
+#[non_exhaustive]
 pub struct Label {
-    pub(crate) name: Lifetime,
+    /// Corresponds to [`syn::Label::name`].
+    pub name: Lifetime,
+}
+impl Label {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(name: Lifetime) -> Self {
+        Self { name }
+    }
 }
 /// An adapter for [`struct@syn::Lifetime`].
 #[derive(Serialize, Deserialize)]
 #[derive(Clone)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, Hash))]
 #[serde(transparent)]
+#[non_exhaustive]
 pub struct Lifetime {
-    pub(crate) ident: Ident,
+    /// Corresponds to [`syn::Lifetime::ident`].
+    pub ident: Ident,
+}
+impl Lifetime {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident) -> Self {
+        Self { ident }
+    }
 }
 /// An adapter for [`struct@syn::LifetimeParam`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct LifetimeParam {
+    /// Corresponds to [`syn::LifetimeParam::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) lifetime: Lifetime,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::LifetimeParam::lifetime`].
+    pub lifetime: Lifetime,
+    /// Whether [`syn::LifetimeParam::colon_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) colon_token: bool,
-    pub(crate) bounds: Punctuated<Lifetime>,
+    pub colon_token: bool,
+    /// Corresponds to [`syn::LifetimeParam::bounds`].
+    pub bounds: Punctuated<Lifetime>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl LifetimeParam {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(lifetime: Lifetime, bounds: Punctuated<Lifetime>) -> Self {
+        Self {
+            attrs: default(),
+            lifetime,
+            colon_token: default(),
+            bounds,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::LitBool`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(transparent)]
+#[non_exhaustive]
 pub struct LitBool {
-    pub(crate) value: bool,
+    /// Corresponds to [`syn::LitBool::value`].
+    pub value: bool,
+}
+impl LitBool {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(value: bool) -> Self {
+        Self { value }
+    }
 }
 /// An adapter for [`struct@syn::Local`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct Local {
+    /// Corresponds to [`syn::Local::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) pat: Pat,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::Local::pat`].
+    pub pat: Pat,
+    /// Corresponds to [`syn::Local::init`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub init: Option<LocalInit>,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) init: Option<LocalInit>,
+    pub span: Option<Location>,
+}
+impl Local {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(pat: Pat) -> Self {
+        Self {
+            attrs: default(),
+            pat,
+            init: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::LocalInit`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct LocalInit {
-    pub(crate) expr: Box<Expr>,
+    /// Corresponds to [`syn::LocalInit::expr`].
+    pub expr: Box<Expr>,
+    /// Corresponds to [`syn::LocalInit::diverge`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) diverge: Option<Box<Expr>>,
+    pub diverge: Option<Box<Expr>>,
+}
+impl LocalInit {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(expr: Box<Expr>) -> Self {
+        Self { expr, diverge: default() }
+    }
 }
 /// An adapter for [`struct@syn::Macro`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "macro-args", serde(try_from = "mac::MacroWithArgs"))]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct Macro {
-    pub(crate) path: Path,
-    pub(crate) delimiter: MacroDelimiter,
-    pub(crate) tokens: TokenStream,
+    /// Corresponds to [`syn::Macro::path`].
+    pub path: Path,
+    /// Corresponds to [`syn::Macro::delimiter`].
+    pub delimiter: MacroDelimiter,
+    /// Corresponds to [`syn::Macro::tokens`].
+    pub tokens: TokenStream,
+}
+impl Macro {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(path: Path, delimiter: MacroDelimiter, tokens: TokenStream) -> Self {
+        Self { path, delimiter, tokens }
+    }
 }
 /// An adapter for [`struct@syn::MetaList`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct MetaList {
-    pub(crate) path: Path,
-    pub(crate) delimiter: MacroDelimiter,
-    pub(crate) tokens: TokenStream,
+    /// Corresponds to [`syn::MetaList::path`].
+    pub path: Path,
+    /// Corresponds to [`syn::MetaList::delimiter`].
+    pub delimiter: MacroDelimiter,
+    /// Corresponds to [`syn::MetaList::tokens`].
+    pub tokens: TokenStream,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl MetaList {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(path: Path, delimiter: MacroDelimiter, tokens: TokenStream) -> Self {
+        Self {
+            path,
+            delimiter,
+            tokens,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::MetaNameValue`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct MetaNameValue {
-    pub(crate) path: Path,
-    pub(crate) value: Expr,
+    /// Corresponds to [`syn::MetaNameValue::path`].
+    pub path: Path,
+    /// Corresponds to [`syn::MetaNameValue::value`].
+    pub value: Expr,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl MetaNameValue {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(path: Path, value: Expr) -> Self {
+        Self { path, value, span: None }
+    }
 }
 /// An adapter for [`struct@syn::ParenthesizedGenericArguments`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct ParenthesizedGenericArguments {
-    pub(crate) inputs: Punctuated<Type>,
+    /// Corresponds to [`syn::ParenthesizedGenericArguments::inputs`].
+    pub inputs: Punctuated<Type>,
+    /// Corresponds to [`syn::ParenthesizedGenericArguments::output`].
     #[serde(default)]
-    pub(crate) output: ReturnType,
+    pub output: ReturnType,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl ParenthesizedGenericArguments {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(inputs: Punctuated<Type>) -> Self {
+        Self {
+            inputs,
+            output: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::PatIdent`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PatIdent {
+    /// Corresponds to [`syn::PatIdent::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Whether [`syn::PatIdent::by_ref`] is present. Serialized as `ref`.
     #[serde(rename = "ref")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) by_ref: bool,
+    pub by_ref: bool,
+    /// Whether [`syn::PatIdent::mutability`] is present. Serialized as `mut`.
     #[serde(rename = "mut")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) mutability: bool,
-    pub(crate) ident: Ident,
+    pub mutability: bool,
+    /// Corresponds to [`syn::PatIdent::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::PatIdent::subpat`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subpat: Option<Box<Pat>>,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) subpat: Option<Box<Pat>>,
+    pub span: Option<Location>,
+}
+impl PatIdent {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident) -> Self {
+        Self {
+            attrs: default(),
+            by_ref: default(),
+            mutability: default(),
+            ident,
+            subpat: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::PatParen`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PatParen {
+    /// Corresponds to [`syn::PatParen::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) pat: Box<Pat>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::PatParen::pat`].
+    pub pat: Box<Pat>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl PatParen {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(pat: Box<Pat>) -> Self {
+        Self {
+            attrs: default(),
+            pat,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::PatReference`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PatReference {
+    /// Corresponds to [`syn::PatReference::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Whether [`syn::PatReference::mutability`] is present. Serialized as `mut`.
     #[serde(rename = "mut")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) mutability: bool,
-    pub(crate) pat: Box<Pat>,
+    pub mutability: bool,
+    /// Corresponds to [`syn::PatReference::pat`].
+    pub pat: Box<Pat>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl PatReference {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(pat: Box<Pat>) -> Self {
+        Self {
+            attrs: default(),
+            mutability: default(),
+            pat,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::PatRest`].
 #[derive(Serialize, Deserialize)]
+#[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PatRest {
+    /// Corresponds to [`syn::PatRest::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
 }
 /// An adapter for [`struct@syn::PatSlice`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PatSlice {
+    /// Corresponds to [`syn::PatSlice::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) elems: Punctuated<Pat>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::PatSlice::elems`].
+    pub elems: Punctuated<Pat>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl PatSlice {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(elems: Punctuated<Pat>) -> Self {
+        Self {
+            attrs: default(),
+            elems,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::PatStruct`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PatStruct {
+    /// Corresponds to [`syn::PatStruct::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::PatStruct::qself`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qself: Option<QSelf>,
+    /// Corresponds to [`syn::PatStruct::path`].
+    pub path: Path,
+    /// Corresponds to [`syn::PatStruct::fields`].
+    pub fields: Punctuated<FieldPat>,
+    /// Corresponds to [`syn::PatStruct::rest`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) qself: Option<QSelf>,
-    pub(crate) path: Path,
-    pub(crate) fields: Punctuated<FieldPat>,
+    pub rest: Option<PatRest>,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) rest: Option<PatRest>,
+    pub span: Option<Location>,
+}
+impl PatStruct {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(path: Path, fields: Punctuated<FieldPat>) -> Self {
+        Self {
+            attrs: default(),
+            qself: default(),
+            path,
+            fields,
+            rest: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::PatTuple`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PatTuple {
+    /// Corresponds to [`syn::PatTuple::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) elems: Punctuated<Pat>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::PatTuple::elems`].
+    pub elems: Punctuated<Pat>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl PatTuple {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(elems: Punctuated<Pat>) -> Self {
+        Self {
+            attrs: default(),
+            elems,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::PatTupleStruct`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PatTupleStruct {
+    /// Corresponds to [`syn::PatTupleStruct::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::PatTupleStruct::qself`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qself: Option<QSelf>,
+    /// Corresponds to [`syn::PatTupleStruct::path`].
+    pub path: Path,
+    /// Corresponds to [`syn::PatTupleStruct::elems`].
+    pub elems: Punctuated<Pat>,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) qself: Option<QSelf>,
-    pub(crate) path: Path,
-    pub(crate) elems: Punctuated<Pat>,
+    pub span: Option<Location>,
+}
+impl PatTupleStruct {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(path: Path, elems: Punctuated<Pat>) -> Self {
+        Self {
+            attrs: default(),
+            qself: default(),
+            path,
+            elems,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::PatType`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PatType {
+    /// Corresponds to [`syn::PatType::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) pat: Box<Pat>,
-    pub(crate) ty: Box<Type>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::PatType::pat`].
+    pub pat: Box<Pat>,
+    /// Corresponds to [`syn::PatType::ty`].
+    pub ty: Box<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl PatType {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(pat: Box<Pat>, ty: Box<Type>) -> Self {
+        Self {
+            attrs: default(),
+            pat,
+            ty,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::PatWild`].
 #[derive(Serialize, Deserialize)]
+#[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PatWild {
+    /// Corresponds to [`syn::PatWild::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
 }
 /// An adapter for [`struct@syn::Path`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct Path {
+    /// Whether [`syn::Path::leading_colon`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) leading_colon: bool,
-    pub(crate) segments: Punctuated<PathSegment>,
+    pub leading_colon: bool,
+    /// Corresponds to [`syn::Path::segments`].
+    pub segments: Punctuated<PathSegment>,
+}
+impl Path {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(segments: Punctuated<PathSegment>) -> Self {
+        Self {
+            leading_colon: default(),
+            segments,
+        }
+    }
 }
 /// An adapter for [`struct@syn::PathSegment`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PathSegment {
-    pub(crate) ident: Ident,
+    /// Corresponds to [`syn::PathSegment::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::PathSegment::arguments`].
     #[serde(default, skip_serializing_if = "PathArguments::is_none")]
-    pub(crate) arguments: PathArguments,
+    pub arguments: PathArguments,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl PathSegment {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident) -> Self {
+        Self {
+            ident,
+            arguments: default(),
+            span: None,
+        }
+    }
+}
+/// An adapter for [`struct@syn::PreciseCapture`].
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct PreciseCapture {
+    /// Corresponds to [`syn::PreciseCapture::params`].
+    pub params: Punctuated<CapturedParam>,
+}
+impl PreciseCapture {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(params: Punctuated<CapturedParam>) -> Self {
+        Self { params }
+    }
 }
 /// An adapter for [`struct@syn::PredicateLifetime`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct PredicateLifetime {
-    pub(crate) lifetime: Lifetime,
-    pub(crate) bounds: Punctuated<Lifetime>,
+    /// Corresponds to [`syn::PredicateLifetime::lifetime`].
+    pub lifetime: Lifetime,
+    /// Corresponds to [`syn::PredicateLifetime::bounds`].
+    pub bounds: Punctuated<Lifetime>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl PredicateLifetime {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(lifetime: Lifetime, bounds: Punctuated<Lifetime>) -> Self {
+        Self {
+            lifetime,
+            bounds,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::QSelf`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct QSelf {
-    pub(crate) ty: Box<Type>,
-    pub(crate) position: usize,
+    /// Corresponds to [`syn::QSelf::ty`].
+    pub ty: Box<Type>,
+    /// Corresponds to [`syn::QSelf::position`].
+    pub position: usize,
+    /// Whether [`syn::QSelf::as_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) as_token: bool,
+    pub as_token: bool,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl QSelf {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ty: Box<Type>, position: usize) -> Self {
+        Self {
+            ty,
+            position,
+            as_token: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::Signature`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct Signature {
+    /// Whether [`syn::Signature::constness`] is present. Serialized as `const`.
     #[serde(rename = "const")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) constness: bool,
+    pub constness: bool,
+    /// Whether [`syn::Signature::asyncness`] is present. Serialized as `async`.
     #[serde(rename = "async")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) asyncness: bool,
+    pub asyncness: bool,
+    /// Whether [`syn::Signature::unsafety`] is present. Serialized as `unsafe`.
     #[serde(rename = "unsafe")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) unsafety: bool,
+    pub unsafety: bool,
+    /// Corresponds to [`syn::Signature::abi`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) abi: Option<Abi>,
-    pub(crate) ident: Ident,
+    pub abi: Option<Abi>,
+    /// Corresponds to [`syn::Signature::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::Signature::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
-    pub(crate) inputs: Punctuated<FnArg>,
+    pub generics: Generics,
+    /// Corresponds to [`syn::Signature::inputs`].
+    pub inputs: Punctuated<FnArg>,
+    /// Corresponds to [`syn::Signature::variadic`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) variadic: Option<Variadic>,
+    pub variadic: Option<Variadic>,
+    /// Corresponds to [`syn::Signature::output`].
     #[serde(default)]
-    pub(crate) output: ReturnType,
+    pub output: ReturnType,
+}
+impl Signature {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, inputs: Punctuated<FnArg>) -> Self {
+        Self {
+            constness: default(),
+            asyncness: default(),
+            unsafety: default(),
+            abi: default(),
+            ident,
+            generics: default(),
+            inputs,
+            variadic: default(),
+            output: default(),
+        }
+    }
 }
 /// An adapter for [`struct@syn::StmtMacro`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct StmtMacro {
+    /// Corresponds to [`syn::StmtMacro::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::StmtMacro::mac`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) mac: Macro,
+    pub mac: Macro,
+    /// Whether [`syn::StmtMacro::semi_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) semi_token: bool,
+    pub semi_token: bool,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl StmtMacro {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(mac: Macro) -> Self {
+        Self {
+            attrs: default(),
+            mac,
+            semi_token: default(),
+            span: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for StmtMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for StmtMacro {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "StmtMacro")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(flatten)]
+            mac: Macro,
+            #[serde(default, skip_serializing_if = "not")]
+            semi_token: bool,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "StmtMacro")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            mac: Macro,
+            semi_token: bool,
+            span: Option<Location>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::TraitBound`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TraitBound {
+    /// Whether [`syn::TraitBound::paren_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) paren_token: bool,
+    pub paren_token: bool,
+    /// Corresponds to [`syn::TraitBound::modifier`].
     #[serde(default, skip_serializing_if = "TraitBoundModifier::is_none")]
-    pub(crate) modifier: TraitBoundModifier,
+    pub modifier: TraitBoundModifier,
+    /// Corresponds to [`syn::TraitBound::lifetimes`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) lifetimes: Option<BoundLifetimes>,
-    pub(crate) path: Path,
+    pub lifetimes: Option<BoundLifetimes>,
+    /// Corresponds to [`syn::TraitBound::path`].
+    pub path: Path,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TraitBound {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(path: Path) -> Self {
+        Self {
+            paren_token: default(),
+            modifier: default(),
+            lifetimes: default(),
+            path,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::TraitItemConst`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TraitItemConst {
+    /// Corresponds to [`syn::TraitItemConst::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) ident: Ident,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::TraitItemConst::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::TraitItemConst::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
-    pub(crate) ty: Type,
+    pub generics: Generics,
+    /// Corresponds to [`syn::TraitItemConst::ty`].
+    pub ty: Type,
+    /// Corresponds to [`syn::TraitItemConst::default`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Expr>,
+    /// The location of this node, if known.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) default: Option<Expr>,
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl TraitItemConst {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, ty: Type) -> Self {
+        Self {
+            attrs: default(),
+            ident,
+            generics: default(),
+            ty,
+            default: default(),
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::TraitItemMacro`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TraitItemMacro {
+    /// Corresponds to [`syn::TraitItemMacro::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::TraitItemMacro::mac`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) mac: Macro,
+    pub mac: Macro,
+    /// Whether [`syn::TraitItemMacro::semi_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) semi_token: bool,
+    pub semi_token: bool,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+impl TraitItemMacro {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(mac: Macro) -> Self {
+        Self {
+            attrs: default(),
+            mac,
+            semi_token: default(),
+            span: None,
+            trivia: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for TraitItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for TraitItemMacro {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "TraitItemMacro")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(flatten)]
+            mac: Macro,
+            #[serde(default, skip_serializing_if = "not")]
+            semi_token: bool,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            trivia: Option<Trivia>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "TraitItemMacro")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            mac: Macro,
+            semi_token: bool,
+            span: Option<Location>,
+            trivia: Option<Trivia>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::TraitItemType`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TraitItemType {
+    /// Corresponds to [`syn::TraitItemType::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) ident: Ident,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::TraitItemType::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::TraitItemType::generics`].
     #[serde(default, skip_serializing_if = "Generics::is_none")]
-    pub(crate) generics: Generics,
+    pub generics: Generics,
+    /// Whether [`syn::TraitItemType::colon_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) colon_token: bool,
+    pub colon_token: bool,
+    /// Corresponds to [`syn::TraitItemType::bounds`].
     #[serde(default, skip_serializing_if = "Punctuated::is_empty")]
-    pub(crate) bounds: Punctuated<TypeParamBound>,
+    pub bounds: Punctuated<TypeParamBound>,
+    /// Corresponds to [`syn::TraitItemType::default`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) default: Option<Type>,
+    pub trivia: Option<Trivia>,
+}
+impl TraitItemType {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident) -> Self {
+        Self {
+            attrs: default(),
+            ident,
+            generics: default(),
+            colon_token: default(),
+            bounds: default(),
+            default: default(),
+            span: None,
+            trivia: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::TypeArray`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypeArray {
-    pub(crate) elem: Box<Type>,
-    pub(crate) len: Expr,
+    /// Corresponds to [`syn::TypeArray::elem`].
+    pub elem: Box<Type>,
+    /// Corresponds to [`syn::TypeArray::len`].
+    pub len: Expr,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypeArray {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(elem: Box<Type>, len: Expr) -> Self {
+        Self { elem, len, span: None }
+    }
 }
 /// An adapter for [`struct@syn::TypeBareFn`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypeBareFn {
+    /// Corresponds to [`syn::TypeBareFn::lifetimes`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) lifetimes: Option<BoundLifetimes>,
+    pub lifetimes: Option<BoundLifetimes>,
+    /// Whether [`syn::TypeBareFn::unsafety`] is present. Serialized as `unsafe`.
     #[serde(rename = "unsafe")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) unsafety: bool,
+    pub unsafety: bool,
+    /// Corresponds to [`syn::TypeBareFn::abi`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) abi: Option<Abi>,
-    pub(crate) inputs: Punctuated<BareFnArg>,
+    pub abi: Option<Abi>,
+    /// Corresponds to [`syn::TypeBareFn::inputs`].
+    pub inputs: Punctuated<BareFnArg>,
+    /// Corresponds to [`syn::TypeBareFn::variadic`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) variadic: Option<BareVariadic>,
+    pub variadic: Option<BareVariadic>,
+    /// Corresponds to [`syn::TypeBareFn::output`].
     #[serde(default)]
-    pub(crate) output: ReturnType,
+    pub output: ReturnType,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypeBareFn {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(inputs: Punctuated<BareFnArg>) -> Self {
+        Self {
+            lifetimes: default(),
+            unsafety: default(),
+            abi: default(),
+            inputs,
+            variadic: default(),
+            output: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::TypeGroup`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypeGroup {
-    pub(crate) elem: Box<Type>,
+    /// Corresponds to [`syn::TypeGroup::elem`].
+    pub elem: Box<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypeGroup {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(elem: Box<Type>) -> Self {
+        Self { elem, span: None }
+    }
 }
 /// An adapter for [`struct@syn::TypeImplTrait`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypeImplTrait {
-    pub(crate) bounds: Punctuated<TypeParamBound>,
+    /// Corresponds to [`syn::TypeImplTrait::bounds`].
+    pub bounds: Punctuated<TypeParamBound>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypeImplTrait {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(bounds: Punctuated<TypeParamBound>) -> Self {
+        Self { bounds, span: None }
+    }
 }
 /// An adapter for [`struct@syn::TypeMacro`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypeMacro {
+    /// Corresponds to [`syn::TypeMacro::mac`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) mac: Macro,
+    pub mac: Macro,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypeMacro {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(mac: Macro) -> Self {
+        Self { mac, span: None }
+    }
+}
+impl<'de> Deserialize<'de> for TypeMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for TypeMacro {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "TypeMacro")]
+        struct Map {
+            #[serde(flatten)]
+            mac: Macro,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "TypeMacro")]
+        struct Seq {
+            mac: Macro,
+            span: Option<Location>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::TypeParam`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypeParam {
+    /// Corresponds to [`syn::TypeParam::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) ident: Ident,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::TypeParam::ident`].
+    pub ident: Ident,
+    /// Whether [`syn::TypeParam::colon_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) colon_token: bool,
+    pub colon_token: bool,
+    /// Corresponds to [`syn::TypeParam::bounds`].
     #[serde(default, skip_serializing_if = "Punctuated::is_empty")]
-    pub(crate) bounds: Punctuated<TypeParamBound>,
+    pub bounds: Punctuated<TypeParamBound>,
+    /// Whether [`syn::TypeParam::eq_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) eq_token: bool,
+    pub eq_token: bool,
+    /// Corresponds to [`syn::TypeParam::default`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) default: Option<Type>,
+    pub default: Option<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypeParam {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident) -> Self {
+        Self {
+            attrs: default(),
+            ident,
+            colon_token: default(),
+            bounds: default(),
+            eq_token: default(),
+            default: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::TypeParen`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypeParen {
-    pub(crate) elem: Box<Type>,
+    /// Corresponds to [`syn::TypeParen::elem`].
+    pub elem: Box<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypeParen {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(elem: Box<Type>) -> Self {
+        Self { elem, span: None }
+    }
 }
 /// An adapter for [`struct@syn::TypePath`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypePath {
+    /// Corresponds to [`syn::TypePath::qself`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) qself: Option<QSelf>,
+    pub qself: Option<QSelf>,
+    /// Corresponds to [`syn::TypePath::path`]. Its fields are serialized in place of this field.
     #[serde(flatten)]
-    pub(crate) path: Path,
+    pub path: Path,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypePath {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(path: Path) -> Self {
+        Self {
+            qself: default(),
+            path,
+            span: None,
+        }
+    }
+}
+impl<'de> Deserialize<'de> for TypePath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+impl flatten::Flattened for TypePath {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "TypePath")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            qself: Option<QSelf>,
+            #[serde(flatten)]
+            path: Path,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+        }
+        Map::deserialize(deserializer)
+    }
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "TypePath")]
+        struct Seq {
+            qself: Option<QSelf>,
+            path: Path,
+            span: Option<Location>,
+        }
+        Seq::deserialize(deserializer)
+    }
 }
 /// An adapter for [`struct@syn::TypePtr`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypePtr {
+    /// Whether [`syn::TypePtr::const_token`] is present. Serialized as `const`.
     #[serde(rename = "const")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) const_token: bool,
+    pub const_token: bool,
+    /// Whether [`syn::TypePtr::mutability`] is present. Serialized as `mut`.
     #[serde(rename = "mut")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) mutability: bool,
-    pub(crate) elem: Box<Type>,
+    pub mutability: bool,
+    /// Corresponds to [`syn::TypePtr::elem`].
+    pub elem: Box<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypePtr {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(elem: Box<Type>) -> Self {
+        Self {
+            const_token: default(),
+            mutability: default(),
+            elem,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::TypeReference`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypeReference {
+    /// Corresponds to [`syn::TypeReference::lifetime`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) lifetime: Option<Lifetime>,
+    pub lifetime: Option<Lifetime>,
+    /// Whether [`syn::TypeReference::mutability`] is present. Serialized as `mut`.
     #[serde(rename = "mut")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) mutability: bool,
-    pub(crate) elem: Box<Type>,
+    pub mutability: bool,
+    /// Corresponds to [`syn::TypeReference::elem`].
+    pub elem: Box<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypeReference {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(elem: Box<Type>) -> Self {
+        Self {
+            lifetime: default(),
+            mutability: default(),
+            elem,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::TypeSlice`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypeSlice {
-    pub(crate) elem: Box<Type>,
+    /// Corresponds to [`syn::TypeSlice::elem`].
+    pub elem: Box<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypeSlice {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(elem: Box<Type>) -> Self {
+        Self { elem, span: None }
+    }
 }
 /// An adapter for [`struct@syn::TypeTraitObject`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypeTraitObject {
+    /// Whether [`syn::TypeTraitObject::dyn_token`] is present. Serialized as `dyn`.
     #[serde(rename = "dyn")]
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) dyn_token: bool,
-    pub(crate) bounds: Punctuated<TypeParamBound>,
+    pub dyn_token: bool,
+    /// Corresponds to [`syn::TypeTraitObject::bounds`].
+    pub bounds: Punctuated<TypeParamBound>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypeTraitObject {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(bounds: Punctuated<TypeParamBound>) -> Self {
+        Self {
+            dyn_token: default(),
+            bounds,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::TypeTuple`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct TypeTuple {
-    pub(crate) elems: Punctuated<Type>,
+    /// Corresponds to [`syn::TypeTuple::elems`].
+    pub elems: Punctuated<Type>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl TypeTuple {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(elems: Punctuated<Type>) -> Self {
+        Self { elems, span: None }
+    }
 }
 /// An adapter for [`struct@syn::UseGroup`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(transparent)]
+#[non_exhaustive]
 pub struct UseGroup {
-    pub(crate) items: Punctuated<UseTree>,
+    /// Corresponds to [`syn::UseGroup::items`].
+    pub items: Punctuated<UseTree>,
+}
+impl UseGroup {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(items: Punctuated<UseTree>) -> Self {
+        Self { items }
+    }
 }
 /// An adapter for [`struct@syn::UseName`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(transparent)]
+#[non_exhaustive]
 pub struct UseName {
-    pub(crate) ident: Ident,
+    /// Corresponds to [`syn::UseName::ident`].
+    pub ident: Ident,
+}
+impl UseName {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident) -> Self {
+        Self { ident }
+    }
 }
 /// An adapter for [`struct@syn::UsePath`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct UsePath {
-    pub(crate) ident: Ident,
-    pub(crate) tree: Box<UseTree>,
+    /// Corresponds to [`syn::UsePath::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::UsePath::tree`].
+    pub tree: Box<UseTree>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl UsePath {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, tree: Box<UseTree>) -> Self {
+        Self { ident, tree, span: None }
+    }
 }
 /// An adapter for [`struct@syn::UseRename`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct UseRename {
-    pub(crate) ident: Ident,
-    pub(crate) rename: Ident,
+    /// Corresponds to [`syn::UseRename::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::UseRename::rename`].
+    pub rename: Ident,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl UseRename {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, rename: Ident) -> Self {
+        Self { ident, rename, span: None }
+    }
 }
 /// An adapter for [`struct@syn::Variadic`].
 #[derive(Serialize, Deserialize)]
+#[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct Variadic {
+    /// Corresponds to [`syn::Variadic::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::Variadic::pat`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) pat: Option<Box<Pat>>,
+    pub pat: Option<Box<Pat>>,
+    /// Whether [`syn::Variadic::comma`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) comma: bool,
+    pub comma: bool,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
 }
 /// An adapter for [`struct@syn::Variant`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct Variant {
+    /// Corresponds to [`syn::Variant::attrs`].
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub(crate) attrs: Vec<Attribute>,
-    pub(crate) ident: Ident,
-    pub(crate) fields: Fields,
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::Variant::ident`].
+    pub ident: Ident,
+    /// Corresponds to [`syn::Variant::fields`].
+    pub fields: Fields,
+    /// Corresponds to [`syn::Variant::discriminant`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) discriminant: Option<Expr>,
+    pub discriminant: Option<Expr>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl Variant {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(ident: Ident, fields: Fields) -> Self {
+        Self {
+            attrs: default(),
+            ident,
+            fields,
+            discriminant: default(),
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::VisRestricted`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
 pub struct VisRestricted {
+    /// Whether [`syn::VisRestricted::in_token`] is present.
     #[serde(default, skip_serializing_if = "not")]
-    pub(crate) in_token: bool,
-    pub(crate) path: Box<Path>,
+    pub in_token: bool,
+    /// Corresponds to [`syn::VisRestricted::path`].
+    pub path: Box<Path>,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+}
+impl VisRestricted {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(path: Box<Path>) -> Self {
+        Self {
+            in_token: default(),
+            path,
+            span: None,
+        }
+    }
 }
 /// An adapter for [`struct@syn::WhereClause`].
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
 #[serde(transparent)]
+#[non_exhaustive]
 pub struct WhereClause {
-    pub(crate) predicates: Punctuated<WherePredicate>,
+    /// Corresponds to [`syn::WhereClause::predicates`].
+    pub predicates: Punctuated<WherePredicate>,
+}
+impl WhereClause {
+    /// Creates an adapter from the fields that are required in the
+    /// serialized form, with the other fields set to their default
+    /// values.
+    #[must_use]
+    pub fn new(predicates: Punctuated<WherePredicate>) -> Self {
+        Self { predicates }
+    }
 }
@@ -9,39 +9,47 @@
     clippy::double_parens,
     clippy::just_underscores_and_digits,
     clippy::match_single_binding,
+    clippy::needless_question_mark,
 )]
 use crate::*;
 syn_trait_impl!(syn::Abi);
 impl From<&syn::Abi> for Abi {
     fn from(node: &syn::Abi) -> Self {
-        Self { name: node.name.map_into() }
-    }
-}
-impl From<&Abi> for syn::Abi {
-    fn from(node: &Abi) -> Self {
         Self {
-            extern_token: default(),
             name: node.name.map_into(),
+            span: None,
         }
     }
 }
+impl TryFromRef<Abi> for syn::Abi {
+    fn try_from_ref(node: &Abi) -> Result<Self, Error> {
+        Ok(Self {
+            extern_token: default(),
+            name: node.name.try_map_into().context("Abi", "name")?,
+        })
+    }
+}
 syn_trait_impl!(syn::AngleBracketedGenericArguments);
 impl From<&syn::AngleBracketedGenericArguments> for AngleBracketedGenericArguments {
     fn from(node: &syn::AngleBracketedGenericArguments) -> Self {
         Self {
             colon2_token: node.colon2_token.is_some(),
             args: node.args.map_into(),
+            span: None,
         }
     }
 }
-impl From<&AngleBracketedGenericArguments> for syn::AngleBracketedGenericArguments {
-    fn from(node: &AngleBracketedGenericArguments) -> Self {
-        Self {
+impl TryFromRef<AngleBracketedGenericArguments> for syn::AngleBracketedGenericArguments {
+    fn try_from_ref(node: &AngleBracketedGenericArguments) -> Result<Self, Error> {
+        Ok(Self {
             colon2_token: default_or_none(node.colon2_token),
             lt_token: default(),
-            args: node.args.map_into(),
+            args: node
+                .args
+                .try_map_into()
+                .context("AngleBracketedGenericArguments", "args")?,
             gt_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::AssocConst);
@@ -51,17 +59,18 @@ impl From<&syn::AssocConst> for AssocConst {
             ident: node.ident.ref_into(),
             generics: node.generics.map_into(),
             value: node.value.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&AssocConst> for syn::AssocConst {
-    fn from(node: &AssocConst) -> Self {
-        Self {
-            ident: node.ident.ref_into(),
-            generics: node.generics.map_into(),
+impl TryFromRef<AssocConst> for syn::AssocConst {
+    fn try_from_ref(node: &AssocConst) -> Result<Self, Error> {
+        Ok(Self {
+            ident: node.ident.try_ref_into().context("AssocConst", "ident")?,
+            generics: node.generics.try_map_into().context("AssocConst", "generics")?,
             eq_token: default(),
-            value: node.value.ref_into(),
-        }
+            value: node.value.try_ref_into().context("AssocConst", "value")?,
+        })
     }
 }
 syn_trait_impl!(syn::AssocType);
@@ -71,17 +80,18 @@ impl From<&syn::AssocType> for AssocType {
             ident: node.ident.ref_into(),
             generics: node.generics.map_into(),
             ty: node.ty.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&AssocType> for syn::AssocType {
-    fn from(node: &AssocType) -> Self {
-        Self {
-            ident: node.ident.ref_into(),
-            generics: node.generics.map_into(),
+impl TryFromRef<AssocType> for syn::AssocType {
+    fn try_from_ref(node: &AssocType) -> Result<Self, Error> {
+        Ok(Self {
+            ident: node.ident.try_ref_into().context("AssocType", "ident")?,
+            generics: node.generics.try_map_into().context("AssocType", "generics")?,
             eq_token: default(),
-            ty: node.ty.ref_into(),
-        }
+            ty: node.ty.try_ref_into().context("AssocType", "ty")?,
+        })
     }
 }
 syn_trait_impl!(syn::AttrStyle);
@@ -93,11 +103,11 @@ impl From<&syn::AttrStyle> for AttrStyle {
         }
     }
 }
-impl From<&AttrStyle> for syn::AttrStyle {
-    fn from(node: &AttrStyle) -> Self {
+impl TryFromRef<AttrStyle> for syn::AttrStyle {
+    fn try_from_ref(node: &AttrStyle) -> Result<Self, Error> {
         match node {
-            AttrStyle::Outer => syn::AttrStyle::Outer,
-            AttrStyle::Inner => syn::AttrStyle::Inner(default()),
+            AttrStyle::Outer => Ok(syn::AttrStyle::Outer),
+            AttrStyle::Inner => Ok(syn::AttrStyle::Inner(default())),
         }
     }
 }
@@ -107,17 +117,18 @@ impl From<&syn::Attribute> for Attribute {
         Self {
             style: node.style.ref_into(),
             meta: node.meta.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&Attribute> for syn::Attribute {
-    fn from(node: &Attribute) -> Self {
-        Self {
+impl TryFromRef<Attribute> for syn::Attribute {
+    fn try_from_ref(node: &Attribute) -> Result<Self, Error> {
+        Ok(Self {
             pound_token: default(),
-            style: node.style.ref_into(),
+            style: node.style.try_ref_into().context("Attribute", "style")?,
             bracket_token: default(),
-            meta: node.meta.ref_into(),
-        }
+            meta: node.meta.try_ref_into().context("Attribute", "meta")?,
+        })
     }
 }
 syn_trait_impl!(syn::BareFnArg);
@@ -127,16 +138,22 @@ impl From<&syn::BareFnArg> for BareFnArg {
             attrs: node.attrs.map_into(),
             name: node.name.ref_map(|(_0, _1)| (*_0).ref_into()),
             ty: node.ty.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&BareFnArg> for syn::BareFnArg {
-    fn from(node: &BareFnArg) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            name: node.name.ref_map(|_0| ((*_0).ref_into(), default())),
-            ty: node.ty.ref_into(),
-        }
+impl TryFromRef<BareFnArg> for syn::BareFnArg {
+    fn try_from_ref(node: &BareFnArg) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("BareFnArg", "attrs")?,
+            name: node
+                .name
+                .try_ref_map(|_0| Ok((
+                    (*_0).try_ref_into().context("BareFnArg", "name")?,
+                    default(),
+                )))?,
+            ty: node.ty.try_ref_into().context("BareFnArg", "ty")?,
+        })
     }
 }
 syn_trait_impl!(syn::BareVariadic);
@@ -146,17 +163,23 @@ impl From<&syn::BareVariadic> for BareVariadic {
             attrs: node.attrs.map_into(),
             name: node.name.ref_map(|(_0, _1)| (*_0).ref_into()),
             comma: node.comma.is_some(),
+            span: None,
         }
     }
 }
-impl From<&BareVariadic> for syn::BareVariadic {
-    fn from(node: &BareVariadic) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            name: node.name.ref_map(|_0| ((*_0).ref_into(), default())),
+impl TryFromRef<BareVariadic> for syn::BareVariadic {
+    fn try_from_ref(node: &BareVariadic) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("BareVariadic", "attrs")?,
+            name: node
+                .name
+                .try_ref_map(|_0| Ok((
+                    (*_0).try_ref_into().context("BareVariadic", "name")?,
+                    default(),
+                )))?,
             dots: default(),
             comma: default_or_none(node.comma),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::BinOp);
@@ -195,37 +218,37 @@ impl From<&syn::BinOp> for BinOp {
         }
     }
 }
-impl From<&BinOp> for syn::BinOp {
-    fn from(node: &BinOp) -> Self {
-        match node {
-            BinOp::Add => syn::BinOp::Add(default()),
-            BinOp::Sub => syn::BinOp::Sub(default()),
-            BinOp::Mul => syn::BinOp::Mul(default()),
-            BinOp::Div => syn::BinOp::Div(default()),
-            BinOp::Rem => syn::BinOp::Rem(default()),
-            BinOp::And => syn::BinOp::And(default()),
-            BinOp::Or => syn::BinOp::Or(default()),
-            BinOp::BitXor => syn::BinOp::BitXor(default()),
-            BinOp::BitAnd => syn::BinOp::BitAnd(default()),
-            BinOp::BitOr => syn::BinOp::BitOr(default()),
-            BinOp::Shl => syn::BinOp::Shl(default()),
-            BinOp::Shr => syn::BinOp::Shr(default()),
-            BinOp::Eq => syn::BinOp::Eq(default()),
-            BinOp::Lt => syn::BinOp::Lt(default()),
-            BinOp::Le => syn::BinOp::Le(default()),
-            BinOp::Ne => syn::BinOp::Ne(default()),
-            BinOp::Ge => syn::BinOp::Ge(default()),
-            BinOp::Gt => syn::BinOp::Gt(default()),
-            BinOp::AddAssign => syn::BinOp::AddAssign(default()),
-            BinOp::SubAssign => syn::BinOp::SubAssign(default()),
-            BinOp::MulAssign => syn::BinOp::MulAssign(default()),
-            BinOp::DivAssign => syn::BinOp::DivAssign(default()),
-            BinOp::RemAssign => syn::BinOp::RemAssign(default()),
-            BinOp::BitXorAssign => syn::BinOp::BitXorAssign(default()),
-            BinOp::BitAndAssign => syn::BinOp::BitAndAssign(default()),
-            BinOp::BitOrAssign => syn::BinOp::BitOrAssign(default()),
-            BinOp::ShlAssign => syn::BinOp::ShlAssign(default()),
-            BinOp::ShrAssign => syn::BinOp::ShrAssign(default()),
+impl TryFromRef<BinOp> for syn::BinOp {
+    fn try_from_ref(node: &BinOp) -> Result<Self, Error> {
+        match node {
+            BinOp::Add => Ok(syn::BinOp::Add(default())),
+            BinOp::Sub => Ok(syn::BinOp::Sub(default())),
+            BinOp::Mul => Ok(syn::BinOp::Mul(default())),
+            BinOp::Div => Ok(syn::BinOp::Div(default())),
+            BinOp::Rem => Ok(syn::BinOp::Rem(default())),
+            BinOp::And => Ok(syn::BinOp::And(default())),
+            BinOp::Or => Ok(syn::BinOp::Or(default())),
+            BinOp::BitXor => Ok(syn::BinOp::BitXor(default())),
+            BinOp::BitAnd => Ok(syn::BinOp::BitAnd(default())),
+            BinOp::BitOr => Ok(syn::BinOp::BitOr(default())),
+            BinOp::Shl => Ok(syn::BinOp::Shl(default())),
+            BinOp::Shr => Ok(syn::BinOp::Shr(default())),
+            BinOp::Eq => Ok(syn::BinOp::Eq(default())),
+            BinOp::Lt => Ok(syn::BinOp::Lt(default())),
+            BinOp::Le => Ok(syn::BinOp::Le(default())),
+            BinOp::Ne => Ok(syn::BinOp::Ne(default())),
+            BinOp::Ge => Ok(syn::BinOp::Ge(default())),
+            BinOp::Gt => Ok(syn::BinOp::Gt(default())),
+            BinOp::AddAssign => Ok(syn::BinOp::AddAssign(default())),
+            BinOp::SubAssign => Ok(syn::BinOp::SubAssign(default())),
+            BinOp::MulAssign => Ok(syn::BinOp::MulAssign(default())),
+            BinOp::DivAssign => Ok(syn::BinOp::DivAssign(default())),
+            BinOp::RemAssign => Ok(syn::BinOp::RemAssign(default())),
+            BinOp::BitXorAssign => Ok(syn::BinOp::BitXorAssign(default())),
+            BinOp::BitAndAssign => Ok(syn::BinOp::BitAndAssign(default())),
+            BinOp::BitOrAssign => Ok(syn::BinOp::BitOrAssign(default())),
+            BinOp::ShlAssign => Ok(syn::BinOp::ShlAssign(default())),
+            BinOp::ShrAssign => Ok(syn::BinOp::ShrAssign(default())),
         }
     }
 }
@@ -237,12 +260,12 @@ impl From<&syn::Block> for Block {
         }
     }
 }
-impl From<&Block> for syn::Block {
-    fn from(node: &Block) -> Self {
-        Self {
+impl TryFromRef<Block> for syn::Block {
+    fn try_from_ref(node: &Block) -> Result<Self, Error> {
+        Ok(Self {
             brace_token: default(),
-            stmts: node.stmts.map_into(),
-        }
+            stmts: node.stmts.try_map_into().context("Block", "stmts")?,
+        })
     }
 }
 syn_trait_impl!(syn::BoundLifetimes);
@@ -253,13 +276,46 @@ impl From<&syn::BoundLifetimes> for BoundLifetimes {
         }
     }
 }
-impl From<&BoundLifetimes> for syn::BoundLifetimes {
-    fn from(node: &BoundLifetimes) -> Self {
-        Self {
+impl TryFromRef<BoundLifetimes> for syn::BoundLifetimes {
+    fn try_from_ref(node: &BoundLifetimes) -> Result<Self, Error> {
+        Ok(Self {
             for_token: default(),
             lt_token: default(),
-            lifetimes: node.lifetimes.map_into(),
+            lifetimes: node
+                .lifetimes
+                .try_map_into()
+                .context("BoundLifetimes", "lifetimes")?,
             gt_token: default(),
+        })
+    }
+}
+syn_trait_impl!(syn::CapturedParam);
+impl From<&syn::CapturedParam> for CapturedParam {
+    fn from(node: &syn::CapturedParam) -> Self {
+        match node {
+            syn::CapturedParam::Lifetime(_0) => CapturedParam::Lifetime((*_0).ref_into()),
+            syn::CapturedParam::Ident(_0) => CapturedParam::Ident((*_0).ref_into()),
+            _ => unreachable!(),
+        }
+    }
+}
+impl TryFromRef<CapturedParam> for syn::CapturedParam {
+    fn try_from_ref(node: &CapturedParam) -> Result<Self, Error> {
+        match node {
+            CapturedParam::Lifetime(_0) => {
+                Ok(
+                    syn::CapturedParam::Lifetime(
+                        (*_0).try_ref_into().context("CapturedParam", "Lifetime")?,
+                    ),
+                )
+            }
+            CapturedParam::Ident(_0) => {
+                Ok(
+                    syn::CapturedParam::Ident(
+                        (*_0).try_ref_into().context("CapturedParam", "Ident")?,
+                    ),
+                )
+            }
         }
     }
 }
@@ -272,20 +328,21 @@ impl From<&syn::ConstParam> for ConstParam {
             ty: node.ty.ref_into(),
             eq_token: node.eq_token.is_some(),
             default: node.default.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ConstParam> for syn::ConstParam {
-    fn from(node: &ConstParam) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ConstParam> for syn::ConstParam {
+    fn try_from_ref(node: &ConstParam) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ConstParam", "attrs")?,
             const_token: default(),
-            ident: node.ident.ref_into(),
+            ident: node.ident.try_ref_into().context("ConstParam", "ident")?,
             colon_token: default(),
-            ty: node.ty.ref_into(),
+            ty: node.ty.try_ref_into().context("ConstParam", "ty")?,
             eq_token: default_or_none(node.eq_token),
-            default: node.default.map_into(),
-        }
+            default: node.default.try_map_into().context("ConstParam", "default")?,
+        })
     }
 }
 syn_trait_impl!(syn::Constraint);
@@ -295,19 +352,102 @@ impl From<&syn::Constraint> for Constraint {
             ident: node.ident.ref_into(),
             generics: node.generics.map_into(),
             bounds: node.bounds.map_into(),
+            span: None,
         }
     }
 }
-impl From<&Constraint> for syn::Constraint {
-    fn from(node: &Constraint) -> Self {
+impl TryFromRef<Constraint> for syn::Constraint {
+    fn try_from_ref(node: &Constraint) -> Result<Self, Error> {
+        Ok(Self {
+            ident: node.ident.try_ref_into().context("Constraint", "ident")?,
+            generics: node.generics.try_map_into().context("Constraint", "generics")?,
+            colon_token: default(),
+            bounds: node.bounds.try_map_into().context("Constraint", "bounds")?,
+        })
+    }
+}
+syn_trait_impl!(syn::Data);
+impl From<&syn::Data> for Data {
+    fn from(node: &syn::Data) -> Self {
+        match node {
+            syn::Data::Struct(_0) => Data::Struct((*_0).ref_into()),
+            syn::Data::Enum(_0) => Data::Enum((*_0).ref_into()),
+            syn::Data::Union(_0) => Data::Union((*_0).ref_into()),
+        }
+    }
+}
+impl TryFromRef<Data> for syn::Data {
+    fn try_from_ref(node: &Data) -> Result<Self, Error> {
+        match node {
+            Data::Struct(_0) => {
+                Ok(syn::Data::Struct((*_0).try_ref_into().context("Data", "Struct")?))
+            }
+            Data::Enum(_0) => {
+                Ok(syn::Data::Enum((*_0).try_ref_into().context("Data", "Enum")?))
+            }
+            Data::Union(_0) => {
+                Ok(syn::Data::Union((*_0).try_ref_into().context("Data", "Union")?))
+            }
+        }
+    }
+}
+syn_trait_impl!(syn::DataEnum);
+impl From<&syn::DataEnum> for DataEnum {
+    fn from(node: &syn::DataEnum) -> Self {
         Self {
+            variants: node.variants.map_into(),
+        }
+    }
+}
+impl TryFromRef<DataEnum> for syn::DataEnum {
+    fn try_from_ref(node: &DataEnum) -> Result<Self, Error> {
+        Ok(Self {
+            enum_token: default(),
+            brace_token: default(),
+            variants: node.variants.try_map_into().context("DataEnum", "variants")?,
+        })
+    }
+}
+syn_trait_impl!(syn::DataUnion);
+impl From<&syn::DataUnion> for DataUnion {
+    fn from(node: &syn::DataUnion) -> Self {
+        Self {
+            fields: node.fields.ref_into(),
+        }
+    }
+}
+impl TryFromRef<DataUnion> for syn::DataUnion {
+    fn try_from_ref(node: &DataUnion) -> Result<Self, Error> {
+        Ok(Self {
+            union_token: default(),
+            fields: node.fields.try_ref_into().context("DataUnion", "fields")?,
+        })
+    }
+}
+syn_trait_impl!(syn::DeriveInput);
+impl From<&syn::DeriveInput> for DeriveInput {
+    fn from(node: &syn::DeriveInput) -> Self {
+        Self {
+            attrs: node.attrs.map_into(),
+            vis: node.vis.ref_into(),
             ident: node.ident.ref_into(),
-            generics: node.generics.map_into(),
-            colon_token: default(),
-            bounds: node.bounds.map_into(),
+            generics: node.generics.ref_into(),
+            data: node.data.ref_into(),
+            span: None,
         }
     }
 }
+impl TryFromRef<DeriveInput> for syn::DeriveInput {
+    fn try_from_ref(node: &DeriveInput) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("DeriveInput", "attrs")?,
+            vis: node.vis.try_ref_into().context("DeriveInput", "vis")?,
+            ident: node.ident.try_ref_into().context("DeriveInput", "ident")?,
+            generics: node.generics.try_ref_into().context("DeriveInput", "generics")?,
+            data: node.data.try_ref_into().context("DeriveInput", "data")?,
+        })
+    }
+}
 syn_trait_impl!(syn::Expr);
 impl From<&syn::Expr> for Expr {
     fn from(node: &syn::Expr) -> Self {
@@ -339,6 +479,7 @@ impl From<&syn::Expr> for Expr {
             syn::Expr::Paren(_0) => Expr::Paren((*_0).ref_into()),
             syn::Expr::Path(_0) => Expr::Path((*_0).ref_into()),
             syn::Expr::Range(_0) => Expr::Range((*_0).ref_into()),
+            syn::Expr::RawAddr(_0) => Expr::RawAddr((*_0).ref_into()),
             syn::Expr::Reference(_0) => Expr::Reference((*_0).ref_into()),
             syn::Expr::Repeat(_0) => Expr::Repeat((*_0).ref_into()),
             syn::Expr::Return(_0) => Expr::Return((*_0).ref_into()),
@@ -355,48 +496,149 @@ impl From<&syn::Expr> for Expr {
         }
     }
 }
-impl From<&Expr> for syn::Expr {
-    fn from(node: &Expr) -> Self {
-        match node {
-            Expr::Array(_0) => syn::Expr::Array((*_0).ref_into()),
-            Expr::Assign(_0) => syn::Expr::Assign((*_0).ref_into()),
-            Expr::Async(_0) => syn::Expr::Async((*_0).ref_into()),
-            Expr::Await(_0) => syn::Expr::Await((*_0).ref_into()),
-            Expr::Binary(_0) => syn::Expr::Binary((*_0).ref_into()),
-            Expr::Block(_0) => syn::Expr::Block((*_0).ref_into()),
-            Expr::Break(_0) => syn::Expr::Break((*_0).ref_into()),
-            Expr::Call(_0) => syn::Expr::Call((*_0).ref_into()),
-            Expr::Cast(_0) => syn::Expr::Cast((*_0).ref_into()),
-            Expr::Closure(_0) => syn::Expr::Closure((*_0).ref_into()),
-            Expr::Const(_0) => syn::Expr::Const((*_0).ref_into()),
-            Expr::Continue(_0) => syn::Expr::Continue((*_0).ref_into()),
-            Expr::Field(_0) => syn::Expr::Field((*_0).ref_into()),
-            Expr::ForLoop(_0) => syn::Expr::ForLoop((*_0).ref_into()),
-            Expr::Group(_0) => syn::Expr::Group((*_0).ref_into()),
-            Expr::If(_0) => syn::Expr::If((*_0).ref_into()),
-            Expr::Index(_0) => syn::Expr::Index((*_0).ref_into()),
-            Expr::Infer(_0) => syn::Expr::Infer((*_0).ref_into()),
-            Expr::Let(_0) => syn::Expr::Let((*_0).ref_into()),
-            Expr::Lit(_0) => syn::Expr::Lit((*_0).ref_into()),
-            Expr::Loop(_0) => syn::Expr::Loop((*_0).ref_into()),
-            Expr::Macro(_0) => syn::Expr::Macro((*_0).ref_into()),
-            Expr::Match(_0) => syn::Expr::Match((*_0).ref_into()),
-            Expr::MethodCall(_0) => syn::Expr::MethodCall((*_0).ref_into()),
-            Expr::Paren(_0) => syn::Expr::Paren((*_0).ref_into()),
-            Expr::Path(_0) => syn::Expr::Path((*_0).ref_into()),
-            Expr::Range(_0) => syn::Expr::Range((*_0).ref_into()),
-            Expr::Reference(_0) => syn::Expr::Reference((*_0).ref_into()),
-            Expr::Repeat(_0) => syn::Expr::Repeat((*_0).ref_into()),
-            Expr::Return(_0) => syn::Expr::Return((*_0).ref_into()),
-            Expr::Struct(_0) => syn::Expr::Struct((*_0).ref_into()),
-            Expr::Try(_0) => syn::Expr::Try((*_0).ref_into()),
-            Expr::TryBlock(_0) => syn::Expr::TryBlock((*_0).ref_into()),
-            Expr::Tuple(_0) => syn::Expr::Tuple((*_0).ref_into()),
-            Expr::Unary(_0) => syn::Expr::Unary((*_0).ref_into()),
-            Expr::Unsafe(_0) => syn::Expr::Unsafe((*_0).ref_into()),
-            Expr::Verbatim(_0) => syn::Expr::Verbatim((*_0).ref_into()),
-            Expr::While(_0) => syn::Expr::While((*_0).ref_into()),
-            Expr::Yield(_0) => syn::Expr::Yield((*_0).ref_into()),
+impl TryFromRef<Expr> for syn::Expr {
+    fn try_from_ref(node: &Expr) -> Result<Self, Error> {
+        match node {
+            Expr::Array(_0) => {
+                Ok(syn::Expr::Array((*_0).try_ref_into().context("Expr", "Array")?))
+            }
+            Expr::Assign(_0) => {
+                Ok(syn::Expr::Assign((*_0).try_ref_into().context("Expr", "Assign")?))
+            }
+            Expr::Async(_0) => {
+                Ok(syn::Expr::Async((*_0).try_ref_into().context("Expr", "Async")?))
+            }
+            Expr::Await(_0) => {
+                Ok(syn::Expr::Await((*_0).try_ref_into().context("Expr", "Await")?))
+            }
+            Expr::Binary(_0) => {
+                Ok(syn::Expr::Binary((*_0).try_ref_into().context("Expr", "Binary")?))
+            }
+            Expr::Block(_0) => {
+                Ok(syn::Expr::Block((*_0).try_ref_into().context("Expr", "Block")?))
+            }
+            Expr::Break(_0) => {
+                Ok(syn::Expr::Break((*_0).try_ref_into().context("Expr", "Break")?))
+            }
+            Expr::Call(_0) => {
+                Ok(syn::Expr::Call((*_0).try_ref_into().context("Expr", "Call")?))
+            }
+            Expr::Cast(_0) => {
+                Ok(syn::Expr::Cast((*_0).try_ref_into().context("Expr", "Cast")?))
+            }
+            Expr::Closure(_0) => {
+                Ok(syn::Expr::Closure((*_0).try_ref_into().context("Expr", "Closure")?))
+            }
+            Expr::Const(_0) => {
+                Ok(syn::Expr::Const((*_0).try_ref_into().context("Expr", "Const")?))
+            }
+            Expr::Continue(_0) => {
+                Ok(
+                    syn::Expr::Continue(
+                        (*_0).try_ref_into().context("Expr", "Continue")?,
+                    ),
+                )
+            }
+            Expr::Field(_0) => {
+                Ok(syn::Expr::Field((*_0).try_ref_into().context("Expr", "Field")?))
+            }
+            Expr::ForLoop(_0) => {
+                Ok(syn::Expr::ForLoop((*_0).try_ref_into().context("Expr", "ForLoop")?))
+            }
+            Expr::Group(_0) => {
+                Ok(syn::Expr::Group((*_0).try_ref_into().context("Expr", "Group")?))
+            }
+            Expr::If(_0) => {
+                Ok(syn::Expr::If((*_0).try_ref_into().context("Expr", "If")?))
+            }
+            Expr::Index(_0) => {
+                Ok(syn::Expr::Index((*_0).try_ref_into().context("Expr", "Index")?))
+            }
+            Expr::Infer(_0) => {
+                Ok(syn::Expr::Infer((*_0).try_ref_into().context("Expr", "Infer")?))
+            }
+            Expr::Let(_0) => {
+                Ok(syn::Expr::Let((*_0).try_ref_into().context("Expr", "Let")?))
+            }
+            Expr::Lit(_0) => {
+                Ok(syn::Expr::Lit((*_0).try_ref_into().context("Expr", "Lit")?))
+            }
+            Expr::Loop(_0) => {
+                Ok(syn::Expr::Loop((*_0).try_ref_into().context("Expr", "Loop")?))
+            }
+            Expr::Macro(_0) => {
+                Ok(syn::Expr::Macro((*_0).try_ref_into().context("Expr", "Macro")?))
+            }
+            Expr::Match(_0) => {
+                Ok(syn::Expr::Match((*_0).try_ref_into().context("Expr", "Match")?))
+            }
+            Expr::MethodCall(_0) => {
+                Ok(
+                    syn::Expr::MethodCall(
+                        (*_0).try_ref_into().context("Expr", "MethodCall")?,
+                    ),
+                )
+            }
+            Expr::Paren(_0) => {
+                Ok(syn::Expr::Paren((*_0).try_ref_into().context("Expr", "Paren")?))
+            }
+            Expr::Path(_0) => {
+                Ok(syn::Expr::Path((*_0).try_ref_into().context("Expr", "Path")?))
+            }
+            Expr::Range(_0) => {
+                Ok(syn::Expr::Range((*_0).try_ref_into().context("Expr", "Range")?))
+            }
+            Expr::RawAddr(_0) => {
+                Ok(syn::Expr::RawAddr((*_0).try_ref_into().context("Expr", "RawAddr")?))
+            }
+            Expr::Reference(_0) => {
+                Ok(
+                    syn::Expr::Reference(
+                        (*_0).try_ref_into().context("Expr", "Reference")?,
+                    ),
+                )
+            }
+            Expr::Repeat(_0) => {
+                Ok(syn::Expr::Repeat((*_0).try_ref_into().context("Expr", "Repeat")?))
+            }
+            Expr::Return(_0) => {
+                Ok(syn::Expr::Return((*_0).try_ref_into().context("Expr", "Return")?))
+            }
+            Expr::Struct(_0) => {
+                Ok(syn::Expr::Struct((*_0).try_ref_into().context("Expr", "Struct")?))
+            }
+            Expr::Try(_0) => {
+                Ok(syn::Expr::Try((*_0).try_ref_into().context("Expr", "Try")?))
+            }
+            Expr::TryBlock(_0) => {
+                Ok(
+                    syn::Expr::TryBlock(
+                        (*_0).try_ref_into().context("Expr", "TryBlock")?,
+                    ),
+                )
+            }
+            Expr::Tuple(_0) => {
+                Ok(syn::Expr::Tuple((*_0).try_ref_into().context("Expr", "Tuple")?))
+            }
+            Expr::Unary(_0) => {
+                Ok(syn::Expr::Unary((*_0).try_ref_into().context("Expr", "Unary")?))
+            }
+            Expr::Unsafe(_0) => {
+                Ok(syn::Expr::Unsafe((*_0).try_ref_into().context("Expr", "Unsafe")?))
+            }
+            Expr::Verbatim(_0) => {
+                Ok(
+                    syn::Expr::Verbatim(
+                        (*_0).try_ref_into().context("Expr", "Verbatim")?,
+                    ),
+                )
+            }
+            Expr::While(_0) => {
+                Ok(syn::Expr::While((*_0).try_ref_into().context("Expr", "While")?))
+            }
+            Expr::Yield(_0) => {
+                Ok(syn::Expr::Yield((*_0).try_ref_into().context("Expr", "Yield")?))
+            }
         }
     }
 }
@@ -406,16 +648,17 @@ impl From<&syn::ExprArray> for ExprArray {
         Self {
             attrs: node.attrs.map_into(),
             elems: node.elems.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprArray> for syn::ExprArray {
-    fn from(node: &ExprArray) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprArray> for syn::ExprArray {
+    fn try_from_ref(node: &ExprArray) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprArray", "attrs")?,
             bracket_token: default(),
-            elems: node.elems.map_into(),
-        }
+            elems: node.elems.try_map_into().context("ExprArray", "elems")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprAssign);
@@ -425,17 +668,18 @@ impl From<&syn::ExprAssign> for ExprAssign {
             attrs: node.attrs.map_into(),
             left: node.left.map_into(),
             right: node.right.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprAssign> for syn::ExprAssign {
-    fn from(node: &ExprAssign) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            left: node.left.map_into(),
+impl TryFromRef<ExprAssign> for syn::ExprAssign {
+    fn try_from_ref(node: &ExprAssign) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprAssign", "attrs")?,
+            left: node.left.try_map_into().context("ExprAssign", "left")?,
             eq_token: default(),
-            right: node.right.map_into(),
-        }
+            right: node.right.try_map_into().context("ExprAssign", "right")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprAsync);
@@ -445,17 +689,18 @@ impl From<&syn::ExprAsync> for ExprAsync {
             attrs: node.attrs.map_into(),
             capture: node.capture.is_some(),
             block: node.block.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprAsync> for syn::ExprAsync {
-    fn from(node: &ExprAsync) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprAsync> for syn::ExprAsync {
+    fn try_from_ref(node: &ExprAsync) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprAsync", "attrs")?,
             async_token: default(),
             capture: default_or_none(node.capture),
-            block: node.block.ref_into(),
-        }
+            block: node.block.try_ref_into().context("ExprAsync", "block")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprAwait);
@@ -464,17 +709,18 @@ impl From<&syn::ExprAwait> for ExprAwait {
         Self {
             attrs: node.attrs.map_into(),
             base: node.base.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprAwait> for syn::ExprAwait {
-    fn from(node: &ExprAwait) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            base: node.base.map_into(),
+impl TryFromRef<ExprAwait> for syn::ExprAwait {
+    fn try_from_ref(node: &ExprAwait) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprAwait", "attrs")?,
+            base: node.base.try_map_into().context("ExprAwait", "base")?,
             dot_token: default(),
             await_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ExprBinary);
@@ -485,17 +731,18 @@ impl From<&syn::ExprBinary> for ExprBinary {
             left: node.left.map_into(),
             op: node.op.ref_into(),
             right: node.right.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprBinary> for syn::ExprBinary {
-    fn from(node: &ExprBinary) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            left: node.left.map_into(),
-            op: node.op.ref_into(),
-            right: node.right.map_into(),
-        }
+impl TryFromRef<ExprBinary> for syn::ExprBinary {
+    fn try_from_ref(node: &ExprBinary) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprBinary", "attrs")?,
+            left: node.left.try_map_into().context("ExprBinary", "left")?,
+            op: node.op.try_ref_into().context("ExprBinary", "op")?,
+            right: node.right.try_map_into().context("ExprBinary", "right")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprBlock);
@@ -505,16 +752,17 @@ impl From<&syn::ExprBlock> for ExprBlock {
             attrs: node.attrs.map_into(),
             label: node.label.map_into(),
             block: node.block.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprBlock> for syn::ExprBlock {
-    fn from(node: &ExprBlock) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            label: node.label.map_into(),
-            block: node.block.ref_into(),
-        }
+impl TryFromRef<ExprBlock> for syn::ExprBlock {
+    fn try_from_ref(node: &ExprBlock) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprBlock", "attrs")?,
+            label: node.label.try_map_into().context("ExprBlock", "label")?,
+            block: node.block.try_ref_into().context("ExprBlock", "block")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprBreak);
@@ -524,17 +772,21 @@ impl From<&syn::ExprBreak> for ExprBreak {
             attrs: node.attrs.map_into(),
             label: node.label.map_into(),
             expr: node.expr.ref_map(MapInto::map_into),
+            span: None,
         }
     }
 }
-impl From<&ExprBreak> for syn::ExprBreak {
-    fn from(node: &ExprBreak) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprBreak> for syn::ExprBreak {
+    fn try_from_ref(node: &ExprBreak) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprBreak", "attrs")?,
             break_token: default(),
-            label: node.label.map_into(),
-            expr: node.expr.ref_map(MapInto::map_into),
-        }
+            label: node.label.try_map_into().context("ExprBreak", "label")?,
+            expr: node
+                .expr
+                .try_ref_map(TryMapInto::try_map_into)
+                .context("ExprBreak", "expr")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprCall);
@@ -544,17 +796,18 @@ impl From<&syn::ExprCall> for ExprCall {
             attrs: node.attrs.map_into(),
             func: node.func.map_into(),
             args: node.args.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprCall> for syn::ExprCall {
-    fn from(node: &ExprCall) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            func: node.func.map_into(),
+impl TryFromRef<ExprCall> for syn::ExprCall {
+    fn try_from_ref(node: &ExprCall) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprCall", "attrs")?,
+            func: node.func.try_map_into().context("ExprCall", "func")?,
             paren_token: default(),
-            args: node.args.map_into(),
-        }
+            args: node.args.try_map_into().context("ExprCall", "args")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprCast);
@@ -564,17 +817,18 @@ impl From<&syn::ExprCast> for ExprCast {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
             ty: node.ty.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprCast> for syn::ExprCast {
-    fn from(node: &ExprCast) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            expr: node.expr.map_into(),
+impl TryFromRef<ExprCast> for syn::ExprCast {
+    fn try_from_ref(node: &ExprCast) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprCast", "attrs")?,
+            expr: node.expr.try_map_into().context("ExprCast", "expr")?,
             as_token: default(),
-            ty: node.ty.map_into(),
-        }
+            ty: node.ty.try_map_into().context("ExprCast", "ty")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprClosure);
@@ -590,24 +844,28 @@ impl From<&syn::ExprClosure> for ExprClosure {
             inputs: node.inputs.map_into(),
             output: node.output.ref_into(),
             body: node.body.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprClosure> for syn::ExprClosure {
-    fn from(node: &ExprClosure) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            lifetimes: node.lifetimes.map_into(),
+impl TryFromRef<ExprClosure> for syn::ExprClosure {
+    fn try_from_ref(node: &ExprClosure) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprClosure", "attrs")?,
+            lifetimes: node
+                .lifetimes
+                .try_map_into()
+                .context("ExprClosure", "lifetimes")?,
             constness: default_or_none(node.constness),
             movability: default_or_none(node.movability),
             asyncness: default_or_none(node.asyncness),
             capture: default_or_none(node.capture),
             or1_token: default(),
-            inputs: node.inputs.map_into(),
+            inputs: node.inputs.try_map_into().context("ExprClosure", "inputs")?,
             or2_token: default(),
-            output: node.output.ref_into(),
-            body: node.body.map_into(),
-        }
+            output: node.output.try_ref_into().context("ExprClosure", "output")?,
+            body: node.body.try_map_into().context("ExprClosure", "body")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprConst);
@@ -616,16 +874,17 @@ impl From<&syn::ExprConst> for ExprConst {
         Self {
             attrs: node.attrs.map_into(),
             block: node.block.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprConst> for syn::ExprConst {
-    fn from(node: &ExprConst) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprConst> for syn::ExprConst {
+    fn try_from_ref(node: &ExprConst) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprConst", "attrs")?,
             const_token: default(),
-            block: node.block.ref_into(),
-        }
+            block: node.block.try_ref_into().context("ExprConst", "block")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprContinue);
@@ -634,16 +893,17 @@ impl From<&syn::ExprContinue> for ExprContinue {
         Self {
             attrs: node.attrs.map_into(),
             label: node.label.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprContinue> for syn::ExprContinue {
-    fn from(node: &ExprContinue) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprContinue> for syn::ExprContinue {
+    fn try_from_ref(node: &ExprContinue) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprContinue", "attrs")?,
             continue_token: default(),
-            label: node.label.map_into(),
-        }
+            label: node.label.try_map_into().context("ExprContinue", "label")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprField);
@@ -653,17 +913,18 @@ impl From<&syn::ExprField> for ExprField {
             attrs: node.attrs.map_into(),
             base: node.base.map_into(),
             member: node.member.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprField> for syn::ExprField {
-    fn from(node: &ExprField) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            base: node.base.map_into(),
+impl TryFromRef<ExprField> for syn::ExprField {
+    fn try_from_ref(node: &ExprField) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprField", "attrs")?,
+            base: node.base.try_map_into().context("ExprField", "base")?,
             dot_token: default(),
-            member: node.member.ref_into(),
-        }
+            member: node.member.try_ref_into().context("ExprField", "member")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprForLoop);
@@ -675,20 +936,21 @@ impl From<&syn::ExprForLoop> for ExprForLoop {
             pat: node.pat.map_into(),
             expr: node.expr.map_into(),
             body: node.body.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprForLoop> for syn::ExprForLoop {
-    fn from(node: &ExprForLoop) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            label: node.label.map_into(),
+impl TryFromRef<ExprForLoop> for syn::ExprForLoop {
+    fn try_from_ref(node: &ExprForLoop) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprForLoop", "attrs")?,
+            label: node.label.try_map_into().context("ExprForLoop", "label")?,
             for_token: default(),
-            pat: node.pat.map_into(),
+            pat: node.pat.try_map_into().context("ExprForLoop", "pat")?,
             in_token: default(),
-            expr: node.expr.map_into(),
-            body: node.body.ref_into(),
-        }
+            expr: node.expr.try_map_into().context("ExprForLoop", "expr")?,
+            body: node.body.try_ref_into().context("ExprForLoop", "body")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprGroup);
@@ -697,16 +959,17 @@ impl From<&syn::ExprGroup> for ExprGroup {
         Self {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprGroup> for syn::ExprGroup {
-    fn from(node: &ExprGroup) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprGroup> for syn::ExprGroup {
+    fn try_from_ref(node: &ExprGroup) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprGroup", "attrs")?,
             group_token: default(),
-            expr: node.expr.map_into(),
-        }
+            expr: node.expr.try_map_into().context("ExprGroup", "expr")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprIf);
@@ -717,18 +980,27 @@ impl From<&syn::ExprIf> for ExprIf {
             cond: node.cond.map_into(),
             then_branch: node.then_branch.ref_into(),
             else_branch: node.else_branch.ref_map(|(_0, _1)| (*_1).map_into()),
+            span: None,
         }
     }
 }
-impl From<&ExprIf> for syn::ExprIf {
-    fn from(node: &ExprIf) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprIf> for syn::ExprIf {
+    fn try_from_ref(node: &ExprIf) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprIf", "attrs")?,
             if_token: default(),
-            cond: node.cond.map_into(),
-            then_branch: node.then_branch.ref_into(),
-            else_branch: node.else_branch.ref_map(|_1| (default(), (*_1).map_into())),
-        }
+            cond: node.cond.try_map_into().context("ExprIf", "cond")?,
+            then_branch: node
+                .then_branch
+                .try_ref_into()
+                .context("ExprIf", "then_branch")?,
+            else_branch: node
+                .else_branch
+                .try_ref_map(|_1| Ok((
+                    default(),
+                    (*_1).try_map_into().context("ExprIf", "else_branch")?,
+                )))?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprIndex);
@@ -738,17 +1010,18 @@ impl From<&syn::ExprIndex> for ExprIndex {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
             index: node.index.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprIndex> for syn::ExprIndex {
-    fn from(node: &ExprIndex) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            expr: node.expr.map_into(),
+impl TryFromRef<ExprIndex> for syn::ExprIndex {
+    fn try_from_ref(node: &ExprIndex) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprIndex", "attrs")?,
+            expr: node.expr.try_map_into().context("ExprIndex", "expr")?,
             bracket_token: default(),
-            index: node.index.map_into(),
-        }
+            index: node.index.try_map_into().context("ExprIndex", "index")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprInfer);
@@ -756,15 +1029,16 @@ impl From<&syn::ExprInfer> for ExprInfer {
     fn from(node: &syn::ExprInfer) -> Self {
         Self {
             attrs: node.attrs.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprInfer> for syn::ExprInfer {
-    fn from(node: &ExprInfer) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprInfer> for syn::ExprInfer {
+    fn try_from_ref(node: &ExprInfer) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprInfer", "attrs")?,
             underscore_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ExprLet);
@@ -774,18 +1048,19 @@ impl From<&syn::ExprLet> for ExprLet {
             attrs: node.attrs.map_into(),
             pat: node.pat.map_into(),
             expr: node.expr.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprLet> for syn::ExprLet {
-    fn from(node: &ExprLet) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprLet> for syn::ExprLet {
+    fn try_from_ref(node: &ExprLet) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprLet", "attrs")?,
             let_token: default(),
-            pat: node.pat.map_into(),
+            pat: node.pat.try_map_into().context("ExprLet", "pat")?,
             eq_token: default(),
-            expr: node.expr.map_into(),
-        }
+            expr: node.expr.try_map_into().context("ExprLet", "expr")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprLit);
@@ -794,15 +1069,16 @@ impl From<&syn::ExprLit> for ExprLit {
         Self {
             attrs: node.attrs.map_into(),
             lit: node.lit.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprLit> for syn::ExprLit {
-    fn from(node: &ExprLit) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            lit: node.lit.ref_into(),
-        }
+impl TryFromRef<ExprLit> for syn::ExprLit {
+    fn try_from_ref(node: &ExprLit) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprLit", "attrs")?,
+            lit: node.lit.try_ref_into().context("ExprLit", "lit")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprLoop);
@@ -812,17 +1088,18 @@ impl From<&syn::ExprLoop> for ExprLoop {
             attrs: node.attrs.map_into(),
             label: node.label.map_into(),
             body: node.body.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprLoop> for syn::ExprLoop {
-    fn from(node: &ExprLoop) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            label: node.label.map_into(),
+impl TryFromRef<ExprLoop> for syn::ExprLoop {
+    fn try_from_ref(node: &ExprLoop) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprLoop", "attrs")?,
+            label: node.label.try_map_into().context("ExprLoop", "label")?,
             loop_token: default(),
-            body: node.body.ref_into(),
-        }
+            body: node.body.try_ref_into().context("ExprLoop", "body")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprMacro);
@@ -831,15 +1108,16 @@ impl From<&syn::ExprMacro> for ExprMacro {
         Self {
             attrs: node.attrs.map_into(),
             mac: node.mac.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprMacro> for syn::ExprMacro {
-    fn from(node: &ExprMacro) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            mac: node.mac.ref_into(),
-        }
+impl TryFromRef<ExprMacro> for syn::ExprMacro {
+    fn try_from_ref(node: &ExprMacro) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprMacro", "attrs")?,
+            mac: node.mac.try_ref_into().context("ExprMacro", "mac")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprMethodCall);
@@ -851,20 +1129,27 @@ impl From<&syn::ExprMethodCall> for ExprMethodCall {
             method: node.method.ref_into(),
             turbofish: node.turbofish.map_into(),
             args: node.args.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprMethodCall> for syn::ExprMethodCall {
-    fn from(node: &ExprMethodCall) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            receiver: node.receiver.map_into(),
+impl TryFromRef<ExprMethodCall> for syn::ExprMethodCall {
+    fn try_from_ref(node: &ExprMethodCall) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprMethodCall", "attrs")?,
+            receiver: node
+                .receiver
+                .try_map_into()
+                .context("ExprMethodCall", "receiver")?,
             dot_token: default(),
-            method: node.method.ref_into(),
-            turbofish: node.turbofish.map_into(),
+            method: node.method.try_ref_into().context("ExprMethodCall", "method")?,
+            turbofish: node
+                .turbofish
+                .try_map_into()
+                .context("ExprMethodCall", "turbofish")?,
             paren_token: default(),
-            args: node.args.map_into(),
-        }
+            args: node.args.try_map_into().context("ExprMethodCall", "args")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprParen);
@@ -873,16 +1158,17 @@ impl From<&syn::ExprParen> for ExprParen {
         Self {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprParen> for syn::ExprParen {
-    fn from(node: &ExprParen) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprParen> for syn::ExprParen {
+    fn try_from_ref(node: &ExprParen) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprParen", "attrs")?,
             paren_token: default(),
-            expr: node.expr.map_into(),
-        }
+            expr: node.expr.try_map_into().context("ExprParen", "expr")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprPath);
@@ -892,16 +1178,17 @@ impl From<&syn::ExprPath> for ExprPath {
             attrs: node.attrs.map_into(),
             qself: node.qself.map_into(),
             path: node.path.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprPath> for syn::ExprPath {
-    fn from(node: &ExprPath) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            qself: node.qself.map_into(),
-            path: node.path.ref_into(),
-        }
+impl TryFromRef<ExprPath> for syn::ExprPath {
+    fn try_from_ref(node: &ExprPath) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprPath", "attrs")?,
+            qself: node.qself.try_map_into().context("ExprPath", "qself")?,
+            path: node.path.try_ref_into().context("ExprPath", "path")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprRange);
@@ -912,19 +1199,51 @@ impl From<&syn::ExprRange> for ExprRange {
             start: node.start.ref_map(MapInto::map_into),
             limits: node.limits.ref_into(),
             end: node.end.ref_map(MapInto::map_into),
+            span: None,
         }
     }
 }
-impl From<&ExprRange> for syn::ExprRange {
-    fn from(node: &ExprRange) -> Self {
+impl TryFromRef<ExprRange> for syn::ExprRange {
+    fn try_from_ref(node: &ExprRange) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprRange", "attrs")?,
+            start: node
+                .start
+                .try_ref_map(TryMapInto::try_map_into)
+                .context("ExprRange", "start")?,
+            limits: node.limits.try_ref_into().context("ExprRange", "limits")?,
+            end: node
+                .end
+                .try_ref_map(TryMapInto::try_map_into)
+                .context("ExprRange", "end")?,
+        })
+    }
+}
+syn_trait_impl!(syn::ExprRawAddr);
+impl From<&syn::ExprRawAddr> for ExprRawAddr {
+    fn from(node: &syn::ExprRawAddr) -> Self {
         Self {
             attrs: node.attrs.map_into(),
-            start: node.start.ref_map(MapInto::map_into),
-            limits: node.limits.ref_into(),
-            end: node.end.ref_map(MapInto::map_into),
+            mutability: node.mutability.ref_into(),
+            expr: node.expr.map_into(),
+            span: None,
         }
     }
 }
+impl TryFromRef<ExprRawAddr> for syn::ExprRawAddr {
+    fn try_from_ref(node: &ExprRawAddr) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprRawAddr", "attrs")?,
+            and_token: default(),
+            raw: default(),
+            mutability: node
+                .mutability
+                .try_ref_into()
+                .context("ExprRawAddr", "mutability")?,
+            expr: node.expr.try_map_into().context("ExprRawAddr", "expr")?,
+        })
+    }
+}
 syn_trait_impl!(syn::ExprReference);
 impl From<&syn::ExprReference> for ExprReference {
     fn from(node: &syn::ExprReference) -> Self {
@@ -932,17 +1251,18 @@ impl From<&syn::ExprReference> for ExprReference {
             attrs: node.attrs.map_into(),
             mutability: node.mutability.is_some(),
             expr: node.expr.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprReference> for syn::ExprReference {
-    fn from(node: &ExprReference) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprReference> for syn::ExprReference {
+    fn try_from_ref(node: &ExprReference) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprReference", "attrs")?,
             and_token: default(),
             mutability: default_or_none(node.mutability),
-            expr: node.expr.map_into(),
-        }
+            expr: node.expr.try_map_into().context("ExprReference", "expr")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprRepeat);
@@ -952,18 +1272,19 @@ impl From<&syn::ExprRepeat> for ExprRepeat {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
             len: node.len.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprRepeat> for syn::ExprRepeat {
-    fn from(node: &ExprRepeat) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprRepeat> for syn::ExprRepeat {
+    fn try_from_ref(node: &ExprRepeat) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprRepeat", "attrs")?,
             bracket_token: default(),
-            expr: node.expr.map_into(),
+            expr: node.expr.try_map_into().context("ExprRepeat", "expr")?,
             semi_token: default(),
-            len: node.len.map_into(),
-        }
+            len: node.len.try_map_into().context("ExprRepeat", "len")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprReturn);
@@ -972,16 +1293,20 @@ impl From<&syn::ExprReturn> for ExprReturn {
         Self {
             attrs: node.attrs.map_into(),
             expr: node.expr.ref_map(MapInto::map_into),
+            span: None,
         }
     }
 }
-impl From<&ExprReturn> for syn::ExprReturn {
-    fn from(node: &ExprReturn) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprReturn> for syn::ExprReturn {
+    fn try_from_ref(node: &ExprReturn) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprReturn", "attrs")?,
             return_token: default(),
-            expr: node.expr.ref_map(MapInto::map_into),
-        }
+            expr: node
+                .expr
+                .try_ref_map(TryMapInto::try_map_into)
+                .context("ExprReturn", "expr")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprStruct);
@@ -994,20 +1319,24 @@ impl From<&syn::ExprStruct> for ExprStruct {
             fields: node.fields.map_into(),
             dot2_token: node.dot2_token.is_some(),
             rest: node.rest.ref_map(MapInto::map_into),
+            span: None,
         }
     }
 }
-impl From<&ExprStruct> for syn::ExprStruct {
-    fn from(node: &ExprStruct) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            qself: node.qself.map_into(),
-            path: node.path.ref_into(),
+impl TryFromRef<ExprStruct> for syn::ExprStruct {
+    fn try_from_ref(node: &ExprStruct) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprStruct", "attrs")?,
+            qself: node.qself.try_map_into().context("ExprStruct", "qself")?,
+            path: node.path.try_ref_into().context("ExprStruct", "path")?,
             brace_token: default(),
-            fields: node.fields.map_into(),
+            fields: node.fields.try_map_into().context("ExprStruct", "fields")?,
             dot2_token: default_or_none(node.dot2_token),
-            rest: node.rest.ref_map(MapInto::map_into),
-        }
+            rest: node
+                .rest
+                .try_ref_map(TryMapInto::try_map_into)
+                .context("ExprStruct", "rest")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprTry);
@@ -1016,16 +1345,17 @@ impl From<&syn::ExprTry> for ExprTry {
         Self {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprTry> for syn::ExprTry {
-    fn from(node: &ExprTry) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            expr: node.expr.map_into(),
+impl TryFromRef<ExprTry> for syn::ExprTry {
+    fn try_from_ref(node: &ExprTry) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprTry", "attrs")?,
+            expr: node.expr.try_map_into().context("ExprTry", "expr")?,
             question_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ExprTryBlock);
@@ -1034,16 +1364,17 @@ impl From<&syn::ExprTryBlock> for ExprTryBlock {
         Self {
             attrs: node.attrs.map_into(),
             block: node.block.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprTryBlock> for syn::ExprTryBlock {
-    fn from(node: &ExprTryBlock) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprTryBlock> for syn::ExprTryBlock {
+    fn try_from_ref(node: &ExprTryBlock) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprTryBlock", "attrs")?,
             try_token: default(),
-            block: node.block.ref_into(),
-        }
+            block: node.block.try_ref_into().context("ExprTryBlock", "block")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprTuple);
@@ -1052,16 +1383,17 @@ impl From<&syn::ExprTuple> for ExprTuple {
         Self {
             attrs: node.attrs.map_into(),
             elems: node.elems.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprTuple> for syn::ExprTuple {
-    fn from(node: &ExprTuple) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprTuple> for syn::ExprTuple {
+    fn try_from_ref(node: &ExprTuple) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprTuple", "attrs")?,
             paren_token: default(),
-            elems: node.elems.map_into(),
-        }
+            elems: node.elems.try_map_into().context("ExprTuple", "elems")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprUnary);
@@ -1071,16 +1403,17 @@ impl From<&syn::ExprUnary> for ExprUnary {
             attrs: node.attrs.map_into(),
             op: node.op.ref_into(),
             expr: node.expr.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprUnary> for syn::ExprUnary {
-    fn from(node: &ExprUnary) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            op: node.op.ref_into(),
-            expr: node.expr.map_into(),
-        }
+impl TryFromRef<ExprUnary> for syn::ExprUnary {
+    fn try_from_ref(node: &ExprUnary) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprUnary", "attrs")?,
+            op: node.op.try_ref_into().context("ExprUnary", "op")?,
+            expr: node.expr.try_map_into().context("ExprUnary", "expr")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprUnsafe);
@@ -1089,16 +1422,17 @@ impl From<&syn::ExprUnsafe> for ExprUnsafe {
         Self {
             attrs: node.attrs.map_into(),
             block: node.block.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprUnsafe> for syn::ExprUnsafe {
-    fn from(node: &ExprUnsafe) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprUnsafe> for syn::ExprUnsafe {
+    fn try_from_ref(node: &ExprUnsafe) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprUnsafe", "attrs")?,
             unsafe_token: default(),
-            block: node.block.ref_into(),
-        }
+            block: node.block.try_ref_into().context("ExprUnsafe", "block")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprWhile);
@@ -1109,18 +1443,19 @@ impl From<&syn::ExprWhile> for ExprWhile {
             label: node.label.map_into(),
             cond: node.cond.map_into(),
             body: node.body.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ExprWhile> for syn::ExprWhile {
-    fn from(node: &ExprWhile) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            label: node.label.map_into(),
-            while_token: default(),
-            cond: node.cond.map_into(),
-            body: node.body.ref_into(),
-        }
+impl TryFromRef<ExprWhile> for syn::ExprWhile {
+    fn try_from_ref(node: &ExprWhile) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprWhile", "attrs")?,
+            label: node.label.try_map_into().context("ExprWhile", "label")?,
+            while_token: default(),
+            cond: node.cond.try_map_into().context("ExprWhile", "cond")?,
+            body: node.body.try_ref_into().context("ExprWhile", "body")?,
+        })
     }
 }
 syn_trait_impl!(syn::ExprYield);
@@ -1129,16 +1464,20 @@ impl From<&syn::ExprYield> for ExprYield {
         Self {
             attrs: node.attrs.map_into(),
             expr: node.expr.ref_map(MapInto::map_into),
+            span: None,
         }
     }
 }
-impl From<&ExprYield> for syn::ExprYield {
-    fn from(node: &ExprYield) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ExprYield> for syn::ExprYield {
+    fn try_from_ref(node: &ExprYield) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ExprYield", "attrs")?,
             yield_token: default(),
-            expr: node.expr.ref_map(MapInto::map_into),
-        }
+            expr: node
+                .expr
+                .try_ref_map(TryMapInto::try_map_into)
+                .context("ExprYield", "expr")?,
+        })
     }
 }
 syn_trait_impl!(syn::Field);
@@ -1151,19 +1490,20 @@ impl From<&syn::Field> for Field {
             ident: node.ident.map_into(),
             colon_token: node.colon_token.is_some(),
             ty: node.ty.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&Field> for syn::Field {
-    fn from(node: &Field) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
-            mutability: node.mutability.ref_into(),
-            ident: node.ident.map_into(),
+impl TryFromRef<Field> for syn::Field {
+    fn try_from_ref(node: &Field) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("Field", "attrs")?,
+            vis: node.vis.try_ref_into().context("Field", "vis")?,
+            mutability: node.mutability.try_ref_into().context("Field", "mutability")?,
+            ident: node.ident.try_map_into().context("Field", "ident")?,
             colon_token: default_or_none(node.colon_token),
-            ty: node.ty.ref_into(),
-        }
+            ty: node.ty.try_ref_into().context("Field", "ty")?,
+        })
     }
 }
 syn_trait_impl!(syn::FieldMutability);
@@ -1175,10 +1515,10 @@ impl From<&syn::FieldMutability> for FieldMutability {
         }
     }
 }
-impl From<&FieldMutability> for syn::FieldMutability {
-    fn from(node: &FieldMutability) -> Self {
+impl TryFromRef<FieldMutability> for syn::FieldMutability {
+    fn try_from_ref(node: &FieldMutability) -> Result<Self, Error> {
         match node {
-            FieldMutability::None => syn::FieldMutability::None,
+            FieldMutability::None => Ok(syn::FieldMutability::None),
         }
     }
 }
@@ -1190,17 +1530,18 @@ impl From<&syn::FieldPat> for FieldPat {
             member: node.member.ref_into(),
             colon_token: node.colon_token.is_some(),
             pat: node.pat.map_into(),
+            span: None,
         }
     }
 }
-impl From<&FieldPat> for syn::FieldPat {
-    fn from(node: &FieldPat) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            member: node.member.ref_into(),
+impl TryFromRef<FieldPat> for syn::FieldPat {
+    fn try_from_ref(node: &FieldPat) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("FieldPat", "attrs")?,
+            member: node.member.try_ref_into().context("FieldPat", "member")?,
             colon_token: default_or_none(node.colon_token),
-            pat: node.pat.map_into(),
-        }
+            pat: node.pat.try_map_into().context("FieldPat", "pat")?,
+        })
     }
 }
 syn_trait_impl!(syn::FieldValue);
@@ -1211,17 +1552,18 @@ impl From<&syn::FieldValue> for FieldValue {
             member: node.member.ref_into(),
             colon_token: node.colon_token.is_some(),
             expr: node.expr.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&FieldValue> for syn::FieldValue {
-    fn from(node: &FieldValue) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            member: node.member.ref_into(),
+impl TryFromRef<FieldValue> for syn::FieldValue {
+    fn try_from_ref(node: &FieldValue) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("FieldValue", "attrs")?,
+            member: node.member.try_ref_into().context("FieldValue", "member")?,
             colon_token: default_or_none(node.colon_token),
-            expr: node.expr.ref_into(),
-        }
+            expr: node.expr.try_ref_into().context("FieldValue", "expr")?,
+        })
     }
 }
 syn_trait_impl!(syn::Fields);
@@ -1234,12 +1576,20 @@ impl From<&syn::Fields> for Fields {
         }
     }
 }
-impl From<&Fields> for syn::Fields {
-    fn from(node: &Fields) -> Self {
+impl TryFromRef<Fields> for syn::Fields {
+    fn try_from_ref(node: &Fields) -> Result<Self, Error> {
         match node {
-            Fields::Named(_0) => syn::Fields::Named((*_0).ref_into()),
-            Fields::Unnamed(_0) => syn::Fields::Unnamed((*_0).ref_into()),
-            Fields::Unit => syn::Fields::Unit,
+            Fields::Named(_0) => {
+                Ok(syn::Fields::Named((*_0).try_ref_into().context("Fields", "Named")?))
+            }
+            Fields::Unnamed(_0) => {
+                Ok(
+                    syn::Fields::Unnamed(
+                        (*_0).try_ref_into().context("Fields", "Unnamed")?,
+                    ),
+                )
+            }
+            Fields::Unit => Ok(syn::Fields::Unit),
         }
     }
 }
@@ -1251,12 +1601,12 @@ impl From<&syn::FieldsNamed> for FieldsNamed {
         }
     }
 }
-impl From<&FieldsNamed> for syn::FieldsNamed {
-    fn from(node: &FieldsNamed) -> Self {
-        Self {
+impl TryFromRef<FieldsNamed> for syn::FieldsNamed {
+    fn try_from_ref(node: &FieldsNamed) -> Result<Self, Error> {
+        Ok(Self {
             brace_token: default(),
-            named: node.named.map_into(),
-        }
+            named: node.named.try_map_into().context("FieldsNamed", "named")?,
+        })
     }
 }
 syn_trait_impl!(syn::FieldsUnnamed);
@@ -1267,12 +1617,12 @@ impl From<&syn::FieldsUnnamed> for FieldsUnnamed {
         }
     }
 }
-impl From<&FieldsUnnamed> for syn::FieldsUnnamed {
-    fn from(node: &FieldsUnnamed) -> Self {
-        Self {
+impl TryFromRef<FieldsUnnamed> for syn::FieldsUnnamed {
+    fn try_from_ref(node: &FieldsUnnamed) -> Result<Self, Error> {
+        Ok(Self {
             paren_token: default(),
-            unnamed: node.unnamed.map_into(),
-        }
+            unnamed: node.unnamed.try_map_into().context("FieldsUnnamed", "unnamed")?,
+        })
     }
 }
 syn_trait_impl!(syn::File);
@@ -1282,16 +1632,18 @@ impl From<&syn::File> for File {
             shebang: node.shebang.map_into(),
             attrs: node.attrs.map_into(),
             items: node.items.map_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&File> for syn::File {
-    fn from(node: &File) -> Self {
-        Self {
-            shebang: node.shebang.map_into(),
-            attrs: node.attrs.map_into(),
-            items: node.items.map_into(),
-        }
+impl TryFromRef<File> for syn::File {
+    fn try_from_ref(node: &File) -> Result<Self, Error> {
+        Ok(Self {
+            shebang: node.shebang.clone(),
+            attrs: node.attrs.try_map_into().context("File", "attrs")?,
+            items: node.items.try_map_into().context("File", "items")?,
+        })
     }
 }
 syn_trait_impl!(syn::FnArg);
@@ -1303,11 +1655,19 @@ impl From<&syn::FnArg> for FnArg {
         }
     }
 }
-impl From<&FnArg> for syn::FnArg {
-    fn from(node: &FnArg) -> Self {
+impl TryFromRef<FnArg> for syn::FnArg {
+    fn try_from_ref(node: &FnArg) -> Result<Self, Error> {
         match node {
-            FnArg::Receiver(_0) => syn::FnArg::Receiver((*_0).ref_into()),
-            FnArg::Typed(_0) => syn::FnArg::Typed((*_0).ref_into()),
+            FnArg::Receiver(_0) => {
+                Ok(
+                    syn::FnArg::Receiver(
+                        (*_0).try_ref_into().context("FnArg", "Receiver")?,
+                    ),
+                )
+            }
+            FnArg::Typed(_0) => {
+                Ok(syn::FnArg::Typed((*_0).try_ref_into().context("FnArg", "Typed")?))
+            }
         }
     }
 }
@@ -1324,14 +1684,44 @@ impl From<&syn::ForeignItem> for ForeignItem {
         }
     }
 }
-impl From<&ForeignItem> for syn::ForeignItem {
-    fn from(node: &ForeignItem) -> Self {
+impl TryFromRef<ForeignItem> for syn::ForeignItem {
+    fn try_from_ref(node: &ForeignItem) -> Result<Self, Error> {
         match node {
-            ForeignItem::Fn(_0) => syn::ForeignItem::Fn((*_0).ref_into()),
-            ForeignItem::Static(_0) => syn::ForeignItem::Static((*_0).ref_into()),
-            ForeignItem::Type(_0) => syn::ForeignItem::Type((*_0).ref_into()),
-            ForeignItem::Macro(_0) => syn::ForeignItem::Macro((*_0).ref_into()),
-            ForeignItem::Verbatim(_0) => syn::ForeignItem::Verbatim((*_0).ref_into()),
+            ForeignItem::Fn(_0) => {
+                Ok(
+                    syn::ForeignItem::Fn(
+                        (*_0).try_ref_into().context("ForeignItem", "Fn")?,
+                    ),
+                )
+            }
+            ForeignItem::Static(_0) => {
+                Ok(
+                    syn::ForeignItem::Static(
+                        (*_0).try_ref_into().context("ForeignItem", "Static")?,
+                    ),
+                )
+            }
+            ForeignItem::Type(_0) => {
+                Ok(
+                    syn::ForeignItem::Type(
+                        (*_0).try_ref_into().context("ForeignItem", "Type")?,
+                    ),
+                )
+            }
+            ForeignItem::Macro(_0) => {
+                Ok(
+                    syn::ForeignItem::Macro(
+                        (*_0).try_ref_into().context("ForeignItem", "Macro")?,
+                    ),
+                )
+            }
+            ForeignItem::Verbatim(_0) => {
+                Ok(
+                    syn::ForeignItem::Verbatim(
+                        (*_0).try_ref_into().context("ForeignItem", "Verbatim")?,
+                    ),
+                )
+            }
         }
     }
 }
@@ -1342,17 +1732,18 @@ impl From<&syn::ForeignItemFn> for ForeignItemFn {
             attrs: node.attrs.map_into(),
             vis: node.vis.ref_into(),
             sig: node.sig.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ForeignItemFn> for syn::ForeignItemFn {
-    fn from(node: &ForeignItemFn) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
-            sig: node.sig.ref_into(),
+impl TryFromRef<ForeignItemFn> for syn::ForeignItemFn {
+    fn try_from_ref(node: &ForeignItemFn) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ForeignItemFn", "attrs")?,
+            vis: node.vis.try_ref_into().context("ForeignItemFn", "vis")?,
+            sig: node.sig.try_ref_into().context("ForeignItemFn", "sig")?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ForeignItemMacro);
@@ -1362,16 +1753,17 @@ impl From<&syn::ForeignItemMacro> for ForeignItemMacro {
             attrs: node.attrs.map_into(),
             mac: node.mac.ref_into(),
             semi_token: node.semi_token.is_some(),
+            span: None,
         }
     }
 }
-impl From<&ForeignItemMacro> for syn::ForeignItemMacro {
-    fn from(node: &ForeignItemMacro) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            mac: node.mac.ref_into(),
+impl TryFromRef<ForeignItemMacro> for syn::ForeignItemMacro {
+    fn try_from_ref(node: &ForeignItemMacro) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ForeignItemMacro", "attrs")?,
+            mac: node.mac.try_ref_into().context("ForeignItemMacro", "mac")?,
             semi_token: default_or_none(node.semi_token),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ForeignItemStatic);
@@ -1383,21 +1775,25 @@ impl From<&syn::ForeignItemStatic> for ForeignItemStatic {
             mutability: node.mutability.ref_into(),
             ident: node.ident.ref_into(),
             ty: node.ty.map_into(),
+            span: None,
         }
     }
 }
-impl From<&ForeignItemStatic> for syn::ForeignItemStatic {
-    fn from(node: &ForeignItemStatic) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ForeignItemStatic> for syn::ForeignItemStatic {
+    fn try_from_ref(node: &ForeignItemStatic) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ForeignItemStatic", "attrs")?,
+            vis: node.vis.try_ref_into().context("ForeignItemStatic", "vis")?,
             static_token: default(),
-            mutability: node.mutability.ref_into(),
-            ident: node.ident.ref_into(),
+            mutability: node
+                .mutability
+                .try_ref_into()
+                .context("ForeignItemStatic", "mutability")?,
+            ident: node.ident.try_ref_into().context("ForeignItemStatic", "ident")?,
             colon_token: default(),
-            ty: node.ty.map_into(),
+            ty: node.ty.try_map_into().context("ForeignItemStatic", "ty")?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ForeignItemType);
@@ -1408,19 +1804,23 @@ impl From<&syn::ForeignItemType> for ForeignItemType {
             vis: node.vis.ref_into(),
             ident: node.ident.ref_into(),
             generics: node.generics.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ForeignItemType> for syn::ForeignItemType {
-    fn from(node: &ForeignItemType) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ForeignItemType> for syn::ForeignItemType {
+    fn try_from_ref(node: &ForeignItemType) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ForeignItemType", "attrs")?,
+            vis: node.vis.try_ref_into().context("ForeignItemType", "vis")?,
             type_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
+            ident: node.ident.try_ref_into().context("ForeignItemType", "ident")?,
+            generics: node
+                .generics
+                .try_ref_into()
+                .context("ForeignItemType", "generics")?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::GenericArgument);
@@ -1445,22 +1845,50 @@ impl From<&syn::GenericArgument> for GenericArgument {
         }
     }
 }
-impl From<&GenericArgument> for syn::GenericArgument {
-    fn from(node: &GenericArgument) -> Self {
+impl TryFromRef<GenericArgument> for syn::GenericArgument {
+    fn try_from_ref(node: &GenericArgument) -> Result<Self, Error> {
         match node {
             GenericArgument::Lifetime(_0) => {
-                syn::GenericArgument::Lifetime((*_0).ref_into())
+                Ok(
+                    syn::GenericArgument::Lifetime(
+                        (*_0).try_ref_into().context("GenericArgument", "Lifetime")?,
+                    ),
+                )
+            }
+            GenericArgument::Type(_0) => {
+                Ok(
+                    syn::GenericArgument::Type(
+                        (*_0).try_ref_into().context("GenericArgument", "Type")?,
+                    ),
+                )
+            }
+            GenericArgument::Const(_0) => {
+                Ok(
+                    syn::GenericArgument::Const(
+                        (*_0).try_ref_into().context("GenericArgument", "Const")?,
+                    ),
+                )
             }
-            GenericArgument::Type(_0) => syn::GenericArgument::Type((*_0).ref_into()),
-            GenericArgument::Const(_0) => syn::GenericArgument::Const((*_0).ref_into()),
             GenericArgument::AssocType(_0) => {
-                syn::GenericArgument::AssocType((*_0).ref_into())
+                Ok(
+                    syn::GenericArgument::AssocType(
+                        (*_0).try_ref_into().context("GenericArgument", "AssocType")?,
+                    ),
+                )
             }
             GenericArgument::AssocConst(_0) => {
-                syn::GenericArgument::AssocConst((*_0).ref_into())
+                Ok(
+                    syn::GenericArgument::AssocConst(
+                        (*_0).try_ref_into().context("GenericArgument", "AssocConst")?,
+                    ),
+                )
             }
             GenericArgument::Constraint(_0) => {
-                syn::GenericArgument::Constraint((*_0).ref_into())
+                Ok(
+                    syn::GenericArgument::Constraint(
+                        (*_0).try_ref_into().context("GenericArgument", "Constraint")?,
+                    ),
+                )
             }
         }
     }
@@ -1475,12 +1903,30 @@ impl From<&syn::GenericParam> for GenericParam {
         }
     }
 }
-impl From<&GenericParam> for syn::GenericParam {
-    fn from(node: &GenericParam) -> Self {
+impl TryFromRef<GenericParam> for syn::GenericParam {
+    fn try_from_ref(node: &GenericParam) -> Result<Self, Error> {
         match node {
-            GenericParam::Lifetime(_0) => syn::GenericParam::Lifetime((*_0).ref_into()),
-            GenericParam::Type(_0) => syn::GenericParam::Type((*_0).ref_into()),
-            GenericParam::Const(_0) => syn::GenericParam::Const((*_0).ref_into()),
+            GenericParam::Lifetime(_0) => {
+                Ok(
+                    syn::GenericParam::Lifetime(
+                        (*_0).try_ref_into().context("GenericParam", "Lifetime")?,
+                    ),
+                )
+            }
+            GenericParam::Type(_0) => {
+                Ok(
+                    syn::GenericParam::Type(
+                        (*_0).try_ref_into().context("GenericParam", "Type")?,
+                    ),
+                )
+            }
+            GenericParam::Const(_0) => {
+                Ok(
+                    syn::GenericParam::Const(
+                        (*_0).try_ref_into().context("GenericParam", "Const")?,
+                    ),
+                )
+            }
         }
     }
 }
@@ -1497,14 +1943,40 @@ impl From<&syn::ImplItem> for ImplItem {
         }
     }
 }
-impl From<&ImplItem> for syn::ImplItem {
-    fn from(node: &ImplItem) -> Self {
+impl TryFromRef<ImplItem> for syn::ImplItem {
+    fn try_from_ref(node: &ImplItem) -> Result<Self, Error> {
         match node {
-            ImplItem::Const(_0) => syn::ImplItem::Const((*_0).ref_into()),
-            ImplItem::Fn(_0) => syn::ImplItem::Fn((*_0).ref_into()),
-            ImplItem::Type(_0) => syn::ImplItem::Type((*_0).ref_into()),
-            ImplItem::Macro(_0) => syn::ImplItem::Macro((*_0).ref_into()),
-            ImplItem::Verbatim(_0) => syn::ImplItem::Verbatim((*_0).ref_into()),
+            ImplItem::Const(_0) => {
+                Ok(
+                    syn::ImplItem::Const(
+                        (*_0).try_ref_into().context("ImplItem", "Const")?,
+                    ),
+                )
+            }
+            ImplItem::Fn(_0) => {
+                Ok(syn::ImplItem::Fn((*_0).try_ref_into().context("ImplItem", "Fn")?))
+            }
+            ImplItem::Type(_0) => {
+                Ok(
+                    syn::ImplItem::Type(
+                        (*_0).try_ref_into().context("ImplItem", "Type")?,
+                    ),
+                )
+            }
+            ImplItem::Macro(_0) => {
+                Ok(
+                    syn::ImplItem::Macro(
+                        (*_0).try_ref_into().context("ImplItem", "Macro")?,
+                    ),
+                )
+            }
+            ImplItem::Verbatim(_0) => {
+                Ok(
+                    syn::ImplItem::Verbatim(
+                        (*_0).try_ref_into().context("ImplItem", "Verbatim")?,
+                    ),
+                )
+            }
         }
     }
 }
@@ -1519,24 +1991,26 @@ impl From<&syn::ImplItemConst> for ImplItemConst {
             generics: node.generics.ref_into(),
             ty: node.ty.ref_into(),
             expr: node.expr.ref_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ImplItemConst> for syn::ImplItemConst {
-    fn from(node: &ImplItemConst) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ImplItemConst> for syn::ImplItemConst {
+    fn try_from_ref(node: &ImplItemConst) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ImplItemConst", "attrs")?,
+            vis: node.vis.try_ref_into().context("ImplItemConst", "vis")?,
             defaultness: default_or_none(node.defaultness),
             const_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
+            ident: node.ident.try_ref_into().context("ImplItemConst", "ident")?,
+            generics: node.generics.try_ref_into().context("ImplItemConst", "generics")?,
             colon_token: default(),
-            ty: node.ty.ref_into(),
+            ty: node.ty.try_ref_into().context("ImplItemConst", "ty")?,
             eq_token: default(),
-            expr: node.expr.ref_into(),
+            expr: node.expr.try_ref_into().context("ImplItemConst", "expr")?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ImplItemFn);
@@ -1548,18 +2022,20 @@ impl From<&syn::ImplItemFn> for ImplItemFn {
             defaultness: node.defaultness.is_some(),
             sig: node.sig.ref_into(),
             block: node.block.ref_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ImplItemFn> for syn::ImplItemFn {
-    fn from(node: &ImplItemFn) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ImplItemFn> for syn::ImplItemFn {
+    fn try_from_ref(node: &ImplItemFn) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ImplItemFn", "attrs")?,
+            vis: node.vis.try_ref_into().context("ImplItemFn", "vis")?,
             defaultness: default_or_none(node.defaultness),
-            sig: node.sig.ref_into(),
-            block: node.block.ref_into(),
-        }
+            sig: node.sig.try_ref_into().context("ImplItemFn", "sig")?,
+            block: node.block.try_ref_into().context("ImplItemFn", "block")?,
+        })
     }
 }
 syn_trait_impl!(syn::ImplItemMacro);
@@ -1569,16 +2045,18 @@ impl From<&syn::ImplItemMacro> for ImplItemMacro {
             attrs: node.attrs.map_into(),
             mac: node.mac.ref_into(),
             semi_token: node.semi_token.is_some(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ImplItemMacro> for syn::ImplItemMacro {
-    fn from(node: &ImplItemMacro) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            mac: node.mac.ref_into(),
+impl TryFromRef<ImplItemMacro> for syn::ImplItemMacro {
+    fn try_from_ref(node: &ImplItemMacro) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ImplItemMacro", "attrs")?,
+            mac: node.mac.try_ref_into().context("ImplItemMacro", "mac")?,
             semi_token: default_or_none(node.semi_token),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ImplItemType);
@@ -1591,22 +2069,24 @@ impl From<&syn::ImplItemType> for ImplItemType {
             ident: node.ident.ref_into(),
             generics: node.generics.ref_into(),
             ty: node.ty.ref_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ImplItemType> for syn::ImplItemType {
-    fn from(node: &ImplItemType) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ImplItemType> for syn::ImplItemType {
+    fn try_from_ref(node: &ImplItemType) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ImplItemType", "attrs")?,
+            vis: node.vis.try_ref_into().context("ImplItemType", "vis")?,
             defaultness: default_or_none(node.defaultness),
             type_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
+            ident: node.ident.try_ref_into().context("ImplItemType", "ident")?,
+            generics: node.generics.try_ref_into().context("ImplItemType", "generics")?,
             eq_token: default(),
-            ty: node.ty.ref_into(),
+            ty: node.ty.try_ref_into().context("ImplItemType", "ty")?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ImplRestriction);
@@ -1617,8 +2097,8 @@ impl From<&syn::ImplRestriction> for ImplRestriction {
         }
     }
 }
-impl From<&ImplRestriction> for syn::ImplRestriction {
-    fn from(node: &ImplRestriction) -> Self {
+impl TryFromRef<ImplRestriction> for syn::ImplRestriction {
+    fn try_from_ref(node: &ImplRestriction) -> Result<Self, Error> {
         match node {
             _ => unreachable!(),
         }
@@ -1630,12 +2110,12 @@ impl From<&syn::Index> for Index {
         Self { index: node.index }
     }
 }
-impl From<&Index> for syn::Index {
-    fn from(node: &Index) -> Self {
-        Self {
+impl TryFromRef<Index> for syn::Index {
+    fn try_from_ref(node: &Index) -> Result<Self, Error> {
+        Ok(Self {
             index: node.index,
             span: proc_macro2::Span::call_site(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::Item);
@@ -1662,25 +2142,73 @@ impl From<&syn::Item> for Item {
         }
     }
 }
-impl From<&Item> for syn::Item {
-    fn from(node: &Item) -> Self {
+impl TryFromRef<Item> for syn::Item {
+    fn try_from_ref(node: &Item) -> Result<Self, Error> {
         match node {
-            Item::Const(_0) => syn::Item::Const((*_0).ref_into()),
-            Item::Enum(_0) => syn::Item::Enum((*_0).ref_into()),
-            Item::ExternCrate(_0) => syn::Item::ExternCrate((*_0).ref_into()),
-            Item::Fn(_0) => syn::Item::Fn((*_0).ref_into()),
-            Item::ForeignMod(_0) => syn::Item::ForeignMod((*_0).ref_into()),
-            Item::Impl(_0) => syn::Item::Impl((*_0).ref_into()),
-            Item::Macro(_0) => syn::Item::Macro((*_0).ref_into()),
-            Item::Mod(_0) => syn::Item::Mod((*_0).ref_into()),
-            Item::Static(_0) => syn::Item::Static((*_0).ref_into()),
-            Item::Struct(_0) => syn::Item::Struct((*_0).ref_into()),
-            Item::Trait(_0) => syn::Item::Trait((*_0).ref_into()),
-            Item::TraitAlias(_0) => syn::Item::TraitAlias((*_0).ref_into()),
-            Item::Type(_0) => syn::Item::Type((*_0).ref_into()),
-            Item::Union(_0) => syn::Item::Union((*_0).ref_into()),
-            Item::Use(_0) => syn::Item::Use((*_0).ref_into()),
-            Item::Verbatim(_0) => syn::Item::Verbatim((*_0).ref_into()),
+            Item::Const(_0) => {
+                Ok(syn::Item::Const((*_0).try_ref_into().context("Item", "Const")?))
+            }
+            Item::Enum(_0) => {
+                Ok(syn::Item::Enum((*_0).try_ref_into().context("Item", "Enum")?))
+            }
+            Item::ExternCrate(_0) => {
+                Ok(
+                    syn::Item::ExternCrate(
+                        (*_0).try_ref_into().context("Item", "ExternCrate")?,
+                    ),
+                )
+            }
+            Item::Fn(_0) => {
+                Ok(syn::Item::Fn((*_0).try_ref_into().context("Item", "Fn")?))
+            }
+            Item::ForeignMod(_0) => {
+                Ok(
+                    syn::Item::ForeignMod(
+                        (*_0).try_ref_into().context("Item", "ForeignMod")?,
+                    ),
+                )
+            }
+            Item::Impl(_0) => {
+                Ok(syn::Item::Impl((*_0).try_ref_into().context("Item", "Impl")?))
+            }
+            Item::Macro(_0) => {
+                Ok(syn::Item::Macro((*_0).try_ref_into().context("Item", "Macro")?))
+            }
+            Item::Mod(_0) => {
+                Ok(syn::Item::Mod((*_0).try_ref_into().context("Item", "Mod")?))
+            }
+            Item::Static(_0) => {
+                Ok(syn::Item::Static((*_0).try_ref_into().context("Item", "Static")?))
+            }
+            Item::Struct(_0) => {
+                Ok(syn::Item::Struct((*_0).try_ref_into().context("Item", "Struct")?))
+            }
+            Item::Trait(_0) => {
+                Ok(syn::Item::Trait((*_0).try_ref_into().context("Item", "Trait")?))
+            }
+            Item::TraitAlias(_0) => {
+                Ok(
+                    syn::Item::TraitAlias(
+                        (*_0).try_ref_into().context("Item", "TraitAlias")?,
+                    ),
+                )
+            }
+            Item::Type(_0) => {
+                Ok(syn::Item::Type((*_0).try_ref_into().context("Item", "Type")?))
+            }
+            Item::Union(_0) => {
+                Ok(syn::Item::Union((*_0).try_ref_into().context("Item", "Union")?))
+            }
+            Item::Use(_0) => {
+                Ok(syn::Item::Use((*_0).try_ref_into().context("Item", "Use")?))
+            }
+            Item::Verbatim(_0) => {
+                Ok(
+                    syn::Item::Verbatim(
+                        (*_0).try_ref_into().context("Item", "Verbatim")?,
+                    ),
+                )
+            }
         }
     }
 }
@@ -1694,23 +2222,25 @@ impl From<&syn::ItemConst> for ItemConst {
             generics: node.generics.ref_into(),
             ty: node.ty.map_into(),
             expr: node.expr.map_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemConst> for syn::ItemConst {
-    fn from(node: &ItemConst) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ItemConst> for syn::ItemConst {
+    fn try_from_ref(node: &ItemConst) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemConst", "attrs")?,
+            vis: node.vis.try_ref_into().context("ItemConst", "vis")?,
             const_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
+            ident: node.ident.try_ref_into().context("ItemConst", "ident")?,
+            generics: node.generics.try_ref_into().context("ItemConst", "generics")?,
             colon_token: default(),
-            ty: node.ty.map_into(),
+            ty: node.ty.try_map_into().context("ItemConst", "ty")?,
             eq_token: default(),
-            expr: node.expr.map_into(),
+            expr: node.expr.try_map_into().context("ItemConst", "expr")?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ItemEnum);
@@ -1722,20 +2252,22 @@ impl From<&syn::ItemEnum> for ItemEnum {
             ident: node.ident.ref_into(),
             generics: node.generics.ref_into(),
             variants: node.variants.map_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemEnum> for syn::ItemEnum {
-    fn from(node: &ItemEnum) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ItemEnum> for syn::ItemEnum {
+    fn try_from_ref(node: &ItemEnum) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemEnum", "attrs")?,
+            vis: node.vis.try_ref_into().context("ItemEnum", "vis")?,
             enum_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
+            ident: node.ident.try_ref_into().context("ItemEnum", "ident")?,
+            generics: node.generics.try_ref_into().context("ItemEnum", "generics")?,
             brace_token: default(),
-            variants: node.variants.map_into(),
-        }
+            variants: node.variants.try_map_into().context("ItemEnum", "variants")?,
+        })
     }
 }
 syn_trait_impl!(syn::ItemExternCrate);
@@ -1746,20 +2278,27 @@ impl From<&syn::ItemExternCrate> for ItemExternCrate {
             vis: node.vis.ref_into(),
             ident: node.ident.ref_into(),
             rename: node.rename.ref_map(|(_0, _1)| (*_1).ref_into()),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemExternCrate> for syn::ItemExternCrate {
-    fn from(node: &ItemExternCrate) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ItemExternCrate> for syn::ItemExternCrate {
+    fn try_from_ref(node: &ItemExternCrate) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemExternCrate", "attrs")?,
+            vis: node.vis.try_ref_into().context("ItemExternCrate", "vis")?,
             extern_token: default(),
             crate_token: default(),
-            ident: node.ident.ref_into(),
-            rename: node.rename.ref_map(|_1| (default(), (*_1).ref_into())),
+            ident: node.ident.try_ref_into().context("ItemExternCrate", "ident")?,
+            rename: node
+                .rename
+                .try_ref_map(|_1| Ok((
+                    default(),
+                    (*_1).try_ref_into().context("ItemExternCrate", "rename")?,
+                )))?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ItemFn);
@@ -1770,17 +2309,19 @@ impl From<&syn::ItemFn> for ItemFn {
             vis: node.vis.ref_into(),
             sig: node.sig.ref_into(),
             block: node.block.map_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemFn> for syn::ItemFn {
-    fn from(node: &ItemFn) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
-            sig: node.sig.ref_into(),
-            block: node.block.map_into(),
-        }
+impl TryFromRef<ItemFn> for syn::ItemFn {
+    fn try_from_ref(node: &ItemFn) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemFn", "attrs")?,
+            vis: node.vis.try_ref_into().context("ItemFn", "vis")?,
+            sig: node.sig.try_ref_into().context("ItemFn", "sig")?,
+            block: node.block.try_map_into().context("ItemFn", "block")?,
+        })
     }
 }
 syn_trait_impl!(syn::ItemForeignMod);
@@ -1791,18 +2332,20 @@ impl From<&syn::ItemForeignMod> for ItemForeignMod {
             unsafety: node.unsafety.is_some(),
             abi: node.abi.ref_into(),
             items: node.items.map_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemForeignMod> for syn::ItemForeignMod {
-    fn from(node: &ItemForeignMod) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ItemForeignMod> for syn::ItemForeignMod {
+    fn try_from_ref(node: &ItemForeignMod) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemForeignMod", "attrs")?,
             unsafety: default_or_none(node.unsafety),
-            abi: node.abi.ref_into(),
+            abi: node.abi.try_ref_into().context("ItemForeignMod", "abi")?,
             brace_token: default(),
-            items: node.items.map_into(),
-        }
+            items: node.items.try_map_into().context("ItemForeignMod", "items")?,
+        })
     }
 }
 syn_trait_impl!(syn::ItemImpl);
@@ -1818,28 +2361,30 @@ impl From<&syn::ItemImpl> for ItemImpl {
                 .ref_map(|(_0, _1, _2)| ((*_0).is_some(), (*_1).ref_into())),
             self_ty: node.self_ty.map_into(),
             items: node.items.map_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemImpl> for syn::ItemImpl {
-    fn from(node: &ItemImpl) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<ItemImpl> for syn::ItemImpl {
+    fn try_from_ref(node: &ItemImpl) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemImpl", "attrs")?,
             defaultness: default_or_none(node.defaultness),
             unsafety: default_or_none(node.unsafety),
             impl_token: default(),
-            generics: node.generics.ref_into(),
+            generics: node.generics.try_ref_into().context("ItemImpl", "generics")?,
             trait_: node
                 .trait_
-                .ref_map(|(_0, _1)| (
+                .try_ref_map(|(_0, _1)| Ok((
                     default_or_none((*_0)),
-                    (*_1).ref_into(),
+                    (*_1).try_ref_into().context("ItemImpl", "trait_")?,
                     default(),
-                )),
-            self_ty: node.self_ty.map_into(),
+                )))?,
+            self_ty: node.self_ty.try_map_into().context("ItemImpl", "self_ty")?,
             brace_token: default(),
-            items: node.items.map_into(),
-        }
+            items: node.items.try_map_into().context("ItemImpl", "items")?,
+        })
     }
 }
 syn_trait_impl!(syn::ItemMacro);
@@ -1850,17 +2395,19 @@ impl From<&syn::ItemMacro> for ItemMacro {
             ident: node.ident.map_into(),
             mac: node.mac.ref_into(),
             semi_token: node.semi_token.is_some(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemMacro> for syn::ItemMacro {
-    fn from(node: &ItemMacro) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            ident: node.ident.map_into(),
-            mac: node.mac.ref_into(),
+impl TryFromRef<ItemMacro> for syn::ItemMacro {
+    fn try_from_ref(node: &ItemMacro) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemMacro", "attrs")?,
+            ident: node.ident.try_map_into().context("ItemMacro", "ident")?,
+            mac: node.mac.try_ref_into().context("ItemMacro", "mac")?,
             semi_token: default_or_none(node.semi_token),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ItemMod);
@@ -1873,20 +2420,27 @@ impl From<&syn::ItemMod> for ItemMod {
             ident: node.ident.ref_into(),
             content: node.content.ref_map(|(_0, _1)| (*_1).map_into()),
             semi: node.semi.is_some(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemMod> for syn::ItemMod {
-    fn from(node: &ItemMod) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ItemMod> for syn::ItemMod {
+    fn try_from_ref(node: &ItemMod) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemMod", "attrs")?,
+            vis: node.vis.try_ref_into().context("ItemMod", "vis")?,
             unsafety: default_or_none(node.unsafety),
             mod_token: default(),
-            ident: node.ident.ref_into(),
-            content: node.content.ref_map(|_1| (default(), (*_1).map_into())),
+            ident: node.ident.try_ref_into().context("ItemMod", "ident")?,
+            content: node
+                .content
+                .try_ref_map(|_1| Ok((
+                    default(),
+                    (*_1).try_map_into().context("ItemMod", "content")?,
+                )))?,
             semi: default_or_none(node.semi),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ItemStatic);
@@ -1899,23 +2453,28 @@ impl From<&syn::ItemStatic> for ItemStatic {
             ident: node.ident.ref_into(),
             ty: node.ty.map_into(),
             expr: node.expr.map_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemStatic> for syn::ItemStatic {
-    fn from(node: &ItemStatic) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ItemStatic> for syn::ItemStatic {
+    fn try_from_ref(node: &ItemStatic) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemStatic", "attrs")?,
+            vis: node.vis.try_ref_into().context("ItemStatic", "vis")?,
             static_token: default(),
-            mutability: node.mutability.ref_into(),
-            ident: node.ident.ref_into(),
+            mutability: node
+                .mutability
+                .try_ref_into()
+                .context("ItemStatic", "mutability")?,
+            ident: node.ident.try_ref_into().context("ItemStatic", "ident")?,
             colon_token: default(),
-            ty: node.ty.map_into(),
+            ty: node.ty.try_map_into().context("ItemStatic", "ty")?,
             eq_token: default(),
-            expr: node.expr.map_into(),
+            expr: node.expr.try_map_into().context("ItemStatic", "expr")?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ItemTrait);
@@ -1932,25 +2491,33 @@ impl From<&syn::ItemTrait> for ItemTrait {
             colon_token: node.colon_token.is_some(),
             supertraits: node.supertraits.map_into(),
             items: node.items.map_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemTrait> for syn::ItemTrait {
-    fn from(node: &ItemTrait) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ItemTrait> for syn::ItemTrait {
+    fn try_from_ref(node: &ItemTrait) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemTrait", "attrs")?,
+            vis: node.vis.try_ref_into().context("ItemTrait", "vis")?,
             unsafety: default_or_none(node.unsafety),
             auto_token: default_or_none(node.auto_token),
-            restriction: node.restriction.map_into(),
+            restriction: node
+                .restriction
+                .try_map_into()
+                .context("ItemTrait", "restriction")?,
             trait_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
+            ident: node.ident.try_ref_into().context("ItemTrait", "ident")?,
+            generics: node.generics.try_ref_into().context("ItemTrait", "generics")?,
             colon_token: default_or_none(node.colon_token),
-            supertraits: node.supertraits.map_into(),
+            supertraits: node
+                .supertraits
+                .try_map_into()
+                .context("ItemTrait", "supertraits")?,
             brace_token: default(),
-            items: node.items.map_into(),
-        }
+            items: node.items.try_map_into().context("ItemTrait", "items")?,
+        })
     }
 }
 syn_trait_impl!(syn::ItemTraitAlias);
@@ -1962,21 +2529,26 @@ impl From<&syn::ItemTraitAlias> for ItemTraitAlias {
             ident: node.ident.ref_into(),
             generics: node.generics.ref_into(),
             bounds: node.bounds.map_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemTraitAlias> for syn::ItemTraitAlias {
-    fn from(node: &ItemTraitAlias) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ItemTraitAlias> for syn::ItemTraitAlias {
+    fn try_from_ref(node: &ItemTraitAlias) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemTraitAlias", "attrs")?,
+            vis: node.vis.try_ref_into().context("ItemTraitAlias", "vis")?,
             trait_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
+            ident: node.ident.try_ref_into().context("ItemTraitAlias", "ident")?,
+            generics: node
+                .generics
+                .try_ref_into()
+                .context("ItemTraitAlias", "generics")?,
             eq_token: default(),
-            bounds: node.bounds.map_into(),
+            bounds: node.bounds.try_map_into().context("ItemTraitAlias", "bounds")?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ItemType);
@@ -1988,21 +2560,23 @@ impl From<&syn::ItemType> for ItemType {
             ident: node.ident.ref_into(),
             generics: node.generics.ref_into(),
             ty: node.ty.map_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemType> for syn::ItemType {
-    fn from(node: &ItemType) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ItemType> for syn::ItemType {
+    fn try_from_ref(node: &ItemType) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemType", "attrs")?,
+            vis: node.vis.try_ref_into().context("ItemType", "vis")?,
             type_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
+            ident: node.ident.try_ref_into().context("ItemType", "ident")?,
+            generics: node.generics.try_ref_into().context("ItemType", "generics")?,
             eq_token: default(),
-            ty: node.ty.map_into(),
+            ty: node.ty.try_map_into().context("ItemType", "ty")?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::ItemUnion);
@@ -2014,19 +2588,21 @@ impl From<&syn::ItemUnion> for ItemUnion {
             ident: node.ident.ref_into(),
             generics: node.generics.ref_into(),
             fields: node.fields.ref_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemUnion> for syn::ItemUnion {
-    fn from(node: &ItemUnion) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ItemUnion> for syn::ItemUnion {
+    fn try_from_ref(node: &ItemUnion) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemUnion", "attrs")?,
+            vis: node.vis.try_ref_into().context("ItemUnion", "vis")?,
             union_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
-            fields: node.fields.ref_into(),
-        }
+            ident: node.ident.try_ref_into().context("ItemUnion", "ident")?,
+            generics: node.generics.try_ref_into().context("ItemUnion", "generics")?,
+            fields: node.fields.try_ref_into().context("ItemUnion", "fields")?,
+        })
     }
 }
 syn_trait_impl!(syn::ItemUse);
@@ -2037,19 +2613,21 @@ impl From<&syn::ItemUse> for ItemUse {
             vis: node.vis.ref_into(),
             leading_colon: node.leading_colon.is_some(),
             tree: node.tree.ref_into(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&ItemUse> for syn::ItemUse {
-    fn from(node: &ItemUse) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            vis: node.vis.ref_into(),
+impl TryFromRef<ItemUse> for syn::ItemUse {
+    fn try_from_ref(node: &ItemUse) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("ItemUse", "attrs")?,
+            vis: node.vis.try_ref_into().context("ItemUse", "vis")?,
             use_token: default(),
             leading_colon: default_or_none(node.leading_colon),
-            tree: node.tree.ref_into(),
+            tree: node.tree.try_ref_into().context("ItemUse", "tree")?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::Label);
@@ -2058,12 +2636,12 @@ impl From<&syn::Label> for Label {
         Self { name: node.name.ref_into() }
     }
 }
-impl From<&Label> for syn::Label {
-    fn from(node: &Label) -> Self {
-        Self {
-            name: node.name.ref_into(),
+impl TryFromRef<Label> for syn::Label {
+    fn try_from_ref(node: &Label) -> Result<Self, Error> {
+        Ok(Self {
+            name: node.name.try_ref_into().context("Label", "name")?,
             colon_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::Lifetime);
@@ -2074,12 +2652,12 @@ impl From<&syn::Lifetime> for Lifetime {
         }
     }
 }
-impl From<&Lifetime> for syn::Lifetime {
-    fn from(node: &Lifetime) -> Self {
-        Self {
+impl TryFromRef<Lifetime> for syn::Lifetime {
+    fn try_from_ref(node: &Lifetime) -> Result<Self, Error> {
+        Ok(Self {
             apostrophe: proc_macro2::Span::call_site(),
-            ident: node.ident.ref_into(),
-        }
+            ident: node.ident.try_ref_into().context("Lifetime", "ident")?,
+        })
     }
 }
 syn_trait_impl!(syn::LifetimeParam);
@@ -2090,17 +2668,18 @@ impl From<&syn::LifetimeParam> for LifetimeParam {
             lifetime: node.lifetime.ref_into(),
             colon_token: node.colon_token.is_some(),
             bounds: node.bounds.map_into(),
+            span: None,
         }
     }
 }
-impl From<&LifetimeParam> for syn::LifetimeParam {
-    fn from(node: &LifetimeParam) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            lifetime: node.lifetime.ref_into(),
+impl TryFromRef<LifetimeParam> for syn::LifetimeParam {
+    fn try_from_ref(node: &LifetimeParam) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("LifetimeParam", "attrs")?,
+            lifetime: node.lifetime.try_ref_into().context("LifetimeParam", "lifetime")?,
             colon_token: default_or_none(node.colon_token),
-            bounds: node.bounds.map_into(),
-        }
+            bounds: node.bounds.try_map_into().context("LifetimeParam", "bounds")?,
+        })
     }
 }
 syn_trait_impl!(syn::Lit);
@@ -2109,6 +2688,7 @@ impl From<&syn::Lit> for Lit {
         match node {
             syn::Lit::Str(_0) => Lit::Str((*_0).ref_into()),
             syn::Lit::ByteStr(_0) => Lit::ByteStr((*_0).ref_into()),
+            syn::Lit::CStr(_0) => Lit::CStr((*_0).ref_into()),
             syn::Lit::Byte(_0) => Lit::Byte((*_0).ref_into()),
             syn::Lit::Char(_0) => Lit::Char((*_0).ref_into()),
             syn::Lit::Int(_0) => Lit::Int((*_0).ref_into()),
@@ -2119,17 +2699,36 @@ impl From<&syn::Lit> for Lit {
         }
     }
 }
-impl From<&Lit> for syn::Lit {
-    fn from(node: &Lit) -> Self {
+impl TryFromRef<Lit> for syn::Lit {
+    fn try_from_ref(node: &Lit) -> Result<Self, Error> {
         match node {
-            Lit::Str(_0) => syn::Lit::Str((*_0).ref_into()),
-            Lit::ByteStr(_0) => syn::Lit::ByteStr((*_0).ref_into()),
-            Lit::Byte(_0) => syn::Lit::Byte((*_0).ref_into()),
-            Lit::Char(_0) => syn::Lit::Char((*_0).ref_into()),
-            Lit::Int(_0) => syn::Lit::Int((*_0).ref_into()),
-            Lit::Float(_0) => syn::Lit::Float((*_0).ref_into()),
-            Lit::Bool(_0) => syn::Lit::Bool((*_0).ref_into()),
-            Lit::Verbatim(_0) => syn::Lit::Verbatim((*_0).ref_into()),
+            Lit::Str(_0) => {
+                Ok(syn::Lit::Str((*_0).try_ref_into().context("Lit", "Str")?))
+            }
+            Lit::ByteStr(_0) => {
+                Ok(syn::Lit::ByteStr((*_0).try_ref_into().context("Lit", "ByteStr")?))
+            }
+            Lit::CStr(_0) => {
+                Ok(syn::Lit::CStr((*_0).try_ref_into().context("Lit", "CStr")?))
+            }
+            Lit::Byte(_0) => {
+                Ok(syn::Lit::Byte((*_0).try_ref_into().context("Lit", "Byte")?))
+            }
+            Lit::Char(_0) => {
+                Ok(syn::Lit::Char((*_0).try_ref_into().context("Lit", "Char")?))
+            }
+            Lit::Int(_0) => {
+                Ok(syn::Lit::Int((*_0).try_ref_into().context("Lit", "Int")?))
+            }
+            Lit::Float(_0) => {
+                Ok(syn::Lit::Float((*_0).try_ref_into().context("Lit", "Float")?))
+            }
+            Lit::Bool(_0) => {
+                Ok(syn::Lit::Bool((*_0).try_ref_into().context("Lit", "Bool")?))
+            }
+            Lit::Verbatim(_0) => {
+                Ok(syn::Lit::Verbatim((*_0).try_ref_into().context("Lit", "Verbatim")?))
+            }
         }
     }
 }
@@ -2139,12 +2738,12 @@ impl From<&syn::LitBool> for LitBool {
         Self { value: node.value }
     }
 }
-impl From<&LitBool> for syn::LitBool {
-    fn from(node: &LitBool) -> Self {
-        Self {
+impl TryFromRef<LitBool> for syn::LitBool {
+    fn try_from_ref(node: &LitBool) -> Result<Self, Error> {
+        Ok(Self {
             value: node.value,
             span: proc_macro2::Span::call_site(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::Local);
@@ -2154,18 +2753,19 @@ impl From<&syn::Local> for Local {
             attrs: node.attrs.map_into(),
             pat: node.pat.ref_into(),
             init: node.init.map_into(),
+            span: None,
         }
     }
 }
-impl From<&Local> for syn::Local {
-    fn from(node: &Local) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<Local> for syn::Local {
+    fn try_from_ref(node: &Local) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("Local", "attrs")?,
             let_token: default(),
-            pat: node.pat.ref_into(),
-            init: node.init.map_into(),
+            pat: node.pat.try_ref_into().context("Local", "pat")?,
+            init: node.init.try_map_into().context("Local", "init")?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::LocalInit);
@@ -2177,13 +2777,18 @@ impl From<&syn::LocalInit> for LocalInit {
         }
     }
 }
-impl From<&LocalInit> for syn::LocalInit {
-    fn from(node: &LocalInit) -> Self {
-        Self {
+impl TryFromRef<LocalInit> for syn::LocalInit {
+    fn try_from_ref(node: &LocalInit) -> Result<Self, Error> {
+        Ok(Self {
             eq_token: default(),
-            expr: node.expr.map_into(),
-            diverge: node.diverge.ref_map(|_1| (default(), (*_1).map_into())),
-        }
+            expr: node.expr.try_map_into().context("LocalInit", "expr")?,
+            diverge: node
+                .diverge
+                .try_ref_map(|_1| Ok((
+                    default(),
+                    (*_1).try_map_into().context("LocalInit", "diverge")?,
+                )))?,
+        })
     }
 }
 syn_trait_impl!(syn::Macro);
@@ -2196,14 +2801,14 @@ impl From<&syn::Macro> for Macro {
         }
     }
 }
-impl From<&Macro> for syn::Macro {
-    fn from(node: &Macro) -> Self {
-        Self {
-            path: node.path.ref_into(),
+impl TryFromRef<Macro> for syn::Macro {
+    fn try_from_ref(node: &Macro) -> Result<Self, Error> {
+        Ok(Self {
+            path: node.path.try_ref_into().context("Macro", "path")?,
             bang_token: default(),
-            delimiter: node.delimiter.ref_into(),
-            tokens: node.tokens.ref_into(),
-        }
+            delimiter: node.delimiter.try_ref_into().context("Macro", "delimiter")?,
+            tokens: node.tokens.try_ref_into().context("Macro", "tokens")?,
+        })
     }
 }
 syn_trait_impl!(syn::MacroDelimiter);
@@ -2216,12 +2821,12 @@ impl From<&syn::MacroDelimiter> for MacroDelimiter {
         }
     }
 }
-impl From<&MacroDelimiter> for syn::MacroDelimiter {
-    fn from(node: &MacroDelimiter) -> Self {
+impl TryFromRef<MacroDelimiter> for syn::MacroDelimiter {
+    fn try_from_ref(node: &MacroDelimiter) -> Result<Self, Error> {
         match node {
-            MacroDelimiter::Paren => syn::MacroDelimiter::Paren(default()),
-            MacroDelimiter::Brace => syn::MacroDelimiter::Brace(default()),
-            MacroDelimiter::Bracket => syn::MacroDelimiter::Bracket(default()),
+            MacroDelimiter::Paren => Ok(syn::MacroDelimiter::Paren(default())),
+            MacroDelimiter::Brace => Ok(syn::MacroDelimiter::Brace(default())),
+            MacroDelimiter::Bracket => Ok(syn::MacroDelimiter::Bracket(default())),
         }
     }
 }
@@ -2234,11 +2839,19 @@ impl From<&syn::Member> for Member {
         }
     }
 }
-impl From<&Member> for syn::Member {
-    fn from(node: &Member) -> Self {
+impl TryFromRef<Member> for syn::Member {
+    fn try_from_ref(node: &Member) -> Result<Self, Error> {
         match node {
-            Member::Named(_0) => syn::Member::Named((*_0).ref_into()),
-            Member::Unnamed(_0) => syn::Member::Unnamed((*_0).ref_into()),
+            Member::Named(_0) => {
+                Ok(syn::Member::Named((*_0).try_ref_into().context("Member", "Named")?))
+            }
+            Member::Unnamed(_0) => {
+                Ok(
+                    syn::Member::Unnamed(
+                        (*_0).try_ref_into().context("Member", "Unnamed")?,
+                    ),
+                )
+            }
         }
     }
 }
@@ -2252,12 +2865,22 @@ impl From<&syn::Meta> for Meta {
         }
     }
 }
-impl From<&Meta> for syn::Meta {
-    fn from(node: &Meta) -> Self {
+impl TryFromRef<Meta> for syn::Meta {
+    fn try_from_ref(node: &Meta) -> Result<Self, Error> {
         match node {
-            Meta::Path(_0) => syn::Meta::Path((*_0).ref_into()),
-            Meta::List(_0) => syn::Meta::List((*_0).ref_into()),
-            Meta::NameValue(_0) => syn::Meta::NameValue((*_0).ref_into()),
+            Meta::Path(_0) => {
+                Ok(syn::Meta::Path((*_0).try_ref_into().context("Meta", "Path")?))
+            }
+            Meta::List(_0) => {
+                Ok(syn::Meta::List((*_0).try_ref_into().context("Meta", "List")?))
+            }
+            Meta::NameValue(_0) => {
+                Ok(
+                    syn::Meta::NameValue(
+                        (*_0).try_ref_into().context("Meta", "NameValue")?,
+                    ),
+                )
+            }
         }
     }
 }
@@ -2268,16 +2891,17 @@ impl From<&syn::MetaList> for MetaList {
             path: node.path.ref_into(),
             delimiter: node.delimiter.ref_into(),
             tokens: node.tokens.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&MetaList> for syn::MetaList {
-    fn from(node: &MetaList) -> Self {
-        Self {
-            path: node.path.ref_into(),
-            delimiter: node.delimiter.ref_into(),
-            tokens: node.tokens.ref_into(),
-        }
+impl TryFromRef<MetaList> for syn::MetaList {
+    fn try_from_ref(node: &MetaList) -> Result<Self, Error> {
+        Ok(Self {
+            path: node.path.try_ref_into().context("MetaList", "path")?,
+            delimiter: node.delimiter.try_ref_into().context("MetaList", "delimiter")?,
+            tokens: node.tokens.try_ref_into().context("MetaList", "tokens")?,
+        })
     }
 }
 syn_trait_impl!(syn::MetaNameValue);
@@ -2286,16 +2910,17 @@ impl From<&syn::MetaNameValue> for MetaNameValue {
         Self {
             path: node.path.ref_into(),
             value: node.value.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&MetaNameValue> for syn::MetaNameValue {
-    fn from(node: &MetaNameValue) -> Self {
-        Self {
-            path: node.path.ref_into(),
+impl TryFromRef<MetaNameValue> for syn::MetaNameValue {
+    fn try_from_ref(node: &MetaNameValue) -> Result<Self, Error> {
+        Ok(Self {
+            path: node.path.try_ref_into().context("MetaNameValue", "path")?,
             eq_token: default(),
-            value: node.value.ref_into(),
-        }
+            value: node.value.try_ref_into().context("MetaNameValue", "value")?,
+        })
     }
 }
 syn_trait_impl!(syn::ParenthesizedGenericArguments);
@@ -2304,16 +2929,23 @@ impl From<&syn::ParenthesizedGenericArguments> for ParenthesizedGenericArguments
         Self {
             inputs: node.inputs.map_into(),
             output: node.output.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&ParenthesizedGenericArguments> for syn::ParenthesizedGenericArguments {
-    fn from(node: &ParenthesizedGenericArguments) -> Self {
-        Self {
+impl TryFromRef<ParenthesizedGenericArguments> for syn::ParenthesizedGenericArguments {
+    fn try_from_ref(node: &ParenthesizedGenericArguments) -> Result<Self, Error> {
+        Ok(Self {
             paren_token: default(),
-            inputs: node.inputs.map_into(),
-            output: node.output.ref_into(),
-        }
+            inputs: node
+                .inputs
+                .try_map_into()
+                .context("ParenthesizedGenericArguments", "inputs")?,
+            output: node
+                .output
+                .try_ref_into()
+                .context("ParenthesizedGenericArguments", "output")?,
+        })
     }
 }
 syn_trait_impl!(syn::Pat);
@@ -2341,26 +2973,66 @@ impl From<&syn::Pat> for Pat {
         }
     }
 }
-impl From<&Pat> for syn::Pat {
-    fn from(node: &Pat) -> Self {
+impl TryFromRef<Pat> for syn::Pat {
+    fn try_from_ref(node: &Pat) -> Result<Self, Error> {
         match node {
-            Pat::Const(_0) => syn::Pat::Const((*_0).ref_into()),
-            Pat::Ident(_0) => syn::Pat::Ident((*_0).ref_into()),
-            Pat::Lit(_0) => syn::Pat::Lit((*_0).ref_into()),
-            Pat::Macro(_0) => syn::Pat::Macro((*_0).ref_into()),
-            Pat::Or(_0) => syn::Pat::Or((*_0).ref_into()),
-            Pat::Paren(_0) => syn::Pat::Paren((*_0).ref_into()),
-            Pat::Path(_0) => syn::Pat::Path((*_0).ref_into()),
-            Pat::Range(_0) => syn::Pat::Range((*_0).ref_into()),
-            Pat::Reference(_0) => syn::Pat::Reference((*_0).ref_into()),
-            Pat::Rest(_0) => syn::Pat::Rest((*_0).ref_into()),
-            Pat::Slice(_0) => syn::Pat::Slice((*_0).ref_into()),
-            Pat::Struct(_0) => syn::Pat::Struct((*_0).ref_into()),
-            Pat::Tuple(_0) => syn::Pat::Tuple((*_0).ref_into()),
-            Pat::TupleStruct(_0) => syn::Pat::TupleStruct((*_0).ref_into()),
-            Pat::Type(_0) => syn::Pat::Type((*_0).ref_into()),
-            Pat::Verbatim(_0) => syn::Pat::Verbatim((*_0).ref_into()),
-            Pat::Wild(_0) => syn::Pat::Wild((*_0).ref_into()),
+            Pat::Const(_0) => {
+                Ok(syn::Pat::Const((*_0).try_ref_into().context("Pat", "Const")?))
+            }
+            Pat::Ident(_0) => {
+                Ok(syn::Pat::Ident((*_0).try_ref_into().context("Pat", "Ident")?))
+            }
+            Pat::Lit(_0) => {
+                Ok(syn::Pat::Lit((*_0).try_ref_into().context("Pat", "Lit")?))
+            }
+            Pat::Macro(_0) => {
+                Ok(syn::Pat::Macro((*_0).try_ref_into().context("Pat", "Macro")?))
+            }
+            Pat::Or(_0) => Ok(syn::Pat::Or((*_0).try_ref_into().context("Pat", "Or")?)),
+            Pat::Paren(_0) => {
+                Ok(syn::Pat::Paren((*_0).try_ref_into().context("Pat", "Paren")?))
+            }
+            Pat::Path(_0) => {
+                Ok(syn::Pat::Path((*_0).try_ref_into().context("Pat", "Path")?))
+            }
+            Pat::Range(_0) => {
+                Ok(syn::Pat::Range((*_0).try_ref_into().context("Pat", "Range")?))
+            }
+            Pat::Reference(_0) => {
+                Ok(
+                    syn::Pat::Reference(
+                        (*_0).try_ref_into().context("Pat", "Reference")?,
+                    ),
+                )
+            }
+            Pat::Rest(_0) => {
+                Ok(syn::Pat::Rest((*_0).try_ref_into().context("Pat", "Rest")?))
+            }
+            Pat::Slice(_0) => {
+                Ok(syn::Pat::Slice((*_0).try_ref_into().context("Pat", "Slice")?))
+            }
+            Pat::Struct(_0) => {
+                Ok(syn::Pat::Struct((*_0).try_ref_into().context("Pat", "Struct")?))
+            }
+            Pat::Tuple(_0) => {
+                Ok(syn::Pat::Tuple((*_0).try_ref_into().context("Pat", "Tuple")?))
+            }
+            Pat::TupleStruct(_0) => {
+                Ok(
+                    syn::Pat::TupleStruct(
+                        (*_0).try_ref_into().context("Pat", "TupleStruct")?,
+                    ),
+                )
+            }
+            Pat::Type(_0) => {
+                Ok(syn::Pat::Type((*_0).try_ref_into().context("Pat", "Type")?))
+            }
+            Pat::Verbatim(_0) => {
+                Ok(syn::Pat::Verbatim((*_0).try_ref_into().context("Pat", "Verbatim")?))
+            }
+            Pat::Wild(_0) => {
+                Ok(syn::Pat::Wild((*_0).try_ref_into().context("Pat", "Wild")?))
+            }
         }
     }
 }
@@ -2373,18 +3045,24 @@ impl From<&syn::PatIdent> for PatIdent {
             mutability: node.mutability.is_some(),
             ident: node.ident.ref_into(),
             subpat: node.subpat.ref_map(|(_0, _1)| (*_1).map_into()),
+            span: None,
         }
     }
 }
-impl From<&PatIdent> for syn::PatIdent {
-    fn from(node: &PatIdent) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<PatIdent> for syn::PatIdent {
+    fn try_from_ref(node: &PatIdent) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("PatIdent", "attrs")?,
             by_ref: default_or_none(node.by_ref),
             mutability: default_or_none(node.mutability),
-            ident: node.ident.ref_into(),
-            subpat: node.subpat.ref_map(|_1| (default(), (*_1).map_into())),
-        }
+            ident: node.ident.try_ref_into().context("PatIdent", "ident")?,
+            subpat: node
+                .subpat
+                .try_ref_map(|_1| Ok((
+                    default(),
+                    (*_1).try_map_into().context("PatIdent", "subpat")?,
+                )))?,
+        })
     }
 }
 syn_trait_impl!(syn::PatOr);
@@ -2394,16 +3072,17 @@ impl From<&syn::PatOr> for PatOr {
             attrs: node.attrs.map_into(),
             leading_vert: node.leading_vert.is_some(),
             cases: node.cases.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PatOr> for syn::PatOr {
-    fn from(node: &PatOr) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<PatOr> for syn::PatOr {
+    fn try_from_ref(node: &PatOr) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("PatOr", "attrs")?,
             leading_vert: default_or_none(node.leading_vert),
-            cases: node.cases.map_into(),
-        }
+            cases: node.cases.try_map_into().context("PatOr", "cases")?,
+        })
     }
 }
 syn_trait_impl!(syn::PatParen);
@@ -2412,16 +3091,17 @@ impl From<&syn::PatParen> for PatParen {
         Self {
             attrs: node.attrs.map_into(),
             pat: node.pat.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PatParen> for syn::PatParen {
-    fn from(node: &PatParen) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<PatParen> for syn::PatParen {
+    fn try_from_ref(node: &PatParen) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("PatParen", "attrs")?,
             paren_token: default(),
-            pat: node.pat.map_into(),
-        }
+            pat: node.pat.try_map_into().context("PatParen", "pat")?,
+        })
     }
 }
 syn_trait_impl!(syn::PatReference);
@@ -2431,17 +3111,18 @@ impl From<&syn::PatReference> for PatReference {
             attrs: node.attrs.map_into(),
             mutability: node.mutability.is_some(),
             pat: node.pat.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PatReference> for syn::PatReference {
-    fn from(node: &PatReference) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<PatReference> for syn::PatReference {
+    fn try_from_ref(node: &PatReference) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("PatReference", "attrs")?,
             and_token: default(),
             mutability: default_or_none(node.mutability),
-            pat: node.pat.map_into(),
-        }
+            pat: node.pat.try_map_into().context("PatReference", "pat")?,
+        })
     }
 }
 syn_trait_impl!(syn::PatRest);
@@ -2449,15 +3130,16 @@ impl From<&syn::PatRest> for PatRest {
     fn from(node: &syn::PatRest) -> Self {
         Self {
             attrs: node.attrs.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PatRest> for syn::PatRest {
-    fn from(node: &PatRest) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<PatRest> for syn::PatRest {
+    fn try_from_ref(node: &PatRest) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("PatRest", "attrs")?,
             dot2_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::PatSlice);
@@ -2466,16 +3148,17 @@ impl From<&syn::PatSlice> for PatSlice {
         Self {
             attrs: node.attrs.map_into(),
             elems: node.elems.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PatSlice> for syn::PatSlice {
-    fn from(node: &PatSlice) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<PatSlice> for syn::PatSlice {
+    fn try_from_ref(node: &PatSlice) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("PatSlice", "attrs")?,
             bracket_token: default(),
-            elems: node.elems.map_into(),
-        }
+            elems: node.elems.try_map_into().context("PatSlice", "elems")?,
+        })
     }
 }
 syn_trait_impl!(syn::PatStruct);
@@ -2487,19 +3170,20 @@ impl From<&syn::PatStruct> for PatStruct {
             path: node.path.ref_into(),
             fields: node.fields.map_into(),
             rest: node.rest.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PatStruct> for syn::PatStruct {
-    fn from(node: &PatStruct) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            qself: node.qself.map_into(),
-            path: node.path.ref_into(),
+impl TryFromRef<PatStruct> for syn::PatStruct {
+    fn try_from_ref(node: &PatStruct) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("PatStruct", "attrs")?,
+            qself: node.qself.try_map_into().context("PatStruct", "qself")?,
+            path: node.path.try_ref_into().context("PatStruct", "path")?,
             brace_token: default(),
-            fields: node.fields.map_into(),
-            rest: node.rest.map_into(),
-        }
+            fields: node.fields.try_map_into().context("PatStruct", "fields")?,
+            rest: node.rest.try_map_into().context("PatStruct", "rest")?,
+        })
     }
 }
 syn_trait_impl!(syn::PatTuple);
@@ -2508,16 +3192,17 @@ impl From<&syn::PatTuple> for PatTuple {
         Self {
             attrs: node.attrs.map_into(),
             elems: node.elems.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PatTuple> for syn::PatTuple {
-    fn from(node: &PatTuple) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<PatTuple> for syn::PatTuple {
+    fn try_from_ref(node: &PatTuple) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("PatTuple", "attrs")?,
             paren_token: default(),
-            elems: node.elems.map_into(),
-        }
+            elems: node.elems.try_map_into().context("PatTuple", "elems")?,
+        })
     }
 }
 syn_trait_impl!(syn::PatTupleStruct);
@@ -2528,18 +3213,19 @@ impl From<&syn::PatTupleStruct> for PatTupleStruct {
             qself: node.qself.map_into(),
             path: node.path.ref_into(),
             elems: node.elems.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PatTupleStruct> for syn::PatTupleStruct {
-    fn from(node: &PatTupleStruct) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            qself: node.qself.map_into(),
-            path: node.path.ref_into(),
+impl TryFromRef<PatTupleStruct> for syn::PatTupleStruct {
+    fn try_from_ref(node: &PatTupleStruct) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("PatTupleStruct", "attrs")?,
+            qself: node.qself.try_map_into().context("PatTupleStruct", "qself")?,
+            path: node.path.try_ref_into().context("PatTupleStruct", "path")?,
             paren_token: default(),
-            elems: node.elems.map_into(),
-        }
+            elems: node.elems.try_map_into().context("PatTupleStruct", "elems")?,
+        })
     }
 }
 syn_trait_impl!(syn::PatType);
@@ -2549,17 +3235,18 @@ impl From<&syn::PatType> for PatType {
             attrs: node.attrs.map_into(),
             pat: node.pat.map_into(),
             ty: node.ty.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PatType> for syn::PatType {
-    fn from(node: &PatType) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            pat: node.pat.map_into(),
+impl TryFromRef<PatType> for syn::PatType {
+    fn try_from_ref(node: &PatType) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("PatType", "attrs")?,
+            pat: node.pat.try_map_into().context("PatType", "pat")?,
             colon_token: default(),
-            ty: node.ty.map_into(),
-        }
+            ty: node.ty.try_map_into().context("PatType", "ty")?,
+        })
     }
 }
 syn_trait_impl!(syn::PatWild);
@@ -2567,15 +3254,16 @@ impl From<&syn::PatWild> for PatWild {
     fn from(node: &syn::PatWild) -> Self {
         Self {
             attrs: node.attrs.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PatWild> for syn::PatWild {
-    fn from(node: &PatWild) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<PatWild> for syn::PatWild {
+    fn try_from_ref(node: &PatWild) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("PatWild", "attrs")?,
             underscore_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::Path);
@@ -2587,12 +3275,12 @@ impl From<&syn::Path> for Path {
         }
     }
 }
-impl From<&Path> for syn::Path {
-    fn from(node: &Path) -> Self {
-        Self {
+impl TryFromRef<Path> for syn::Path {
+    fn try_from_ref(node: &Path) -> Result<Self, Error> {
+        Ok(Self {
             leading_colon: default_or_none(node.leading_colon),
-            segments: node.segments.map_into(),
-        }
+            segments: node.segments.try_map_into().context("Path", "segments")?,
+        })
     }
 }
 syn_trait_impl!(syn::PathArguments);
@@ -2609,15 +3297,23 @@ impl From<&syn::PathArguments> for PathArguments {
         }
     }
 }
-impl From<&PathArguments> for syn::PathArguments {
-    fn from(node: &PathArguments) -> Self {
+impl TryFromRef<PathArguments> for syn::PathArguments {
+    fn try_from_ref(node: &PathArguments) -> Result<Self, Error> {
         match node {
-            PathArguments::None => syn::PathArguments::None,
+            PathArguments::None => Ok(syn::PathArguments::None),
             PathArguments::AngleBracketed(_0) => {
-                syn::PathArguments::AngleBracketed((*_0).ref_into())
+                Ok(
+                    syn::PathArguments::AngleBracketed(
+                        (*_0).try_ref_into().context("PathArguments", "AngleBracketed")?,
+                    ),
+                )
             }
             PathArguments::Parenthesized(_0) => {
-                syn::PathArguments::Parenthesized((*_0).ref_into())
+                Ok(
+                    syn::PathArguments::Parenthesized(
+                        (*_0).try_ref_into().context("PathArguments", "Parenthesized")?,
+                    ),
+                )
             }
         }
     }
@@ -2628,33 +3324,73 @@ impl From<&syn::PathSegment> for PathSegment {
         Self {
             ident: node.ident.ref_into(),
             arguments: node.arguments.ref_into(),
+            span: None,
+        }
+    }
+}
+impl TryFromRef<PathSegment> for syn::PathSegment {
+    fn try_from_ref(node: &PathSegment) -> Result<Self, Error> {
+        Ok(Self {
+            ident: node.ident.try_ref_into().context("PathSegment", "ident")?,
+            arguments: node.arguments.try_ref_into().context("PathSegment", "arguments")?,
+        })
+    }
+}
+syn_trait_impl!(syn::PointerMutability);
+impl From<&syn::PointerMutability> for PointerMutability {
+    fn from(node: &syn::PointerMutability) -> Self {
+        match node {
+            syn::PointerMutability::Const(..) => PointerMutability::Const,
+            syn::PointerMutability::Mut(..) => PointerMutability::Mut,
         }
     }
 }
-impl From<&PathSegment> for syn::PathSegment {
-    fn from(node: &PathSegment) -> Self {
+impl TryFromRef<PointerMutability> for syn::PointerMutability {
+    fn try_from_ref(node: &PointerMutability) -> Result<Self, Error> {
+        match node {
+            PointerMutability::Const => Ok(syn::PointerMutability::Const(default())),
+            PointerMutability::Mut => Ok(syn::PointerMutability::Mut(default())),
+        }
+    }
+}
+syn_trait_impl!(syn::PreciseCapture);
+impl From<&syn::PreciseCapture> for PreciseCapture {
+    fn from(node: &syn::PreciseCapture) -> Self {
         Self {
-            ident: node.ident.ref_into(),
-            arguments: node.arguments.ref_into(),
+            params: node.params.map_into(),
         }
     }
 }
+impl TryFromRef<PreciseCapture> for syn::PreciseCapture {
+    fn try_from_ref(node: &PreciseCapture) -> Result<Self, Error> {
+        Ok(Self {
+            use_token: default(),
+            lt_token: default(),
+            params: node.params.try_map_into().context("PreciseCapture", "params")?,
+            gt_token: default(),
+        })
+    }
+}
 syn_trait_impl!(syn::PredicateLifetime);
 impl From<&syn::PredicateLifetime> for PredicateLifetime {
     fn from(node: &syn::PredicateLifetime) -> Self {
         Self {
             lifetime: node.lifetime.ref_into(),
             bounds: node.bounds.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PredicateLifetime> for syn::PredicateLifetime {
-    fn from(node: &PredicateLifetime) -> Self {
-        Self {
-            lifetime: node.lifetime.ref_into(),
+impl TryFromRef<PredicateLifetime> for syn::PredicateLifetime {
+    fn try_from_ref(node: &PredicateLifetime) -> Result<Self, Error> {
+        Ok(Self {
+            lifetime: node
+                .lifetime
+                .try_ref_into()
+                .context("PredicateLifetime", "lifetime")?,
             colon_token: default(),
-            bounds: node.bounds.map_into(),
-        }
+            bounds: node.bounds.try_map_into().context("PredicateLifetime", "bounds")?,
+        })
     }
 }
 syn_trait_impl!(syn::PredicateType);
@@ -2664,17 +3400,24 @@ impl From<&syn::PredicateType> for PredicateType {
             lifetimes: node.lifetimes.map_into(),
             bounded_ty: node.bounded_ty.ref_into(),
             bounds: node.bounds.map_into(),
+            span: None,
         }
     }
 }
-impl From<&PredicateType> for syn::PredicateType {
-    fn from(node: &PredicateType) -> Self {
-        Self {
-            lifetimes: node.lifetimes.map_into(),
-            bounded_ty: node.bounded_ty.ref_into(),
+impl TryFromRef<PredicateType> for syn::PredicateType {
+    fn try_from_ref(node: &PredicateType) -> Result<Self, Error> {
+        Ok(Self {
+            lifetimes: node
+                .lifetimes
+                .try_map_into()
+                .context("PredicateType", "lifetimes")?,
+            bounded_ty: node
+                .bounded_ty
+                .try_ref_into()
+                .context("PredicateType", "bounded_ty")?,
             colon_token: default(),
-            bounds: node.bounds.map_into(),
-        }
+            bounds: node.bounds.try_map_into().context("PredicateType", "bounds")?,
+        })
     }
 }
 syn_trait_impl!(syn::QSelf);
@@ -2684,18 +3427,19 @@ impl From<&syn::QSelf> for QSelf {
             ty: node.ty.map_into(),
             position: node.position,
             as_token: node.as_token.is_some(),
+            span: None,
         }
     }
 }
-impl From<&QSelf> for syn::QSelf {
-    fn from(node: &QSelf) -> Self {
-        Self {
+impl TryFromRef<QSelf> for syn::QSelf {
+    fn try_from_ref(node: &QSelf) -> Result<Self, Error> {
+        Ok(Self {
             lt_token: default(),
-            ty: node.ty.map_into(),
+            ty: node.ty.try_map_into().context("QSelf", "ty")?,
             position: node.position,
             as_token: default_or_none(node.as_token),
             gt_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::RangeLimits);
@@ -2707,11 +3451,11 @@ impl From<&syn::RangeLimits> for RangeLimits {
         }
     }
 }
-impl From<&RangeLimits> for syn::RangeLimits {
-    fn from(node: &RangeLimits) -> Self {
+impl TryFromRef<RangeLimits> for syn::RangeLimits {
+    fn try_from_ref(node: &RangeLimits) -> Result<Self, Error> {
         match node {
-            RangeLimits::HalfOpen => syn::RangeLimits::HalfOpen(default()),
-            RangeLimits::Closed => syn::RangeLimits::Closed(default()),
+            RangeLimits::HalfOpen => Ok(syn::RangeLimits::HalfOpen(default())),
+            RangeLimits::Closed => Ok(syn::RangeLimits::Closed(default())),
         }
     }
 }
@@ -2731,21 +3475,21 @@ impl From<&syn::Signature> for Signature {
         }
     }
 }
-impl From<&Signature> for syn::Signature {
-    fn from(node: &Signature) -> Self {
-        Self {
+impl TryFromRef<Signature> for syn::Signature {
+    fn try_from_ref(node: &Signature) -> Result<Self, Error> {
+        Ok(Self {
             constness: default_or_none(node.constness),
             asyncness: default_or_none(node.asyncness),
             unsafety: default_or_none(node.unsafety),
-            abi: node.abi.map_into(),
+            abi: node.abi.try_map_into().context("Signature", "abi")?,
             fn_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
+            ident: node.ident.try_ref_into().context("Signature", "ident")?,
+            generics: node.generics.try_ref_into().context("Signature", "generics")?,
             paren_token: default(),
-            inputs: node.inputs.map_into(),
-            variadic: node.variadic.map_into(),
-            output: node.output.ref_into(),
-        }
+            inputs: node.inputs.try_map_into().context("Signature", "inputs")?,
+            variadic: node.variadic.try_map_into().context("Signature", "variadic")?,
+            output: node.output.try_ref_into().context("Signature", "output")?,
+        })
     }
 }
 syn_trait_impl!(syn::StaticMutability);
@@ -2758,11 +3502,11 @@ impl From<&syn::StaticMutability> for StaticMutability {
         }
     }
 }
-impl From<&StaticMutability> for syn::StaticMutability {
-    fn from(node: &StaticMutability) -> Self {
+impl TryFromRef<StaticMutability> for syn::StaticMutability {
+    fn try_from_ref(node: &StaticMutability) -> Result<Self, Error> {
         match node {
-            StaticMutability::Mut => syn::StaticMutability::Mut(default()),
-            StaticMutability::None => syn::StaticMutability::None,
+            StaticMutability::Mut => Ok(syn::StaticMutability::Mut(default())),
+            StaticMutability::None => Ok(syn::StaticMutability::None),
         }
     }
 }
@@ -2777,15 +3521,26 @@ impl From<&syn::Stmt> for Stmt {
         }
     }
 }
-impl From<&Stmt> for syn::Stmt {
-    fn from(node: &Stmt) -> Self {
+impl TryFromRef<Stmt> for syn::Stmt {
+    fn try_from_ref(node: &Stmt) -> Result<Self, Error> {
         match node {
-            Stmt::Local(_0) => syn::Stmt::Local((*_0).ref_into()),
-            Stmt::Item(_0) => syn::Stmt::Item((*_0).ref_into()),
+            Stmt::Local(_0) => {
+                Ok(syn::Stmt::Local((*_0).try_ref_into().context("Stmt", "Local")?))
+            }
+            Stmt::Item(_0) => {
+                Ok(syn::Stmt::Item((*_0).try_ref_into().context("Stmt", "Item")?))
+            }
             Stmt::Expr(_0, _1) => {
-                syn::Stmt::Expr((*_0).ref_into(), default_or_none((*_1)))
+                Ok(
+                    syn::Stmt::Expr(
+                        (*_0).try_ref_into().context("Stmt", "Expr")?,
+                        default_or_none((*_1)),
+                    ),
+                )
+            }
+            Stmt::Macro(_0) => {
+                Ok(syn::Stmt::Macro((*_0).try_ref_into().context("Stmt", "Macro")?))
             }
-            Stmt::Macro(_0) => syn::Stmt::Macro((*_0).ref_into()),
         }
     }
 }
@@ -2796,16 +3551,17 @@ impl From<&syn::StmtMacro> for StmtMacro {
             attrs: node.attrs.map_into(),
             mac: node.mac.ref_into(),
             semi_token: node.semi_token.is_some(),
+            span: None,
         }
     }
 }
-impl From<&StmtMacro> for syn::StmtMacro {
-    fn from(node: &StmtMacro) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            mac: node.mac.ref_into(),
+impl TryFromRef<StmtMacro> for syn::StmtMacro {
+    fn try_from_ref(node: &StmtMacro) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("StmtMacro", "attrs")?,
+            mac: node.mac.try_ref_into().context("StmtMacro", "mac")?,
             semi_token: default_or_none(node.semi_token),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::TraitBound);
@@ -2816,17 +3572,18 @@ impl From<&syn::TraitBound> for TraitBound {
             modifier: node.modifier.ref_into(),
             lifetimes: node.lifetimes.map_into(),
             path: node.path.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&TraitBound> for syn::TraitBound {
-    fn from(node: &TraitBound) -> Self {
-        Self {
+impl TryFromRef<TraitBound> for syn::TraitBound {
+    fn try_from_ref(node: &TraitBound) -> Result<Self, Error> {
+        Ok(Self {
             paren_token: default_or_none(node.paren_token),
-            modifier: node.modifier.ref_into(),
-            lifetimes: node.lifetimes.map_into(),
-            path: node.path.ref_into(),
-        }
+            modifier: node.modifier.try_ref_into().context("TraitBound", "modifier")?,
+            lifetimes: node.lifetimes.try_map_into().context("TraitBound", "lifetimes")?,
+            path: node.path.try_ref_into().context("TraitBound", "path")?,
+        })
     }
 }
 syn_trait_impl!(syn::TraitBoundModifier);
@@ -2838,11 +3595,11 @@ impl From<&syn::TraitBoundModifier> for TraitBoundModifier {
         }
     }
 }
-impl From<&TraitBoundModifier> for syn::TraitBoundModifier {
-    fn from(node: &TraitBoundModifier) -> Self {
+impl TryFromRef<TraitBoundModifier> for syn::TraitBoundModifier {
+    fn try_from_ref(node: &TraitBoundModifier) -> Result<Self, Error> {
         match node {
-            TraitBoundModifier::None => syn::TraitBoundModifier::None,
-            TraitBoundModifier::Maybe => syn::TraitBoundModifier::Maybe(default()),
+            TraitBoundModifier::None => Ok(syn::TraitBoundModifier::None),
+            TraitBoundModifier::Maybe => Ok(syn::TraitBoundModifier::Maybe(default())),
         }
     }
 }
@@ -2859,14 +3616,40 @@ impl From<&syn::TraitItem> for TraitItem {
         }
     }
 }
-impl From<&TraitItem> for syn::TraitItem {
-    fn from(node: &TraitItem) -> Self {
+impl TryFromRef<TraitItem> for syn::TraitItem {
+    fn try_from_ref(node: &TraitItem) -> Result<Self, Error> {
         match node {
-            TraitItem::Const(_0) => syn::TraitItem::Const((*_0).ref_into()),
-            TraitItem::Fn(_0) => syn::TraitItem::Fn((*_0).ref_into()),
-            TraitItem::Type(_0) => syn::TraitItem::Type((*_0).ref_into()),
-            TraitItem::Macro(_0) => syn::TraitItem::Macro((*_0).ref_into()),
-            TraitItem::Verbatim(_0) => syn::TraitItem::Verbatim((*_0).ref_into()),
+            TraitItem::Const(_0) => {
+                Ok(
+                    syn::TraitItem::Const(
+                        (*_0).try_ref_into().context("TraitItem", "Const")?,
+                    ),
+                )
+            }
+            TraitItem::Fn(_0) => {
+                Ok(syn::TraitItem::Fn((*_0).try_ref_into().context("TraitItem", "Fn")?))
+            }
+            TraitItem::Type(_0) => {
+                Ok(
+                    syn::TraitItem::Type(
+                        (*_0).try_ref_into().context("TraitItem", "Type")?,
+                    ),
+                )
+            }
+            TraitItem::Macro(_0) => {
+                Ok(
+                    syn::TraitItem::Macro(
+                        (*_0).try_ref_into().context("TraitItem", "Macro")?,
+                    ),
+                )
+            }
+            TraitItem::Verbatim(_0) => {
+                Ok(
+                    syn::TraitItem::Verbatim(
+                        (*_0).try_ref_into().context("TraitItem", "Verbatim")?,
+                    ),
+                )
+            }
         }
     }
 }
@@ -2879,21 +3662,31 @@ impl From<&syn::TraitItemConst> for TraitItemConst {
             generics: node.generics.ref_into(),
             ty: node.ty.ref_into(),
             default: node.default.ref_map(|(_0, _1)| (*_1).ref_into()),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&TraitItemConst> for syn::TraitItemConst {
-    fn from(node: &TraitItemConst) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<TraitItemConst> for syn::TraitItemConst {
+    fn try_from_ref(node: &TraitItemConst) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("TraitItemConst", "attrs")?,
             const_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
+            ident: node.ident.try_ref_into().context("TraitItemConst", "ident")?,
+            generics: node
+                .generics
+                .try_ref_into()
+                .context("TraitItemConst", "generics")?,
             colon_token: default(),
-            ty: node.ty.ref_into(),
-            default: node.default.ref_map(|_1| (default(), (*_1).ref_into())),
+            ty: node.ty.try_ref_into().context("TraitItemConst", "ty")?,
+            default: node
+                .default
+                .try_ref_map(|_1| Ok((
+                    default(),
+                    (*_1).try_ref_into().context("TraitItemConst", "default")?,
+                )))?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::TraitItemMacro);
@@ -2903,16 +3696,18 @@ impl From<&syn::TraitItemMacro> for TraitItemMacro {
             attrs: node.attrs.map_into(),
             mac: node.mac.ref_into(),
             semi_token: node.semi_token.is_some(),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&TraitItemMacro> for syn::TraitItemMacro {
-    fn from(node: &TraitItemMacro) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            mac: node.mac.ref_into(),
+impl TryFromRef<TraitItemMacro> for syn::TraitItemMacro {
+    fn try_from_ref(node: &TraitItemMacro) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("TraitItemMacro", "attrs")?,
+            mac: node.mac.try_ref_into().context("TraitItemMacro", "mac")?,
             semi_token: default_or_none(node.semi_token),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::TraitItemType);
@@ -2925,21 +3720,28 @@ impl From<&syn::TraitItemType> for TraitItemType {
             colon_token: node.colon_token.is_some(),
             bounds: node.bounds.map_into(),
             default: node.default.ref_map(|(_0, _1)| (*_1).ref_into()),
+            span: None,
+            trivia: None,
         }
     }
 }
-impl From<&TraitItemType> for syn::TraitItemType {
-    fn from(node: &TraitItemType) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
+impl TryFromRef<TraitItemType> for syn::TraitItemType {
+    fn try_from_ref(node: &TraitItemType) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("TraitItemType", "attrs")?,
             type_token: default(),
-            ident: node.ident.ref_into(),
-            generics: node.generics.ref_into(),
+            ident: node.ident.try_ref_into().context("TraitItemType", "ident")?,
+            generics: node.generics.try_ref_into().context("TraitItemType", "generics")?,
             colon_token: default_or_none(node.colon_token),
-            bounds: node.bounds.map_into(),
-            default: node.default.ref_map(|_1| (default(), (*_1).ref_into())),
+            bounds: node.bounds.try_map_into().context("TraitItemType", "bounds")?,
+            default: node
+                .default
+                .try_ref_map(|_1| Ok((
+                    default(),
+                    (*_1).try_ref_into().context("TraitItemType", "default")?,
+                )))?,
             semi_token: default(),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::Type);
@@ -2965,32 +3767,78 @@ impl From<&syn::Type> for Type {
         }
     }
 }
-impl From<&Type> for syn::Type {
-    fn from(node: &Type) -> Self {
+impl TryFromRef<Type> for syn::Type {
+    fn try_from_ref(node: &Type) -> Result<Self, Error> {
         match node {
-            Type::Array(_0) => syn::Type::Array((*_0).ref_into()),
-            Type::BareFn(_0) => syn::Type::BareFn((*_0).ref_into()),
-            Type::Group(_0) => syn::Type::Group((*_0).ref_into()),
-            Type::ImplTrait(_0) => syn::Type::ImplTrait((*_0).ref_into()),
+            Type::Array(_0) => {
+                Ok(syn::Type::Array((*_0).try_ref_into().context("Type", "Array")?))
+            }
+            Type::BareFn(_0) => {
+                Ok(syn::Type::BareFn((*_0).try_ref_into().context("Type", "BareFn")?))
+            }
+            Type::Group(_0) => {
+                Ok(syn::Type::Group((*_0).try_ref_into().context("Type", "Group")?))
+            }
+            Type::ImplTrait(_0) => {
+                Ok(
+                    syn::Type::ImplTrait(
+                        (*_0).try_ref_into().context("Type", "ImplTrait")?,
+                    ),
+                )
+            }
             Type::Infer => {
-                syn::Type::Infer(syn::TypeInfer {
-                    underscore_token: default(),
-                })
+                Ok(
+                    syn::Type::Infer(syn::TypeInfer {
+                        underscore_token: default(),
+                    }),
+                )
+            }
+            Type::Macro(_0) => {
+                Ok(syn::Type::Macro((*_0).try_ref_into().context("Type", "Macro")?))
             }
-            Type::Macro(_0) => syn::Type::Macro((*_0).ref_into()),
             Type::Never => {
-                syn::Type::Never(syn::TypeNever {
-                    bang_token: default(),
-                })
+                Ok(
+                    syn::Type::Never(syn::TypeNever {
+                        bang_token: default(),
+                    }),
+                )
+            }
+            Type::Paren(_0) => {
+                Ok(syn::Type::Paren((*_0).try_ref_into().context("Type", "Paren")?))
+            }
+            Type::Path(_0) => {
+                Ok(syn::Type::Path((*_0).try_ref_into().context("Type", "Path")?))
+            }
+            Type::Ptr(_0) => {
+                Ok(syn::Type::Ptr((*_0).try_ref_into().context("Type", "Ptr")?))
+            }
+            Type::Reference(_0) => {
+                Ok(
+                    syn::Type::Reference(
+                        (*_0).try_ref_into().context("Type", "Reference")?,
+                    ),
+                )
+            }
+            Type::Slice(_0) => {
+                Ok(syn::Type::Slice((*_0).try_ref_into().context("Type", "Slice")?))
+            }
+            Type::TraitObject(_0) => {
+                Ok(
+                    syn::Type::TraitObject(
+                        (*_0).try_ref_into().context("Type", "TraitObject")?,
+                    ),
+                )
+            }
+            Type::Tuple(_0) => {
+                Ok(syn::Type::Tuple((*_0).try_ref_into().context("Type", "Tuple")?))
+            }
+            Type::Verbatim(_0) => {
+                Ok(
+                    syn::Type::Verbatim(
+                        (*_0).try_ref_into().context("Type", "Verbatim")?,
+                    ),
+                )
             }
-            Type::Paren(_0) => syn::Type::Paren((*_0).ref_into()),
-            Type::Path(_0) => syn::Type::Path((*_0).ref_into()),
-            Type::Ptr(_0) => syn::Type::Ptr((*_0).ref_into()),
-            Type::Reference(_0) => syn::Type::Reference((*_0).ref_into()),
-            Type::Slice(_0) => syn::Type::Slice((*_0).ref_into()),
-            Type::TraitObject(_0) => syn::Type::TraitObject((*_0).ref_into()),
-            Type::Tuple(_0) => syn::Type::Tuple((*_0).ref_into()),
-            Type::Verbatim(_0) => syn::Type::Verbatim((*_0).ref_into()),
         }
     }
 }
@@ -3000,17 +3848,18 @@ impl From<&syn::TypeArray> for TypeArray {
         Self {
             elem: node.elem.map_into(),
             len: node.len.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&TypeArray> for syn::TypeArray {
-    fn from(node: &TypeArray) -> Self {
-        Self {
+impl TryFromRef<TypeArray> for syn::TypeArray {
+    fn try_from_ref(node: &TypeArray) -> Result<Self, Error> {
+        Ok(Self {
             bracket_token: default(),
-            elem: node.elem.map_into(),
+            elem: node.elem.try_map_into().context("TypeArray", "elem")?,
             semi_token: default(),
-            len: node.len.ref_into(),
-        }
+            len: node.len.try_ref_into().context("TypeArray", "len")?,
+        })
     }
 }
 syn_trait_impl!(syn::TypeBareFn);
@@ -3023,62 +3872,72 @@ impl From<&syn::TypeBareFn> for TypeBareFn {
             inputs: node.inputs.map_into(),
             variadic: node.variadic.map_into(),
             output: node.output.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&TypeBareFn> for syn::TypeBareFn {
-    fn from(node: &TypeBareFn) -> Self {
-        Self {
-            lifetimes: node.lifetimes.map_into(),
+impl TryFromRef<TypeBareFn> for syn::TypeBareFn {
+    fn try_from_ref(node: &TypeBareFn) -> Result<Self, Error> {
+        Ok(Self {
+            lifetimes: node.lifetimes.try_map_into().context("TypeBareFn", "lifetimes")?,
             unsafety: default_or_none(node.unsafety),
-            abi: node.abi.map_into(),
+            abi: node.abi.try_map_into().context("TypeBareFn", "abi")?,
             fn_token: default(),
             paren_token: default(),
-            inputs: node.inputs.map_into(),
-            variadic: node.variadic.map_into(),
-            output: node.output.ref_into(),
-        }
+            inputs: node.inputs.try_map_into().context("TypeBareFn", "inputs")?,
+            variadic: node.variadic.try_map_into().context("TypeBareFn", "variadic")?,
+            output: node.output.try_ref_into().context("TypeBareFn", "output")?,
+        })
     }
 }
 syn_trait_impl!(syn::TypeGroup);
 impl From<&syn::TypeGroup> for TypeGroup {
     fn from(node: &syn::TypeGroup) -> Self {
-        Self { elem: node.elem.map_into() }
-    }
-}
-impl From<&TypeGroup> for syn::TypeGroup {
-    fn from(node: &TypeGroup) -> Self {
         Self {
-            group_token: default(),
             elem: node.elem.map_into(),
+            span: None,
         }
     }
 }
+impl TryFromRef<TypeGroup> for syn::TypeGroup {
+    fn try_from_ref(node: &TypeGroup) -> Result<Self, Error> {
+        Ok(Self {
+            group_token: default(),
+            elem: node.elem.try_map_into().context("TypeGroup", "elem")?,
+        })
+    }
+}
 syn_trait_impl!(syn::TypeImplTrait);
 impl From<&syn::TypeImplTrait> for TypeImplTrait {
     fn from(node: &syn::TypeImplTrait) -> Self {
         Self {
             bounds: node.bounds.map_into(),
+            span: None,
         }
     }
 }
-impl From<&TypeImplTrait> for syn::TypeImplTrait {
-    fn from(node: &TypeImplTrait) -> Self {
-        Self {
+impl TryFromRef<TypeImplTrait> for syn::TypeImplTrait {
+    fn try_from_ref(node: &TypeImplTrait) -> Result<Self, Error> {
+        Ok(Self {
             impl_token: default(),
-            bounds: node.bounds.map_into(),
-        }
+            bounds: node.bounds.try_map_into().context("TypeImplTrait", "bounds")?,
+        })
     }
 }
 syn_trait_impl!(syn::TypeMacro);
 impl From<&syn::TypeMacro> for TypeMacro {
     fn from(node: &syn::TypeMacro) -> Self {
-        Self { mac: node.mac.ref_into() }
+        Self {
+            mac: node.mac.ref_into(),
+            span: None,
+        }
     }
 }
-impl From<&TypeMacro> for syn::TypeMacro {
-    fn from(node: &TypeMacro) -> Self {
-        Self { mac: node.mac.ref_into() }
+impl TryFromRef<TypeMacro> for syn::TypeMacro {
+    fn try_from_ref(node: &TypeMacro) -> Result<Self, Error> {
+        Ok(Self {
+            mac: node.mac.try_ref_into().context("TypeMacro", "mac")?,
+        })
     }
 }
 syn_trait_impl!(syn::TypeParam);
@@ -3091,19 +3950,20 @@ impl From<&syn::TypeParam> for TypeParam {
             bounds: node.bounds.map_into(),
             eq_token: node.eq_token.is_some(),
             default: node.default.map_into(),
+            span: None,
         }
     }
 }
-impl From<&TypeParam> for syn::TypeParam {
-    fn from(node: &TypeParam) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            ident: node.ident.ref_into(),
+impl TryFromRef<TypeParam> for syn::TypeParam {
+    fn try_from_ref(node: &TypeParam) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("TypeParam", "attrs")?,
+            ident: node.ident.try_ref_into().context("TypeParam", "ident")?,
             colon_token: default_or_none(node.colon_token),
-            bounds: node.bounds.map_into(),
+            bounds: node.bounds.try_map_into().context("TypeParam", "bounds")?,
             eq_token: default_or_none(node.eq_token),
-            default: node.default.map_into(),
-        }
+            default: node.default.try_map_into().context("TypeParam", "default")?,
+        })
     }
 }
 syn_trait_impl!(syn::TypeParamBound);
@@ -3114,6 +3974,9 @@ impl From<&syn::TypeParamBound> for TypeParamBound {
             syn::TypeParamBound::Lifetime(_0) => {
                 TypeParamBound::Lifetime((*_0).ref_into())
             }
+            syn::TypeParamBound::PreciseCapture(_0) => {
+                TypeParamBound::PreciseCapture((*_0).ref_into())
+            }
             syn::TypeParamBound::Verbatim(_0) => {
                 TypeParamBound::Verbatim((*_0).ref_into())
             }
@@ -3121,15 +3984,36 @@ impl From<&syn::TypeParamBound> for TypeParamBound {
         }
     }
 }
-impl From<&TypeParamBound> for syn::TypeParamBound {
-    fn from(node: &TypeParamBound) -> Self {
+impl TryFromRef<TypeParamBound> for syn::TypeParamBound {
+    fn try_from_ref(node: &TypeParamBound) -> Result<Self, Error> {
         match node {
-            TypeParamBound::Trait(_0) => syn::TypeParamBound::Trait((*_0).ref_into()),
+            TypeParamBound::Trait(_0) => {
+                Ok(
+                    syn::TypeParamBound::Trait(
+                        (*_0).try_ref_into().context("TypeParamBound", "Trait")?,
+                    ),
+                )
+            }
             TypeParamBound::Lifetime(_0) => {
-                syn::TypeParamBound::Lifetime((*_0).ref_into())
+                Ok(
+                    syn::TypeParamBound::Lifetime(
+                        (*_0).try_ref_into().context("TypeParamBound", "Lifetime")?,
+                    ),
+                )
+            }
+            TypeParamBound::PreciseCapture(_0) => {
+                Ok(
+                    syn::TypeParamBound::PreciseCapture(
+                        (*_0).try_ref_into().context("TypeParamBound", "PreciseCapture")?,
+                    ),
+                )
             }
             TypeParamBound::Verbatim(_0) => {
-                syn::TypeParamBound::Verbatim((*_0).ref_into())
+                Ok(
+                    syn::TypeParamBound::Verbatim(
+                        (*_0).try_ref_into().context("TypeParamBound", "Verbatim")?,
+                    ),
+                )
             }
         }
     }
@@ -3137,32 +4021,36 @@ impl From<&TypeParamBound> for syn::TypeParamBound {
 syn_trait_impl!(syn::TypeParen);
 impl From<&syn::TypeParen> for TypeParen {
     fn from(node: &syn::TypeParen) -> Self {
-        Self { elem: node.elem.map_into() }
-    }
-}
-impl From<&TypeParen> for syn::TypeParen {
-    fn from(node: &TypeParen) -> Self {
         Self {
-            paren_token: default(),
             elem: node.elem.map_into(),
+            span: None,
         }
     }
 }
+impl TryFromRef<TypeParen> for syn::TypeParen {
+    fn try_from_ref(node: &TypeParen) -> Result<Self, Error> {
+        Ok(Self {
+            paren_token: default(),
+            elem: node.elem.try_map_into().context("TypeParen", "elem")?,
+        })
+    }
+}
 syn_trait_impl!(syn::TypePath);
 impl From<&syn::TypePath> for TypePath {
     fn from(node: &syn::TypePath) -> Self {
         Self {
             qself: node.qself.map_into(),
             path: node.path.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&TypePath> for syn::TypePath {
-    fn from(node: &TypePath) -> Self {
-        Self {
-            qself: node.qself.map_into(),
-            path: node.path.ref_into(),
-        }
+impl TryFromRef<TypePath> for syn::TypePath {
+    fn try_from_ref(node: &TypePath) -> Result<Self, Error> {
+        Ok(Self {
+            qself: node.qself.try_map_into().context("TypePath", "qself")?,
+            path: node.path.try_ref_into().context("TypePath", "path")?,
+        })
     }
 }
 syn_trait_impl!(syn::TypePtr);
@@ -3172,17 +4060,18 @@ impl From<&syn::TypePtr> for TypePtr {
             const_token: node.const_token.is_some(),
             mutability: node.mutability.is_some(),
             elem: node.elem.map_into(),
+            span: None,
         }
     }
 }
-impl From<&TypePtr> for syn::TypePtr {
-    fn from(node: &TypePtr) -> Self {
-        Self {
+impl TryFromRef<TypePtr> for syn::TypePtr {
+    fn try_from_ref(node: &TypePtr) -> Result<Self, Error> {
+        Ok(Self {
             star_token: default(),
             const_token: default_or_none(node.const_token),
             mutability: default_or_none(node.mutability),
-            elem: node.elem.map_into(),
-        }
+            elem: node.elem.try_map_into().context("TypePtr", "elem")?,
+        })
     }
 }
 syn_trait_impl!(syn::TypeReference);
@@ -3192,48 +4081,53 @@ impl From<&syn::TypeReference> for TypeReference {
             lifetime: node.lifetime.map_into(),
             mutability: node.mutability.is_some(),
             elem: node.elem.map_into(),
+            span: None,
         }
     }
 }
-impl From<&TypeReference> for syn::TypeReference {
-    fn from(node: &TypeReference) -> Self {
-        Self {
+impl TryFromRef<TypeReference> for syn::TypeReference {
+    fn try_from_ref(node: &TypeReference) -> Result<Self, Error> {
+        Ok(Self {
             and_token: default(),
-            lifetime: node.lifetime.map_into(),
+            lifetime: node.lifetime.try_map_into().context("TypeReference", "lifetime")?,
             mutability: default_or_none(node.mutability),
-            elem: node.elem.map_into(),
-        }
+            elem: node.elem.try_map_into().context("TypeReference", "elem")?,
+        })
     }
 }
 syn_trait_impl!(syn::TypeSlice);
 impl From<&syn::TypeSlice> for TypeSlice {
     fn from(node: &syn::TypeSlice) -> Self {
-        Self { elem: node.elem.map_into() }
-    }
-}
-impl From<&TypeSlice> for syn::TypeSlice {
-    fn from(node: &TypeSlice) -> Self {
         Self {
-            bracket_token: default(),
             elem: node.elem.map_into(),
+            span: None,
         }
     }
 }
+impl TryFromRef<TypeSlice> for syn::TypeSlice {
+    fn try_from_ref(node: &TypeSlice) -> Result<Self, Error> {
+        Ok(Self {
+            bracket_token: default(),
+            elem: node.elem.try_map_into().context("TypeSlice", "elem")?,
+        })
+    }
+}
 syn_trait_impl!(syn::TypeTraitObject);
 impl From<&syn::TypeTraitObject> for TypeTraitObject {
     fn from(node: &syn::TypeTraitObject) -> Self {
         Self {
             dyn_token: node.dyn_token.is_some(),
             bounds: node.bounds.map_into(),
+            span: None,
         }
     }
 }
-impl From<&TypeTraitObject> for syn::TypeTraitObject {
-    fn from(node: &TypeTraitObject) -> Self {
-        Self {
+impl TryFromRef<TypeTraitObject> for syn::TypeTraitObject {
+    fn try_from_ref(node: &TypeTraitObject) -> Result<Self, Error> {
+        Ok(Self {
             dyn_token: default_or_none(node.dyn_token),
-            bounds: node.bounds.map_into(),
-        }
+            bounds: node.bounds.try_map_into().context("TypeTraitObject", "bounds")?,
+        })
     }
 }
 syn_trait_impl!(syn::TypeTuple);
@@ -3241,15 +4135,16 @@ impl From<&syn::TypeTuple> for TypeTuple {
     fn from(node: &syn::TypeTuple) -> Self {
         Self {
             elems: node.elems.map_into(),
+            span: None,
         }
     }
 }
-impl From<&TypeTuple> for syn::TypeTuple {
-    fn from(node: &TypeTuple) -> Self {
-        Self {
+impl TryFromRef<TypeTuple> for syn::TypeTuple {
+    fn try_from_ref(node: &TypeTuple) -> Result<Self, Error> {
+        Ok(Self {
             paren_token: default(),
-            elems: node.elems.map_into(),
-        }
+            elems: node.elems.try_map_into().context("TypeTuple", "elems")?,
+        })
     }
 }
 syn_trait_impl!(syn::UnOp);
@@ -3263,12 +4158,12 @@ impl From<&syn::UnOp> for UnOp {
         }
     }
 }
-impl From<&UnOp> for syn::UnOp {
-    fn from(node: &UnOp) -> Self {
+impl TryFromRef<UnOp> for syn::UnOp {
+    fn try_from_ref(node: &UnOp) -> Result<Self, Error> {
         match node {
-            UnOp::Deref => syn::UnOp::Deref(default()),
-            UnOp::Not => syn::UnOp::Not(default()),
-            UnOp::Neg => syn::UnOp::Neg(default()),
+            UnOp::Deref => Ok(syn::UnOp::Deref(default())),
+            UnOp::Not => Ok(syn::UnOp::Not(default())),
+            UnOp::Neg => Ok(syn::UnOp::Neg(default())),
         }
     }
 }
@@ -3280,12 +4175,12 @@ impl From<&syn::UseGroup> for UseGroup {
         }
     }
 }
-impl From<&UseGroup> for syn::UseGroup {
-    fn from(node: &UseGroup) -> Self {
-        Self {
+impl TryFromRef<UseGroup> for syn::UseGroup {
+    fn try_from_ref(node: &UseGroup) -> Result<Self, Error> {
+        Ok(Self {
             brace_token: default(),
-            items: node.items.map_into(),
-        }
+            items: node.items.try_map_into().context("UseGroup", "items")?,
+        })
     }
 }
 syn_trait_impl!(syn::UseName);
@@ -3296,11 +4191,11 @@ impl From<&syn::UseName> for UseName {
         }
     }
 }
-impl From<&UseName> for syn::UseName {
-    fn from(node: &UseName) -> Self {
-        Self {
-            ident: node.ident.ref_into(),
-        }
+impl TryFromRef<UseName> for syn::UseName {
+    fn try_from_ref(node: &UseName) -> Result<Self, Error> {
+        Ok(Self {
+            ident: node.ident.try_ref_into().context("UseName", "ident")?,
+        })
     }
 }
 syn_trait_impl!(syn::UsePath);
@@ -3309,16 +4204,17 @@ impl From<&syn::UsePath> for UsePath {
         Self {
             ident: node.ident.ref_into(),
             tree: node.tree.map_into(),
+            span: None,
         }
     }
 }
-impl From<&UsePath> for syn::UsePath {
-    fn from(node: &UsePath) -> Self {
-        Self {
-            ident: node.ident.ref_into(),
+impl TryFromRef<UsePath> for syn::UsePath {
+    fn try_from_ref(node: &UsePath) -> Result<Self, Error> {
+        Ok(Self {
+            ident: node.ident.try_ref_into().context("UsePath", "ident")?,
             colon2_token: default(),
-            tree: node.tree.map_into(),
-        }
+            tree: node.tree.try_map_into().context("UsePath", "tree")?,
+        })
     }
 }
 syn_trait_impl!(syn::UseRename);
@@ -3327,16 +4223,17 @@ impl From<&syn::UseRename> for UseRename {
         Self {
             ident: node.ident.ref_into(),
             rename: node.rename.ref_into(),
+            span: None,
         }
     }
 }
-impl From<&UseRename> for syn::UseRename {
-    fn from(node: &UseRename) -> Self {
-        Self {
-            ident: node.ident.ref_into(),
+impl TryFromRef<UseRename> for syn::UseRename {
+    fn try_from_ref(node: &UseRename) -> Result<Self, Error> {
+        Ok(Self {
+            ident: node.ident.try_ref_into().context("UseRename", "ident")?,
             as_token: default(),
-            rename: node.rename.ref_into(),
-        }
+            rename: node.rename.try_ref_into().context("UseRename", "rename")?,
+        })
     }
 }
 syn_trait_impl!(syn::UseTree);
@@ -3351,18 +4248,36 @@ impl From<&syn::UseTree> for UseTree {
         }
     }
 }
-impl From<&UseTree> for syn::UseTree {
-    fn from(node: &UseTree) -> Self {
+impl TryFromRef<UseTree> for syn::UseTree {
+    fn try_from_ref(node: &UseTree) -> Result<Self, Error> {
         match node {
-            UseTree::Path(_0) => syn::UseTree::Path((*_0).ref_into()),
-            UseTree::Name(_0) => syn::UseTree::Name((*_0).ref_into()),
-            UseTree::Rename(_0) => syn::UseTree::Rename((*_0).ref_into()),
+            UseTree::Path(_0) => {
+                Ok(syn::UseTree::Path((*_0).try_ref_into().context("UseTree", "Path")?))
+            }
+            UseTree::Name(_0) => {
+                Ok(syn::UseTree::Name((*_0).try_ref_into().context("UseTree", "Name")?))
+            }
+            UseTree::Rename(_0) => {
+                Ok(
+                    syn::UseTree::Rename(
+                        (*_0).try_ref_into().context("UseTree", "Rename")?,
+                    ),
+                )
+            }
             UseTree::Glob => {
-                syn::UseTree::Glob(syn::UseGlob {
-                    star_token: default(),
-                })
+                Ok(
+                    syn::UseTree::Glob(syn::UseGlob {
+                        star_token: default(),
+                    }),
+                )
+            }
+            UseTree::Group(_0) => {
+                Ok(
+                    syn::UseTree::Group(
+                        (*_0).try_ref_into().context("UseTree", "Group")?,
+                    ),
+                )
             }
-            UseTree::Group(_0) => syn::UseTree::Group((*_0).ref_into()),
         }
     }
 }
@@ -3373,17 +4288,23 @@ impl From<&syn::Variadic> for Variadic {
             attrs: node.attrs.map_into(),
             pat: node.pat.ref_map(|(_0, _1)| (*_0).map_into()),
             comma: node.comma.is_some(),
+            span: None,
         }
     }
 }
-impl From<&Variadic> for syn::Variadic {
-    fn from(node: &Variadic) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            pat: node.pat.ref_map(|_0| ((*_0).map_into(), default())),
+impl TryFromRef<Variadic> for syn::Variadic {
+    fn try_from_ref(node: &Variadic) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("Variadic", "attrs")?,
+            pat: node
+                .pat
+                .try_ref_map(|_0| Ok((
+                    (*_0).try_map_into().context("Variadic", "pat")?,
+                    default(),
+                )))?,
             dots: default(),
             comma: default_or_none(node.comma),
-        }
+        })
     }
 }
 syn_trait_impl!(syn::Variant);
@@ -3394,17 +4315,23 @@ impl From<&syn::Variant> for Variant {
             ident: node.ident.ref_into(),
             fields: node.fields.ref_into(),
             discriminant: node.discriminant.ref_map(|(_0, _1)| (*_1).ref_into()),
+            span: None,
         }
     }
 }
-impl From<&Variant> for syn::Variant {
-    fn from(node: &Variant) -> Self {
-        Self {
-            attrs: node.attrs.map_into(),
-            ident: node.ident.ref_into(),
-            fields: node.fields.ref_into(),
-            discriminant: node.discriminant.ref_map(|_1| (default(), (*_1).ref_into())),
-        }
+impl TryFromRef<Variant> for syn::Variant {
+    fn try_from_ref(node: &Variant) -> Result<Self, Error> {
+        Ok(Self {
+            attrs: node.attrs.try_map_into().context("Variant", "attrs")?,
+            ident: node.ident.try_ref_into().context("Variant", "ident")?,
+            fields: node.fields.try_ref_into().context("Variant", "fields")?,
+            discriminant: node
+                .discriminant
+                .try_ref_map(|_1| Ok((
+                    default(),
+                    (*_1).try_ref_into().context("Variant", "discriminant")?,
+                )))?,
+        })
     }
 }
 syn_trait_impl!(syn::VisRestricted);
@@ -3413,17 +4340,18 @@ impl From<&syn::VisRestricted> for VisRestricted {
         Self {
             in_token: node.in_token.is_some(),
             path: node.path.map_into(),
+            span: None,
         }
     }
 }
-impl From<&VisRestricted> for syn::VisRestricted {
-    fn from(node: &VisRestricted) -> Self {
-        Self {
+impl TryFromRef<VisRestricted> for syn::VisRestricted {
+    fn try_from_ref(node: &VisRestricted) -> Result<Self, Error> {
+        Ok(Self {
             pub_token: default(),
             paren_token: default(),
             in_token: default_or_none(node.in_token),
-            path: node.path.map_into(),
-        }
+            path: node.path.try_map_into().context("VisRestricted", "path")?,
+        })
     }
 }
 syn_trait_impl!(syn::Visibility);
@@ -3436,12 +4364,18 @@ impl From<&syn::Visibility> for Visibility {
         }
     }
 }
-impl From<&Visibility> for syn::Visibility {
-    fn from(node: &Visibility) -> Self {
+impl TryFromRef<Visibility> for syn::Visibility {
+    fn try_from_ref(node: &Visibility) -> Result<Self, Error> {
         match node {
-            Visibility::Public => syn::Visibility::Public(default()),
-            Visibility::Restricted(_0) => syn::Visibility::Restricted((*_0).ref_into()),
-            Visibility::Inherited => syn::Visibility::Inherited,
+            Visibility::Public => Ok(syn::Visibility::Public(default())),
+            Visibility::Restricted(_0) => {
+                Ok(
+                    syn::Visibility::Restricted(
+                        (*_0).try_ref_into().context("Visibility", "Restricted")?,
+                    ),
+                )
+            }
+            Visibility::Inherited => Ok(syn::Visibility::Inherited),
         }
     }
 }
@@ -3453,12 +4387,15 @@ impl From<&syn::WhereClause> for WhereClause {
         }
     }
 }
-impl From<&WhereClause> for syn::WhereClause {
-    fn from(node: &WhereClause) -> Self {
-        Self {
+impl TryFromRef<WhereClause> for syn::WhereClause {
+    fn try_from_ref(node: &WhereClause) -> Result<Self, Error> {
+        Ok(Self {
             where_token: default(),
-            predicates: node.predicates.map_into(),
-        }
+            predicates: node
+                .predicates
+                .try_map_into()
+                .context("WhereClause", "predicates")?,
+        })
     }
 }
 syn_trait_impl!(syn::WherePredicate);
@@ -3473,13 +4410,23 @@ impl From<&syn::WherePredicate> for WherePredicate {
         }
     }
 }
-impl From<&WherePredicate> for syn::WherePredicate {
-    fn from(node: &WherePredicate) -> Self {
+impl TryFromRef<WherePredicate> for syn::WherePredicate {
+    fn try_from_ref(node: &WherePredicate) -> Result<Self, Error> {
         match node {
             WherePredicate::Lifetime(_0) => {
-                syn::WherePredicate::Lifetime((*_0).ref_into())
+                Ok(
+                    syn::WherePredicate::Lifetime(
+                        (*_0).try_ref_into().context("WherePredicate", "Lifetime")?,
+                    ),
+                )
+            }
+            WherePredicate::Type(_0) => {
+                Ok(
+                    syn::WherePredicate::Type(
+                        (*_0).try_ref_into().context("WherePredicate", "Type")?,
+                    ),
+                )
             }
-            WherePredicate::Type(_0) => syn::WherePredicate::Type((*_0).ref_into()),
         }
     }
 }
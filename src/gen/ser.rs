@@ -0,0 +1,6121 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// This file is @generated by syn-serde-internal-codegen
+// (generate function at tools/codegen/src/ser.rs).
+// It is not intended for manual editing.
+
+#![cfg_attr(rustfmt, rustfmt::skip)]
+#![allow(
+    clippy::if_not_else,
+    clippy::just_underscores_and_digits,
+    clippy::match_same_arms,
+    clippy::match_single_binding,
+    clippy::too_many_lines,
+)]
+use serde::ser::{
+    SerializeMap, SerializeStruct as _, SerializeTupleVariant as _, Serializer,
+};
+use crate::*;
+impl Serialize for Ser<'_, syn::Abi> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "Abi",
+                usize::from(explicit || node.name.is_some()) + usize::from(keep_span),
+            )?;
+        if explicit || node.name.is_some() {
+            state.serialize_field("name", &Ser(&node.name, self.1))?;
+        } else {
+            state.skip_field("name")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::AngleBracketedGenericArguments> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "AngleBracketedGenericArguments",
+                usize::from(explicit || node.colon2_token.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || node.colon2_token.is_some() {
+            state.serialize_field("colon2_token", &node.colon2_token.is_some())?;
+        } else {
+            state.skip_field("colon2_token")?;
+        }
+        state.serialize_field("args", &Ser(&node.args, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::AssocConst> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "AssocConst",
+                1 + usize::from(explicit || node.generics.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || node.generics.is_some() {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("value", &Ser(&node.value, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::AssocType> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "AssocType",
+                1 + usize::from(explicit || node.generics.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || node.generics.is_some() {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::AttrStyle> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            syn::AttrStyle::Outer => {
+                serializer.serialize_unit_variant("AttrStyle", 0, "outer")
+            }
+            syn::AttrStyle::Inner(_) => {
+                serializer.serialize_unit_variant("AttrStyle", 1, "inner")
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::Attribute> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("Attribute", 1 + 1 + usize::from(keep_span))?;
+        state.serialize_field("style", &Ser(&node.style, self.1))?;
+        state.serialize_field("meta", &Ser(&node.meta, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::BareFnArg> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "BareFnArg",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.name.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.name.is_some() {
+            state
+                .serialize_field(
+                    "name",
+                    &node.name.as_ref().map(|(_0, _1)| Ser(_0, self.1)),
+                )?;
+        } else {
+            state.skip_field("name")?;
+        }
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::BareVariadic> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "BareVariadic",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.name.is_some())
+                    + usize::from(explicit || node.comma.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.name.is_some() {
+            state
+                .serialize_field(
+                    "name",
+                    &node.name.as_ref().map(|(_0, _1)| Ser(_0, self.1)),
+                )?;
+        } else {
+            state.skip_field("name")?;
+        }
+        if explicit || node.comma.is_some() {
+            state.serialize_field("comma", &node.comma.is_some())?;
+        } else {
+            state.skip_field("comma")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::BinOp> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            syn::BinOp::Add(_) => serializer.serialize_unit_variant("BinOp", 0, "+"),
+            syn::BinOp::Sub(_) => serializer.serialize_unit_variant("BinOp", 1, "-"),
+            syn::BinOp::Mul(_) => serializer.serialize_unit_variant("BinOp", 2, "*"),
+            syn::BinOp::Div(_) => serializer.serialize_unit_variant("BinOp", 3, "/"),
+            syn::BinOp::Rem(_) => serializer.serialize_unit_variant("BinOp", 4, "%"),
+            syn::BinOp::And(_) => serializer.serialize_unit_variant("BinOp", 5, "&&"),
+            syn::BinOp::Or(_) => serializer.serialize_unit_variant("BinOp", 6, "||"),
+            syn::BinOp::BitXor(_) => serializer.serialize_unit_variant("BinOp", 7, "^"),
+            syn::BinOp::BitAnd(_) => serializer.serialize_unit_variant("BinOp", 8, "&"),
+            syn::BinOp::BitOr(_) => serializer.serialize_unit_variant("BinOp", 9, "|"),
+            syn::BinOp::Shl(_) => serializer.serialize_unit_variant("BinOp", 10, "<<"),
+            syn::BinOp::Shr(_) => serializer.serialize_unit_variant("BinOp", 11, ">>"),
+            syn::BinOp::Eq(_) => serializer.serialize_unit_variant("BinOp", 12, "=="),
+            syn::BinOp::Lt(_) => serializer.serialize_unit_variant("BinOp", 13, "<"),
+            syn::BinOp::Le(_) => serializer.serialize_unit_variant("BinOp", 14, "<="),
+            syn::BinOp::Ne(_) => serializer.serialize_unit_variant("BinOp", 15, "!="),
+            syn::BinOp::Ge(_) => serializer.serialize_unit_variant("BinOp", 16, ">="),
+            syn::BinOp::Gt(_) => serializer.serialize_unit_variant("BinOp", 17, ">"),
+            syn::BinOp::AddAssign(_) => {
+                serializer.serialize_unit_variant("BinOp", 18, "+=")
+            }
+            syn::BinOp::SubAssign(_) => {
+                serializer.serialize_unit_variant("BinOp", 19, "-=")
+            }
+            syn::BinOp::MulAssign(_) => {
+                serializer.serialize_unit_variant("BinOp", 20, "*=")
+            }
+            syn::BinOp::DivAssign(_) => {
+                serializer.serialize_unit_variant("BinOp", 21, "/=")
+            }
+            syn::BinOp::RemAssign(_) => {
+                serializer.serialize_unit_variant("BinOp", 22, "%=")
+            }
+            syn::BinOp::BitXorAssign(_) => {
+                serializer.serialize_unit_variant("BinOp", 23, "^=")
+            }
+            syn::BinOp::BitAndAssign(_) => {
+                serializer.serialize_unit_variant("BinOp", 24, "&=")
+            }
+            syn::BinOp::BitOrAssign(_) => {
+                serializer.serialize_unit_variant("BinOp", 25, "|=")
+            }
+            syn::BinOp::ShlAssign(_) => {
+                serializer.serialize_unit_variant("BinOp", 26, "<<=")
+            }
+            syn::BinOp::ShrAssign(_) => {
+                serializer.serialize_unit_variant("BinOp", 27, ">>=")
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::Block> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.stmts, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::BoundLifetimes> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.lifetimes, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::CapturedParam> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<
+            _,
+            CapturedParam,
+        >::new(serializer, self.1);
+        match self.0 {
+            syn::CapturedParam::Lifetime(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "CapturedParam",
+                        0,
+                        "lifetime",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::CapturedParam::Ident(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "CapturedParam",
+                        1,
+                        "ident",
+                        &Ser(_0, self.1),
+                    )
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::ConstParam> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ConstParam",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(explicit || node.eq_token.is_some())
+                    + usize::from(explicit || node.default.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        if explicit || node.eq_token.is_some() {
+            state.serialize_field("eq_token", &node.eq_token.is_some())?;
+        } else {
+            state.skip_field("eq_token")?;
+        }
+        if explicit || node.default.is_some() {
+            state.serialize_field("default", &Ser(&node.default, self.1))?;
+        } else {
+            state.skip_field("default")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::Constraint> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "Constraint",
+                1 + usize::from(explicit || node.generics.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || node.generics.is_some() {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("bounds", &Ser(&node.bounds, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::Data> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, Data>::new(serializer, self.1);
+        match self.0 {
+            syn::Data::Struct(_0) => {
+                serializer
+                    .serialize_newtype_variant("Data", 0, "struct", &Ser(_0, self.1))
+            }
+            syn::Data::Enum(_0) => {
+                serializer.serialize_newtype_variant("Data", 1, "enum", &Ser(_0, self.1))
+            }
+            syn::Data::Union(_0) => {
+                serializer
+                    .serialize_newtype_variant("Data", 2, "union", &Ser(_0, self.1))
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::DataEnum> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.variants, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::DataUnion> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.fields, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::DeriveInput> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "DeriveInput",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + 1 + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("data", &Ser(&node.data, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::Expr> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, Expr>::new(serializer, self.1);
+        match self.0 {
+            syn::Expr::Array(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 0, "array", &Ser(_0, self.1))
+            }
+            syn::Expr::Assign(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 1, "assign", &Ser(_0, self.1))
+            }
+            syn::Expr::Async(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 2, "async", &Ser(_0, self.1))
+            }
+            syn::Expr::Await(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 3, "await", &Ser(_0, self.1))
+            }
+            syn::Expr::Binary(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 4, "binary", &Ser(_0, self.1))
+            }
+            syn::Expr::Block(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 5, "block", &Ser(_0, self.1))
+            }
+            syn::Expr::Break(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 6, "break", &Ser(_0, self.1))
+            }
+            syn::Expr::Call(_0) => {
+                serializer.serialize_newtype_variant("Expr", 7, "call", &Ser(_0, self.1))
+            }
+            syn::Expr::Cast(_0) => {
+                serializer.serialize_newtype_variant("Expr", 8, "cast", &Ser(_0, self.1))
+            }
+            syn::Expr::Closure(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 9, "closure", &Ser(_0, self.1))
+            }
+            syn::Expr::Const(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 10, "const", &Ser(_0, self.1))
+            }
+            syn::Expr::Continue(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 11, "continue", &Ser(_0, self.1))
+            }
+            syn::Expr::Field(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 12, "field", &Ser(_0, self.1))
+            }
+            syn::Expr::ForLoop(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 13, "for_loop", &Ser(_0, self.1))
+            }
+            syn::Expr::Group(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 14, "group", &Ser(_0, self.1))
+            }
+            syn::Expr::If(_0) => {
+                serializer.serialize_newtype_variant("Expr", 15, "if", &Ser(_0, self.1))
+            }
+            syn::Expr::Index(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 16, "index", &Ser(_0, self.1))
+            }
+            syn::Expr::Infer(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 17, "infer", &Ser(_0, self.1))
+            }
+            syn::Expr::Let(_0) => {
+                serializer.serialize_newtype_variant("Expr", 18, "let", &Ser(_0, self.1))
+            }
+            syn::Expr::Lit(_0) => {
+                serializer.serialize_newtype_variant("Expr", 19, "lit", &Ser(_0, self.1))
+            }
+            syn::Expr::Loop(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 20, "loop", &Ser(_0, self.1))
+            }
+            syn::Expr::Macro(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 21, "macro", &Ser(_0, self.1))
+            }
+            syn::Expr::Match(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 22, "match", &Ser(_0, self.1))
+            }
+            syn::Expr::MethodCall(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "Expr",
+                        23,
+                        "method_call",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::Expr::Paren(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 24, "paren", &Ser(_0, self.1))
+            }
+            syn::Expr::Path(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 25, "path", &Ser(_0, self.1))
+            }
+            syn::Expr::Range(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 26, "range", &Ser(_0, self.1))
+            }
+            syn::Expr::RawAddr(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 27, "raw_addr", &Ser(_0, self.1))
+            }
+            syn::Expr::Reference(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 28, "reference", &Ser(_0, self.1))
+            }
+            syn::Expr::Repeat(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 29, "repeat", &Ser(_0, self.1))
+            }
+            syn::Expr::Return(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 30, "return", &Ser(_0, self.1))
+            }
+            syn::Expr::Struct(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 31, "struct", &Ser(_0, self.1))
+            }
+            syn::Expr::Try(_0) => {
+                serializer.serialize_newtype_variant("Expr", 32, "try", &Ser(_0, self.1))
+            }
+            syn::Expr::TryBlock(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 33, "try_block", &Ser(_0, self.1))
+            }
+            syn::Expr::Tuple(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 34, "tuple", &Ser(_0, self.1))
+            }
+            syn::Expr::Unary(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 35, "unary", &Ser(_0, self.1))
+            }
+            syn::Expr::Unsafe(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 36, "unsafe", &Ser(_0, self.1))
+            }
+            syn::Expr::Verbatim(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 37, "verbatim", &Ser(_0, self.1))
+            }
+            syn::Expr::While(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 38, "while", &Ser(_0, self.1))
+            }
+            syn::Expr::Yield(_0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 39, "yield", &Ser(_0, self.1))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::ExprArray> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprArray",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("elems", &Ser(&node.elems, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprAssign> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprAssign",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("left", &Ser(&node.left, self.1))?;
+        state.serialize_field("right", &Ser(&node.right, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprAsync> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprAsync",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.capture.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.capture.is_some() {
+            state.serialize_field("move", &node.capture.is_some())?;
+        } else {
+            state.skip_field("move")?;
+        }
+        state.serialize_field("stmts", &Ser(&node.block, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprAwait> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprAwait",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("base", &Ser(&node.base, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprBinary> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprBinary",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("left", &Ser(&node.left, self.1))?;
+        state.serialize_field("op", &Ser(&node.op, self.1))?;
+        state.serialize_field("right", &Ser(&node.right, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprBlock> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprBlock",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.label.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.label.is_some() {
+            state.serialize_field("label", &Ser(&node.label, self.1))?;
+        } else {
+            state.skip_field("label")?;
+        }
+        state.serialize_field("stmts", &Ser(&node.block, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprBreak> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprBreak",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.label.is_some())
+                    + usize::from(explicit || node.expr.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.label.is_some() {
+            state.serialize_field("label", &Ser(&node.label, self.1))?;
+        } else {
+            state.skip_field("label")?;
+        }
+        if explicit || node.expr.is_some() {
+            state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        } else {
+            state.skip_field("expr")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprCall> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprCall",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("func", &Ser(&node.func, self.1))?;
+        state.serialize_field("args", &Ser(&node.args, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprCast> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprCast",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprClosure> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprClosure",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.lifetimes.is_some())
+                    + usize::from(explicit || node.constness.is_some())
+                    + usize::from(explicit || node.movability.is_some())
+                    + usize::from(explicit || node.asyncness.is_some())
+                    + usize::from(explicit || node.capture.is_some()) + 1 + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.lifetimes.is_some() {
+            state.serialize_field("lifetimes", &Ser(&node.lifetimes, self.1))?;
+        } else {
+            state.skip_field("lifetimes")?;
+        }
+        if explicit || node.constness.is_some() {
+            state.serialize_field("const", &node.constness.is_some())?;
+        } else {
+            state.skip_field("const")?;
+        }
+        if explicit || node.movability.is_some() {
+            state.serialize_field("static", &node.movability.is_some())?;
+        } else {
+            state.skip_field("static")?;
+        }
+        if explicit || node.asyncness.is_some() {
+            state.serialize_field("async", &node.asyncness.is_some())?;
+        } else {
+            state.skip_field("async")?;
+        }
+        if explicit || node.capture.is_some() {
+            state.serialize_field("move", &node.capture.is_some())?;
+        } else {
+            state.skip_field("move")?;
+        }
+        state.serialize_field("inputs", &Ser(&node.inputs, self.1))?;
+        state.serialize_field("output", &Ser(&node.output, self.1))?;
+        state.serialize_field("body", &Ser(&node.body, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprConst> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprConst",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("stmts", &Ser(&node.block, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprContinue> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprContinue",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.label.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.label.is_some() {
+            state.serialize_field("label", &Ser(&node.label, self.1))?;
+        } else {
+            state.skip_field("label")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprField> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "ExprField",
+                    usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            state.serialize_field("base", &Ser(&node.base, self.1))?;
+            state.serialize_field("member", &Ser(&node.member, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            map.serialize_entry("base", &Ser(&node.base, self.1))?;
+            Ser(&node.member, self.1).serialize_entries(map)?;
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprForLoop> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprForLoop",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.label.is_some()) + 1 + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.label.is_some() {
+            state.serialize_field("label", &Ser(&node.label, self.1))?;
+        } else {
+            state.skip_field("label")?;
+        }
+        state.serialize_field("pat", &Ser(&node.pat, self.1))?;
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        state.serialize_field("body", &Ser(&node.body, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprGroup> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprGroup",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprIf> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprIf",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(explicit || node.else_branch.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("cond", &Ser(&node.cond, self.1))?;
+        state.serialize_field("then_branch", &Ser(&node.then_branch, self.1))?;
+        if explicit || node.else_branch.is_some() {
+            state
+                .serialize_field(
+                    "else_branch",
+                    &node.else_branch.as_ref().map(|(_0, _1)| Ser(_1, self.1)),
+                )?;
+        } else {
+            state.skip_field("else_branch")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprIndex> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprIndex",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        state.serialize_field("index", &Ser(&node.index, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprInfer> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprInfer",
+                usize::from(explicit || !node.attrs.is_empty()) + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprLet> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprLet",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("pat", &Ser(&node.pat, self.1))?;
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprLit> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "ExprLit",
+                    usize::from(explicit || !node.attrs.is_empty()) + 1
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            state.serialize_field("lit", &Ser(&node.lit, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            Ser(&node.lit, self.1).serialize_entries(map)?;
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprLoop> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprLoop",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.label.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.label.is_some() {
+            state.serialize_field("label", &Ser(&node.label, self.1))?;
+        } else {
+            state.skip_field("label")?;
+        }
+        state.serialize_field("body", &Ser(&node.body, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprMacro> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "ExprMacro",
+                    usize::from(explicit || !node.attrs.is_empty()) + 1
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            state.serialize_field("mac", &Ser(&node.mac, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            Ser(&node.mac, self.1).serialize_entries(map)?;
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprMatch> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprMatch",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        state.serialize_field("arms", &Ser(&node.arms, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprMethodCall> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprMethodCall",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(explicit || node.turbofish.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("receiver", &Ser(&node.receiver, self.1))?;
+        state.serialize_field("method", &Ser(&node.method, self.1))?;
+        if explicit || node.turbofish.is_some() {
+            state.serialize_field("turbofish", &Ser(&node.turbofish, self.1))?;
+        } else {
+            state.skip_field("turbofish")?;
+        }
+        state.serialize_field("args", &Ser(&node.args, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprParen> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprParen",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprPath> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "ExprPath",
+                    usize::from(explicit || !node.attrs.is_empty())
+                        + usize::from(explicit || node.qself.is_some()) + 1
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            if explicit || node.qself.is_some() {
+                state.serialize_field("qself", &Ser(&node.qself, self.1))?;
+            } else {
+                state.skip_field("qself")?;
+            }
+            state.serialize_field("path", &Ser(&node.path, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            if explicit || node.qself.is_some() {
+                map.serialize_entry("qself", &Ser(&node.qself, self.1))?;
+            }
+            Ser(&node.path, self.1).serialize_entries(map)?;
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprRange> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprRange",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.start.is_some()) + 1
+                    + usize::from(explicit || node.end.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.start.is_some() {
+            state.serialize_field("start", &Ser(&node.start, self.1))?;
+        } else {
+            state.skip_field("start")?;
+        }
+        state.serialize_field("limits", &Ser(&node.limits, self.1))?;
+        if explicit || node.end.is_some() {
+            state.serialize_field("end", &Ser(&node.end, self.1))?;
+        } else {
+            state.skip_field("end")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprRawAddr> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprRawAddr",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("mutability", &Ser(&node.mutability, self.1))?;
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprReference> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprReference",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.mutability.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.mutability.is_some() {
+            state.serialize_field("mut", &node.mutability.is_some())?;
+        } else {
+            state.skip_field("mut")?;
+        }
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprRepeat> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprRepeat",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        state.serialize_field("len", &Ser(&node.len, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprReturn> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprReturn",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.expr.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.expr.is_some() {
+            state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        } else {
+            state.skip_field("expr")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprStruct> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprStruct",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.qself.is_some()) + 1 + 1
+                    + usize::from(explicit || node.dot2_token.is_some())
+                    + usize::from(explicit || node.rest.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.qself.is_some() {
+            state.serialize_field("qself", &Ser(&node.qself, self.1))?;
+        } else {
+            state.skip_field("qself")?;
+        }
+        state.serialize_field("path", &Ser(&node.path, self.1))?;
+        state.serialize_field("fields", &Ser(&node.fields, self.1))?;
+        if explicit || node.dot2_token.is_some() {
+            state.serialize_field("dot2_token", &node.dot2_token.is_some())?;
+        } else {
+            state.skip_field("dot2_token")?;
+        }
+        if explicit || node.rest.is_some() {
+            state.serialize_field("rest", &Ser(&node.rest, self.1))?;
+        } else {
+            state.skip_field("rest")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprTry> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprTry",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprTryBlock> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprTryBlock",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("stmts", &Ser(&node.block, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprTuple> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprTuple",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("elems", &Ser(&node.elems, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprUnary> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprUnary",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("op", &Ser(&node.op, self.1))?;
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprUnsafe> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprUnsafe",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("stmts", &Ser(&node.block, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprWhile> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprWhile",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.label.is_some()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.label.is_some() {
+            state.serialize_field("label", &Ser(&node.label, self.1))?;
+        } else {
+            state.skip_field("label")?;
+        }
+        state.serialize_field("cond", &Ser(&node.cond, self.1))?;
+        state.serialize_field("body", &Ser(&node.body, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ExprYield> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ExprYield",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.expr.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.expr.is_some() {
+            state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        } else {
+            state.skip_field("expr")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::Field> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "Field",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    )
+                    + usize::from(
+                        explicit
+                            || !matches!(node.mutability, syn::FieldMutability::None),
+                    ) + usize::from(explicit || node.ident.is_some())
+                    + usize::from(explicit || node.colon_token.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        if explicit || !matches!(node.mutability, syn::FieldMutability::None) {
+            state.serialize_field("mut", &Ser(&node.mutability, self.1))?;
+        } else {
+            state.skip_field("mut")?;
+        }
+        if explicit || node.ident.is_some() {
+            state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        } else {
+            state.skip_field("ident")?;
+        }
+        if explicit || node.colon_token.is_some() {
+            state.serialize_field("colon_token", &node.colon_token.is_some())?;
+        } else {
+            state.skip_field("colon_token")?;
+        }
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::FieldMutability> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            syn::FieldMutability::None => {
+                serializer.serialize_unit_variant("FieldMutability", 0, "none")
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::FieldPat> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "FieldPat",
+                    usize::from(explicit || !node.attrs.is_empty()) + 1
+                        + usize::from(explicit || node.colon_token.is_some()) + 1
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            state.serialize_field("member", &Ser(&node.member, self.1))?;
+            if explicit || node.colon_token.is_some() {
+                state.serialize_field("colon_token", &node.colon_token.is_some())?;
+            } else {
+                state.skip_field("colon_token")?;
+            }
+            state.serialize_field("pat", &Ser(&node.pat, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            Ser(&node.member, self.1).serialize_entries(map)?;
+            if explicit || node.colon_token.is_some() {
+                map.serialize_entry("colon_token", &node.colon_token.is_some())?;
+            }
+            map.serialize_entry("pat", &Ser(&node.pat, self.1))?;
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::FieldValue> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "FieldValue",
+                    usize::from(explicit || !node.attrs.is_empty()) + 1
+                        + usize::from(explicit || node.colon_token.is_some()) + 1
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            state.serialize_field("member", &Ser(&node.member, self.1))?;
+            if explicit || node.colon_token.is_some() {
+                state.serialize_field("colon_token", &node.colon_token.is_some())?;
+            } else {
+                state.skip_field("colon_token")?;
+            }
+            state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            Ser(&node.member, self.1).serialize_entries(map)?;
+            if explicit || node.colon_token.is_some() {
+                map.serialize_entry("colon_token", &node.colon_token.is_some())?;
+            }
+            map.serialize_entry("expr", &Ser(&node.expr, self.1))?;
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::Fields> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, Fields>::new(serializer, self.1);
+        match self.0 {
+            syn::Fields::Named(_0) => {
+                serializer
+                    .serialize_newtype_variant("Fields", 0, "named", &Ser(_0, self.1))
+            }
+            syn::Fields::Unnamed(_0) => {
+                serializer
+                    .serialize_newtype_variant("Fields", 1, "unnamed", &Ser(_0, self.1))
+            }
+            syn::Fields::Unit => serializer.serialize_unit_variant("Fields", 2, "unit"),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::FieldsNamed> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.named, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::FieldsUnnamed> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.unnamed, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::File> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "File",
+                usize::from(explicit || node.shebang.is_some())
+                    + usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || node.shebang.is_some() {
+            state.serialize_field("shebang", &Ser(&node.shebang, self.1))?;
+        } else {
+            state.skip_field("shebang")?;
+        }
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("items", &Ser(&node.items, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::FnArg> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, FnArg>::new(serializer, self.1);
+        match self.0 {
+            syn::FnArg::Receiver(_0) => {
+                serializer
+                    .serialize_newtype_variant("FnArg", 0, "receiver", &Ser(_0, self.1))
+            }
+            syn::FnArg::Typed(_0) => {
+                serializer
+                    .serialize_newtype_variant("FnArg", 1, "typed", &Ser(_0, self.1))
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::ForeignItem> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<
+            _,
+            ForeignItem,
+        >::new(serializer, self.1);
+        match self.0 {
+            syn::ForeignItem::Fn(_0) => {
+                serializer
+                    .serialize_newtype_variant("ForeignItem", 0, "fn", &Ser(_0, self.1))
+            }
+            syn::ForeignItem::Static(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "ForeignItem",
+                        1,
+                        "static",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::ForeignItem::Type(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "ForeignItem",
+                        2,
+                        "type",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::ForeignItem::Macro(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "ForeignItem",
+                        3,
+                        "macro",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::ForeignItem::Verbatim(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "ForeignItem",
+                        4,
+                        "verbatim",
+                        &Ser(_0, self.1),
+                    )
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::ForeignItemFn> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "ForeignItemFn",
+                    usize::from(explicit || !node.attrs.is_empty())
+                        + usize::from(
+                            explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                        ) + 1 + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+                state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+            } else {
+                state.skip_field("vis")?;
+            }
+            state.serialize_field("sig", &Ser(&node.sig, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+                map.serialize_entry("vis", &Ser(&node.vis, self.1))?;
+            }
+            Ser(&node.sig, self.1).serialize_entries(map)?;
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ForeignItemMacro> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "ForeignItemMacro",
+                    usize::from(explicit || !node.attrs.is_empty()) + 1
+                        + usize::from(explicit || node.semi_token.is_some())
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            state.serialize_field("mac", &Ser(&node.mac, self.1))?;
+            if explicit || node.semi_token.is_some() {
+                state.serialize_field("semi_token", &node.semi_token.is_some())?;
+            } else {
+                state.skip_field("semi_token")?;
+            }
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            Ser(&node.mac, self.1).serialize_entries(map)?;
+            if explicit || node.semi_token.is_some() {
+                map.serialize_entry("semi_token", &node.semi_token.is_some())?;
+            }
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ForeignItemStatic> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ForeignItemStatic",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    )
+                    + usize::from(
+                        explicit
+                            || !matches!(node.mutability, syn::StaticMutability::None),
+                    ) + 1 + 1 + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        if explicit || !matches!(node.mutability, syn::StaticMutability::None) {
+            state.serialize_field("mut", &Ser(&node.mutability, self.1))?;
+        } else {
+            state.skip_field("mut")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ForeignItemType> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ForeignItemType",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::GenericArgument> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<
+            _,
+            GenericArgument,
+        >::new(serializer, self.1);
+        match self.0 {
+            syn::GenericArgument::Lifetime(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericArgument",
+                        0,
+                        "lifetime",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::GenericArgument::Type(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericArgument",
+                        1,
+                        "type",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::GenericArgument::Const(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericArgument",
+                        2,
+                        "const",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::GenericArgument::AssocType(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericArgument",
+                        3,
+                        "assoc_type",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::GenericArgument::AssocConst(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericArgument",
+                        4,
+                        "assoc_const",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::GenericArgument::Constraint(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericArgument",
+                        5,
+                        "constraint",
+                        &Ser(_0, self.1),
+                    )
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::GenericParam> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<
+            _,
+            GenericParam,
+        >::new(serializer, self.1);
+        match self.0 {
+            syn::GenericParam::Lifetime(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericParam",
+                        0,
+                        "lifetime",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::GenericParam::Type(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericParam",
+                        1,
+                        "type",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::GenericParam::Const(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericParam",
+                        2,
+                        "const",
+                        &Ser(_0, self.1),
+                    )
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::ImplItem> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, ImplItem>::new(serializer, self.1);
+        match self.0 {
+            syn::ImplItem::Const(_0) => {
+                serializer
+                    .serialize_newtype_variant("ImplItem", 0, "const", &Ser(_0, self.1))
+            }
+            syn::ImplItem::Fn(_0) => {
+                serializer
+                    .serialize_newtype_variant("ImplItem", 1, "fn", &Ser(_0, self.1))
+            }
+            syn::ImplItem::Type(_0) => {
+                serializer
+                    .serialize_newtype_variant("ImplItem", 2, "type", &Ser(_0, self.1))
+            }
+            syn::ImplItem::Macro(_0) => {
+                serializer
+                    .serialize_newtype_variant("ImplItem", 3, "macro", &Ser(_0, self.1))
+            }
+            syn::ImplItem::Verbatim(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "ImplItem",
+                        4,
+                        "verbatim",
+                        &Ser(_0, self.1),
+                    )
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::ImplItemConst> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ImplItemConst",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + usize::from(explicit || node.defaultness.is_some()) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + 1 + 1 + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        if explicit || node.defaultness.is_some() {
+            state.serialize_field("default", &node.defaultness.is_some())?;
+        } else {
+            state.skip_field("default")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ImplItemFn> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "ImplItemFn",
+                    usize::from(explicit || !node.attrs.is_empty())
+                        + usize::from(
+                            explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                        ) + usize::from(explicit || node.defaultness.is_some()) + 1 + 1
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+                state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+            } else {
+                state.skip_field("vis")?;
+            }
+            if explicit || node.defaultness.is_some() {
+                state.serialize_field("default", &node.defaultness.is_some())?;
+            } else {
+                state.skip_field("default")?;
+            }
+            state.serialize_field("sig", &Ser(&node.sig, self.1))?;
+            state.serialize_field("stmts", &Ser(&node.block, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            if self.1.is_binary() {
+                state.serialize_field("trivia", &None::<Trivia>)?;
+            } else {
+                state.skip_field("trivia")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+                map.serialize_entry("vis", &Ser(&node.vis, self.1))?;
+            }
+            if explicit || node.defaultness.is_some() {
+                map.serialize_entry("default", &node.defaultness.is_some())?;
+            }
+            Ser(&node.sig, self.1).serialize_entries(map)?;
+            map.serialize_entry("stmts", &Ser(&node.block, self.1))?;
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ImplItemMacro> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "ImplItemMacro",
+                    usize::from(explicit || !node.attrs.is_empty()) + 1
+                        + usize::from(explicit || node.semi_token.is_some())
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            state.serialize_field("mac", &Ser(&node.mac, self.1))?;
+            if explicit || node.semi_token.is_some() {
+                state.serialize_field("semi_token", &node.semi_token.is_some())?;
+            } else {
+                state.skip_field("semi_token")?;
+            }
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            if self.1.is_binary() {
+                state.serialize_field("trivia", &None::<Trivia>)?;
+            } else {
+                state.skip_field("trivia")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            Ser(&node.mac, self.1).serialize_entries(map)?;
+            if explicit || node.semi_token.is_some() {
+                map.serialize_entry("semi_token", &node.semi_token.is_some())?;
+            }
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ImplItemType> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ImplItemType",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + usize::from(explicit || node.defaultness.is_some()) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + 1 + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        if explicit || node.defaultness.is_some() {
+            state.serialize_field("default", &node.defaultness.is_some())?;
+        } else {
+            state.skip_field("default")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ImplRestriction> {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::Index> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.index, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::Item> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, Item>::new(serializer, self.1);
+        match self.0 {
+            syn::Item::Const(_0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 0, "const", &Ser(_0, self.1))
+            }
+            syn::Item::Enum(_0) => {
+                serializer.serialize_newtype_variant("Item", 1, "enum", &Ser(_0, self.1))
+            }
+            syn::Item::ExternCrate(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "Item",
+                        2,
+                        "extern_crate",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::Item::Fn(_0) => {
+                serializer.serialize_newtype_variant("Item", 3, "fn", &Ser(_0, self.1))
+            }
+            syn::Item::ForeignMod(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "Item",
+                        4,
+                        "foreign_mod",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::Item::Impl(_0) => {
+                serializer.serialize_newtype_variant("Item", 5, "impl", &Ser(_0, self.1))
+            }
+            syn::Item::Macro(_0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 6, "macro", &Ser(_0, self.1))
+            }
+            syn::Item::Mod(_0) => {
+                serializer.serialize_newtype_variant("Item", 7, "mod", &Ser(_0, self.1))
+            }
+            syn::Item::Static(_0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 8, "static", &Ser(_0, self.1))
+            }
+            syn::Item::Struct(_0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 9, "struct", &Ser(_0, self.1))
+            }
+            syn::Item::Trait(_0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 10, "trait", &Ser(_0, self.1))
+            }
+            syn::Item::TraitAlias(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "Item",
+                        11,
+                        "trait_alias",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::Item::Type(_0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 12, "type", &Ser(_0, self.1))
+            }
+            syn::Item::Union(_0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 13, "union", &Ser(_0, self.1))
+            }
+            syn::Item::Use(_0) => {
+                serializer.serialize_newtype_variant("Item", 14, "use", &Ser(_0, self.1))
+            }
+            syn::Item::Verbatim(_0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 15, "verbatim", &Ser(_0, self.1))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::ItemConst> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemConst",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + 1 + 1 + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemEnum> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemEnum",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + 1 + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("variants", &Ser(&node.variants, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemExternCrate> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemExternCrate",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + 1 + usize::from(explicit || node.rename.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || node.rename.is_some() {
+            state
+                .serialize_field(
+                    "rename",
+                    &node.rename.as_ref().map(|(_0, _1)| Ser(_1, self.1)),
+                )?;
+        } else {
+            state.skip_field("rename")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemFn> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "ItemFn",
+                    usize::from(explicit || !node.attrs.is_empty())
+                        + usize::from(
+                            explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                        ) + 1 + 1 + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+                state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+            } else {
+                state.skip_field("vis")?;
+            }
+            state.serialize_field("sig", &Ser(&node.sig, self.1))?;
+            state.serialize_field("stmts", &Ser(&node.block, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            if self.1.is_binary() {
+                state.serialize_field("trivia", &None::<Trivia>)?;
+            } else {
+                state.skip_field("trivia")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+                map.serialize_entry("vis", &Ser(&node.vis, self.1))?;
+            }
+            Ser(&node.sig, self.1).serialize_entries(map)?;
+            map.serialize_entry("stmts", &Ser(&node.block, self.1))?;
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemForeignMod> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemForeignMod",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.unsafety.is_some()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.unsafety.is_some() {
+            state.serialize_field("unsafe", &node.unsafety.is_some())?;
+        } else {
+            state.skip_field("unsafe")?;
+        }
+        state.serialize_field("abi", &Ser(&node.abi, self.1))?;
+        state.serialize_field("items", &Ser(&node.items, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemImpl> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemImpl",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.defaultness.is_some())
+                    + usize::from(explicit || node.unsafety.is_some())
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + usize::from(explicit || node.trait_.is_some()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.defaultness.is_some() {
+            state.serialize_field("default", &node.defaultness.is_some())?;
+        } else {
+            state.skip_field("default")?;
+        }
+        if explicit || node.unsafety.is_some() {
+            state.serialize_field("unsafe", &node.unsafety.is_some())?;
+        } else {
+            state.skip_field("unsafe")?;
+        }
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        if explicit || node.trait_.is_some() {
+            state
+                .serialize_field(
+                    "trait",
+                    &node
+                        .trait_
+                        .as_ref()
+                        .map(|(_0, _1, _2)| (_0.is_some(), Ser(_1, self.1))),
+                )?;
+        } else {
+            state.skip_field("trait")?;
+        }
+        state.serialize_field("self_ty", &Ser(&node.self_ty, self.1))?;
+        state.serialize_field("items", &Ser(&node.items, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemMacro> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "ItemMacro",
+                    usize::from(explicit || !node.attrs.is_empty())
+                        + usize::from(explicit || node.ident.is_some()) + 1
+                        + usize::from(explicit || node.semi_token.is_some())
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            if explicit || node.ident.is_some() {
+                state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+            } else {
+                state.skip_field("ident")?;
+            }
+            state.serialize_field("mac", &Ser(&node.mac, self.1))?;
+            if explicit || node.semi_token.is_some() {
+                state.serialize_field("semi_token", &node.semi_token.is_some())?;
+            } else {
+                state.skip_field("semi_token")?;
+            }
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            if self.1.is_binary() {
+                state.serialize_field("trivia", &None::<Trivia>)?;
+            } else {
+                state.skip_field("trivia")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            if explicit || node.ident.is_some() {
+                map.serialize_entry("ident", &Ser(&node.ident, self.1))?;
+            }
+            Ser(&node.mac, self.1).serialize_entries(map)?;
+            if explicit || node.semi_token.is_some() {
+                map.serialize_entry("semi_token", &node.semi_token.is_some())?;
+            }
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemMod> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemMod",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + usize::from(explicit || node.unsafety.is_some()) + 1
+                    + usize::from(explicit || node.content.is_some())
+                    + usize::from(explicit || node.semi.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        if explicit || node.unsafety.is_some() {
+            state.serialize_field("unsafe", &node.unsafety.is_some())?;
+        } else {
+            state.skip_field("unsafe")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || node.content.is_some() {
+            state
+                .serialize_field(
+                    "content",
+                    &node.content.as_ref().map(|(_0, _1)| Ser(_1, self.1)),
+                )?;
+        } else {
+            state.skip_field("content")?;
+        }
+        if explicit || node.semi.is_some() {
+            state.serialize_field("semi", &node.semi.is_some())?;
+        } else {
+            state.skip_field("semi")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemStatic> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemStatic",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    )
+                    + usize::from(
+                        explicit
+                            || !matches!(node.mutability, syn::StaticMutability::None),
+                    ) + 1 + 1 + 1 + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        if explicit || !matches!(node.mutability, syn::StaticMutability::None) {
+            state.serialize_field("mut", &Ser(&node.mutability, self.1))?;
+        } else {
+            state.skip_field("mut")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemTrait> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemTrait",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + usize::from(explicit || node.unsafety.is_some())
+                    + usize::from(explicit || node.auto_token.is_some())
+                    + usize::from(explicit || node.restriction.is_some()) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + usize::from(explicit || node.colon_token.is_some())
+                    + usize::from(explicit || !node.supertraits.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        if explicit || node.unsafety.is_some() {
+            state.serialize_field("unsafe", &node.unsafety.is_some())?;
+        } else {
+            state.skip_field("unsafe")?;
+        }
+        if explicit || node.auto_token.is_some() {
+            state.serialize_field("auto", &node.auto_token.is_some())?;
+        } else {
+            state.skip_field("auto")?;
+        }
+        if explicit || node.restriction.is_some() {
+            state.serialize_field("restriction", &Ser(&node.restriction, self.1))?;
+        } else {
+            state.skip_field("restriction")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        if explicit || node.colon_token.is_some() {
+            state.serialize_field("colon_token", &node.colon_token.is_some())?;
+        } else {
+            state.skip_field("colon_token")?;
+        }
+        if explicit || !node.supertraits.is_empty() {
+            state.serialize_field("supertraits", &Ser(&node.supertraits, self.1))?;
+        } else {
+            state.skip_field("supertraits")?;
+        }
+        state.serialize_field("items", &Ser(&node.items, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemTraitAlias> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemTraitAlias",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + 1 + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("bounds", &Ser(&node.bounds, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemType> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemType",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + 1 + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemUnion> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemUnion",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + 1 + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("fields", &Ser(&node.fields, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ItemUse> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ItemUse",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(
+                        explicit || !matches!(node.vis, syn::Visibility::Inherited),
+                    ) + usize::from(explicit || node.leading_colon.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+            state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+        } else {
+            state.skip_field("vis")?;
+        }
+        if explicit || node.leading_colon.is_some() {
+            state.serialize_field("leading_colon", &node.leading_colon.is_some())?;
+        } else {
+            state.skip_field("leading_colon")?;
+        }
+        state.serialize_field("tree", &Ser(&node.tree, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::Label> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.name, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::Lifetime> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.ident, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::LifetimeParam> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "LifetimeParam",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(explicit || node.colon_token.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("lifetime", &Ser(&node.lifetime, self.1))?;
+        if explicit || node.colon_token.is_some() {
+            state.serialize_field("colon_token", &node.colon_token.is_some())?;
+        } else {
+            state.skip_field("colon_token")?;
+        }
+        state.serialize_field("bounds", &Ser(&node.bounds, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl SerializeEntries for Ser<'_, syn::Lit> {
+    fn serialize_entries<M>(&self, map: &mut M) -> Result<(), M::Error>
+    where
+        M: SerializeMap,
+    {
+        match self.0 {
+            syn::Lit::Str(_0) => map.serialize_entry("str", &Ser(_0, self.1)),
+            syn::Lit::ByteStr(_0) => map.serialize_entry("byte_str", &Ser(_0, self.1)),
+            syn::Lit::CStr(_0) => map.serialize_entry("c_str", &Ser(_0, self.1)),
+            syn::Lit::Byte(_0) => map.serialize_entry("byte", &Ser(_0, self.1)),
+            syn::Lit::Char(_0) => map.serialize_entry("char", &Ser(_0, self.1)),
+            syn::Lit::Int(_0) => map.serialize_entry("int", &Ser(_0, self.1)),
+            syn::Lit::Float(_0) => map.serialize_entry("float", &Ser(_0, self.1)),
+            syn::Lit::Bool(_0) => map.serialize_entry("bool", &Ser(_0, self.1)),
+            syn::Lit::Verbatim(_0) => map.serialize_entry("verbatim", &Ser(_0, self.1)),
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::Lit> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            syn::Lit::Str(_0) => {
+                serializer.serialize_newtype_variant("Lit", 0, "str", &Ser(_0, self.1))
+            }
+            syn::Lit::ByteStr(_0) => {
+                serializer
+                    .serialize_newtype_variant("Lit", 1, "byte_str", &Ser(_0, self.1))
+            }
+            syn::Lit::CStr(_0) => {
+                serializer.serialize_newtype_variant("Lit", 2, "c_str", &Ser(_0, self.1))
+            }
+            syn::Lit::Byte(_0) => {
+                serializer.serialize_newtype_variant("Lit", 3, "byte", &Ser(_0, self.1))
+            }
+            syn::Lit::Char(_0) => {
+                serializer.serialize_newtype_variant("Lit", 4, "char", &Ser(_0, self.1))
+            }
+            syn::Lit::Int(_0) => {
+                serializer.serialize_newtype_variant("Lit", 5, "int", &Ser(_0, self.1))
+            }
+            syn::Lit::Float(_0) => {
+                serializer.serialize_newtype_variant("Lit", 6, "float", &Ser(_0, self.1))
+            }
+            syn::Lit::Bool(_0) => {
+                serializer.serialize_newtype_variant("Lit", 7, "bool", &Ser(_0, self.1))
+            }
+            syn::Lit::Verbatim(_0) => {
+                serializer
+                    .serialize_newtype_variant("Lit", 8, "verbatim", &Ser(_0, self.1))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::LitBool> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.value, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::Local> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "Local",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(explicit || node.init.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("pat", &Ser(&node.pat, self.1))?;
+        if explicit || node.init.is_some() {
+            state.serialize_field("init", &Ser(&node.init, self.1))?;
+        } else {
+            state.skip_field("init")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::LocalInit> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let mut state = serializer
+            .serialize_struct(
+                "LocalInit",
+                1 + usize::from(explicit || node.diverge.is_some()),
+            )?;
+        state.serialize_field("expr", &Ser(&node.expr, self.1))?;
+        if explicit || node.diverge.is_some() {
+            state
+                .serialize_field(
+                    "diverge",
+                    &node.diverge.as_ref().map(|(_0, _1)| Ser(_1, self.1)),
+                )?;
+        } else {
+            state.skip_field("diverge")?;
+        }
+        state.end()
+    }
+}
+impl SerializeEntries for Ser<'_, syn::Macro> {
+    fn serialize_entries<M>(&self, map: &mut M) -> Result<(), M::Error>
+    where
+        M: SerializeMap,
+    {
+        let node = self.0;
+        let args = mac::args::parse(node, self.1);
+        map.serialize_entry("path", &Ser(&node.path, self.1))?;
+        map.serialize_entry("delimiter", &Ser(&node.delimiter, self.1))?;
+        map.serialize_entry("tokens", &Ser(&node.tokens, self.1))?;
+        if let Some(args) = &args {
+            map.serialize_entry("args", &Ser(args, self.1))?;
+        }
+        Ok(())
+    }
+}
+impl Serialize for Ser<'_, syn::Macro> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let args = mac::args::parse(node, self.1);
+        let keep_args = args.is_some()
+            || cfg!(feature = "macro-args") && self.1.is_binary();
+        let mut state = serializer
+            .serialize_struct("Macro", 1 + 1 + 1 + usize::from(keep_args))?;
+        state.serialize_field("path", &Ser(&node.path, self.1))?;
+        state.serialize_field("delimiter", &Ser(&node.delimiter, self.1))?;
+        state.serialize_field("tokens", &Ser(&node.tokens, self.1))?;
+        if let Some(args) = &args {
+            state.serialize_field("args", &Ser(args, self.1))?;
+        } else if keep_args {
+            state.serialize_field("args", &None::<()>)?;
+        } else {
+            state.skip_field("args")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::MacroDelimiter> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            syn::MacroDelimiter::Paren(_) => {
+                serializer.serialize_unit_variant("MacroDelimiter", 0, "paren")
+            }
+            syn::MacroDelimiter::Brace(_) => {
+                serializer.serialize_unit_variant("MacroDelimiter", 1, "brace")
+            }
+            syn::MacroDelimiter::Bracket(_) => {
+                serializer.serialize_unit_variant("MacroDelimiter", 2, "bracket")
+            }
+        }
+    }
+}
+impl SerializeEntries for Ser<'_, syn::Member> {
+    fn serialize_entries<M>(&self, map: &mut M) -> Result<(), M::Error>
+    where
+        M: SerializeMap,
+    {
+        match self.0 {
+            syn::Member::Named(_0) => map.serialize_entry("ident", &Ser(_0, self.1)),
+            syn::Member::Unnamed(_0) => map.serialize_entry("index", &Ser(_0, self.1)),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::Member> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            syn::Member::Named(_0) => {
+                serializer
+                    .serialize_newtype_variant("Member", 0, "ident", &Ser(_0, self.1))
+            }
+            syn::Member::Unnamed(_0) => {
+                serializer
+                    .serialize_newtype_variant("Member", 1, "index", &Ser(_0, self.1))
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::Meta> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, Meta>::new(serializer, self.1);
+        match self.0 {
+            syn::Meta::Path(_0) => {
+                serializer.serialize_newtype_variant("Meta", 0, "path", &Ser(_0, self.1))
+            }
+            syn::Meta::List(_0) => {
+                serializer.serialize_newtype_variant("Meta", 1, "list", &Ser(_0, self.1))
+            }
+            syn::Meta::NameValue(_0) => {
+                serializer
+                    .serialize_newtype_variant("Meta", 2, "name_value", &Ser(_0, self.1))
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::MetaList> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("MetaList", 1 + 1 + 1 + usize::from(keep_span))?;
+        state.serialize_field("path", &Ser(&node.path, self.1))?;
+        state.serialize_field("delimiter", &Ser(&node.delimiter, self.1))?;
+        state.serialize_field("tokens", &Ser(&node.tokens, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::MetaNameValue> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("MetaNameValue", 1 + 1 + usize::from(keep_span))?;
+        state.serialize_field("path", &Ser(&node.path, self.1))?;
+        state.serialize_field("value", &Ser(&node.value, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::ParenthesizedGenericArguments> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "ParenthesizedGenericArguments",
+                1 + 1 + usize::from(keep_span),
+            )?;
+        state.serialize_field("inputs", &Ser(&node.inputs, self.1))?;
+        state.serialize_field("output", &Ser(&node.output, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::Pat> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, Pat>::new(serializer, self.1);
+        match self.0 {
+            syn::Pat::Const(_0) => {
+                serializer.serialize_newtype_variant("Pat", 0, "const", &Ser(_0, self.1))
+            }
+            syn::Pat::Ident(_0) => {
+                serializer.serialize_newtype_variant("Pat", 1, "ident", &Ser(_0, self.1))
+            }
+            syn::Pat::Lit(_0) => {
+                serializer.serialize_newtype_variant("Pat", 2, "lit", &Ser(_0, self.1))
+            }
+            syn::Pat::Macro(_0) => {
+                serializer.serialize_newtype_variant("Pat", 3, "macro", &Ser(_0, self.1))
+            }
+            syn::Pat::Or(_0) => {
+                serializer.serialize_newtype_variant("Pat", 4, "or", &Ser(_0, self.1))
+            }
+            syn::Pat::Paren(_0) => {
+                serializer.serialize_newtype_variant("Pat", 5, "paren", &Ser(_0, self.1))
+            }
+            syn::Pat::Path(_0) => {
+                serializer.serialize_newtype_variant("Pat", 6, "path", &Ser(_0, self.1))
+            }
+            syn::Pat::Range(_0) => {
+                serializer.serialize_newtype_variant("Pat", 7, "range", &Ser(_0, self.1))
+            }
+            syn::Pat::Reference(_0) => {
+                serializer
+                    .serialize_newtype_variant("Pat", 8, "reference", &Ser(_0, self.1))
+            }
+            syn::Pat::Rest(_0) => {
+                serializer.serialize_newtype_variant("Pat", 9, "rest", &Ser(_0, self.1))
+            }
+            syn::Pat::Slice(_0) => {
+                serializer
+                    .serialize_newtype_variant("Pat", 10, "slice", &Ser(_0, self.1))
+            }
+            syn::Pat::Struct(_0) => {
+                serializer
+                    .serialize_newtype_variant("Pat", 11, "struct", &Ser(_0, self.1))
+            }
+            syn::Pat::Tuple(_0) => {
+                serializer
+                    .serialize_newtype_variant("Pat", 12, "tuple", &Ser(_0, self.1))
+            }
+            syn::Pat::TupleStruct(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "Pat",
+                        13,
+                        "tuple_struct",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::Pat::Type(_0) => {
+                serializer.serialize_newtype_variant("Pat", 14, "type", &Ser(_0, self.1))
+            }
+            syn::Pat::Verbatim(_0) => {
+                serializer
+                    .serialize_newtype_variant("Pat", 15, "verbatim", &Ser(_0, self.1))
+            }
+            syn::Pat::Wild(_0) => {
+                serializer.serialize_newtype_variant("Pat", 16, "_", &Ser(_0, self.1))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::PatIdent> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PatIdent",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.by_ref.is_some())
+                    + usize::from(explicit || node.mutability.is_some()) + 1
+                    + usize::from(explicit || node.subpat.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.by_ref.is_some() {
+            state.serialize_field("ref", &node.by_ref.is_some())?;
+        } else {
+            state.skip_field("ref")?;
+        }
+        if explicit || node.mutability.is_some() {
+            state.serialize_field("mut", &node.mutability.is_some())?;
+        } else {
+            state.skip_field("mut")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || node.subpat.is_some() {
+            state
+                .serialize_field(
+                    "subpat",
+                    &node.subpat.as_ref().map(|(_0, _1)| Ser(_1, self.1)),
+                )?;
+        } else {
+            state.skip_field("subpat")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PatOr> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PatOr",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.leading_vert.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.leading_vert.is_some() {
+            state.serialize_field("leading_vert", &node.leading_vert.is_some())?;
+        } else {
+            state.skip_field("leading_vert")?;
+        }
+        state.serialize_field("cases", &Ser(&node.cases, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PatParen> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PatParen",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("pat", &Ser(&node.pat, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PatReference> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PatReference",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.mutability.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.mutability.is_some() {
+            state.serialize_field("mut", &node.mutability.is_some())?;
+        } else {
+            state.skip_field("mut")?;
+        }
+        state.serialize_field("pat", &Ser(&node.pat, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PatRest> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PatRest",
+                usize::from(explicit || !node.attrs.is_empty()) + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PatSlice> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PatSlice",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("elems", &Ser(&node.elems, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PatStruct> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PatStruct",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.qself.is_some()) + 1 + 1
+                    + usize::from(explicit || node.rest.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.qself.is_some() {
+            state.serialize_field("qself", &Ser(&node.qself, self.1))?;
+        } else {
+            state.skip_field("qself")?;
+        }
+        state.serialize_field("path", &Ser(&node.path, self.1))?;
+        state.serialize_field("fields", &Ser(&node.fields, self.1))?;
+        if explicit || node.rest.is_some() {
+            state.serialize_field("rest", &Ser(&node.rest, self.1))?;
+        } else {
+            state.skip_field("rest")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PatTuple> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PatTuple",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("elems", &Ser(&node.elems, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PatTupleStruct> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PatTupleStruct",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.qself.is_some()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.qself.is_some() {
+            state.serialize_field("qself", &Ser(&node.qself, self.1))?;
+        } else {
+            state.skip_field("qself")?;
+        }
+        state.serialize_field("path", &Ser(&node.path, self.1))?;
+        state.serialize_field("elems", &Ser(&node.elems, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PatType> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PatType",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("pat", &Ser(&node.pat, self.1))?;
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PatWild> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PatWild",
+                usize::from(explicit || !node.attrs.is_empty()) + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl SerializeEntries for Ser<'_, syn::Path> {
+    fn serialize_entries<M>(&self, map: &mut M) -> Result<(), M::Error>
+    where
+        M: SerializeMap,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        if explicit || node.leading_colon.is_some() {
+            map.serialize_entry("leading_colon", &node.leading_colon.is_some())?;
+        }
+        map.serialize_entry("segments", &Ser(&node.segments, self.1))?;
+        Ok(())
+    }
+}
+impl Serialize for Ser<'_, syn::Path> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let mut state = serializer
+            .serialize_struct(
+                "Path",
+                usize::from(explicit || node.leading_colon.is_some()) + 1,
+            )?;
+        if explicit || node.leading_colon.is_some() {
+            state.serialize_field("leading_colon", &node.leading_colon.is_some())?;
+        } else {
+            state.skip_field("leading_colon")?;
+        }
+        state.serialize_field("segments", &Ser(&node.segments, self.1))?;
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PathArguments> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<
+            _,
+            PathArguments,
+        >::new(serializer, self.1);
+        match self.0 {
+            syn::PathArguments::None => {
+                serializer.serialize_unit_variant("PathArguments", 0, "none")
+            }
+            syn::PathArguments::AngleBracketed(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "PathArguments",
+                        1,
+                        "angle_bracketed",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::PathArguments::Parenthesized(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "PathArguments",
+                        2,
+                        "parenthesized",
+                        &Ser(_0, self.1),
+                    )
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::PathSegment> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PathSegment",
+                1
+                    + usize::from(
+                        explicit || !matches!(node.arguments, syn::PathArguments::None),
+                    ) + usize::from(keep_span),
+            )?;
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !matches!(node.arguments, syn::PathArguments::None) {
+            state.serialize_field("arguments", &Ser(&node.arguments, self.1))?;
+        } else {
+            state.skip_field("arguments")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PointerMutability> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            syn::PointerMutability::Const(_) => {
+                serializer.serialize_unit_variant("PointerMutability", 0, "const")
+            }
+            syn::PointerMutability::Mut(_) => {
+                serializer.serialize_unit_variant("PointerMutability", 1, "mut")
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::PreciseCapture> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.params, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::PredicateLifetime> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("PredicateLifetime", 1 + 1 + usize::from(keep_span))?;
+        state.serialize_field("lifetime", &Ser(&node.lifetime, self.1))?;
+        state.serialize_field("bounds", &Ser(&node.bounds, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::PredicateType> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "PredicateType",
+                usize::from(explicit || node.lifetimes.is_some()) + 1 + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || node.lifetimes.is_some() {
+            state.serialize_field("lifetimes", &Ser(&node.lifetimes, self.1))?;
+        } else {
+            state.skip_field("lifetimes")?;
+        }
+        state.serialize_field("bounded_ty", &Ser(&node.bounded_ty, self.1))?;
+        state.serialize_field("bounds", &Ser(&node.bounds, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::QSelf> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "QSelf",
+                1 + 1 + usize::from(explicit || node.as_token.is_some())
+                    + usize::from(keep_span),
+            )?;
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        state.serialize_field("position", &Ser(&node.position, self.1))?;
+        if explicit || node.as_token.is_some() {
+            state.serialize_field("as_token", &node.as_token.is_some())?;
+        } else {
+            state.skip_field("as_token")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::RangeLimits> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            syn::RangeLimits::HalfOpen(_) => {
+                serializer.serialize_unit_variant("RangeLimits", 0, "..")
+            }
+            syn::RangeLimits::Closed(_) => {
+                serializer.serialize_unit_variant("RangeLimits", 1, "..=")
+            }
+        }
+    }
+}
+impl SerializeEntries for Ser<'_, syn::Signature> {
+    fn serialize_entries<M>(&self, map: &mut M) -> Result<(), M::Error>
+    where
+        M: SerializeMap,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        if explicit || node.constness.is_some() {
+            map.serialize_entry("const", &node.constness.is_some())?;
+        }
+        if explicit || node.asyncness.is_some() {
+            map.serialize_entry("async", &node.asyncness.is_some())?;
+        }
+        if explicit || node.unsafety.is_some() {
+            map.serialize_entry("unsafe", &node.unsafety.is_some())?;
+        }
+        if explicit || node.abi.is_some() {
+            map.serialize_entry("abi", &Ser(&node.abi, self.1))?;
+        }
+        map.serialize_entry("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            map.serialize_entry("generics", &Ser(&node.generics, self.1))?;
+        }
+        map.serialize_entry("inputs", &Ser(&node.inputs, self.1))?;
+        if explicit || node.variadic.is_some() {
+            map.serialize_entry("variadic", &Ser(&node.variadic, self.1))?;
+        }
+        map.serialize_entry("output", &Ser(&node.output, self.1))?;
+        Ok(())
+    }
+}
+impl Serialize for Ser<'_, syn::Signature> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let mut state = serializer
+            .serialize_struct(
+                "Signature",
+                usize::from(explicit || node.constness.is_some())
+                    + usize::from(explicit || node.asyncness.is_some())
+                    + usize::from(explicit || node.unsafety.is_some())
+                    + usize::from(explicit || node.abi.is_some()) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + 1 + usize::from(explicit || node.variadic.is_some()) + 1,
+            )?;
+        if explicit || node.constness.is_some() {
+            state.serialize_field("const", &node.constness.is_some())?;
+        } else {
+            state.skip_field("const")?;
+        }
+        if explicit || node.asyncness.is_some() {
+            state.serialize_field("async", &node.asyncness.is_some())?;
+        } else {
+            state.skip_field("async")?;
+        }
+        if explicit || node.unsafety.is_some() {
+            state.serialize_field("unsafe", &node.unsafety.is_some())?;
+        } else {
+            state.skip_field("unsafe")?;
+        }
+        if explicit || node.abi.is_some() {
+            state.serialize_field("abi", &Ser(&node.abi, self.1))?;
+        } else {
+            state.skip_field("abi")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("inputs", &Ser(&node.inputs, self.1))?;
+        if explicit || node.variadic.is_some() {
+            state.serialize_field("variadic", &Ser(&node.variadic, self.1))?;
+        } else {
+            state.skip_field("variadic")?;
+        }
+        state.serialize_field("output", &Ser(&node.output, self.1))?;
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::StaticMutability> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            syn::StaticMutability::Mut(_) => {
+                serializer.serialize_unit_variant("StaticMutability", 0, "mut")
+            }
+            syn::StaticMutability::None => {
+                serializer.serialize_unit_variant("StaticMutability", 1, "none")
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::Stmt> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, Stmt>::new(serializer, self.1);
+        match self.0 {
+            syn::Stmt::Local(_0) => {
+                serializer.serialize_newtype_variant("Stmt", 0, "let", &Ser(_0, self.1))
+            }
+            syn::Stmt::Item(_0) => {
+                serializer.serialize_newtype_variant("Stmt", 1, "item", &Ser(_0, self.1))
+            }
+            syn::Stmt::Expr(_0, _1) => {
+                if serializer.fields_as_tuple() {
+                    return serializer
+                        .serialize_newtype_variant(
+                            "Stmt",
+                            2,
+                            "expr",
+                            &(Ser(_0, self.1), _1.is_some()),
+                        );
+                }
+                let mut state = serializer
+                    .serialize_tuple_variant("Stmt", 2, "expr", 2)?;
+                state.serialize_field(&Ser(_0, self.1))?;
+                state.serialize_field(&_1.is_some())?;
+                state.end()
+            }
+            syn::Stmt::Macro(_0) => {
+                serializer
+                    .serialize_newtype_variant("Stmt", 3, "macro", &Ser(_0, self.1))
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::StmtMacro> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "StmtMacro",
+                    usize::from(explicit || !node.attrs.is_empty()) + 1
+                        + usize::from(explicit || node.semi_token.is_some())
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            state.serialize_field("mac", &Ser(&node.mac, self.1))?;
+            if explicit || node.semi_token.is_some() {
+                state.serialize_field("semi_token", &node.semi_token.is_some())?;
+            } else {
+                state.skip_field("semi_token")?;
+            }
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            Ser(&node.mac, self.1).serialize_entries(map)?;
+            if explicit || node.semi_token.is_some() {
+                map.serialize_entry("semi_token", &node.semi_token.is_some())?;
+            }
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TraitBound> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "TraitBound",
+                usize::from(explicit || node.paren_token.is_some())
+                    + usize::from(
+                        explicit
+                            || !matches!(node.modifier, syn::TraitBoundModifier::None),
+                    ) + usize::from(explicit || node.lifetimes.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || node.paren_token.is_some() {
+            state.serialize_field("paren_token", &node.paren_token.is_some())?;
+        } else {
+            state.skip_field("paren_token")?;
+        }
+        if explicit || !matches!(node.modifier, syn::TraitBoundModifier::None) {
+            state.serialize_field("modifier", &Ser(&node.modifier, self.1))?;
+        } else {
+            state.skip_field("modifier")?;
+        }
+        if explicit || node.lifetimes.is_some() {
+            state.serialize_field("lifetimes", &Ser(&node.lifetimes, self.1))?;
+        } else {
+            state.skip_field("lifetimes")?;
+        }
+        state.serialize_field("path", &Ser(&node.path, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TraitBoundModifier> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            syn::TraitBoundModifier::None => {
+                serializer.serialize_unit_variant("TraitBoundModifier", 0, "none")
+            }
+            syn::TraitBoundModifier::Maybe(_) => {
+                serializer.serialize_unit_variant("TraitBoundModifier", 1, "maybe")
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::TraitItem> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, TraitItem>::new(serializer, self.1);
+        match self.0 {
+            syn::TraitItem::Const(_0) => {
+                serializer
+                    .serialize_newtype_variant("TraitItem", 0, "const", &Ser(_0, self.1))
+            }
+            syn::TraitItem::Fn(_0) => {
+                serializer
+                    .serialize_newtype_variant("TraitItem", 1, "fn", &Ser(_0, self.1))
+            }
+            syn::TraitItem::Type(_0) => {
+                serializer
+                    .serialize_newtype_variant("TraitItem", 2, "type", &Ser(_0, self.1))
+            }
+            syn::TraitItem::Macro(_0) => {
+                serializer
+                    .serialize_newtype_variant("TraitItem", 3, "macro", &Ser(_0, self.1))
+            }
+            syn::TraitItem::Verbatim(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "TraitItem",
+                        4,
+                        "verbatim",
+                        &Ser(_0, self.1),
+                    )
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::TraitItemConst> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "TraitItemConst",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + 1 + usize::from(explicit || node.default.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+        if explicit || node.default.is_some() {
+            state
+                .serialize_field(
+                    "default",
+                    &node.default.as_ref().map(|(_0, _1)| Ser(_1, self.1)),
+                )?;
+        } else {
+            state.skip_field("default")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TraitItemMacro> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "TraitItemMacro",
+                    usize::from(explicit || !node.attrs.is_empty()) + 1
+                        + usize::from(explicit || node.semi_token.is_some())
+                        + usize::from(keep_span),
+                )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            state.serialize_field("mac", &Ser(&node.mac, self.1))?;
+            if explicit || node.semi_token.is_some() {
+                state.serialize_field("semi_token", &node.semi_token.is_some())?;
+            } else {
+                state.skip_field("semi_token")?;
+            }
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            if self.1.is_binary() {
+                state.serialize_field("trivia", &None::<Trivia>)?;
+            } else {
+                state.skip_field("trivia")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
+            }
+            Ser(&node.mac, self.1).serialize_entries(map)?;
+            if explicit || node.semi_token.is_some() {
+                map.serialize_entry("semi_token", &node.semi_token.is_some())?;
+            }
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TraitItemType> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "TraitItemType",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(
+                        explicit || !node.generics.params.is_empty()
+                            || node.generics.where_clause.is_some(),
+                    ) + usize::from(explicit || node.colon_token.is_some())
+                    + usize::from(explicit || !node.bounds.is_empty())
+                    + usize::from(explicit || node.default.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || !node.generics.params.is_empty()
+            || node.generics.where_clause.is_some()
+        {
+            state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+        } else {
+            state.skip_field("generics")?;
+        }
+        if explicit || node.colon_token.is_some() {
+            state.serialize_field("colon_token", &node.colon_token.is_some())?;
+        } else {
+            state.skip_field("colon_token")?;
+        }
+        if explicit || !node.bounds.is_empty() {
+            state.serialize_field("bounds", &Ser(&node.bounds, self.1))?;
+        } else {
+            state.skip_field("bounds")?;
+        }
+        if explicit || node.default.is_some() {
+            state
+                .serialize_field(
+                    "default",
+                    &node.default.as_ref().map(|(_0, _1)| Ser(_1, self.1)),
+                )?;
+        } else {
+            state.skip_field("default")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        if self.1.is_binary() {
+            state.serialize_field("trivia", &None::<Trivia>)?;
+        } else {
+            state.skip_field("trivia")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::Type> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, Type>::new(serializer, self.1);
+        match self.0 {
+            syn::Type::Array(_0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 0, "array", &Ser(_0, self.1))
+            }
+            syn::Type::BareFn(_0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 1, "bare_fn", &Ser(_0, self.1))
+            }
+            syn::Type::Group(_0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 2, "group", &Ser(_0, self.1))
+            }
+            syn::Type::ImplTrait(_0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 3, "impl_trait", &Ser(_0, self.1))
+            }
+            syn::Type::Infer(_) => serializer.serialize_unit_variant("Type", 4, "_"),
+            syn::Type::Macro(_0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 5, "macro", &Ser(_0, self.1))
+            }
+            syn::Type::Never(_) => serializer.serialize_unit_variant("Type", 6, "!"),
+            syn::Type::Paren(_0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 7, "paren", &Ser(_0, self.1))
+            }
+            syn::Type::Path(_0) => {
+                serializer.serialize_newtype_variant("Type", 8, "path", &Ser(_0, self.1))
+            }
+            syn::Type::Ptr(_0) => {
+                serializer.serialize_newtype_variant("Type", 9, "ptr", &Ser(_0, self.1))
+            }
+            syn::Type::Reference(_0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 10, "reference", &Ser(_0, self.1))
+            }
+            syn::Type::Slice(_0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 11, "slice", &Ser(_0, self.1))
+            }
+            syn::Type::TraitObject(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "Type",
+                        12,
+                        "trait_object",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::Type::Tuple(_0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 13, "tuple", &Ser(_0, self.1))
+            }
+            syn::Type::Verbatim(_0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 14, "verbatim", &Ser(_0, self.1))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::TypeArray> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("TypeArray", 1 + 1 + usize::from(keep_span))?;
+        state.serialize_field("elem", &Ser(&node.elem, self.1))?;
+        state.serialize_field("len", &Ser(&node.len, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypeBareFn> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "TypeBareFn",
+                usize::from(explicit || node.lifetimes.is_some())
+                    + usize::from(explicit || node.unsafety.is_some())
+                    + usize::from(explicit || node.abi.is_some()) + 1
+                    + usize::from(explicit || node.variadic.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || node.lifetimes.is_some() {
+            state.serialize_field("lifetimes", &Ser(&node.lifetimes, self.1))?;
+        } else {
+            state.skip_field("lifetimes")?;
+        }
+        if explicit || node.unsafety.is_some() {
+            state.serialize_field("unsafe", &node.unsafety.is_some())?;
+        } else {
+            state.skip_field("unsafe")?;
+        }
+        if explicit || node.abi.is_some() {
+            state.serialize_field("abi", &Ser(&node.abi, self.1))?;
+        } else {
+            state.skip_field("abi")?;
+        }
+        state.serialize_field("inputs", &Ser(&node.inputs, self.1))?;
+        if explicit || node.variadic.is_some() {
+            state.serialize_field("variadic", &Ser(&node.variadic, self.1))?;
+        } else {
+            state.skip_field("variadic")?;
+        }
+        state.serialize_field("output", &Ser(&node.output, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypeGroup> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("TypeGroup", 1 + usize::from(keep_span))?;
+        state.serialize_field("elem", &Ser(&node.elem, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypeImplTrait> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("TypeImplTrait", 1 + usize::from(keep_span))?;
+        state.serialize_field("bounds", &Ser(&node.bounds, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypeMacro> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct("TypeMacro", 1 + usize::from(keep_span))?;
+            state.serialize_field("mac", &Ser(&node.mac, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            Ser(&node.mac, self.1).serialize_entries(map)?;
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypeParam> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "TypeParam",
+                usize::from(explicit || !node.attrs.is_empty()) + 1
+                    + usize::from(explicit || node.colon_token.is_some())
+                    + usize::from(explicit || !node.bounds.is_empty())
+                    + usize::from(explicit || node.eq_token.is_some())
+                    + usize::from(explicit || node.default.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        if explicit || node.colon_token.is_some() {
+            state.serialize_field("colon_token", &node.colon_token.is_some())?;
+        } else {
+            state.skip_field("colon_token")?;
+        }
+        if explicit || !node.bounds.is_empty() {
+            state.serialize_field("bounds", &Ser(&node.bounds, self.1))?;
+        } else {
+            state.skip_field("bounds")?;
+        }
+        if explicit || node.eq_token.is_some() {
+            state.serialize_field("eq_token", &node.eq_token.is_some())?;
+        } else {
+            state.skip_field("eq_token")?;
+        }
+        if explicit || node.default.is_some() {
+            state.serialize_field("default", &Ser(&node.default, self.1))?;
+        } else {
+            state.skip_field("default")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypeParamBound> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<
+            _,
+            TypeParamBound,
+        >::new(serializer, self.1);
+        match self.0 {
+            syn::TypeParamBound::Trait(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "TypeParamBound",
+                        0,
+                        "trait",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::TypeParamBound::Lifetime(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "TypeParamBound",
+                        1,
+                        "lifetime",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::TypeParamBound::PreciseCapture(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "TypeParamBound",
+                        2,
+                        "precise_capture",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::TypeParamBound::Verbatim(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "TypeParamBound",
+                        3,
+                        "verbatim",
+                        &Ser(_0, self.1),
+                    )
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::TypeParen> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("TypeParen", 1 + usize::from(keep_span))?;
+        state.serialize_field("elem", &Ser(&node.elem, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypePath> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        if self.1.is_binary() {
+            let mut state = serializer
+                .serialize_struct(
+                    "TypePath",
+                    usize::from(explicit || node.qself.is_some()) + 1
+                        + usize::from(keep_span),
+                )?;
+            if explicit || node.qself.is_some() {
+                state.serialize_field("qself", &Ser(&node.qself, self.1))?;
+            } else {
+                state.skip_field("qself")?;
+            }
+            state.serialize_field("path", &Ser(&node.path, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            return state.end();
+        }
+        let mut map = serializer.serialize_map(None)?;
+        {
+            let map = &mut map;
+            if explicit || node.qself.is_some() {
+                map.serialize_entry("qself", &Ser(&node.qself, self.1))?;
+            }
+            Ser(&node.path, self.1).serialize_entries(map)?;
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        }
+        map.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypePtr> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "TypePtr",
+                usize::from(explicit || node.const_token.is_some())
+                    + usize::from(explicit || node.mutability.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || node.const_token.is_some() {
+            state.serialize_field("const", &node.const_token.is_some())?;
+        } else {
+            state.skip_field("const")?;
+        }
+        if explicit || node.mutability.is_some() {
+            state.serialize_field("mut", &node.mutability.is_some())?;
+        } else {
+            state.skip_field("mut")?;
+        }
+        state.serialize_field("elem", &Ser(&node.elem, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypeReference> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "TypeReference",
+                usize::from(explicit || node.lifetime.is_some())
+                    + usize::from(explicit || node.mutability.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || node.lifetime.is_some() {
+            state.serialize_field("lifetime", &Ser(&node.lifetime, self.1))?;
+        } else {
+            state.skip_field("lifetime")?;
+        }
+        if explicit || node.mutability.is_some() {
+            state.serialize_field("mut", &node.mutability.is_some())?;
+        } else {
+            state.skip_field("mut")?;
+        }
+        state.serialize_field("elem", &Ser(&node.elem, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypeSlice> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("TypeSlice", 1 + usize::from(keep_span))?;
+        state.serialize_field("elem", &Ser(&node.elem, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypeTraitObject> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "TypeTraitObject",
+                usize::from(explicit || node.dyn_token.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || node.dyn_token.is_some() {
+            state.serialize_field("dyn", &node.dyn_token.is_some())?;
+        } else {
+            state.skip_field("dyn")?;
+        }
+        state.serialize_field("bounds", &Ser(&node.bounds, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::TypeTuple> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("TypeTuple", 1 + usize::from(keep_span))?;
+        state.serialize_field("elems", &Ser(&node.elems, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::UnOp> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            syn::UnOp::Deref(_) => serializer.serialize_unit_variant("UnOp", 0, "*"),
+            syn::UnOp::Not(_) => serializer.serialize_unit_variant("UnOp", 1, "!"),
+            syn::UnOp::Neg(_) => serializer.serialize_unit_variant("UnOp", 2, "-"),
+            _ => unreachable!(),
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::UseGroup> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.items, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::UseName> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.ident, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::UsePath> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("UsePath", 1 + 1 + usize::from(keep_span))?;
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        state.serialize_field("tree", &Ser(&node.tree, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::UseRename> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct("UseRename", 1 + 1 + usize::from(keep_span))?;
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        state.serialize_field("rename", &Ser(&node.rename, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::UseTree> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<_, UseTree>::new(serializer, self.1);
+        match self.0 {
+            syn::UseTree::Path(_0) => {
+                serializer
+                    .serialize_newtype_variant("UseTree", 0, "path", &Ser(_0, self.1))
+            }
+            syn::UseTree::Name(_0) => {
+                serializer
+                    .serialize_newtype_variant("UseTree", 1, "ident", &Ser(_0, self.1))
+            }
+            syn::UseTree::Rename(_0) => {
+                serializer
+                    .serialize_newtype_variant("UseTree", 2, "rename", &Ser(_0, self.1))
+            }
+            syn::UseTree::Glob(_) => serializer.serialize_unit_variant("UseTree", 3, "*"),
+            syn::UseTree::Group(_0) => {
+                serializer
+                    .serialize_newtype_variant("UseTree", 4, "group", &Ser(_0, self.1))
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::Variadic> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "Variadic",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.pat.is_some())
+                    + usize::from(explicit || node.comma.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        if explicit || node.pat.is_some() {
+            state
+                .serialize_field(
+                    "pat",
+                    &node.pat.as_ref().map(|(_0, _1)| Ser(_0, self.1)),
+                )?;
+        } else {
+            state.skip_field("pat")?;
+        }
+        if explicit || node.comma.is_some() {
+            state.serialize_field("comma", &node.comma.is_some())?;
+        } else {
+            state.skip_field("comma")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::Variant> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "Variant",
+                usize::from(explicit || !node.attrs.is_empty()) + 1 + 1
+                    + usize::from(explicit || node.discriminant.is_some())
+                    + usize::from(keep_span),
+            )?;
+        if explicit || !node.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+        state.serialize_field("fields", &Ser(&node.fields, self.1))?;
+        if explicit || node.discriminant.is_some() {
+            state
+                .serialize_field(
+                    "discriminant",
+                    &node.discriminant.as_ref().map(|(_0, _1)| Ser(_1, self.1)),
+                )?;
+        } else {
+            state.skip_field("discriminant")?;
+        }
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::VisRestricted> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        let explicit = self.1.has_explicit_fields();
+        let span = Location::of(node, self.1);
+        let keep_span = self.1.is_binary() || span.is_some();
+        let mut state = serializer
+            .serialize_struct(
+                "VisRestricted",
+                usize::from(explicit || node.in_token.is_some()) + 1
+                    + usize::from(keep_span),
+            )?;
+        if explicit || node.in_token.is_some() {
+            state.serialize_field("in_token", &node.in_token.is_some())?;
+        } else {
+            state.skip_field("in_token")?;
+        }
+        state.serialize_field("path", &Ser(&node.path, self.1))?;
+        if keep_span {
+            state.serialize_field("span", &span)?;
+        } else {
+            state.skip_field("span")?;
+        }
+        state.end()
+    }
+}
+impl Serialize for Ser<'_, syn::Visibility> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<
+            _,
+            Visibility,
+        >::new(serializer, self.1);
+        match self.0 {
+            syn::Visibility::Public(_) => {
+                serializer.serialize_unit_variant("Visibility", 0, "pub")
+            }
+            syn::Visibility::Restricted(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "Visibility",
+                        1,
+                        "restricted",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::Visibility::Inherited => {
+                serializer.serialize_unit_variant("Visibility", 2, "inherited")
+            }
+        }
+    }
+}
+impl Serialize for Ser<'_, syn::WhereClause> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.0;
+        Ser(&node.predicates, self.1).serialize(serializer)
+    }
+}
+impl Serialize for Ser<'_, syn::WherePredicate> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = tagged::EnumSerializer::<
+            _,
+            WherePredicate,
+        >::new(serializer, self.1);
+        match self.0 {
+            syn::WherePredicate::Lifetime(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "WherePredicate",
+                        0,
+                        "lifetime",
+                        &Ser(_0, self.1),
+                    )
+            }
+            syn::WherePredicate::Type(_0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "WherePredicate",
+                        1,
+                        "type",
+                        &Ser(_0, self.1),
+                    )
+            }
+            _ => unreachable!(),
+        }
+    }
+}
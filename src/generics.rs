@@ -2,25 +2,31 @@
 
 use super::*;
 pub use crate::{
-    ast_enum::{GenericParam, TraitBoundModifier, TypeParamBound, WherePredicate},
+    ast_enum::{CapturedParam, GenericParam, TraitBoundModifier, TypeParamBound, WherePredicate},
     ast_struct::{
-        BoundLifetimes, ConstParam, LifetimeParam, PredicateLifetime, TraitBound, TypeParam,
-        WhereClause,
+        BoundLifetimes, ConstParam, LifetimeParam, PreciseCapture, PredicateLifetime, TraitBound,
+        TypeParam, WhereClause,
     },
 };
 
 ast_struct! {
     /// An adapter for [`struct@syn::Generics`].
     #[derive(Default)]
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
     pub struct Generics {
         // #[serde(default, skip_serializing_if = "not")]
-        // pub(crate) lt_token: bool,
+        // pub lt_token: bool,
+        /// Corresponds to [`syn::Generics::params`].
         #[serde(default, skip_serializing_if = "Punctuated::is_empty")]
-        pub(crate) params: Punctuated<GenericParam>,
+        pub params: Punctuated<GenericParam>,
         // #[serde(default, skip_serializing_if = "not")]
-        // pub(crate) gt_token: bool,
+        // pub gt_token: bool,
+        /// Corresponds to [`syn::Generics::where_clause`].
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub(crate) where_clause: Option<WhereClause>,
+        pub where_clause: Option<WhereClause>,
+        /// The location of this node, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub span: Option<Location>,
     }
 }
 
@@ -47,13 +53,29 @@ impl Default for TraitBoundModifier {
 
 ast_struct! {
     /// An adapter for [`struct@syn::PredicateType`].
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
     pub struct PredicateType {
+        /// Corresponds to [`syn::PredicateType::lifetimes`].
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub(crate) lifetimes: Option<BoundLifetimes>,
-        pub(crate) bounded_ty: Type,
+        pub lifetimes: Option<BoundLifetimes>,
+        /// Corresponds to [`syn::PredicateType::bounded_ty`].
+        pub bounded_ty: Type,
         // TODO: should allow default?
         // #[serde(default, skip_serializing_if = "Punctuated::is_empty")]
-        pub(crate) bounds: Punctuated<TypeParamBound>,
+        /// Corresponds to [`syn::PredicateType::bounds`].
+        pub bounds: Punctuated<TypeParamBound>,
+        /// The location of this node, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub span: Option<Location>,
+    }
+}
+
+impl PredicateType {
+    /// Creates an adapter from the fields that are required in the serialized
+    /// form, with the other fields set to their default values.
+    #[must_use]
+    pub fn new(bounded_ty: Type, bounds: Punctuated<TypeParamBound>) -> Self {
+        Self { lifetimes: default(), bounded_ty, bounds, span: None }
     }
 }
 
@@ -69,17 +91,57 @@ mod convert {
             // `ident T`
             assert!(other.params.is_empty() || other.lt_token.is_some(), "expected `<`");
 
-            Self { params: other.params.map_into(), where_clause: other.where_clause.map_into() }
+            Self {
+                params: other.params.map_into(),
+                where_clause: other.where_clause.map_into(),
+                span: None,
+            }
         }
     }
-    impl From<&Generics> for syn::Generics {
-        fn from(other: &Generics) -> Self {
-            Self {
+    impl TryFromRef<Generics> for syn::Generics {
+        fn try_from_ref(other: &Generics) -> Result<Self, Error> {
+            Ok(Self {
                 lt_token: default_or_none(!other.params.is_empty()),
-                params: other.params.map_into(),
+                params: other.params.try_map_into().context("Generics", "params")?,
                 gt_token: default_or_none(!other.params.is_empty()),
-                where_clause: other.where_clause.map_into(),
+                where_clause: other
+                    .where_clause
+                    .try_map_into()
+                    .context("Generics", "where_clause")?,
+            })
+        }
+    }
+    impl Serialize for Ser<'_, syn::Generics> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let node = self.0;
+            let explicit = self.1.has_explicit_fields();
+            let span = Location::of(node, self.1);
+            let keep_span = self.1.is_binary() || span.is_some();
+            let mut state = serializer.serialize_struct(
+                "Generics",
+                usize::from(explicit || !node.params.is_empty())
+                    + usize::from(explicit || node.where_clause.is_some())
+                    + usize::from(keep_span),
+            )?;
+            if explicit || !node.params.is_empty() {
+                state.serialize_field("params", &Ser(&node.params, self.1))?;
+            } else {
+                state.skip_field("params")?;
+            }
+            if explicit || node.where_clause.is_some() {
+                state.serialize_field("where_clause", &Ser(&node.where_clause, self.1))?;
+            } else {
+                state.skip_field("where_clause")?;
+            }
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
             }
+            state.end()
         }
     }
 }
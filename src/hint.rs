@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Some adapters are deserialized from a value whose type selects their
+// representation, e.g., an enum from a variant name, a map with a single key,
+// or a map with `kind`, and structs with flattened fields are deserialized
+// from a map whose keys are not known in advance. Both require a
+// self-describing format. The binary format is not self-describing, so these
+// adapters pass a hint of the type that it writes for them.
+//
+// The hint is the name of a newtype struct: other formats call
+// `Visitor::visit_newtype_struct`, which the visitors of these adapters
+// implement by calling `deserialize_any`, and the deserializer in binary.rs
+// reads the hinted type instead.
+
+use serde::de::{Deserializer, Visitor};
+
+/// The type that the binary format writes for an adapter.
+#[derive(Clone, Copy)]
+pub(crate) enum Hint {
+    /// An enum, read with `Deserializer::deserialize_enum`.
+    Enum,
+    /// A string, read with `Deserializer::deserialize_str`.
+    Str,
+    /// A sequence, read with `Deserializer::deserialize_seq`.
+    Seq,
+    /// A tuple of two elements, read with `Deserializer::deserialize_tuple`.
+    Pair,
+    /// A struct whose fields are written in order, read with
+    /// `Deserializer::deserialize_tuple`. The visitor reads as many fields as
+    /// the struct has.
+    Struct,
+}
+
+const NAMES: &[&str] = &[
+    "$syn_serde::hint::Enum",
+    "$syn_serde::hint::Str",
+    "$syn_serde::hint::Seq",
+    "$syn_serde::hint::Pair",
+    "$syn_serde::hint::Struct",
+];
+
+impl Hint {
+    /// Returns the hint passed as the name of a newtype struct, if any.
+    #[cfg(feature = "binary")]
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match NAMES.iter().position(|&n| n == name)? {
+            0 => Some(Self::Enum),
+            1 => Some(Self::Str),
+            2 => Some(Self::Seq),
+            3 => Some(Self::Pair),
+            4 => Some(Self::Struct),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Deserializes a value whose representation is selected by its type, passing
+/// `hint` for the binary format.
+///
+/// `visitor` must implement `visit_newtype_struct` by calling
+/// `deserialize_any` with itself.
+pub(crate) fn deserialize<'de, D, V>(
+    deserializer: D,
+    hint: Hint,
+    visitor: V,
+) -> Result<V::Value, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Visitor<'de>,
+{
+    deserializer.deserialize_newtype_struct(NAMES[hint as usize], visitor)
+}
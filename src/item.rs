@@ -16,71 +16,228 @@ pub use crate::{
 
 ast_struct! {
     /// An adapter for [`struct@syn::ItemMod`].
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
     pub struct ItemMod {
+        /// Corresponds to [`syn::ItemMod::attrs`].
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
-        pub(crate) attrs: Vec<Attribute>,
+        pub attrs: Vec<Attribute>,
+        /// Corresponds to [`syn::ItemMod::vis`].
         #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-        pub(crate) vis: Visibility,
+        pub vis: Visibility,
+        /// Whether [`syn::ItemMod::unsafety`] is present. Serialized as `unsafe`.
         #[serde(rename = "unsafe")]
         #[serde(default, skip_serializing_if = "not")]
-        pub(crate) unsafety: bool,
-        pub(crate) ident: Ident,
+        pub unsafety: bool,
+        /// Corresponds to [`syn::ItemMod::ident`].
+        pub ident: Ident,
         // TODO: should not skip_serializing_if
+        /// Corresponds to [`syn::ItemMod::content`].
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub(crate) content: Option<Vec<Item>>,
+        pub content: Option<Vec<Item>>,
         // TODO: can remove
+        /// Whether [`syn::ItemMod::semi`] is present.
         #[serde(default, skip_serializing_if = "not")]
-        pub(crate) semi: bool,
+        pub semi: bool,
+        /// The location of this node, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub span: Option<Location>,
+        /// The comments and whitespace of this node, if kept by
+        /// `lossless::parse_file`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub trivia: Option<Trivia>,
+    }
+}
+
+impl ItemMod {
+    /// Creates an adapter from the fields that are required in the serialized
+    /// form, with the other fields set to their default values.
+    #[must_use]
+    pub fn new(ident: Ident) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            unsafety: default(),
+            ident,
+            content: default(),
+            semi: default(),
+            span: None,
+            trivia: None,
+        }
     }
 }
 
 ast_struct! {
     /// An adapter for [`struct@syn::ItemStruct`].
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
     pub struct ItemStruct {
+        /// Corresponds to [`syn::ItemStruct::attrs`].
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
-        pub(crate) attrs: Vec<Attribute>,
+        pub attrs: Vec<Attribute>,
+        /// Corresponds to [`syn::ItemStruct::vis`].
         #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
-        pub(crate) vis: Visibility,
-        pub(crate) ident: Ident,
+        pub vis: Visibility,
+        /// Corresponds to [`syn::ItemStruct::ident`].
+        pub ident: Ident,
+        /// Corresponds to [`syn::ItemStruct::generics`].
         #[serde(default, skip_serializing_if = "Generics::is_none")]
-        pub(crate) generics: Generics,
-        pub(crate) fields: Fields,
+        pub generics: Generics,
+        /// Corresponds to [`syn::ItemStruct::fields`].
+        pub fields: Fields,
         // #[serde(default, skip_serializing_if = "not")]
-        // pub(crate) semi_token: bool,
+        // pub semi_token: bool,
+        /// The location of this node, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub span: Option<Location>,
+        /// The comments and whitespace of this node, if kept by
+        /// `lossless::parse_file`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub trivia: Option<Trivia>,
     }
 }
 
-ast_struct! {
-    /// An adapter for [`struct@syn::TraitItemFn`].
-    pub struct TraitItemFn {
-        #[serde(default, skip_serializing_if = "Vec::is_empty")]
-        pub(crate) attrs: Vec<Attribute>,
-        #[serde(flatten)]
-        pub(crate) sig: Signature,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub(crate) default: Option<Block>,
-        // #[serde(default, skip_serializing_if = "not")]
-        // pub(crate) semi_token: bool,
+impl ItemStruct {
+    /// Creates an adapter from the fields that are required in the serialized
+    /// form, with the other fields set to their default values.
+    #[must_use]
+    pub fn new(ident: Ident, fields: Fields) -> Self {
+        Self {
+            attrs: default(),
+            vis: default(),
+            ident,
+            generics: default(),
+            fields,
+            span: None,
+            trivia: None,
+        }
+    }
+}
+
+/// An adapter for [`struct@syn::TraitItemFn`].
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[non_exhaustive]
+pub struct TraitItemFn {
+    /// Corresponds to [`syn::TraitItemFn::attrs`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attrs: Vec<Attribute>,
+    /// Corresponds to [`syn::TraitItemFn::sig`]. Its fields are serialized in place of this field.
+    #[serde(flatten)]
+    pub sig: Signature,
+    /// Corresponds to [`syn::TraitItemFn::default`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Block>,
+    // #[serde(default, skip_serializing_if = "not")]
+    // pub semi_token: bool,
+    /// The location of this node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Location>,
+    /// The comments and whitespace of this node, if kept by
+    /// `lossless::parse_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trivia: Option<Trivia>,
+}
+
+impl<'de> Deserialize<'de> for TraitItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        flatten::deserialize(deserializer)
+    }
+}
+
+impl flatten::Flattened for TraitItemFn {
+    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "TraitItemFn")]
+        struct Map {
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            attrs: Vec<Attribute>,
+            #[serde(flatten)]
+            sig: Signature,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            default: Option<Block>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            span: Option<Location>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            trivia: Option<Trivia>,
+        }
+        Map::deserialize(deserializer)
+    }
+
+    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(remote = "TraitItemFn")]
+        struct Seq {
+            attrs: Vec<Attribute>,
+            sig: Signature,
+            default: Option<Block>,
+            span: Option<Location>,
+            trivia: Option<Trivia>,
+        }
+        Seq::deserialize(deserializer)
+    }
+}
+
+impl TraitItemFn {
+    /// Creates an adapter from the fields that are required in the serialized
+    /// form, with the other fields set to their default values.
+    #[must_use]
+    pub fn new(sig: Signature) -> Self {
+        Self { attrs: default(), sig, default: default(), span: None, trivia: None }
     }
 }
 
 ast_struct! {
     /// An adapter for [`struct@syn::Receiver`].
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
     pub struct Receiver {
+        /// Corresponds to [`syn::Receiver::attrs`].
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
-        pub(crate) attrs: Vec<Attribute>,
+        pub attrs: Vec<Attribute>,
+        /// Whether [`syn::Receiver::reference`] is present. Serialized as `ref`.
         #[serde(rename = "ref")]
         #[serde(default, skip_serializing_if = "not")]
-        pub(crate) reference: bool,
+        pub reference: bool,
+        /// Corresponds to [`syn::Receiver::lifetime`].
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub(crate) lifetime: Option<Lifetime>,
+        pub lifetime: Option<Lifetime>,
+        /// Whether [`syn::Receiver::mutability`] is present. Serialized as `mut`.
         #[serde(rename = "mut")]
         #[serde(default, skip_serializing_if = "not")]
-        pub(crate) mutability: bool,
+        pub mutability: bool,
+        /// Whether [`syn::Receiver::colon_token`] is present.
         #[serde(default, skip_serializing_if = "not")]
-        pub(crate) colon_token: bool,
+        pub colon_token: bool,
         // TODO: skip if colon_token=false?
-        pub(crate) ty: Box<Type>,
+        /// Corresponds to [`syn::Receiver::ty`].
+        pub ty: Box<Type>,
+        /// The location of this node, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub span: Option<Location>,
+    }
+}
+
+impl Receiver {
+    /// Creates an adapter from the fields that are required in the serialized
+    /// form, with the other fields set to their default values.
+    #[must_use]
+    pub fn new(ty: Box<Type>) -> Self {
+        Self {
+            attrs: default(),
+            reference: default(),
+            lifetime: default(),
+            mutability: default(),
+            colon_token: default(),
+            ty,
+            span: None,
+        }
     }
 }
 
@@ -111,20 +268,73 @@ mod convert {
                 ident: other.ident.ref_into(),
                 generics: other.generics.ref_into(),
                 fields,
+                span: None,
+                trivia: None,
             }
         }
     }
-    impl From<&ItemStruct> for syn::ItemStruct {
-        fn from(other: &ItemStruct) -> Self {
-            Self {
-                attrs: other.attrs.map_into(),
-                vis: other.vis.ref_into(),
+    impl TryFromRef<ItemStruct> for syn::ItemStruct {
+        fn try_from_ref(other: &ItemStruct) -> Result<Self, Error> {
+            Ok(Self {
+                attrs: other.attrs.try_map_into().context("ItemStruct", "attrs")?,
+                vis: other.vis.try_ref_into().context("ItemStruct", "vis")?,
                 struct_token: default(),
-                ident: other.ident.ref_into(),
-                generics: other.generics.ref_into(),
-                fields: other.fields.ref_into(),
+                ident: other.ident.try_ref_into().context("ItemStruct", "ident")?,
+                generics: other.generics.try_ref_into().context("ItemStruct", "generics")?,
+                fields: other.fields.try_ref_into().context("ItemStruct", "fields")?,
                 semi_token: default_or_none(!other.fields.is_named()),
+            })
+        }
+    }
+    impl Serialize for Ser<'_, syn::ItemStruct> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let node = self.0;
+            let explicit = self.1.has_explicit_fields();
+            let span = Location::of(node, self.1);
+            let keep_span = self.1.is_binary() || span.is_some();
+            let has_generics = explicit
+                || !node.generics.params.is_empty()
+                || node.generics.where_clause.is_some();
+            let mut state = serializer.serialize_struct(
+                "ItemStruct",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || !matches!(node.vis, syn::Visibility::Inherited))
+                    + 1
+                    + usize::from(has_generics)
+                    + 1
+                    + usize::from(keep_span),
+            )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            if explicit || !matches!(node.vis, syn::Visibility::Inherited) {
+                state.serialize_field("vis", &Ser(&node.vis, self.1))?;
+            } else {
+                state.skip_field("vis")?;
+            }
+            state.serialize_field("ident", &Ser(&node.ident, self.1))?;
+            if has_generics {
+                state.serialize_field("generics", &Ser(&node.generics, self.1))?;
+            } else {
+                state.skip_field("generics")?;
+            }
+            state.serialize_field("fields", &Ser(&node.fields, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
             }
+            if self.1.is_binary() {
+                state.serialize_field("trivia", &None::<Trivia>)?;
+            } else {
+                state.skip_field("trivia")?;
+            }
+            state.end()
         }
     }
 
@@ -144,17 +354,52 @@ mod convert {
                 attrs: other.attrs.map_into(),
                 sig: other.sig.ref_into(),
                 default: other.default.map_into(),
+                span: None,
+                trivia: None,
             }
         }
     }
-    impl From<&TraitItemFn> for syn::TraitItemFn {
-        fn from(other: &TraitItemFn) -> Self {
-            Self {
-                attrs: other.attrs.map_into(),
-                sig: other.sig.ref_into(),
-                default: other.default.map_into(),
+    impl TryFromRef<TraitItemFn> for syn::TraitItemFn {
+        fn try_from_ref(other: &TraitItemFn) -> Result<Self, Error> {
+            Ok(Self {
+                attrs: other.attrs.try_map_into().context("TraitItemFn", "attrs")?,
+                sig: other.sig.try_ref_into().context("TraitItemFn", "sig")?,
+                default: other.default.try_map_into().context("TraitItemFn", "default")?,
                 semi_token: default_or_none(other.default.is_none()),
+            })
+        }
+    }
+    impl Serialize for Ser<'_, syn::TraitItemFn> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let node = self.0;
+            let explicit = self.1.has_explicit_fields();
+            let span = Location::of(node, self.1);
+            let keep_span = self.1.is_binary() || span.is_some();
+            if self.1.is_binary() {
+                let mut state = serializer.serialize_struct("TraitItemFn", 5)?;
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+                state.serialize_field("sig", &Ser(&node.sig, self.1))?;
+                state.serialize_field("default", &Ser(&node.default, self.1))?;
+                state.serialize_field("span", &span)?;
+                state.serialize_field("trivia", &None::<Trivia>)?;
+                return state.end();
+            }
+            // `sig` is flattened, so this is serialized as a map.
+            let mut map = serializer.serialize_map(None)?;
+            if explicit || !node.attrs.is_empty() {
+                map.serialize_entry("attrs", &Ser(&node.attrs, self.1))?;
             }
+            Ser(&node.sig, self.1).serialize_entries(&mut map)?;
+            if explicit || node.default.is_some() {
+                map.serialize_entry("default", &Ser(&node.default, self.1))?;
+            }
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+            map.end()
         }
     }
 
@@ -169,23 +414,79 @@ mod convert {
                 mutability: node.mutability.is_some(),
                 colon_token: node.colon_token.is_some(),
                 ty: node.ty.map_into(),
+                span: None,
             }
         }
     }
-    impl From<&Receiver> for syn::Receiver {
-        fn from(node: &Receiver) -> Self {
-            Self {
-                attrs: node.attrs.map_into(),
+    impl TryFromRef<Receiver> for syn::Receiver {
+        fn try_from_ref(node: &Receiver) -> Result<Self, Error> {
+            Ok(Self {
+                attrs: node.attrs.try_map_into().context("Receiver", "attrs")?,
                 reference: if node.reference {
-                    Some((default(), node.lifetime.map_into()))
+                    Some((default(), node.lifetime.try_map_into().context("Receiver", "lifetime")?))
                 } else {
                     None
                 },
                 mutability: default_or_none(node.mutability),
                 self_token: default(),
                 colon_token: default_or_none(node.colon_token),
-                ty: node.ty.map_into(),
+                ty: node.ty.try_map_into().context("Receiver", "ty")?,
+            })
+        }
+    }
+    impl Serialize for Ser<'_, syn::Receiver> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let node = self.0;
+            let explicit = self.1.has_explicit_fields();
+            let span = Location::of(node, self.1);
+            let keep_span = self.1.is_binary() || span.is_some();
+            let lifetime = node.reference.as_ref().and_then(|(_, lifetime)| lifetime.as_ref());
+            let mut state = serializer.serialize_struct(
+                "Receiver",
+                usize::from(explicit || !node.attrs.is_empty())
+                    + usize::from(explicit || node.reference.is_some())
+                    + usize::from(explicit || lifetime.is_some())
+                    + usize::from(explicit || node.mutability.is_some())
+                    + usize::from(explicit || node.colon_token.is_some())
+                    + 1
+                    + usize::from(keep_span),
+            )?;
+            if explicit || !node.attrs.is_empty() {
+                state.serialize_field("attrs", &Ser(&node.attrs, self.1))?;
+            } else {
+                state.skip_field("attrs")?;
+            }
+            if explicit || node.reference.is_some() {
+                state.serialize_field("ref", &node.reference.is_some())?;
+            } else {
+                state.skip_field("ref")?;
+            }
+            if explicit || lifetime.is_some() {
+                state
+                    .serialize_field("lifetime", &lifetime.map(|lifetime| Ser(lifetime, self.1)))?;
+            } else {
+                state.skip_field("lifetime")?;
+            }
+            if explicit || node.mutability.is_some() {
+                state.serialize_field("mut", &node.mutability.is_some())?;
+            } else {
+                state.skip_field("mut")?;
+            }
+            if explicit || node.colon_token.is_some() {
+                state.serialize_field("colon_token", &node.colon_token.is_some())?;
+            } else {
+                state.skip_field("colon_token")?;
+            }
+            state.serialize_field("ty", &Ser(&node.ty, self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
             }
+            state.end()
         }
     }
 }
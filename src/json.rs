@@ -5,6 +5,7 @@
 use alloc::{string::String, vec::Vec};
 use std::io;
 
+use serde::de;
 use serde_json::Result;
 
 use super::*;
@@ -21,10 +22,10 @@ use super::*;
 /// # where
 /// #     W: io::Write,
 /// # {
-/// use syn_serde::Syn;
+/// use syn_serde::Serializable;
 ///
-/// let adapter = syn_file.to_adapter();
-/// serde_json::to_writer(writer, &adapter)
+/// let serializable = Serializable::new(syn_file);
+/// serde_json::to_writer(writer, &serializable)
 /// # }
 /// ```
 pub fn to_writer<S, W>(writer: W, syn: &S) -> Result<()>
@@ -32,8 +33,8 @@ where
     S: Syn,
     W: io::Write,
 {
-    let adapter = syn.to_adapter();
-    serde_json::to_writer(writer, &adapter)
+    let serializable = Serializable::new(syn);
+    serde_json::to_writer(writer, &serializable)
 }
 
 /// Serialize the given [`Syn`] type as pretty-printed JSON into the IO
@@ -44,10 +45,10 @@ where
 /// ```
 /// # use std::io;
 /// # fn to_writer_pretty<W: io::Write>(writer: W, syn_file: &syn::File) -> serde_json::Result<()> {
-/// use syn_serde::Syn;
+/// use syn_serde::Serializable;
 ///
-/// let adapter = syn_file.to_adapter();
-/// serde_json::to_writer_pretty(writer, &adapter)
+/// let serializable = Serializable::new(syn_file);
+/// serde_json::to_writer_pretty(writer, &serializable)
 /// # }
 /// ```
 pub fn to_writer_pretty<S, W>(writer: W, syn: &S) -> Result<()>
@@ -55,8 +56,8 @@ where
     S: Syn,
     W: io::Write,
 {
-    let adapter = syn.to_adapter();
-    serde_json::to_writer_pretty(writer, &adapter)
+    let serializable = Serializable::new(syn);
+    serde_json::to_writer_pretty(writer, &serializable)
 }
 
 /// Serialize the given [`Syn`] type as a JSON byte vector.
@@ -65,10 +66,10 @@ where
 ///
 /// ```
 /// # fn to_vec(syn_file: &syn::File) -> Vec<u8> {
-/// use syn_serde::Syn;
+/// use syn_serde::Serializable;
 ///
-/// let adapter = syn_file.to_adapter();
-/// serde_json::to_vec(&adapter).unwrap()
+/// let serializable = Serializable::new(syn_file);
+/// serde_json::to_vec(&serializable).unwrap()
 /// # }
 /// ```
 // All of the data structures in syn-serde are compatible with JSON so unwrap will never fail.
@@ -77,8 +78,8 @@ pub fn to_vec<S>(syn: &S) -> Vec<u8>
 where
     S: Syn,
 {
-    let adapter = syn.to_adapter();
-    serde_json::to_vec(&adapter).unwrap()
+    let serializable = Serializable::new(syn);
+    serde_json::to_vec(&serializable).unwrap()
 }
 
 /// Serialize the given [`Syn`] type as a pretty-printed JSON byte vector.
@@ -87,10 +88,10 @@ where
 ///
 /// ```
 /// # fn to_vec_pretty(syn_file: &syn::File) -> Vec<u8> {
-/// use syn_serde::Syn;
+/// use syn_serde::Serializable;
 ///
-/// let adapter = syn_file.to_adapter();
-/// serde_json::to_vec_pretty(&adapter).unwrap()
+/// let serializable = Serializable::new(syn_file);
+/// serde_json::to_vec_pretty(&serializable).unwrap()
 /// # }
 /// ```
 // All of the data structures in syn-serde are compatible with JSON so unwrap will never fail.
@@ -99,8 +100,8 @@ pub fn to_vec_pretty<S>(syn: &S) -> Vec<u8>
 where
     S: Syn,
 {
-    let adapter = syn.to_adapter();
-    serde_json::to_vec_pretty(&adapter).unwrap()
+    let serializable = Serializable::new(syn);
+    serde_json::to_vec_pretty(&serializable).unwrap()
 }
 
 /// Serialize the given [`Syn`] type as a String of JSON.
@@ -109,10 +110,10 @@ where
 ///
 /// ```
 /// # fn to_string(syn_file: &syn::File) -> String {
-/// use syn_serde::Syn;
+/// use syn_serde::Serializable;
 ///
-/// let adapter = syn_file.to_adapter();
-/// serde_json::to_string(&adapter).unwrap()
+/// let serializable = Serializable::new(syn_file);
+/// serde_json::to_string(&serializable).unwrap()
 /// # }
 /// ```
 // All of the data structures in syn-serde are compatible with JSON so unwrap will never fail.
@@ -121,8 +122,8 @@ pub fn to_string<S>(syn: &S) -> String
 where
     S: Syn,
 {
-    let adapter = syn.to_adapter();
-    serde_json::to_string(&adapter).unwrap()
+    let serializable = Serializable::new(syn);
+    serde_json::to_string(&serializable).unwrap()
 }
 
 /// Serialize the given [`Syn`] type as a pretty-printed String of JSON.
@@ -131,10 +132,10 @@ where
 ///
 /// ```
 /// # fn to_string_pretty(syn_file: &syn::File) -> String {
-/// use syn_serde::Syn;
+/// use syn_serde::Serializable;
 ///
-/// let adapter = syn_file.to_adapter();
-/// serde_json::to_string_pretty(&adapter).unwrap()
+/// let serializable = Serializable::new(syn_file);
+/// serde_json::to_string_pretty(&serializable).unwrap()
 /// # }
 /// ```
 // All of the data structures in syn-serde are compatible with JSON so unwrap will never fail.
@@ -143,8 +144,160 @@ pub fn to_string_pretty<S>(syn: &S) -> String
 where
     S: Syn,
 {
-    let adapter = syn.to_adapter();
-    serde_json::to_string_pretty(&adapter).unwrap()
+    let serializable = Serializable::new(syn);
+    serde_json::to_string_pretty(&serializable).unwrap()
+}
+
+/// Serialize the given [`Syn`] type as JSON into the I/O stream, with the
+/// given options.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn to_writer_with_options<W>(
+/// #     writer: W,
+/// #     syn_file: &syn::File,
+/// #     options: syn_serde::SerializeOptions,
+/// # ) -> serde_json::Result<()>
+/// # where
+/// #     W: io::Write,
+/// # {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file).with_options(options);
+/// serde_json::to_writer(writer, &serializable)
+/// # }
+/// ```
+///
+/// If [`SerializeOptions::pretty`] is set, this is the same as
+/// [`to_writer_pretty_with_options`].
+///
+/// # Examples
+///
+/// ```
+/// use syn_serde::{json, SerializeOptions};
+///
+/// let item: syn::Item = syn::parse_quote!(struct S;);
+/// let options = SerializeOptions::new().explicit_fields(true);
+///
+/// let mut buf = vec![];
+/// json::to_writer_with_options(&mut buf, &item, options)?;
+/// assert_eq!(
+///     String::from_utf8(buf)?,
+///     r#"{"struct":{"attrs":[],"vis":"inherited","ident":"S","generics":{"params":[],"where_clause":null},"fields":"unit"}}"#,
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn to_writer_with_options<S, W>(writer: W, syn: &S, options: SerializeOptions) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    if options.is_pretty() {
+        serde_json::to_writer_pretty(writer, &serializable)
+    } else {
+        serde_json::to_writer(writer, &serializable)
+    }
+}
+
+/// Serialize the given [`Syn`] type as pretty-printed JSON into the IO
+/// stream, with the given options.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn to_writer_pretty_with_options<W>(
+/// #     writer: W,
+/// #     syn_file: &syn::File,
+/// #     options: syn_serde::SerializeOptions,
+/// # ) -> serde_json::Result<()>
+/// # where
+/// #     W: io::Write,
+/// # {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file).with_options(options);
+/// serde_json::to_writer_pretty(writer, &serializable)
+/// # }
+/// ```
+pub fn to_writer_pretty_with_options<S, W>(
+    writer: W,
+    syn: &S,
+    options: SerializeOptions,
+) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    serde_json::to_writer_pretty(writer, &serializable)
+}
+
+/// Serialize the given [`Syn`] type as a JSON byte vector, with the given
+/// options.
+///
+/// See [`to_writer_with_options`] for details.
+// All of the data structures in syn-serde are compatible with JSON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec_with_options<S>(syn: &S, options: SerializeOptions) -> Vec<u8>
+where
+    S: Syn,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    if options.is_pretty() {
+        serde_json::to_vec_pretty(&serializable).unwrap()
+    } else {
+        serde_json::to_vec(&serializable).unwrap()
+    }
+}
+
+/// Serialize the given [`Syn`] type as a pretty-printed JSON byte vector, with
+/// the given options.
+///
+/// See [`to_writer_pretty_with_options`] for details.
+// All of the data structures in syn-serde are compatible with JSON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec_pretty_with_options<S>(syn: &S, options: SerializeOptions) -> Vec<u8>
+where
+    S: Syn,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    serde_json::to_vec_pretty(&serializable).unwrap()
+}
+
+/// Serialize the given [`Syn`] type as a String of JSON, with the given
+/// options.
+///
+/// See [`to_writer_with_options`] for details.
+// All of the data structures in syn-serde are compatible with JSON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_string_with_options<S>(syn: &S, options: SerializeOptions) -> String
+where
+    S: Syn,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    if options.is_pretty() {
+        serde_json::to_string_pretty(&serializable).unwrap()
+    } else {
+        serde_json::to_string(&serializable).unwrap()
+    }
+}
+
+/// Serialize the given [`Syn`] type as a pretty-printed String of JSON, with
+/// the given options.
+///
+/// See [`to_writer_pretty_with_options`] for details.
+// All of the data structures in syn-serde are compatible with JSON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_string_pretty_with_options<S>(syn: &S, options: SerializeOptions) -> String
+where
+    S: Syn,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    serde_json::to_string_pretty(&serializable).unwrap()
 }
 
 // Deserialize JSON data to [`Syn`] type.
@@ -159,7 +312,7 @@ where
 /// use syn_serde::Syn;
 ///
 /// let adapter: <syn::File as Syn>::Adapter = serde_json::from_reader(reader)?;
-/// let syn_file = syn::File::from_adapter(&adapter);
+/// let syn_file = syn::File::try_from_adapter(&adapter).map_err(serde::de::Error::custom)?;
 /// Ok(syn_file)
 /// # }
 /// ```
@@ -169,7 +322,7 @@ where
     R: io::Read,
 {
     let adapter: S::Adapter = serde_json::from_reader(reader)?;
-    Ok(S::from_adapter(&adapter))
+    S::try_from_adapter(&adapter).map_err(de::Error::custom)
 }
 
 /// Deserialize an instance of [`Syn`] type from bytes of JSON text.
@@ -181,7 +334,7 @@ where
 /// use syn_serde::Syn;
 ///
 /// let adapter: <syn::File as Syn>::Adapter = serde_json::from_slice(v)?;
-/// let syn_file = syn::File::from_adapter(&adapter);
+/// let syn_file = syn::File::try_from_adapter(&adapter).map_err(serde::de::Error::custom)?;
 /// Ok(syn_file)
 /// # }
 /// ```
@@ -190,7 +343,7 @@ where
     S: Syn,
 {
     let adapter: S::Adapter = serde_json::from_slice(v)?;
-    Ok(S::from_adapter(&adapter))
+    S::try_from_adapter(&adapter).map_err(de::Error::custom)
 }
 
 /// Deserialize an instance of [`Syn`] type from a string of JSON text.
@@ -202,7 +355,7 @@ where
 /// use syn_serde::Syn;
 ///
 /// let adapter: <syn::File as Syn>::Adapter = serde_json::from_str(s)?;
-/// let syn_file = syn::File::from_adapter(&adapter);
+/// let syn_file = syn::File::try_from_adapter(&adapter).map_err(serde::de::Error::custom)?;
 /// Ok(syn_file)
 /// # }
 /// ```
@@ -211,5 +364,225 @@ where
     S: Syn,
 {
     let adapter: S::Adapter = serde_json::from_str(s)?;
-    Ok(S::from_adapter(&adapter))
+    S::try_from_adapter(&adapter).map_err(de::Error::custom)
+}
+
+// Serialize and deserialize JSON Lines, one top-level item per line.
+
+/// Serialize the given file as [JSON Lines] into the I/O stream.
+///
+/// The first line records the file-level data (the shebang and the inner
+/// attributes) as a [`File`] with no items, and each of the following lines
+/// records one top-level [`Item`] of the file. Use [`ItemsReader`] to read
+/// the file back.
+///
+/// # Examples
+///
+/// ```
+/// let file: syn::File = syn::parse_quote! {
+///     #![no_std]
+///     struct Unit;
+///     fn f() {}
+/// };
+///
+/// let mut buf = vec![];
+/// syn_serde::json::items_to_writer_lines(&mut buf, &file).unwrap();
+/// assert_eq!(
+///     String::from_utf8(buf).unwrap(),
+///     concat!(
+///         r#"{"attrs":[{"style":"inner","meta":{"path":{"segments":[{"ident":"no_std"}]}}}],"items":[]}"#,
+///         "\n",
+///         r#"{"struct":{"ident":"Unit","fields":"unit"}}"#,
+///         "\n",
+///         r#"{"fn":{"ident":"f","inputs":[],"output":null,"stmts":[]}}"#,
+///         "\n",
+///     ),
+/// );
+/// ```
+///
+/// [JSON Lines]: https://jsonlines.org
+pub fn items_to_writer_lines<W>(writer: W, file: &syn::File) -> Result<()>
+where
+    W: io::Write,
+{
+    items_to_writer_lines_with_options(writer, file, SerializeOptions::new())
+}
+
+/// Serialize the given file as [JSON Lines] into the I/O stream with the given
+/// options.
+///
+/// See [`items_to_writer_lines`] for the format. The options apply to the
+/// file-level data on the first line as well as to the items, except for
+/// [`SerializeOptions::pretty`], which is ignored.
+///
+/// [JSON Lines]: https://jsonlines.org
+pub fn items_to_writer_lines_with_options<W>(
+    mut writer: W,
+    file: &syn::File,
+    options: SerializeOptions,
+) -> Result<()>
+where
+    W: io::Write,
+{
+    serde_json::to_writer(&mut writer, &Header(file, options))?;
+    writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+    for item in &file.items {
+        serde_json::to_writer(&mut writer, &Serializable::new(item).with_options(options))?;
+        writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+    }
+    Ok(())
+}
+
+// The first line of JSON Lines: a `File` with the shebang and the inner
+// attributes of the file, and no items.
+struct Header<'a>(&'a syn::File, SerializeOptions);
+
+impl Serialize for Header<'_> {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (file, options) = (self.0, self.1);
+        let explicit = options.has_explicit_fields();
+        let mut state = serializer.serialize_struct(
+            "File",
+            usize::from(explicit || file.shebang.is_some())
+                + usize::from(explicit || !file.attrs.is_empty())
+                + 1,
+        )?;
+        if explicit || file.shebang.is_some() {
+            state.serialize_field("shebang", &Ser(&file.shebang, options))?;
+        } else {
+            state.skip_field("shebang")?;
+        }
+        if explicit || !file.attrs.is_empty() {
+            state.serialize_field("attrs", &Ser(&file.attrs, options))?;
+        } else {
+            state.skip_field("attrs")?;
+        }
+        state.serialize_field("items", &[(); 0])?;
+        state.end()
+    }
+}
+
+/// A reader that rebuilds a [`syn::File`] from an I/O stream of [JSON Lines]
+/// written by [`items_to_writer_lines`].
+///
+/// The file-level data is read when the reader is created, and the items are
+/// read one line at a time by iterating over the reader, or all at once with
+/// [`into_file`](Self::into_file). Empty lines are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use syn_serde::json::ItemsReader;
+///
+/// let file: syn::File = syn::parse_quote! {
+///     #![no_std]
+///     struct Unit;
+///     fn f() {}
+/// };
+/// let mut buf = vec![];
+/// syn_serde::json::items_to_writer_lines(&mut buf, &file).unwrap();
+///
+/// let mut reader = ItemsReader::new(&*buf).unwrap();
+/// assert_eq!(reader.attrs().len(), 1);
+/// let item = reader.next().unwrap().unwrap();
+/// assert!(matches!(item, syn::Item::Struct(_)));
+/// let item = reader.next().unwrap().unwrap();
+/// assert!(matches!(item, syn::Item::Fn(_)));
+/// assert!(reader.next().is_none());
+///
+/// let file = ItemsReader::new(&*buf).unwrap().into_file().unwrap();
+/// assert_eq!(file.attrs.len(), 1);
+/// assert_eq!(file.items.len(), 2);
+/// ```
+///
+/// [JSON Lines]: https://jsonlines.org
+pub struct ItemsReader<R> {
+    reader: io::BufReader<R>,
+    line: String,
+    file: syn::File,
+    has_read_items: bool,
+}
+
+impl<R> ItemsReader<R>
+where
+    R: io::Read,
+{
+    /// Creates a new reader and reads the file-level data from the first line
+    /// of `reader`.
+    pub fn new(reader: R) -> Result<Self> {
+        let mut this = Self {
+            reader: io::BufReader::new(reader),
+            line: String::new(),
+            file: syn::File { shebang: None, attrs: Vec::new(), items: Vec::new() },
+            has_read_items: false,
+        };
+        this.read_line()?;
+        this.file = from_str(&this.line)?;
+        Ok(this)
+    }
+
+    /// Returns the shebang line of the file, if any.
+    pub fn shebang(&self) -> Option<&str> {
+        self.file.shebang.as_deref()
+    }
+
+    /// Returns the inner attributes of the file.
+    pub fn attrs(&self) -> &[syn::Attribute] {
+        &self.file.attrs
+    }
+
+    /// Reads all the items and returns the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if some items have already been read by iterating over
+    /// the reader, because they are not kept, or if an item cannot be read.
+    pub fn into_file(mut self) -> Result<syn::File> {
+        if self.has_read_items {
+            return Err(de::Error::custom(
+                "cannot rebuild the file after some of its items have been read",
+            ));
+        }
+        while let Some(item) = self.next() {
+            self.file.items.push(item?);
+        }
+        Ok(self.file)
+    }
+
+    /// Reads the next non-empty line into `self.line`, or returns `false` at
+    /// the end of the stream.
+    fn read_line(&mut self) -> Result<bool> {
+        loop {
+            self.line.clear();
+            let n = io::BufRead::read_line(&mut self.reader, &mut self.line)
+                .map_err(serde_json::Error::io)?;
+            if n == 0 {
+                return Ok(false);
+            }
+            if !self.line.trim().is_empty() {
+                return Ok(true);
+            }
+        }
+    }
+}
+
+impl<R> Iterator for ItemsReader<R>
+where
+    R: io::Read,
+{
+    type Item = Result<syn::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_line() {
+            Ok(true) => {
+                self.has_read_items = true;
+                Some(from_str(&self.line))
+            }
+            Ok(false) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
 }
@@ -83,10 +83,59 @@ and print out a JSON representation of the syntax tree.
 The [`json2rust`] example parse a JSON file into a `syn_serde::File` and
 print out a Rust syntax tree.
 
+### Rust source file -> Pickle representation of the syntax tree
+
+The [`rust2pickle`] example parse a Rust source file into a `syn_serde::File`
+and print out a Pickle representation of the syntax tree, which can be loaded
+by Python's `pickle` module.
+
+### JSON Schema
+
+[`schema/syn-serde.schema.json`] is a [JSON Schema] (draft 2020-12) that
+describes the JSON representation of a `syn_serde::File`. The schema of each
+adapter is defined in `$defs`, under the name of the adapter type.
+
+[`schema/syn-serde.d.ts`] provides the corresponding TypeScript type
+definitions. Enums are represented as unions of objects with a single property
+named after the variant (or as string literals for variants without data), and
+fields that may be omitted are optional properties.
+
 ## Optional features
 
+- **`binary`** — Provides functions for serializing and deserializing a
+  compact binary format, intended for caching parsed syntax trees.
+- **`cbor`** — Provides functions for CBOR <-> Rust serializing and
+  deserializing. The data has the same shape as the JSON representation.
+- **`extra-traits`** — Implements `Debug`, `Clone`, `PartialEq`, `Eq`, and
+  `Hash` for all adapters.
+- **`fold`** — Provides the `Fold` trait to transform owned adapters of a
+  syntax tree, mirroring `syn::fold`.
 - **`json`** — Provides functions for JSON <-> Rust serializing and
   deserializing.
+- **`lossless`** — Provides the `lossless` module to parse a file while keeping
+  its comments and whitespace as trivia attached to the file and its items, and
+  to print it back byte-for-byte. Implies `span-locations`.
+- **`macro-args`** — Serializes the arguments of well-known macros of the
+  standard library (e.g., `println!`, `write!`, `assert_eq!`, and `vec!`) as
+  expressions next to their tokens with `SerializeOptions::macro_args`, and
+  rebuilds the tokens from them when deserializing.
+- **`msgpack`** — Provides functions for MessagePack <-> Rust serializing and
+  deserializing. The data has the same shape as the JSON representation.
+- **`pickle`** — Provides functions for Pickle <-> Rust serializing and
+  deserializing, for use with Python's `pickle` module.
+- **`ron`** — Provides functions for RON <-> Rust serializing and
+  deserializing. The data has the same shape as the JSON representation.
+- **`span-locations`** — Records the start and end line and column of nodes
+  and tokens, which are serialized in a `"span"` field with
+  `SerializeOptions::spans`. This is ignored when converting back to a syntax
+  tree. Locations are only available for syntax trees parsed outside of
+  procedural macros (see proc-macro2's `span-locations` feature).
+- **`visit`** — Provides the `Visit` trait to walk a shared borrow of the
+  adapters of a syntax tree, mirroring `syn::visit`.
+- **`visit-mut`** — Provides the `VisitMut` trait to mutate the adapters of a
+  syntax tree in place, mirroring `syn::visit_mut`.
+- **`yaml`** — Provides functions for YAML <-> Rust serializing and
+  deserializing. The data has the same shape as the JSON representation.
 
 ## Relationship to Syn
 
@@ -94,6 +143,15 @@ syn-serde is a fork of [Syn], and syn-serde provides a set of data structures
 similar but not identical to [Syn]. All data structures provided by syn-serde
 can be converted to the data structures of [Syn] and [proc-macro2].
 
+The fields of these data structures are public, so a syntax tree can be
+inspected and built directly, e.g., after deserializing it, without converting
+it to [Syn]'s data structures. Tokens that carry no information, such as
+brackets and keywords, are omitted or represented as `bool`s, and identifiers
+and literals are validated only when converting to [Syn]'s data structures.
+The structs are `#[non_exhaustive]`: they are created with `new` from the
+fields that are required in the serialized form (or with `Default` if there are
+none), and the other fields can be set afterwards.
+
 The data structures of syn-serde 0.3 is compatible with the data structures of
 [Syn] 2.x.
 
@@ -101,6 +159,10 @@ The data structures of syn-serde 0.3 is compatible with the data structures of
 [proc-macro2]: https://github.com/alexcrichton/proc-macro2
 [`rust2json`]: https://github.com/taiki-e/syn-serde/tree/HEAD/examples/rust2json
 [`json2rust`]: https://github.com/taiki-e/syn-serde/tree/HEAD/examples/json2rust
+[`rust2pickle`]: https://github.com/taiki-e/syn-serde/tree/HEAD/examples/rust2pickle
+[`schema/syn-serde.schema.json`]: https://github.com/taiki-e/syn-serde/blob/HEAD/schema/syn-serde.schema.json
+[`schema/syn-serde.d.ts`]: https://github.com/taiki-e/syn-serde/blob/HEAD/schema/syn-serde.d.ts
+[JSON Schema]: https://json-schema.org
 
 <!-- tidy:sync-markdown-to-rustdoc:end -->
 */
@@ -153,47 +215,51 @@ mod ast_enum;
 #[path = "gen/convert.rs"]
 mod convert;
 
+#[path = "gen/ser.rs"]
+mod ser;
+
 mod attr {
     pub use crate::{
         ast_enum::{AttrStyle, Meta},
         ast_struct::{Attribute, MetaList, MetaNameValue},
     };
 }
-#[doc(hidden)]
 pub use self::attr::{AttrStyle, Attribute, Meta, MetaList, MetaNameValue};
 
 mod data;
 pub(crate) use self::data::assert_struct_semi;
-#[doc(hidden)]
 pub use self::data::{Field, Fields, FieldsNamed, FieldsUnnamed, Variant};
 
+mod derive;
+pub use self::derive::{Data, DataEnum, DataStruct, DataUnion, DeriveInput};
+
+mod error;
+pub use self::error::Error;
+pub(crate) use self::error::ResultExt as _;
+
 mod expr;
-#[doc(hidden)]
 pub use self::expr::{
     Arm, Expr, ExprArray, ExprAssign, ExprAsync, ExprAwait, ExprBinary, ExprBlock, ExprBreak,
     ExprCall, ExprCast, ExprClosure, ExprConst, ExprContinue, ExprField, ExprForLoop, ExprGroup,
     ExprIf, ExprIndex, ExprInfer, ExprLet, ExprLit, ExprLoop, ExprMacro, ExprMatch, ExprMethodCall,
-    ExprParen, ExprPath, ExprRange, ExprReference, ExprRepeat, ExprReturn, ExprStruct, ExprTry,
-    ExprTryBlock, ExprTuple, ExprUnary, ExprUnsafe, ExprWhile, ExprYield, FieldValue, Index, Label,
-    Member, RangeLimits,
+    ExprParen, ExprPath, ExprRange, ExprRawAddr, ExprReference, ExprRepeat, ExprReturn, ExprStruct,
+    ExprTry, ExprTryBlock, ExprTuple, ExprUnary, ExprUnsafe, ExprWhile, ExprYield, FieldValue,
+    Index, Label, Member, PointerMutability, RangeLimits,
 };
 
 mod file {
     pub use crate::ast_struct::File;
 }
-#[doc(hidden)]
 pub use self::file::File;
 
 mod generics;
-#[doc(hidden)]
 pub use self::generics::{
-    BoundLifetimes, ConstParam, GenericParam, Generics, LifetimeParam, PredicateLifetime,
-    PredicateType, TraitBound, TraitBoundModifier, TypeParam, TypeParamBound, WhereClause,
-    WherePredicate,
+    BoundLifetimes, CapturedParam, ConstParam, GenericParam, Generics, LifetimeParam,
+    PreciseCapture, PredicateLifetime, PredicateType, TraitBound, TraitBoundModifier, TypeParam,
+    TypeParamBound, WhereClause, WherePredicate,
 };
 
 mod item;
-#[doc(hidden)]
 pub use self::item::{
     FnArg, ForeignItem, ForeignItemFn, ForeignItemMacro, ForeignItemStatic, ForeignItemType,
     ImplItem, ImplItemConst, ImplItemFn, ImplItemMacro, ImplItemType, ImplRestriction, Item,
@@ -206,56 +272,68 @@ pub use self::item::{
 mod lifetime {
     pub use crate::ast_struct::Lifetime;
 }
-#[doc(hidden)]
 pub use self::lifetime::Lifetime;
 
 mod lit;
-#[doc(hidden)]
 pub use self::lit::{
-    Lit, LitBool, LitByte, LitByteStr, LitChar, LitFloat, LitInt, LitStr, StrStyle,
+    Lit, LitBool, LitByte, LitByteStr, LitCStr, LitChar, LitFloat, LitInt, LitStr, StrStyle,
 };
 
-mod mac {
-    pub use crate::{ast_enum::MacroDelimiter, ast_struct::Macro};
-}
-#[doc(hidden)]
+mod mac;
+#[cfg(feature = "macro-args")]
+pub use self::mac::{FormatArg, FormatArgs, MacroArgs, RepeatArgs};
 pub use self::mac::{Macro, MacroDelimiter};
 
 mod op {
     pub use crate::ast_enum::{BinOp, UnOp};
 }
-#[doc(hidden)]
 pub use self::op::{BinOp, UnOp};
 
+mod options;
+pub use self::options::SerializeOptions;
+
 mod pat;
-#[doc(hidden)]
-pub use self::expr::{
-    ExprConst as PatConst, ExprLit as PatLit, ExprMacro as PatMacro, ExprPath as PatPath,
-    ExprRange as PatRange,
-};
-#[doc(hidden)]
-pub use self::pat::{
-    FieldPat, Pat, PatIdent, PatOr, PatParen, PatReference, PatRest, PatSlice, PatStruct, PatTuple,
-    PatTupleStruct, PatType, PatWild,
+pub use self::{
+    expr::{
+        ExprConst as PatConst, ExprLit as PatLit, ExprMacro as PatMacro, ExprPath as PatPath,
+        ExprRange as PatRange,
+    },
+    pat::{
+        FieldPat, Pat, PatIdent, PatOr, PatParen, PatReference, PatRest, PatSlice, PatStruct,
+        PatTuple, PatTupleStruct, PatType, PatWild,
+    },
 };
 
 mod path;
-#[doc(hidden)]
 pub use self::path::{
     AngleBracketedGenericArguments, AssocConst, AssocType, Constraint, GenericArgument,
     ParenthesizedGenericArguments, Path, PathArguments, PathSegment, QSelf,
 };
 
+mod span;
+pub use self::span::{LineColumn, Location};
+
+mod trivia;
+pub use self::trivia::{Spelling, Trivia};
+
 mod restriction;
-#[doc(hidden)]
 pub use self::restriction::{FieldMutability, VisRestricted, Visibility};
 
 mod stmt;
-#[doc(hidden)]
 pub use self::stmt::{Block, Local, LocalInit, Stmt, StmtMacro};
 
+mod tagged;
+
+mod content;
+
+mod flatten;
+
+mod hint;
+
+#[cfg(any(feature = "ron", feature = "yaml"))]
+mod singleton_map;
+
 mod ty;
-#[doc(hidden)]
 pub use self::ty::{
     Abi, BareFnArg, BareVariadic, ReturnType, Type, TypeArray, TypeBareFn, TypeGroup,
     TypeImplTrait, TypeMacro, TypeParen, TypePath, TypePtr, TypeReference, TypeSlice,
@@ -263,17 +341,162 @@ pub use self::ty::{
 };
 
 mod token_stream;
-#[doc(hidden)]
 pub use self::token_stream::{
     Delimiter, Group, Ident, Literal, Punct, Spacing, TokenStream, TokenTree,
 };
 
+#[cfg(feature = "binary")]
+pub mod binary;
+
+#[cfg(feature = "cbor")]
+pub mod cbor;
+
 #[cfg(feature = "json")]
 pub mod json;
 
+#[cfg(feature = "lossless")]
+pub mod lossless;
+
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+
+#[cfg(feature = "pickle")]
+pub mod pickle;
+
+#[cfg(feature = "ron")]
+pub mod ron;
+
+#[cfg(feature = "yaml")]
+pub mod yaml;
+
+/// Syntax tree traversal to walk a shared borrow of the adapters of a syntax
+/// tree.
+///
+/// This mirrors [`syn::visit`], but works on the adapters, e.g., a syntax tree
+/// that was deserialized from JSON, without converting it into a [`Syn`] type.
+///
+/// Each method of the [`Visit`](visit::Visit) trait is a hook that can be
+/// overridden to customize the behavior when visiting the corresponding type
+/// of node. By default, every method recursively visits the substructure of
+/// the input by invoking the right visitor method of each of its fields.
+///
+/// # Examples
+///
+/// ```
+/// use syn_serde::{Syn, visit::Visit};
+///
+/// struct ExprCount(usize);
+///
+/// impl<'ast> Visit<'ast> for ExprCount {
+///     fn visit_expr(&mut self, node: &'ast syn_serde::Expr) {
+///         self.0 += 1;
+///         syn_serde::visit::visit_expr(self, node);
+///     }
+/// }
+///
+/// let expr: syn::Expr = syn::parse_quote!(a + b * c);
+/// let mut count = ExprCount(0);
+/// count.visit_expr(&expr.to_adapter());
+/// assert_eq!(count.0, 5);
+/// ```
+///
+/// [`syn::visit`]: https://docs.rs/syn/2/syn/visit/index.html
+#[cfg(feature = "visit")]
+#[path = "gen/visit.rs"]
+pub mod visit;
+
+/// Syntax tree traversal to mutate an exclusive borrow of the adapters of a
+/// syntax tree in place.
+///
+/// This mirrors [`syn::visit_mut`], but works on the adapters, e.g., a syntax
+/// tree that was deserialized from JSON, without converting it into a [`Syn`]
+/// type.
+///
+/// Each method of the [`VisitMut`](visit_mut::VisitMut) trait is a hook that
+/// can be overridden to customize the behavior when mutating the corresponding
+/// type of node. By default, every method recursively visits the substructure
+/// of the input by invoking the right visitor method of each of its fields.
+///
+/// # Examples
+///
+/// ```
+/// use syn_serde::{Syn, visit_mut::VisitMut};
+///
+/// struct RenameIdents;
+///
+/// impl VisitMut for RenameIdents {
+///     fn visit_ident_mut(&mut self, node: &mut syn_serde::Ident) {
+///         let ident = proc_macro2::Ident::from_adapter(node);
+///         let ident = quote::format_ident!("renamed_{}", ident);
+///         *node = ident.to_adapter();
+///     }
+/// }
+///
+/// let expr: syn::Expr = syn::parse_quote!(a + b);
+/// let mut adapter = expr.to_adapter();
+/// RenameIdents.visit_expr_mut(&mut adapter);
+/// let expected: syn::Expr = syn::parse_quote!(renamed_a + renamed_b);
+/// assert_eq!(syn::Expr::from_adapter(&adapter), expected);
+/// ```
+///
+/// [`syn::visit_mut`]: https://docs.rs/syn/2/syn/visit_mut/index.html
+#[cfg(feature = "visit-mut")]
+#[path = "gen/visit_mut.rs"]
+pub mod visit_mut;
+
+/// Syntax tree traversal to transform the nodes of an owned adapter of a
+/// syntax tree.
+///
+/// This mirrors [`syn::fold`], but works on the adapters, e.g., a syntax tree
+/// that was deserialized from JSON, without converting it into a [`Syn`] type.
+///
+/// Each method of the [`Fold`](fold::Fold) trait is a hook that can be
+/// overridden to customize the behavior when transforming the corresponding
+/// type of node. By default, every method recursively visits the substructure
+/// of the input by invoking the right folder method of each of its fields,
+/// and reconstructs the node from the results.
+///
+/// # Examples
+///
+/// ```
+/// use syn_serde::{Syn, fold::Fold};
+///
+/// struct RemoveParens;
+///
+/// impl Fold for RemoveParens {
+///     fn fold_expr(&mut self, node: syn_serde::Expr) -> syn_serde::Expr {
+///         let expr = syn::Expr::from_adapter(&node);
+///         match expr {
+///             syn::Expr::Paren(paren) => self.fold_expr(paren.expr.to_adapter()),
+///             _ => syn_serde::fold::fold_expr(self, node),
+///         }
+///     }
+/// }
+///
+/// let expr: syn::Expr = syn::parse_quote!((a) + (b));
+/// let adapter = RemoveParens.fold_expr(expr.to_adapter());
+/// let expected: syn::Expr = syn::parse_quote!(a + b);
+/// assert_eq!(syn::Expr::from_adapter(&adapter), expected);
+/// ```
+///
+/// [`syn::fold`]: https://docs.rs/syn/2/syn/fold/index.html
+#[cfg(feature = "fold")]
+#[path = "gen/fold.rs"]
+pub mod fold;
+
 mod sealed {
     #[allow(unknown_lints, unnameable_types)] // Not public API. unnameable_types is available on Rust 1.79+
-    pub trait Sealed {}
+    pub trait Sealed {
+        // Serializes `self` in the same way as its adapter, with the given
+        // options. See `Serializable`.
+        fn serialize_ref<S>(
+            &self,
+            serializer: S,
+            options: crate::SerializeOptions,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer;
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -330,7 +553,39 @@ pub trait Syn: Sized + sealed::Sealed {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the adapter does not describe a valid syntax tree. Use
+    /// [`try_from_adapter`](Self::try_from_adapter) to handle this case.
     fn from_adapter(adapter: &Self::Adapter) -> Self;
+
+    /// Converts an adapter into a `Syn` type, returning an error if the
+    /// adapter does not describe a valid syntax tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "json")]
+    /// # fn dox() -> Result<(), Box<dyn std::error::Error>> {
+    /// use syn_serde::Syn;
+    ///
+    /// // `struct 1abc;`
+    /// let json = r#"{
+    ///   "struct": {
+    ///     "ident": "1abc",
+    ///     "fields": "unit"
+    ///   }
+    /// }"#;
+    ///
+    /// let serializable_item: <syn::Item as Syn>::Adapter = serde_json::from_str(json)?;
+    /// let err = syn::Item::try_from_adapter(&serializable_item).unwrap_err();
+    /// assert_eq!(err.node(), "ItemStruct");
+    /// assert_eq!(err.field(), Some("ident"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_from_adapter(adapter: &Self::Adapter) -> Result<Self, Error>;
 }
 
 // -----------------------------------------------------------------------------
@@ -339,7 +594,10 @@ use alloc::{boxed::Box, string::String, vec::Vec};
 use core::ops;
 
 use proc_macro2::Span;
-use serde::{de::Deserialize, ser::Serialize};
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, SerializeMap, SerializeSeq as _, SerializeStruct as _, Serializer},
+};
 use serde_derive::{Deserialize, Serialize};
 
 type Punctuated<T> = Vec<T>;
@@ -454,3 +712,241 @@ impl<T, U> MapInto<U, Box<U>> for Box<T> {
         Box::new(f(self))
     }
 }
+
+// Fallible counterparts of `From`/`RefInto`/`MapInto` used when converting
+// adapters into `Syn` types.
+trait TryFromRef<T>: Sized {
+    fn try_from_ref(other: &T) -> Result<Self, Error>;
+}
+
+trait TryRefInto<U>: Sized {
+    fn try_ref_into(&self) -> Result<U, Error>
+    where
+        U: TryFromRef<Self>,
+    {
+        U::try_from_ref(self)
+    }
+}
+
+impl<T, U> TryRefInto<U> for T {}
+
+trait TryMapInto<U, M> {
+    type T;
+
+    fn try_ref_map<'a, F>(&'a self, f: F) -> Result<M, Error>
+    where
+        Self::T: 'a,
+        F: FnMut(&'a Self::T) -> Result<U, Error>;
+
+    fn try_map_into(&self) -> Result<M, Error>
+    where
+        U: TryFromRef<Self::T>,
+    {
+        self.try_ref_map(U::try_from_ref)
+    }
+}
+
+impl<T, U> TryMapInto<U, Vec<U>> for Vec<T> {
+    type T = T;
+
+    fn try_ref_map<'a, F>(&'a self, f: F) -> Result<Vec<U>, Error>
+    where
+        F: FnMut(&'a Self::T) -> Result<U, Error>,
+    {
+        self.iter().map(f).collect()
+    }
+}
+
+impl<T, U, P> TryMapInto<U, syn::punctuated::Punctuated<U, P>> for Vec<T>
+where
+    P: Default,
+{
+    type T = T;
+
+    fn try_ref_map<'a, F>(&'a self, f: F) -> Result<syn::punctuated::Punctuated<U, P>, Error>
+    where
+        F: FnMut(&'a Self::T) -> Result<U, Error>,
+    {
+        self.iter().map(f).collect()
+    }
+}
+
+impl<T, U> TryMapInto<U, Option<U>> for Option<T> {
+    type T = T;
+
+    fn try_ref_map<'a, F>(&'a self, f: F) -> Result<Option<U>, Error>
+    where
+        F: FnMut(&'a Self::T) -> Result<U, Error>,
+    {
+        self.as_ref().map(f).transpose()
+    }
+}
+
+impl<T, U> TryMapInto<U, Box<U>> for Box<T> {
+    type T = T;
+
+    fn try_ref_map<'a, F>(&'a self, mut f: F) -> Result<Box<U>, Error>
+    where
+        F: FnMut(&'a Self::T) -> Result<U, Error>,
+    {
+        f(self).map(Box::new)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Serializable
+
+/// A wrapper that serializes a [`Syn`] type without converting it into an
+/// adapter.
+///
+/// The output is exactly the same as that of serializing the adapter returned
+/// by [`Syn::to_adapter`], but no intermediate adapter tree is allocated, so
+/// this is faster and uses less memory. [`with_options`](Self::with_options)
+/// changes the representation of some nodes.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "json")]
+/// # fn dox() -> Result<(), Box<dyn std::error::Error>> {
+/// use syn_serde::{Serializable, Syn as _};
+///
+/// let syn_file: syn::File = syn::parse_quote! {
+///     fn main() {
+///         println!("Hello, world!");
+///     }
+/// };
+///
+/// let json = serde_json::to_string(&Serializable::new(&syn_file))?;
+/// assert_eq!(json, serde_json::to_string(&syn_file.to_adapter())?);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Serializable<'a, T> {
+    syn: &'a T,
+    options: SerializeOptions,
+}
+
+impl<'a, T> Serializable<'a, T>
+where
+    T: Syn,
+{
+    /// Wraps a reference to a `Syn` type.
+    #[must_use]
+    pub fn new(syn: &'a T) -> Self {
+        Self { syn, options: SerializeOptions::new() }
+    }
+
+    /// Serializes the syntax tree with the given options.
+    #[must_use]
+    pub fn with_options(self, options: SerializeOptions) -> Self {
+        Self { options, ..self }
+    }
+}
+
+impl<T> Clone for Serializable<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Serializable<'_, T> {}
+
+impl<T> Serialize for Serializable<'_, T>
+where
+    T: Syn,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.syn.serialize_ref(serializer, self.options)
+    }
+}
+
+// Serializes a `Syn` type (or a container of them) in the same way as its
+// adapter, with the given options. The impls for the syntax tree types are
+// generated in gen/ser.rs, except for those whose adapters are written by hand.
+struct Ser<'a, T: ?Sized>(&'a T, SerializeOptions);
+
+// Serializes the fields of a type whose adapter is flattened into the adapter
+// of its parent.
+trait SerializeEntries {
+    fn serialize_entries<M>(&self, map: &mut M) -> Result<(), M::Error>
+    where
+        M: SerializeMap;
+}
+
+impl<T> Serialize for Ser<'_, Box<T>>
+where
+    for<'a> Ser<'a, T>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Ser(&**self.0, self.1).serialize(serializer)
+    }
+}
+
+impl<T> Serialize for Ser<'_, Option<T>>
+where
+    for<'a> Ser<'a, T>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            Some(x) => serializer.serialize_some(&Ser(x, self.1)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<T> Serialize for Ser<'_, Vec<T>>
+where
+    for<'a> Ser<'a, T>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for x in self.0 {
+            seq.serialize_element(&Ser(x, self.1))?;
+        }
+        seq.end()
+    }
+}
+
+impl<T, P> Serialize for Ser<'_, syn::punctuated::Punctuated<T, P>>
+where
+    for<'a> Ser<'a, T>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for x in self.0 {
+            seq.serialize_element(&Ser(x, self.1))?;
+        }
+        seq.end()
+    }
+}
+
+macro_rules! ser_std_impl {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Serialize for Ser<'_, $ty> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                self.0.serialize(serializer)
+            }
+        }
+    )*};
+}
+
+ser_std_impl!(bool, String, u32, usize);
@@ -3,6 +3,8 @@
 use alloc::{
     borrow::ToOwned as _,
     boxed::Box,
+    ffi::CString,
+    format,
     string::{String, ToString as _},
     vec,
     vec::Vec,
@@ -11,62 +13,395 @@ use alloc::{
 use super::*;
 pub use crate::{ast_enum::Lit, ast_struct::LitBool};
 
-ast_struct! {
-    /// An adapter for [`struct@syn::LitStr`].
-    #[serde(transparent)]
-    pub struct LitStr {
-        token: Literal,
-    }
+/// An adapter for [`struct@syn::LitStr`].
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+pub struct LitStr {
+    token: Literal,
 }
 
-ast_struct! {
-    /// An adapter for [`struct@syn::LitByteStr`].
-    #[serde(transparent)]
-    pub struct LitByteStr {
-        token: Literal,
-    }
+/// An adapter for [`struct@syn::LitByteStr`].
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+pub struct LitByteStr {
+    token: Literal,
 }
 
-ast_struct! {
-    /// An adapter for [`struct@syn::LitByte`].
-    #[serde(transparent)]
-    pub struct LitByte {
-        token: Literal,
-    }
+/// An adapter for [`struct@syn::LitCStr`].
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+pub struct LitCStr {
+    token: Literal,
 }
 
-ast_struct! {
-    /// An adapter for [`struct@syn::LitChar`].
-    #[serde(transparent)]
-    pub struct LitChar {
-        token: Literal,
-    }
+/// An adapter for [`struct@syn::LitByte`].
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+pub struct LitByte {
+    token: Literal,
 }
 
-ast_struct! {
-    /// An adapter for [`struct@syn::LitInt`].
-    #[serde(transparent)]
-    pub struct LitInt {
-        token: Literal,
-    }
+/// An adapter for [`struct@syn::LitChar`].
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+pub struct LitChar {
+    token: Literal,
 }
 
-ast_struct! {
-    /// An adapter for [`struct@syn::LitFloat`].
-    #[serde(transparent)]
-    pub struct LitFloat {
-        token: Literal,
-    }
+/// An adapter for [`struct@syn::LitInt`].
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+pub struct LitInt {
+    token: Literal,
+}
+
+/// An adapter for [`struct@syn::LitFloat`].
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+pub struct LitFloat {
+    token: Literal,
 }
 
+macro_rules! lit_token_impl {
+    ($($ty:ident),* $(,)?) => {$(
+        impl $ty {
+            /// Creates a literal from its token.
+            ///
+            /// The token is not checked here: a token of another kind of literal
+            /// is rejected when the adapter is converted into a syntax tree.
+            #[must_use]
+            pub fn new(token: Literal) -> Self {
+                Self { token }
+            }
+
+            /// Returns the token of this literal.
+            #[must_use]
+            pub fn token(&self) -> &Literal {
+                &self.token
+            }
+        }
+    )*};
+}
+
+lit_token_impl!(LitStr, LitByteStr, LitCStr, LitByte, LitChar, LitInt, LitFloat);
+
 ast_enum! {
     /// An adapter for [`enum@syn::StrStyle`].
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
     pub enum StrStyle {
         Cooked,
         Raw(usize),
     }
 }
 
+mod structured {
+    use core::{fmt, marker::PhantomData};
+
+    use serde::{
+        de::{
+            self, MapAccess, Visitor,
+            value::{
+                BorrowedStrDeserializer, MapAccessDeserializer, StrDeserializer, StringDeserializer,
+            },
+        },
+        ser,
+    };
+
+    use super::{convert::LitToken, *};
+
+    /// The representation of a literal with
+    /// [`SerializeOptions::structured_literals`].
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Structured<V> {
+        value: V,
+        #[serde(default)]
+        suffix: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        style: Option<StrStyle>,
+    }
+
+    impl<V> Structured<V> {
+        fn new(value: V, suffix: &str, style: Option<StrStyle>) -> Self {
+            Self { value, suffix: suffix.to_owned(), style }
+        }
+
+        fn no_style(self, kind: &str) -> Result<Self, String> {
+            if self.style.is_some() {
+                return Err(format!("{kind} literal does not have a style"));
+            }
+            Ok(self)
+        }
+    }
+
+    // Conversions between a literal and its structured representation.
+    trait StructuredLit: LitToken {
+        type Value: Serialize + for<'de> Deserialize<'de>;
+
+        fn from_token(token: Literal) -> Self;
+        fn to_structured(&self) -> Result<Structured<Self::Value>, Error>;
+        fn from_structured(lit: Structured<Self::Value>) -> Result<Self, String>;
+    }
+
+    /// Returns the style of a string literal whose token is `text`, without
+    /// its prefix (e.g., `b` of byte strings).
+    fn str_style(text: &str) -> StrStyle {
+        match text.strip_prefix('r') {
+            Some(s) => StrStyle::Raw(s.bytes().take_while(|&b| b == b'#').count()),
+            None => StrStyle::Cooked,
+        }
+    }
+
+    /// Returns the token of a raw string literal, e.g., `br#"..."#`.
+    fn raw_str(prefix: &str, value: &str, pounds: usize, suffix: &str) -> Result<Literal, String> {
+        let pounds = "#".repeat(pounds);
+        if value.contains(&format!("\"{pounds}")) || value.contains('\r') {
+            return Err(format!(
+                "{value:?} cannot be written as a raw string with {} `#`",
+                pounds.len()
+            ));
+        }
+        Ok(Literal::new(&format!("{prefix}r{pounds}\"{value}\"{pounds}{suffix}")))
+    }
+
+    fn with_suffix(token: Literal, suffix: &str) -> Literal {
+        if suffix.is_empty() { token } else { Literal::new(&format!("{token}{suffix}")) }
+    }
+
+    impl StructuredLit for LitStr {
+        type Value = String;
+
+        fn from_token(token: Literal) -> Self {
+            Self { token }
+        }
+        fn to_structured(&self) -> Result<Structured<String>, Error> {
+            let lit = syn::LitStr::try_from_ref(self)?;
+            let style = str_style(&self.token.text);
+            Ok(Structured::new(lit.value(), lit.suffix(), Some(style)))
+        }
+        fn from_structured(lit: Structured<String>) -> Result<Self, String> {
+            let token = match lit.style {
+                None | Some(StrStyle::Cooked) => {
+                    with_suffix(Literal::string(&lit.value), &lit.suffix)
+                }
+                Some(StrStyle::Raw(pounds)) => raw_str("", &lit.value, pounds, &lit.suffix)?,
+            };
+            Ok(Self { token })
+        }
+    }
+
+    impl StructuredLit for LitByteStr {
+        type Value = Vec<u8>;
+
+        fn from_token(token: Literal) -> Self {
+            Self { token }
+        }
+        fn to_structured(&self) -> Result<Structured<Vec<u8>>, Error> {
+            let lit = syn::LitByteStr::try_from_ref(self)?;
+            let style = str_style(&self.token.text[1..]);
+            Ok(Structured::new(lit.value(), lit.suffix(), Some(style)))
+        }
+        fn from_structured(lit: Structured<Vec<u8>>) -> Result<Self, String> {
+            let token = match lit.style {
+                None | Some(StrStyle::Cooked) => {
+                    with_suffix(Literal::byte_string(&lit.value), &lit.suffix)
+                }
+                Some(StrStyle::Raw(pounds)) => {
+                    let value = String::from_utf8(lit.value)
+                        .ok()
+                        .filter(|value| value.is_ascii())
+                        .ok_or("raw byte string literal must be ASCII")?;
+                    raw_str("b", &value, pounds, &lit.suffix)?
+                }
+            };
+            Ok(Self { token })
+        }
+    }
+
+    impl StructuredLit for LitCStr {
+        type Value = Vec<u8>;
+
+        fn from_token(token: Literal) -> Self {
+            Self { token }
+        }
+        fn to_structured(&self) -> Result<Structured<Vec<u8>>, Error> {
+            let lit = syn::LitCStr::try_from_ref(self)?;
+            let style = str_style(&self.token.text[1..]);
+            Ok(Structured::new(lit.value().into_bytes(), lit.suffix(), Some(style)))
+        }
+        fn from_structured(lit: Structured<Vec<u8>>) -> Result<Self, String> {
+            let value =
+                CString::new(lit.value).map_err(|_| "C string literal must not contain nul")?;
+            let token = match lit.style {
+                None | Some(StrStyle::Cooked) => {
+                    with_suffix(Literal::c_string(&value), &lit.suffix)
+                }
+                Some(StrStyle::Raw(pounds)) => {
+                    let value =
+                        value.into_string().map_err(|_| "raw C string literal must be UTF-8")?;
+                    raw_str("c", &value, pounds, &lit.suffix)?
+                }
+            };
+            Ok(Self { token })
+        }
+    }
+
+    impl StructuredLit for LitByte {
+        type Value = u8;
+
+        fn from_token(token: Literal) -> Self {
+            Self { token }
+        }
+        fn to_structured(&self) -> Result<Structured<u8>, Error> {
+            let lit = syn::LitByte::try_from_ref(self)?;
+            Ok(Structured::new(lit.value(), lit.suffix(), None))
+        }
+        fn from_structured(lit: Structured<u8>) -> Result<Self, String> {
+            let lit = lit.no_style(Self::KIND)?;
+            Ok(Self { token: with_suffix(Literal::byte_character(lit.value), &lit.suffix) })
+        }
+    }
+
+    impl StructuredLit for LitChar {
+        type Value = char;
+
+        fn from_token(token: Literal) -> Self {
+            Self { token }
+        }
+        fn to_structured(&self) -> Result<Structured<char>, Error> {
+            let lit = syn::LitChar::try_from_ref(self)?;
+            Ok(Structured::new(lit.value(), lit.suffix(), None))
+        }
+        fn from_structured(lit: Structured<char>) -> Result<Self, String> {
+            let lit = lit.no_style(Self::KIND)?;
+            Ok(Self { token: with_suffix(Literal::character(lit.value), &lit.suffix) })
+        }
+    }
+
+    impl StructuredLit for LitInt {
+        type Value = String;
+
+        fn from_token(token: Literal) -> Self {
+            Self { token }
+        }
+        fn to_structured(&self) -> Result<Structured<String>, Error> {
+            let lit = syn::LitInt::try_from_ref(self)?;
+            Ok(Structured::new(lit.base10_digits().to_owned(), lit.suffix(), None))
+        }
+        fn from_structured(lit: Structured<String>) -> Result<Self, String> {
+            let lit = lit.no_style(Self::KIND)?;
+            Ok(Self { token: Literal::new(&format!("{}{}", lit.value, lit.suffix)) })
+        }
+    }
+
+    impl StructuredLit for LitFloat {
+        type Value = String;
+
+        fn from_token(token: Literal) -> Self {
+            Self { token }
+        }
+        fn to_structured(&self) -> Result<Structured<String>, Error> {
+            let lit = syn::LitFloat::try_from_ref(self)?;
+            Ok(Structured::new(lit.base10_digits().to_owned(), lit.suffix(), None))
+        }
+        fn from_structured(lit: Structured<String>) -> Result<Self, String> {
+            let lit = lit.no_style(Self::KIND)?;
+            Ok(Self { token: Literal::new(&format!("{}{}", lit.value, lit.suffix)) })
+        }
+    }
+
+    // Accepts either the token of a literal or its structured representation.
+    struct LitVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for LitVisitor<T>
+    where
+        T: StructuredLit,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a {} literal or its structured representation", T::KIND)
+        }
+        fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            checked(T::from_token(Literal::deserialize(BorrowedStrDeserializer::new(v))?))
+        }
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            checked(T::from_token(Literal::deserialize(StrDeserializer::new(v))?))
+        }
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            checked(T::from_token(Literal::deserialize(StringDeserializer::new(v))?))
+        }
+        fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let lit = Structured::deserialize(MapAccessDeserializer::new(map))?;
+            checked(T::from_structured(lit).map_err(de::Error::custom)?)
+        }
+    }
+
+    // Rejects literals of another kind, e.g., an integer in `LitStr`.
+    fn checked<T, E>(lit: T) -> Result<T, E>
+    where
+        T: LitToken,
+        E: de::Error,
+    {
+        lit.check().map_err(E::custom)?;
+        Ok(lit)
+    }
+
+    macro_rules! lit_serde_impl {
+        ($($ty:ident),* $(,)?) => {$(
+            impl Serialize for $ty {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.serialize_str(&self.token.text)
+                }
+            }
+
+            impl $ty {
+                // Serializes `self` in the structured representation if
+                // `options` selects it.
+                pub(crate) fn serialize_with_options<S>(
+                    &self,
+                    serializer: S,
+                    options: SerializeOptions,
+                ) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    if options.has_structured_literals() {
+                        self.to_structured().map_err(ser::Error::custom)?.serialize(serializer)
+                    } else {
+                        self.serialize(serializer)
+                    }
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    hint::deserialize(deserializer, hint::Hint::Str, LitVisitor(PhantomData))
+                }
+            }
+        )*};
+    }
+
+    lit_serde_impl!(LitStr, LitByteStr, LitCStr, LitByte, LitChar, LitInt, LitFloat);
+}
+
 mod value {
     use core::{
         char,
@@ -248,6 +583,78 @@ mod value {
         String::from(parse_lit_str_raw(&s[1..]).0).into_bytes()
     }
 
+    pub(crate) fn parse_lit_c_str(s: &str) -> CString {
+        assert_eq!(byte(s, 0), b'c');
+        match byte(s, 1) {
+            b'"' => parse_lit_c_str_cooked(s),
+            b'r' => parse_lit_c_str_raw(s),
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_lit_c_str_cooked(mut s: &str) -> CString {
+        assert_eq!(byte(s, 0), b'c');
+        assert_eq!(byte(s, 1), b'"');
+        s = &s[2..];
+
+        let mut out = vec![];
+        'outer: loop {
+            match byte(s, 0) {
+                b'"' => break,
+                b'\\' => {
+                    let b = byte(s, 1);
+                    s = &s[2..];
+                    match b {
+                        b'x' => {
+                            let (b, rest) = backslash_x(s);
+                            s = rest;
+                            out.push(b);
+                        }
+                        b'u' => {
+                            let (chr, rest) = backslash_u(s);
+                            s = rest;
+                            out.extend_from_slice(chr.encode_utf8(&mut [0; 4]).as_bytes());
+                        }
+                        b'n' => out.push(b'\n'),
+                        b'r' => out.push(b'\r'),
+                        b't' => out.push(b'\t'),
+                        b'\\' => out.push(b'\\'),
+                        b'\'' => out.push(b'\''),
+                        b'"' => out.push(b'"'),
+                        b'\r' | b'\n' => loop {
+                            let ch = next_chr(s);
+                            if ch.is_whitespace() {
+                                s = &s[ch.len_utf8()..];
+                            } else {
+                                continue 'outer;
+                            }
+                        },
+                        b => panic!("unexpected byte {b:?} after \\ character in C string literal"),
+                    }
+                }
+                b'\r' => {
+                    assert_eq!(byte(s, 1), b'\n', "Bare CR not allowed in string");
+                    s = &s[2..];
+                    out.push(b'\n');
+                }
+                _ => {
+                    let ch = next_chr(s);
+                    s = &s[ch.len_utf8()..];
+                    out.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+            }
+        }
+
+        assert_eq!(s, "\"");
+        CString::new(out).expect("nul byte in C string literal")
+    }
+
+    fn parse_lit_c_str_raw(s: &str) -> CString {
+        assert_eq!(byte(s, 0), b'c');
+        CString::new(String::from(parse_lit_str_raw(&s[1..]).0))
+            .expect("nul byte in C string literal")
+    }
+
     pub(crate) fn parse_lit_byte(s: &str) -> u8 {
         assert_eq!(byte(s, 0), b'b');
         assert_eq!(byte(s, 1), b'\'');
@@ -382,10 +789,151 @@ mod value {
         }
     }
 
+    // Whether `syn::LitInt::new` accepts `s`. Port of `syn::lit::value::parse_lit_int`.
+    pub(crate) fn is_lit_int(mut s: &str) -> bool {
+        if byte(s, 0) == b'-' {
+            s = &s[1..];
+        }
+
+        let base = match (byte(s, 0), byte(s, 1)) {
+            (b'0', b'x') => {
+                s = &s[2..];
+                16
+            }
+            (b'0', b'o') => {
+                s = &s[2..];
+                8
+            }
+            (b'0', b'b') => {
+                s = &s[2..];
+                2
+            }
+            (b'0'..=b'9', _) => 10,
+            _ => return false,
+        };
+
+        let mut has_digit = false;
+        'outer: loop {
+            let b = byte(s, 0);
+            let digit = match b {
+                b'0'..=b'9' => b - b'0',
+                b'a'..=b'f' if base > 10 => b - b'a' + 10,
+                b'A'..=b'F' if base > 10 => b - b'A' + 10,
+                b'_' => {
+                    s = &s[1..];
+                    continue;
+                }
+                // If looking at a floating point literal, we don't want to
+                // consider it an integer.
+                b'.' if base == 10 => return false,
+                b'e' | b'E' if base == 10 => {
+                    let mut has_exp = false;
+                    for (i, b) in s[1..].bytes().enumerate() {
+                        match b {
+                            b'_' => {}
+                            b'-' | b'+' => return false,
+                            b'0'..=b'9' => has_exp = true,
+                            _ => {
+                                let suffix = &s[1 + i..];
+                                if has_exp && xid_ok(suffix) {
+                                    return false;
+                                }
+                                break 'outer;
+                            }
+                        }
+                    }
+                    if has_exp {
+                        return false;
+                    }
+                    break;
+                }
+                _ => break,
+            };
+
+            if digit >= base {
+                return false;
+            }
+
+            has_digit = true;
+            s = &s[1..];
+        }
+
+        has_digit && (s.is_empty() || xid_ok(s))
+    }
+
+    // Whether `syn::LitFloat::new` accepts `s`. Port of `syn::lit::value::parse_lit_float`.
+    pub(crate) fn is_lit_float(s: &str) -> bool {
+        let bytes = s.as_bytes();
+
+        let start = (byte(s, 0) == b'-') as usize;
+        if !byte(s, start).is_ascii_digit() {
+            return false;
+        }
+
+        let mut read = start;
+        let mut has_dot = false;
+        let mut has_e = false;
+        let mut has_sign = false;
+        let mut has_exponent = false;
+        while read < bytes.len() {
+            match bytes[read] {
+                b'_' => {}
+                b'0'..=b'9' => {
+                    if has_e {
+                        has_exponent = true;
+                    }
+                }
+                b'.' => {
+                    if has_e || has_dot {
+                        return false;
+                    }
+                    has_dot = true;
+                }
+                b'e' | b'E' => {
+                    match bytes[read + 1..].iter().find(|b| **b != b'_').unwrap_or(&b'\0') {
+                        b'-' | b'+' | b'0'..=b'9' => {}
+                        _ => break,
+                    }
+                    if has_e {
+                        if has_exponent {
+                            break;
+                        }
+                        return false;
+                    }
+                    has_e = true;
+                }
+                b'-' | b'+' => {
+                    if has_sign || has_exponent || !has_e {
+                        return false;
+                    }
+                    has_sign = true;
+                }
+                _ => break,
+            }
+            read += 1;
+        }
+
+        if has_e && !has_exponent {
+            return false;
+        }
+
+        // Without a fraction or an exponent (e.g., `1` or `1u8`), this is an
+        // integer unless the suffix is a float type.
+        let suffix = &s[read..];
+        (has_dot || has_e || matches!(suffix, "f32" | "f64"))
+            && (suffix.is_empty() || xid_ok(suffix))
+    }
+
+    pub(super) fn xid_ok(symbol: &str) -> bool {
+        let mut chars = symbol.chars();
+        matches!(chars.next(), Some(ch) if ch == '_' || ch.is_alphabetic())
+            && chars.all(|ch| ch == '_' || ch.is_alphanumeric())
+    }
+
     pub(crate) fn to_literal(s: &str) -> Literal {
         let stream = s.parse::<TokenStream>().unwrap();
         match stream.into_iter().next().unwrap() {
-            TokenTree::Literal(l) => l.ref_into(),
+            TokenTree::Literal(l) => Literal::new(&l.to_string()),
             _ => unreachable!(),
         }
     }
@@ -394,79 +942,229 @@ mod value {
 mod convert {
     use super::*;
 
+    /// A literal adapter, which consists of a single literal token.
+    pub(super) trait LitToken: Sized {
+        const NODE: &'static str;
+        /// The kind of the literal in error messages, e.g., `"string"`.
+        const KIND: &'static str;
+
+        fn token(&self) -> &Literal;
+
+        /// Returns whether `s`, which is a single literal, is of this kind.
+        fn is_kind(s: &str) -> bool;
+
+        /// Checks that the token is a single literal of the expected kind, so
+        /// that the `value::parse_lit_*` functions can't panic on it.
+        fn check(&self) -> Result<(), Error> {
+            let token = self.token();
+            if token.to_token().is_none() {
+                return Err(Error::new(
+                    Self::NODE,
+                    format!("`{}` is not a valid literal", token.text),
+                ));
+            }
+            if !Self::is_kind(&token.text) {
+                return Err(Error::new(
+                    Self::NODE,
+                    format!("expected {} literal, found `{}`", Self::KIND, token.text),
+                ));
+            }
+            Ok(())
+        }
+    }
+
+    macro_rules! lit_kind_impl {
+        ($($ty:ident => $kind:literal, $is_kind:expr;)*) => {$(
+            impl LitToken for $ty {
+                const NODE: &'static str = stringify!($ty);
+                const KIND: &'static str = $kind;
+
+                fn token(&self) -> &Literal {
+                    &self.token
+                }
+                fn is_kind(s: &str) -> bool {
+                    $is_kind(s)
+                }
+            }
+        )*};
+    }
+
+    // The closing quote (or `#` of a raw string) may be followed by a suffix,
+    // which syn keeps because the tokens of these literals are kept as they
+    // are spelled (see `spelled`).
+    fn is_quoted(s: &str, prefix: &[&str], end: &[char]) -> bool {
+        prefix.iter().any(|p| s.starts_with(p))
+            && s.rfind(end).is_some_and(|i| {
+                let suffix = &s[i + 1..];
+                suffix.is_empty() || value::xid_ok(suffix)
+            })
+    }
+
+    /// Returns the literal of syn that keeps the spelling of the token of
+    /// `other`, which has been checked.
+    fn spelled<T>(other: &T) -> syn::Lit
+    where
+        T: LitToken,
+    {
+        syn::Lit::new(other.token().to_token().unwrap())
+    }
+
+    // Older versions serialized byte literals as `u8`-suffixed integers.
+    fn legacy_byte(s: &str) -> Option<u8> {
+        s.strip_suffix("u8").and_then(|s| s.parse::<u8>().ok())
+    }
+
+    lit_kind_impl! {
+        LitStr => "string", |s| is_quoted(s, &["\"", "r"], &['"', '#']);
+        LitByteStr => "byte string", |s| is_quoted(s, &["b\"", "br"], &['"', '#']);
+        LitCStr => "C string", |s| is_quoted(s, &["c\"", "cr"], &['"', '#']);
+        LitByte => "byte", |s| is_quoted(s, &["b'"], &['\'']) || legacy_byte(s).is_some();
+        LitChar => "character", |s| is_quoted(s, &["'"], &['\'']);
+        LitInt => "integer", value::is_lit_int;
+        LitFloat => "float", value::is_lit_float;
+    }
+
     // LitStr
+    from_adapter_impl!(syn::LitStr);
     impl From<&syn::LitStr> for LitStr {
         fn from(other: &syn::LitStr) -> Self {
             Self { token: Literal::string(&other.value()) }
         }
     }
-    impl From<&LitStr> for syn::LitStr {
-        fn from(other: &LitStr) -> Self {
+    impl TryFromRef<LitStr> for syn::LitStr {
+        fn try_from_ref(other: &LitStr) -> Result<Self, Error> {
+            other.check()?;
+            if let syn::Lit::Str(lit) = spelled(other) {
+                return Ok(lit);
+            }
             let (value, _) = value::parse_lit_str(&other.token.text);
-            Self::new(&value, Span::call_site())
+            Ok(Self::new(&value, Span::call_site()))
         }
     }
 
     // LitByteStr
+    from_adapter_impl!(syn::LitByteStr);
     impl From<&syn::LitByteStr> for LitByteStr {
         fn from(other: &syn::LitByteStr) -> Self {
             Self { token: Literal::byte_string(&other.value()) }
         }
     }
-    impl From<&LitByteStr> for syn::LitByteStr {
-        fn from(other: &LitByteStr) -> Self {
+    impl TryFromRef<LitByteStr> for syn::LitByteStr {
+        fn try_from_ref(other: &LitByteStr) -> Result<Self, Error> {
+            other.check()?;
+            if let syn::Lit::ByteStr(lit) = spelled(other) {
+                return Ok(lit);
+            }
             let value = value::parse_lit_byte_str(&other.token.text);
-            Self::new(&value, Span::call_site())
+            Ok(Self::new(&value, Span::call_site()))
+        }
+    }
+
+    // LitCStr
+    from_adapter_impl!(syn::LitCStr);
+    impl From<&syn::LitCStr> for LitCStr {
+        fn from(other: &syn::LitCStr) -> Self {
+            Self { token: Literal::c_string(&other.value()) }
+        }
+    }
+    impl TryFromRef<LitCStr> for syn::LitCStr {
+        fn try_from_ref(other: &LitCStr) -> Result<Self, Error> {
+            other.check()?;
+            if let syn::Lit::CStr(lit) = spelled(other) {
+                return Ok(lit);
+            }
+            let value = value::parse_lit_c_str(&other.token.text);
+            Ok(Self::new(&value, Span::call_site()))
         }
     }
 
     // LitByte
+    from_adapter_impl!(syn::LitByte);
     impl From<&syn::LitByte> for LitByte {
         fn from(other: &syn::LitByte) -> Self {
-            Self { token: Literal::u8_suffixed(other.value()) }
+            Self { token: Literal::byte_character(other.value()) }
         }
     }
-    impl From<&LitByte> for syn::LitByte {
-        fn from(other: &LitByte) -> Self {
+    impl TryFromRef<LitByte> for syn::LitByte {
+        fn try_from_ref(other: &LitByte) -> Result<Self, Error> {
+            other.check()?;
+            if let Some(value) = legacy_byte(&other.token.text) {
+                return Ok(Self::new(value, Span::call_site()));
+            }
+            if let syn::Lit::Byte(lit) = spelled(other) {
+                return Ok(lit);
+            }
             let value = value::parse_lit_byte(&other.token.text);
-            Self::new(value, Span::call_site())
+            Ok(Self::new(value, Span::call_site()))
         }
     }
 
     // LitChar
+    from_adapter_impl!(syn::LitChar);
     impl From<&syn::LitChar> for LitChar {
         fn from(other: &syn::LitChar) -> Self {
             Self { token: Literal::character(other.value()) }
         }
     }
-    impl From<&LitChar> for syn::LitChar {
-        fn from(other: &LitChar) -> Self {
+    impl TryFromRef<LitChar> for syn::LitChar {
+        fn try_from_ref(other: &LitChar) -> Result<Self, Error> {
+            other.check()?;
+            if let syn::Lit::Char(lit) = spelled(other) {
+                return Ok(lit);
+            }
             let value = value::parse_lit_char(&other.token.text);
-            Self::new(value, Span::call_site())
+            Ok(Self::new(value, Span::call_site()))
         }
     }
 
     // LitInt
+    from_adapter_impl!(syn::LitInt);
     impl From<&syn::LitInt> for LitInt {
         fn from(other: &syn::LitInt) -> Self {
             Self { token: value::to_literal(&other.to_string()) }
         }
     }
-    impl From<&LitInt> for syn::LitInt {
-        fn from(other: &LitInt) -> Self {
-            Self::new(&other.token.text, Span::call_site())
+    impl TryFromRef<LitInt> for syn::LitInt {
+        fn try_from_ref(other: &LitInt) -> Result<Self, Error> {
+            other.check()?;
+            Ok(Self::new(&other.token.text, Span::call_site()))
         }
     }
 
     // LitFloat
+    from_adapter_impl!(syn::LitFloat);
     impl From<&syn::LitFloat> for LitFloat {
         fn from(other: &syn::LitFloat) -> Self {
             Self { token: value::to_literal(&other.to_string()) }
         }
     }
-    impl From<&LitFloat> for syn::LitFloat {
-        fn from(other: &LitFloat) -> Self {
-            Self::new(&other.token.text, Span::call_site())
+    impl TryFromRef<LitFloat> for syn::LitFloat {
+        fn try_from_ref(other: &LitFloat) -> Result<Self, Error> {
+            other.check()?;
+            Ok(Self::new(&other.token.text, Span::call_site()))
         }
     }
+
+    // The tokens of literals are rebuilt from their values (see the `From`
+    // impls above) unless `SerializeOptions::literal_spelling` is set, so these
+    // go through the (small) adapters.
+    macro_rules! ser_lit_impl {
+        ($($ty:ident),* $(,)?) => {$(
+            impl Serialize for Ser<'_, syn::$ty> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let adapter = if self.1.has_literal_spelling() {
+                        $ty { token: Literal::new(&self.0.token().to_string()) }
+                    } else {
+                        $ty::from(self.0)
+                    };
+                    adapter.serialize_with_options(serializer, self.1)
+                }
+            }
+        )*};
+    }
+
+    ser_lit_impl!(LitStr, LitByteStr, LitCStr, LitByte, LitChar, LitInt, LitFloat);
 }
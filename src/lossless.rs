@@ -0,0 +1,576 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A module to provide a lossless mode that keeps comments and formatting.
+//!
+//! [`parse_file`] records the comments and whitespace of a file and its items
+//! as [`Trivia`], and [`print_file`] reproduces the source code from the
+//! adapters byte-for-byte.
+//!
+//! The trivia of an item is attached to the item, so the syntax tree can be
+//! edited, e.g., after serializing it to JSON, without reformatting the whole
+//! file: items can be added, removed, or moved, and tokens can be replaced
+//! (e.g., renaming an identifier) while keeping the trivia. An item that no
+//! longer matches its trivia, e.g. because tokens were added to or removed
+//! from it, or an item that has no trivia, is printed with [prettyplease]
+//! along with the items it contains. The comments before such an item are
+//! kept.
+//!
+//! Items directly in a file, an inline module, an `impl` block, or a trait
+//! have trivia. Items that Syn cannot represent as a syntax tree
+//! (`Verbatim`) are always printed with [prettyplease].
+//!
+//! # Examples
+//!
+//! ```
+//! # fn dox() -> Result<(), Box<dyn std::error::Error>> {
+//! let source = "\
+//! // A comment.
+//! fn   main() {
+//!     println!(\"Hello, world!\"); // Another comment.
+//! }
+//! ";
+//!
+//! let file = syn_serde::lossless::parse_file(source)?;
+//! let json = serde_json::to_string(&file)?;
+//!
+//! let file: syn_serde::File = serde_json::from_str(&json)?;
+//! assert_eq!(syn_serde::lossless::print_file(&file)?, source);
+//! # Ok(())
+//! # }
+//! # dox().unwrap();
+//! ```
+//!
+//! [prettyplease]: https://github.com/dtolnay/prettyplease
+
+use alloc::{
+    borrow::ToOwned as _,
+    format,
+    string::{String, ToString as _},
+    vec,
+    vec::Vec,
+};
+use core::{mem, ops::Range};
+
+use proc_macro2::{Delimiter, TokenTree};
+use quote::ToTokens;
+
+use super::*;
+
+/// The number of tokens to look ahead when the tokens of the source code and
+/// the printed tokens differ.
+const LOOKAHEAD: usize = 8;
+
+macro_rules! trivia {
+    ($ty:ident { $($variant:ident),* $(,)? }, $get:ident, $get_mut:ident) => {
+        fn $get(node: &$ty) -> Option<&Option<Trivia>> {
+            match node {
+                $($ty::$variant(node) => Some(&node.trivia),)*
+                _ => None,
+            }
+        }
+
+        fn $get_mut(node: &mut $ty) -> Option<&mut Option<Trivia>> {
+            match node {
+                $($ty::$variant(node) => Some(&mut node.trivia),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+trivia!(
+    Item {
+        Const,
+        Enum,
+        ExternCrate,
+        Fn,
+        ForeignMod,
+        Impl,
+        Macro,
+        Mod,
+        Static,
+        Struct,
+        Trait,
+        TraitAlias,
+        Type,
+        Union,
+        Use,
+    },
+    item_trivia,
+    item_trivia_mut
+);
+trivia!(ImplItem { Const, Fn, Type, Macro }, impl_item_trivia, impl_item_trivia_mut);
+trivia!(TraitItem { Const, Fn, Type, Macro }, trait_item_trivia, trait_item_trivia_mut);
+
+/// Where the items contained in a node are printed.
+#[derive(Clone, Copy)]
+enum Hole {
+    /// The node contains no items.
+    None,
+    /// After the last token, e.g., the items of a file.
+    End,
+    /// Before the last token, e.g., the items of an `impl` block, which are
+    /// followed by the closing brace.
+    BeforeLast,
+}
+
+impl Hole {
+    fn index(self, len: usize) -> Option<usize> {
+        match self {
+            Self::None => None,
+            Self::End => Some(len),
+            Self::BeforeLast => Some(len - 1),
+        }
+    }
+}
+
+/// Calls `f` with the text and span of each token, with the delimiters of
+/// groups as separate tokens.
+fn flatten(stream: proc_macro2::TokenStream, f: &mut impl FnMut(String, proc_macro2::Span)) {
+    for tt in stream {
+        match tt {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => {
+                        flatten(group.stream(), f);
+                        continue;
+                    }
+                };
+                f(open.to_owned(), group.span_open());
+                flatten(group.stream(), f);
+                f(close.to_owned(), group.span_close());
+            }
+            TokenTree::Ident(ident) => f(ident.to_string(), ident.span()),
+            TokenTree::Punct(punct) => f(punct.as_char().to_string(), punct.span()),
+            TokenTree::Literal(literal) => f(literal.to_string(), literal.span()),
+        }
+    }
+}
+
+/// Returns the tokens that `node` prints.
+fn tokens<T: ToTokens>(node: &T) -> Vec<String> {
+    let mut tokens = vec![];
+    flatten(node.to_token_stream(), &mut |text, _| tokens.push(text));
+    tokens
+}
+
+/// Returns the index of the source token that matches each printed token.
+///
+/// The printed tokens usually match the tokens of the source code, except for
+/// tokens that the adapters do not keep (e.g., trailing commas) or add.
+fn align(source: &[&str], printed: &[String]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; printed.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < source.len() && j < printed.len() {
+        if source[i] == printed[j] {
+            matches[j] = Some(i);
+            i += 1;
+            j += 1;
+            continue;
+        }
+        // Skip to the nearest tokens that match again.
+        let next = (1..=LOOKAHEAD * 2).find_map(|distance| {
+            (0..=distance).map(|skip| (skip, distance - skip)).find(|&(skip_i, skip_j)| {
+                source.get(i + skip_i).is_some_and(|&s| printed.get(j + skip_j) == Some(&s.into()))
+            })
+        });
+        let (skip_i, skip_j) = next.unwrap_or((1, 1));
+        i += skip_i;
+        j += skip_j;
+    }
+    matches
+}
+
+// -----------------------------------------------------------------------------
+// Parse
+
+/// Parses a Rust source file into an adapter, recording the comments and
+/// whitespace of the file and its items.
+///
+/// The source code can be reproduced by [`print_file`].
+///
+/// This function must not be called from within a procedural macro, since the
+/// locations of the tokens are only available outside of procedural macros
+/// (see proc-macro2's `span-locations` feature).
+///
+/// # Examples
+///
+/// ```
+/// # fn dox() -> syn::Result<()> {
+/// let file = syn_serde::lossless::parse_file("// comment\nstruct S;\n")?;
+/// let syn_serde::Item::Struct(item) = &file.items[0] else { unreachable!() };
+/// assert_eq!(item.trivia.as_ref().unwrap().leading[0], "// comment\n");
+/// # Ok(())
+/// # }
+/// # dox().unwrap();
+/// ```
+pub fn parse_file(source: &str) -> syn::Result<File> {
+    let mut syn_file = syn::parse_file(source)?;
+    let mut file = syn_file.to_adapter();
+
+    // `syn::parse_file` strips the byte order mark and the shebang, and the
+    // locations of the tokens are relative to the rest.
+    let bom = source.starts_with('\u{feff}');
+    let mut rest = source.strip_prefix('\u{feff}').unwrap_or(source);
+    if let Some(shebang) = &syn_file.shebang {
+        rest = &rest[shebang.len()..];
+    }
+
+    let mut parser = Parser::new(rest);
+    let items = mem::take(&mut syn_file.items);
+    let mut trivia =
+        parser.node(&syn_file, Hole::End, |parser| parser.items(items, &mut file.items));
+    trivia.trailing = rest[parser.pos..].into();
+    trivia.bom = bom;
+    file.trivia = Some(trivia);
+    Ok(file)
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    /// The byte offset of the start of each line.
+    lines: Vec<usize>,
+    /// The byte offset of the end of the last token that has been recorded.
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        let lines = core::iter::once(0).chain(source.match_indices('\n').map(|(i, _)| i + 1));
+        Self { source, lines: lines.collect(), pos: 0 }
+    }
+
+    fn offset(&self, location: proc_macro2::LineColumn) -> usize {
+        let start = self.lines[location.line - 1];
+        let line = &self.source[start..];
+        start + line.char_indices().nth(location.column).map_or(line.len(), |(i, _)| i)
+    }
+
+    /// Records the trivia of the tokens of `node`, calling `children` to
+    /// record the trivia of the items it contains at `hole`.
+    fn node<T>(&mut self, node: &T, hole: Hole, children: impl FnOnce(&mut Self)) -> Trivia
+    where
+        T: Syn + ToTokens,
+    {
+        let mut source: Vec<(String, Range<usize>)> = vec![];
+        flatten(node.to_token_stream(), &mut |text, span| {
+            let (start, end) = (span.start(), span.end());
+            // Tokens that have no location are not in the source code.
+            if start != end {
+                source.push((text, self.offset(start)..self.offset(end)));
+            }
+        });
+        let printed = tokens(&T::from_adapter(&node.to_adapter()));
+        let texts: Vec<_> = source.iter().map(|(text, _)| &**text).collect();
+        let matches = align(&texts, &printed);
+
+        let hole = hole.index(printed.len());
+        let mut children = Some(children);
+        let mut trivia = Trivia::default();
+        for (j, token) in printed.iter().enumerate() {
+            if hole == Some(j) {
+                children.take().unwrap()(self);
+            }
+            let (leading, text) = match matches[j] {
+                Some(i) if source[i].1.start >= self.pos => {
+                    let range = source[i].1.clone();
+                    let leading = &self.source[self.pos..range.start];
+                    self.pos = range.end;
+                    (leading, &self.source[range])
+                }
+                // Tokens that are not in the source code, or that are part of
+                // the previous token in the source code, e.g., the tokens of
+                // a doc comment.
+                _ => ("", ""),
+            };
+            trivia.leading.push(leading.to_owned());
+            if text != token {
+                match trivia.spellings.last_mut() {
+                    Some(spelling)
+                        if text.is_empty()
+                            && leading.is_empty()
+                            && spelling.index + spelling.tokens.len() == j =>
+                    {
+                        spelling.tokens.push(token.clone());
+                    }
+                    _ => trivia.spellings.push(Spelling {
+                        index: j,
+                        tokens: vec![token.clone()],
+                        text: text.to_owned(),
+                    }),
+                }
+            }
+        }
+        if let Some(children) = children {
+            if hole.is_some() {
+                children(self);
+            }
+        }
+        trivia
+    }
+
+    fn items(&mut self, items: Vec<syn::Item>, adapters: &mut [Item]) {
+        for (mut item, adapter) in items.into_iter().zip(adapters) {
+            let trivia = match (&mut item, &mut *adapter) {
+                (
+                    syn::Item::Mod(syn::ItemMod { content: Some((_, items)), .. }),
+                    Item::Mod(mod_),
+                ) if mod_.content.is_some() => {
+                    let items = mem::take(items);
+                    let adapters = mod_.content.as_mut().unwrap();
+                    self.node(&item, Hole::BeforeLast, |parser| parser.items(items, adapters))
+                }
+                (syn::Item::Impl(impl_), Item::Impl(adapter)) => {
+                    let items = mem::take(&mut impl_.items);
+                    let adapters = &mut adapter.items;
+                    self.node(&item, Hole::BeforeLast, |parser| parser.impl_items(items, adapters))
+                }
+                (syn::Item::Trait(trait_), Item::Trait(adapter)) => {
+                    let items = mem::take(&mut trait_.items);
+                    let adapters = &mut adapter.items;
+                    self.node(&item, Hole::BeforeLast, |parser| parser.trait_items(items, adapters))
+                }
+                _ => self.node(&item, Hole::None, |_| {}),
+            };
+            if let Some(slot) = item_trivia_mut(adapter) {
+                *slot = Some(trivia);
+            }
+        }
+    }
+
+    fn impl_items(&mut self, items: Vec<syn::ImplItem>, adapters: &mut [ImplItem]) {
+        for (item, adapter) in items.into_iter().zip(adapters) {
+            let trivia = self.node(&item, Hole::None, |_| {});
+            if let Some(slot) = impl_item_trivia_mut(adapter) {
+                *slot = Some(trivia);
+            }
+        }
+    }
+
+    fn trait_items(&mut self, items: Vec<syn::TraitItem>, adapters: &mut [TraitItem]) {
+        for (item, adapter) in items.into_iter().zip(adapters) {
+            let trivia = self.node(&item, Hole::None, |_| {});
+            if let Some(slot) = trait_item_trivia_mut(adapter) {
+                *slot = Some(trivia);
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Print
+
+/// Prints an adapter of a file as Rust source code.
+///
+/// The parts of the file that have [`Trivia`] recorded by [`parse_file`] are
+/// printed as they were in the source code, and the rest are printed with
+/// [prettyplease].
+///
+/// [prettyplease]: https://github.com/dtolnay/prettyplease
+pub fn print_file(file: &File) -> Result<String, Error> {
+    let mut printer = Printer { out: String::new(), depth: 0 };
+    let trivia = file.trivia.as_ref();
+    if trivia.is_some_and(|trivia| trivia.bom) {
+        printer.out.push('\u{feff}');
+    }
+    if let Some(shebang) = &file.shebang {
+        printer.out.push_str(shebang);
+        if trivia.is_none() {
+            printer.out.push('\n');
+        }
+    }
+
+    let attrs = file.attrs.try_map_into().context("File", "attrs")?;
+    let syn_file = syn::File { shebang: None, attrs, items: vec![] };
+    let tokens = tokens(&syn_file);
+    if !printer.tokens(&tokens, trivia, Hole::End, |printer| printer.items(&file.items))? {
+        printer.out.push_str(&prettyplease::unparse(&syn_file));
+        printer.items(&file.items)?;
+    }
+    match trivia {
+        Some(trivia) => printer.out.push_str(&trivia.trailing),
+        None => {
+            if !printer.out.is_empty() && !printer.out.ends_with('\n') {
+                printer.out.push('\n');
+            }
+        }
+    }
+    Ok(printer.out)
+}
+
+struct Printer {
+    out: String,
+    /// The number of items that contain the items being printed.
+    depth: usize,
+}
+
+impl Printer {
+    /// Prints `tokens` with `trivia`, calling `children` to print the items
+    /// they contain at `hole`.
+    ///
+    /// Returns `false` without printing anything if `trivia` does not match
+    /// `tokens`.
+    fn tokens(
+        &mut self,
+        tokens: &[String],
+        trivia: Option<&Trivia>,
+        hole: Hole,
+        children: impl FnOnce(&mut Self) -> Result<(), Error>,
+    ) -> Result<bool, Error> {
+        let Some(trivia) = trivia.filter(|trivia| trivia.leading.len() == tokens.len()) else {
+            return Ok(false);
+        };
+        let mut spelled = vec![None; tokens.len()];
+        for spelling in &trivia.spellings {
+            let range = spelling.index..spelling.index + spelling.tokens.len();
+            if tokens.get(range.clone()) == Some(&*spelling.tokens) {
+                spelled[range].fill(Some(""));
+                spelled[spelling.index] = Some(&*spelling.text);
+            }
+        }
+
+        let hole = hole.index(tokens.len());
+        let mut children = Some(children);
+        for (j, token) in tokens.iter().enumerate() {
+            if hole == Some(j) {
+                self.nested(children.take().unwrap())?;
+            }
+            self.out.push_str(&trivia.leading[j]);
+            self.out.push_str(spelled[j].unwrap_or(token));
+        }
+        if let Some(children) = children {
+            if hole.is_some() {
+                self.nested(children)?;
+            }
+        }
+        Ok(true)
+    }
+
+    fn nested(&mut self, f: impl FnOnce(&mut Self) -> Result<(), Error>) -> Result<(), Error> {
+        // The items of a file are not nested.
+        let nested = !self.out.is_empty() || self.depth > 0;
+        self.depth += usize::from(nested);
+        let result = f(self);
+        self.depth -= usize::from(nested);
+        result
+    }
+
+    /// Prints `text`, which was printed by prettyplease, in place of an item
+    /// whose trivia does not match.
+    fn unparsed(&mut self, trivia: Option<&Trivia>, text: &str) {
+        let leading = match trivia.and_then(|trivia| trivia.leading.first()) {
+            Some(leading) => leading.clone(),
+            None => {
+                let indent = "    ".repeat(self.depth);
+                if self.out.is_empty() {
+                    String::new()
+                } else if self.out.ends_with('\n') || self.out.ends_with('{') {
+                    format!("\n{indent}")
+                } else {
+                    format!("\n\n{indent}")
+                }
+            }
+        };
+        let indent = match leading.rsplit_once('\n') {
+            Some((_, indent)) if indent.trim().is_empty() => indent.to_owned(),
+            _ => "    ".repeat(self.depth),
+        };
+        self.out.push_str(&leading);
+        for (i, line) in text.trim_end().lines().enumerate() {
+            if i > 0 {
+                self.out.push('\n');
+                if !line.is_empty() {
+                    self.out.push_str(&indent);
+                }
+            }
+            self.out.push_str(line);
+        }
+    }
+
+    /// Returns the item that `wrapper` contains as printed by prettyplease.
+    fn unparse_nested(wrapper: syn::Item) -> String {
+        let text = prettyplease::unparse(&syn::File {
+            shebang: None,
+            attrs: vec![],
+            items: vec![wrapper],
+        });
+        let mut lines: Vec<_> = text.trim_end().lines().collect();
+        lines.remove(0);
+        lines.pop();
+        lines
+            .iter()
+            .map(|line| line.strip_prefix("    ").unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn items(&mut self, items: &[Item]) -> Result<(), Error> {
+        for item in items {
+            let trivia = item_trivia(item).and_then(Option::as_ref);
+            let mut syn_item = syn::Item::try_from_adapter(item)?;
+            let printed = match (&mut syn_item, item) {
+                (
+                    syn::Item::Mod(syn::ItemMod { content: Some((_, items)), .. }),
+                    Item::Mod(mod_),
+                ) if mod_.content.is_some() => {
+                    items.clear();
+                    let children = mod_.content.as_ref().unwrap();
+                    self.tokens(&tokens(&syn_item), trivia, Hole::BeforeLast, |printer| {
+                        printer.items(children)
+                    })?
+                }
+                (syn::Item::Impl(impl_), Item::Impl(adapter)) => {
+                    impl_.items.clear();
+                    self.tokens(&tokens(&syn_item), trivia, Hole::BeforeLast, |printer| {
+                        printer.impl_items(&adapter.items)
+                    })?
+                }
+                (syn::Item::Trait(trait_), Item::Trait(adapter)) => {
+                    trait_.items.clear();
+                    self.tokens(&tokens(&syn_item), trivia, Hole::BeforeLast, |printer| {
+                        printer.trait_items(&adapter.items)
+                    })?
+                }
+                _ => self.tokens(&tokens(&syn_item), trivia, Hole::None, |_| Ok(()))?,
+            };
+            if !printed {
+                let syn_item = syn::Item::try_from_adapter(item)?;
+                let items = vec![syn_item];
+                self.unparsed(
+                    trivia,
+                    &prettyplease::unparse(&syn::File { shebang: None, attrs: vec![], items }),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn impl_items(&mut self, items: &[ImplItem]) -> Result<(), Error> {
+        for item in items {
+            let trivia = impl_item_trivia(item).and_then(Option::as_ref);
+            let syn_item = syn::ImplItem::try_from_adapter(item)?;
+            if !self.tokens(&tokens(&syn_item), trivia, Hole::None, |_| Ok(()))? {
+                let mut wrapper: syn::ItemImpl = syn::parse_quote! { impl T {} };
+                wrapper.items.push(syn_item);
+                self.unparsed(trivia, &Self::unparse_nested(syn::Item::Impl(wrapper)));
+            }
+        }
+        Ok(())
+    }
+
+    fn trait_items(&mut self, items: &[TraitItem]) -> Result<(), Error> {
+        for item in items {
+            let trivia = trait_item_trivia(item).and_then(Option::as_ref);
+            let syn_item = syn::TraitItem::try_from_adapter(item)?;
+            if !self.tokens(&tokens(&syn_item), trivia, Hole::None, |_| Ok(()))? {
+                let mut wrapper: syn::ItemTrait = syn::parse_quote! { trait T {} };
+                wrapper.items.push(syn_item);
+                self.unparsed(trivia, &Self::unparse_nested(syn::Item::Trait(wrapper)));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,463 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(feature = "macro-args")]
+pub(crate) use self::args::MacroWithArgs;
+#[cfg(feature = "macro-args")]
+pub use self::args::{FormatArg, FormatArgs, MacroArgs, RepeatArgs};
+use super::*;
+pub use crate::{ast_enum::MacroDelimiter, ast_struct::Macro};
+
+#[cfg(not(feature = "macro-args"))]
+pub(crate) mod args {
+    use super::*;
+
+    pub(crate) enum Args {}
+
+    pub(crate) fn parse(_node: &syn::Macro, _options: SerializeOptions) -> Option<Args> {
+        None
+    }
+
+    impl Serialize for Ser<'_, Args> {
+        fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match *self.0 {}
+        }
+    }
+}
+
+#[cfg(feature = "macro-args")]
+pub(crate) mod args {
+    use alloc::{string::ToString as _, vec};
+
+    use quote::ToTokens as _;
+    use syn::{
+        Token,
+        parse::{ParseStream, Parser as _},
+        punctuated::Punctuated,
+    };
+
+    use super::*;
+
+    ast_enum! {
+        /// The arguments of a well-known macro of the standard library, e.g.,
+        /// `println!` or `vec!`, which are serialized next to the tokens of the
+        /// macro with [`SerializeOptions::macro_args`].
+        ///
+        /// When deserializing a [`Macro`] whose `tokens` are empty or omitted,
+        /// the tokens are rebuilt from its arguments. If both are present, they
+        /// must describe the same arguments.
+        #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+        pub enum MacroArgs {
+            /// The arguments of a macro that takes a format string, e.g.,
+            /// `println!`, `write!`, or `assert!`.
+            Format(FormatArgs),
+            /// The elements of `vec![a, b, c]`.
+            List(Vec<Expr>),
+            /// The element and the length of `vec![x; n]`.
+            Repeat(RepeatArgs),
+        }
+    }
+
+    ast_struct! {
+        /// The arguments of a macro that takes a format string, e.g.,
+        /// `write!(f, "{} {x}", a, x = 1)`.
+        #[derive(Default)]
+        #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+        pub struct FormatArgs {
+            /// The arguments before the format string, e.g., the destination of
+            /// `write!` or the condition of `assert!`.
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            pub exprs: Vec<Expr>,
+            /// The format string, or `None` if the macro is called without one,
+            /// e.g., `println!()` or `assert!(x)`.
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub string: Option<LitStr>,
+            /// The arguments after the format string.
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            pub args: Vec<FormatArg>,
+        }
+    }
+
+    ast_struct! {
+        /// An argument after the format string, e.g., `a` or `x = 1`.
+        #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+        pub struct FormatArg {
+            /// The name of a named argument, e.g., `x` in `x = 1`.
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub name: Option<Ident>,
+            /// The value of the argument.
+            pub expr: Expr,
+        }
+    }
+
+    ast_struct! {
+        /// The arguments of `vec![x; n]`.
+        #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+        pub struct RepeatArgs {
+            /// The element, e.g., `x` in `vec![x; n]`.
+            pub expr: Box<Expr>,
+            /// The length, e.g., `n` in `vec![x; n]`.
+            pub len: Box<Expr>,
+        }
+    }
+
+    impl FormatArg {
+        /// Creates a positional argument.
+        #[must_use]
+        pub fn new(expr: Expr) -> Self {
+            Self { name: None, expr }
+        }
+    }
+
+    impl RepeatArgs {
+        /// Creates the arguments of `vec![expr; len]`.
+        #[must_use]
+        pub fn new(expr: Box<Expr>, len: Box<Expr>) -> Self {
+            Self { expr, len }
+        }
+    }
+
+    impl MacroArgs {
+        /// Converts these arguments into the arguments of a syntax tree.
+        fn to_syn(&self) -> Result<Args, Error> {
+            Ok(match self {
+                Self::Format(args) => {
+                    let mut format = Format {
+                        exprs: args.exprs.try_map_into().context("FormatArgs", "exprs")?,
+                        string: args.string.try_map_into().context("FormatArgs", "string")?,
+                        args: Vec::with_capacity(args.args.len()),
+                    };
+                    for arg in &args.args {
+                        let name = arg.name.try_map_into().context("FormatArg", "name")?;
+                        let expr = arg.expr.try_ref_into().context("FormatArg", "expr")?;
+                        format.args.push((name, expr));
+                    }
+                    Args::Format(format)
+                }
+                Self::List(exprs) => Args::List(exprs.try_map_into().context("MacroArgs", "list")?),
+                Self::Repeat(args) => Args::Repeat(
+                    args.expr.try_map_into().context("RepeatArgs", "expr")?,
+                    args.len.try_map_into().context("RepeatArgs", "len")?,
+                ),
+            })
+        }
+    }
+
+    impl From<&Args> for MacroArgs {
+        fn from(other: &Args) -> Self {
+            match other {
+                Args::Format(args) => Self::Format(FormatArgs {
+                    exprs: args.exprs.map_into(),
+                    string: args.string.map_into(),
+                    args: args
+                        .args
+                        .iter()
+                        .map(|(name, expr)| FormatArg { name: name.map_into(), expr: expr.into() })
+                        .collect(),
+                }),
+                Args::List(exprs) => Self::List(exprs.map_into()),
+                Args::Repeat(expr, len) => {
+                    Self::Repeat(RepeatArgs::new(expr.map_into(), len.map_into()))
+                }
+            }
+        }
+    }
+
+    impl Args {
+        /// Returns the tokens of the macro that takes these arguments.
+        fn to_tokens(&self) -> proc_macro2::TokenStream {
+            let mut tokens = proc_macro2::TokenStream::new();
+            match self {
+                Self::Format(args) => {
+                    let mut items = Punctuated::<_, Token![,]>::new();
+                    for expr in &args.exprs {
+                        items.push(expr.into_token_stream());
+                    }
+                    if let Some(string) = &args.string {
+                        items.push(string.into_token_stream());
+                    }
+                    for (name, expr) in &args.args {
+                        let mut item = proc_macro2::TokenStream::new();
+                        if let Some(name) = name {
+                            name.to_tokens(&mut item);
+                            <Token![=]>::default().to_tokens(&mut item);
+                        }
+                        expr.to_tokens(&mut item);
+                        items.push(item);
+                    }
+                    items.to_tokens(&mut tokens);
+                }
+                Self::List(exprs) => {
+                    let exprs: Punctuated<_, Token![,]> = exprs.iter().collect();
+                    exprs.to_tokens(&mut tokens);
+                }
+                Self::Repeat(expr, len) => {
+                    expr.to_tokens(&mut tokens);
+                    <Token![;]>::default().to_tokens(&mut tokens);
+                    len.to_tokens(&mut tokens);
+                }
+            }
+            tokens
+        }
+    }
+
+    /// The form of `Macro` that is deserialized, which may have `args` instead
+    /// of `tokens`.
+    #[derive(Deserialize)]
+    pub(crate) struct MacroWithArgs {
+        path: Path,
+        delimiter: MacroDelimiter,
+        #[serde(default)]
+        tokens: TokenStream,
+        #[serde(default)]
+        args: Option<MacroArgs>,
+    }
+
+    impl TryFrom<MacroWithArgs> for Macro {
+        type Error = Error;
+
+        fn try_from(other: MacroWithArgs) -> Result<Self, Error> {
+            let Some(args) = &other.args else {
+                return Ok(Self {
+                    path: other.path,
+                    delimiter: other.delimiter,
+                    tokens: other.tokens,
+                });
+            };
+            let expected = args.to_syn()?.to_tokens();
+            if other.tokens.trees().is_empty() {
+                let tokens = (&expected).into();
+                return Ok(Self { path: other.path, delimiter: other.delimiter, tokens });
+            }
+            // Both are written, e.g., by `SerializeOptions::macro_args`, so
+            // they must describe the same arguments. The arguments parsed from
+            // `tokens` are converted into adapters too, which, e.g., drop
+            // trailing commas.
+            let path: syn::Path = other.path.try_ref_into().context("Macro", "path")?;
+            let tokens: proc_macro2::TokenStream =
+                other.tokens.try_ref_into().context("Macro", "tokens")?;
+            let actual = match parse_tokens(&path, tokens) {
+                Some(args) => Some(MacroArgs::from(&args).to_syn()?.to_tokens().to_string()),
+                None => None,
+            };
+            if actual != Some(expected.to_string()) {
+                return Err(Error::new(
+                    "Macro",
+                    "`tokens` and `args` describe different arguments",
+                ));
+            }
+            Ok(Self { path: other.path, delimiter: other.delimiter, tokens: other.tokens })
+        }
+    }
+
+    // -----------------------------------------------------------------------------
+    // Serialize
+
+    /// The arguments of a well-known macro, as parsed from its tokens.
+    pub(crate) enum Args {
+        Format(Format),
+        List(Vec<syn::Expr>),
+        Repeat(Box<syn::Expr>, Box<syn::Expr>),
+    }
+
+    pub(crate) struct Format {
+        exprs: Vec<syn::Expr>,
+        string: Option<syn::LitStr>,
+        args: Vec<(Option<proc_macro2::Ident>, syn::Expr)>,
+    }
+
+    /// Parses the arguments of `node` if it is a call to a well-known macro and
+    /// `SerializeOptions::macro_args` is enabled in `options`.
+    pub(crate) fn parse(node: &syn::Macro, options: SerializeOptions) -> Option<Args> {
+        if !options.has_macro_args() {
+            return None;
+        }
+        parse_tokens(&node.path, node.tokens.clone())
+    }
+
+    /// Parses `tokens` as the arguments of the macro at `path`, if it is a
+    /// well-known macro.
+    fn parse_tokens(path: &syn::Path, tokens: proc_macro2::TokenStream) -> Option<Args> {
+        let (std, name) = match path.segments.len() {
+            1 => (None, &path.segments[0]),
+            2 => (Some(&path.segments[0]), &path.segments[1]),
+            _ => return None,
+        };
+        if std.is_some_and(|s| !["std", "core", "alloc"].iter().any(|std| s.ident == std))
+            || path.segments.iter().any(|s| !s.arguments.is_none())
+        {
+            return None;
+        }
+        let leading = match &*name.ident.to_string() {
+            "vec" => return parse_vec.parse2(tokens).ok(),
+            "format" | "format_args" | "print" | "println" | "eprint" | "eprintln" | "panic"
+            | "todo" | "unimplemented" | "unreachable" => 0,
+            "write" | "writeln" | "assert" | "debug_assert" => 1,
+            "assert_eq" | "assert_ne" | "debug_assert_eq" | "debug_assert_ne" => 2,
+            _ => return None,
+        };
+        let parser = |input: ParseStream<'_>| parse_format(input, leading);
+        parser.parse2(tokens).ok().map(Args::Format)
+    }
+
+    fn parse_format(input: ParseStream<'_>, leading: usize) -> syn::Result<Format> {
+        let mut exprs = Vec::with_capacity(leading);
+        for i in 0..leading {
+            if i > 0 {
+                input.parse::<Token![,]>()?;
+            }
+            exprs.push(input.parse()?);
+        }
+        if leading > 0 && !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+        let string = if input.is_empty() { None } else { Some(input.parse()?) };
+        let mut args = vec![];
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let name =
+                if input.peek(syn::Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+                    let name = input.parse()?;
+                    input.parse::<Token![=]>()?;
+                    Some(name)
+                } else {
+                    None
+                };
+            args.push((name, input.parse()?));
+        }
+        Ok(Format { exprs, string, args })
+    }
+
+    fn parse_vec(input: ParseStream<'_>) -> syn::Result<Args> {
+        if input.is_empty() {
+            return Ok(Args::List(vec![]));
+        }
+        let expr = input.parse()?;
+        if input.peek(Token![;]) {
+            input.parse::<Token![;]>()?;
+            return Ok(Args::Repeat(Box::new(expr), input.parse()?));
+        }
+        let mut exprs = vec![expr];
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            exprs.push(input.parse()?);
+        }
+        Ok(Args::List(exprs))
+    }
+
+    impl Serialize for Ser<'_, Args> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self.0 {
+                Args::Format(args) => serializer.serialize_newtype_variant(
+                    "MacroArgs",
+                    0,
+                    "format",
+                    &Ser(args, self.1),
+                ),
+                Args::List(exprs) => serializer.serialize_newtype_variant(
+                    "MacroArgs",
+                    1,
+                    "list",
+                    &Ser(exprs, self.1),
+                ),
+                Args::Repeat(expr, len) => {
+                    serializer.serialize_newtype_variant("MacroArgs", 2, "repeat", &RepeatSer {
+                        expr,
+                        len,
+                        options: self.1,
+                    })
+                }
+            }
+        }
+    }
+
+    impl Serialize for Ser<'_, Format> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let node = self.0;
+            let explicit = self.1.has_explicit_fields();
+            let mut state = serializer.serialize_struct(
+                "FormatArgs",
+                usize::from(explicit || !node.exprs.is_empty())
+                    + usize::from(explicit || node.string.is_some())
+                    + usize::from(explicit || !node.args.is_empty()),
+            )?;
+            if explicit || !node.exprs.is_empty() {
+                state.serialize_field("exprs", &Ser(&node.exprs, self.1))?;
+            } else {
+                state.skip_field("exprs")?;
+            }
+            if explicit || node.string.is_some() {
+                state.serialize_field("string", &Ser(&node.string, self.1))?;
+            } else {
+                state.skip_field("string")?;
+            }
+            if explicit || !node.args.is_empty() {
+                let args: Vec<_> = node
+                    .args
+                    .iter()
+                    .map(|(name, expr)| FormatArgSer { name, expr, options: self.1 })
+                    .collect();
+                state.serialize_field("args", &args)?;
+            } else {
+                state.skip_field("args")?;
+            }
+            state.end()
+        }
+    }
+
+    struct FormatArgSer<'a> {
+        name: &'a Option<proc_macro2::Ident>,
+        expr: &'a syn::Expr,
+        options: SerializeOptions,
+    }
+
+    impl Serialize for FormatArgSer<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let explicit = self.options.has_explicit_fields();
+            let mut state = serializer
+                .serialize_struct("FormatArg", usize::from(explicit || self.name.is_some()) + 1)?;
+            if explicit || self.name.is_some() {
+                state.serialize_field("name", &Ser(self.name, self.options))?;
+            } else {
+                state.skip_field("name")?;
+            }
+            state.serialize_field("expr", &Ser(self.expr, self.options))?;
+            state.end()
+        }
+    }
+
+    struct RepeatSer<'a> {
+        expr: &'a syn::Expr,
+        len: &'a syn::Expr,
+        options: SerializeOptions,
+    }
+
+    impl Serialize for RepeatSer<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("RepeatArgs", 2)?;
+            state.serialize_field("expr", &Ser(self.expr, self.options))?;
+            state.serialize_field("len", &Ser(self.len, self.options))?;
+            state.end()
+        }
+    }
+}
@@ -7,6 +7,7 @@ macro_rules! ast_struct {
     ) => {
         #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
         $(#[$attrs])*
+        #[non_exhaustive]
         pub struct $name $($rest)*
     };
 }
@@ -25,7 +26,18 @@ macro_rules! ast_enum {
 
 macro_rules! syn_trait_impl {
     ($path:ident :: $ty:ident) => {
-        impl crate::sealed::Sealed for $path::$ty {}
+        impl crate::sealed::Sealed for $path::$ty {
+            fn serialize_ref<S>(
+                &self,
+                serializer: S,
+                options: crate::SerializeOptions,
+            ) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serde::Serialize::serialize(&crate::Ser(self, options), serializer)
+            }
+        }
         impl crate::Syn for $path::$ty {
             type Adapter = $ty;
             #[allow(unreachable_code)] // For syn::ImplRestriction
@@ -35,6 +47,22 @@ macro_rules! syn_trait_impl {
             fn from_adapter(adapter: &Self::Adapter) -> Self {
                 Self::from(adapter)
             }
+            fn try_from_adapter(adapter: &Self::Adapter) -> Result<Self, crate::Error> {
+                crate::TryFromRef::try_from_ref(adapter)
+            }
+        }
+        from_adapter_impl!($path::$ty);
+    };
+}
+
+// Infallible conversion from an adapter, which panics if the adapter does not
+// describe a valid syntax tree.
+macro_rules! from_adapter_impl {
+    ($path:ident :: $ty:ident) => {
+        impl From<&$ty> for $path::$ty {
+            fn from(adapter: &$ty) -> Self {
+                crate::TryFromRef::try_from_ref(adapter).unwrap_or_else(|e| panic!("{e}"))
+            }
         }
     };
 }
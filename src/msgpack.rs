@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A module to provide functions for MessagePack <-> Rust serialize and
+//! deserialize.
+//!
+//! The data has the same shape as the JSON representation: structs and enum
+//! variants with data are written as maps keyed by field and variant names,
+//! and variants without data as strings.
+
+use alloc::vec::Vec;
+use std::io;
+
+use rmp_serde::{decode, encode};
+use serde::de::Error as _;
+
+use super::*;
+
+// Serialize [`Syn`] type into MessagePack data.
+
+/// Serialize the given [`Syn`] type as MessagePack into the I/O stream.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn to_writer<W>(mut writer: W, syn_file: &syn::File) -> Result<(), rmp_serde::encode::Error>
+/// # where
+/// #     W: io::Write,
+/// # {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file);
+/// rmp_serde::encode::write_named(&mut writer, &serializable)
+/// # }
+/// ```
+pub fn to_writer<S, W>(mut writer: W, syn: &S) -> Result<(), encode::Error>
+where
+    S: Syn,
+    W: io::Write,
+{
+    let serializable = Serializable::new(syn);
+    encode::write_named(&mut writer, &serializable)
+}
+
+/// Serialize the given [`Syn`] type as a MessagePack byte vector.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # fn to_vec(syn_file: &syn::File) -> Vec<u8> {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file);
+/// rmp_serde::to_vec_named(&serializable).unwrap()
+/// # }
+/// ```
+// All of the data structures in syn-serde are compatible with MessagePack so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec<S>(syn: &S) -> Vec<u8>
+where
+    S: Syn,
+{
+    let serializable = Serializable::new(syn);
+    rmp_serde::to_vec_named(&serializable).unwrap()
+}
+
+/// Serialize the given [`Syn`] type as MessagePack into the I/O stream, with
+/// the given options.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn to_writer_with_options<W>(
+/// #     mut writer: W,
+/// #     syn_file: &syn::File,
+/// #     options: syn_serde::SerializeOptions,
+/// # ) -> Result<(), rmp_serde::encode::Error>
+/// # where
+/// #     W: io::Write,
+/// # {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file).with_options(options);
+/// rmp_serde::encode::write_named(&mut writer, &serializable)
+/// # }
+/// ```
+pub fn to_writer_with_options<S, W>(
+    mut writer: W,
+    syn: &S,
+    options: SerializeOptions,
+) -> Result<(), encode::Error>
+where
+    S: Syn,
+    W: io::Write,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    encode::write_named(&mut writer, &serializable)
+}
+
+/// Serialize the given [`Syn`] type as a MessagePack byte vector, with the
+/// given options.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # fn to_vec_with_options(syn_file: &syn::File, options: syn_serde::SerializeOptions) -> Vec<u8> {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file).with_options(options);
+/// rmp_serde::to_vec_named(&serializable).unwrap()
+/// # }
+/// ```
+// All of the data structures in syn-serde are compatible with MessagePack so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec_with_options<S>(syn: &S, options: SerializeOptions) -> Vec<u8>
+where
+    S: Syn,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    rmp_serde::to_vec_named(&serializable).unwrap()
+}
+
+// Deserialize MessagePack data to [`Syn`] type.
+
+/// Deserialize an instance of [`Syn`] type from an I/O stream of MessagePack.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn from_reader<R: io::Read>(reader: R) -> Result<syn::File, rmp_serde::decode::Error> {
+/// use serde::de::Error as _;
+/// use syn_serde::Syn;
+///
+/// let adapter: <syn::File as Syn>::Adapter = rmp_serde::from_read(reader)?;
+/// let syn_file =
+///     syn::File::try_from_adapter(&adapter).map_err(rmp_serde::decode::Error::custom)?;
+/// Ok(syn_file)
+/// # }
+/// ```
+pub fn from_reader<S, R>(reader: R) -> Result<S, decode::Error>
+where
+    S: Syn,
+    R: io::Read,
+{
+    let adapter: S::Adapter = rmp_serde::from_read(reader)?;
+    S::try_from_adapter(&adapter).map_err(decode::Error::custom)
+}
+
+/// Deserialize an instance of [`Syn`] type from bytes of MessagePack.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # fn from_slice(v: &[u8]) -> Result<syn::File, rmp_serde::decode::Error> {
+/// use serde::de::Error as _;
+/// use syn_serde::Syn;
+///
+/// let adapter: <syn::File as Syn>::Adapter = rmp_serde::from_slice(v)?;
+/// let syn_file =
+///     syn::File::try_from_adapter(&adapter).map_err(rmp_serde::decode::Error::custom)?;
+/// Ok(syn_file)
+/// # }
+/// ```
+pub fn from_slice<S>(v: &[u8]) -> Result<S, decode::Error>
+where
+    S: Syn,
+{
+    let adapter: S::Adapter = rmp_serde::from_slice(v)?;
+    S::try_from_adapter(&adapter).map_err(decode::Error::custom)
+}
@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// Options that change the representation of a syntax tree serialized through
+/// [`Serializable`](crate::Serializable).
+///
+/// The default options produce the same output as serializing the adapter
+/// returned by [`Syn::to_adapter`](crate::Syn::to_adapter). Deserialization
+/// accepts the output of any options.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "json")]
+/// # fn dox() -> Result<(), Box<dyn std::error::Error>> {
+/// use syn_serde::{Serializable, SerializeOptions};
+///
+/// let lit: syn::Lit = syn::parse_quote!(255u8);
+/// let options = SerializeOptions::new().structured_literals(true);
+///
+/// let json = serde_json::to_string(&Serializable::new(&lit).with_options(options))?;
+/// assert_eq!(json, r#"{"int":{"value":"255","suffix":"u8"}}"#);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SerializeOptions {
+    structured_literals: bool,
+    literal_spelling: bool,
+    explicit_fields: bool,
+    internally_tagged: bool,
+    token_text: bool,
+    spans: bool,
+    #[cfg(feature = "macro-args")]
+    macro_args: bool,
+    pretty: bool,
+    singleton_maps: bool,
+    binary: bool,
+}
+
+impl SerializeOptions {
+    /// Creates the default options.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            structured_literals: false,
+            literal_spelling: false,
+            explicit_fields: false,
+            internally_tagged: false,
+            token_text: false,
+            spans: false,
+            #[cfg(feature = "macro-args")]
+            macro_args: false,
+            pretty: false,
+            singleton_maps: false,
+            binary: false,
+        }
+    }
+
+    /// Serializes literals as objects with their decoded value instead of
+    /// their source text, e.g., `{"value": "a\nb", "suffix": "", "style":
+    /// "cooked"}` instead of `"\"a\\nb\""`.
+    ///
+    /// The value of a string literal is a string, that of a byte string or a
+    /// C string literal (without the nul terminator) is an array of bytes, that
+    /// of a byte literal is an integer, that of a character literal is a
+    /// string of one character, and that of an integer or a float literal is
+    /// the string of its digits in base 10, without underscores (so, e.g.,
+    /// `0x_FF` is serialized as `"255"`). Only string, byte string, and C
+    /// string literals have a `style`, which is the representation of
+    /// [`StrStyle`](crate::StrStyle).
+    ///
+    /// Literals in token streams, e.g., the arguments of macros, are still
+    /// serialized as their source text.
+    #[must_use]
+    pub const fn structured_literals(mut self, yes: bool) -> Self {
+        self.structured_literals = yes;
+        self
+    }
+
+    /// Serializes string, byte string, C string, byte, and character literals
+    /// as they are spelled in the source code (e.g., raw strings, suffixes,
+    /// escapes, and non-ASCII characters) instead of rebuilding them from
+    /// their values, e.g., `"r#\"a\"#"` instead of `"\"a\""` for `r#"a"#`.
+    ///
+    /// With [`structured_literals`](Self::structured_literals), this keeps the
+    /// `style` and `suffix` of the literals. The spelling of the literals is
+    /// kept when deserializing either form.
+    #[must_use]
+    pub const fn literal_spelling(mut self, yes: bool) -> Self {
+        self.literal_spelling = yes;
+        self
+    }
+
+    /// Serializes the fields that the adapters omit when they have their
+    /// default value, e.g., empty `attrs`, inherited `vis`, `false` flags, and
+    /// `None` options, so consumers do not need to know the defaults.
+    ///
+    /// For example, `fn f() {}` is serialized as `{"fn": {"attrs": [], "vis":
+    /// "inherited", "const": false, ...}}` instead of `{"fn": {"ident": "f",
+    /// ...}}`. `span` is still only serialized when it is known, and `trivia`
+    /// is never serialized.
+    #[must_use]
+    pub const fn explicit_fields(mut self, yes: bool) -> Self {
+        self.explicit_fields = yes;
+        self
+    }
+
+    /// Serializes the variants of enums with a `kind` field, e.g., `{"kind":
+    /// "ItemFn", "ident": "f", ...}` instead of `{"fn": {"ident": "f",
+    /// ...}}`, so consumers can dispatch on a single field.
+    ///
+    /// The kind of a variant is the name of the enum followed by the name of
+    /// the variant. The fields of a variant whose payload is serialized as an
+    /// object are written next to `kind`. The payload of other variants is
+    /// written under `value`, e.g., `{"kind": "TypeParamBoundLifetime",
+    /// "value": "a"}`, and unit variants are still serialized as strings.
+    /// Only the variants of enums are tagged: structs, e.g., the `generics` of
+    /// an `ItemFn`, have a single type and are serialized as plain objects.
+    ///
+    /// When deserializing, `kind` may be any field of the object, but the
+    /// fields before it are buffered, so writing it first is faster. The JSON
+    /// schema and the TypeScript definitions describe both representations.
+    #[must_use]
+    pub const fn internally_tagged(mut self, yes: bool) -> Self {
+        self.internally_tagged = yes;
+        self
+    }
+
+    /// Serializes token streams, e.g., the arguments of macros and attributes,
+    /// as their source text instead of arrays of tokens, e.g., `"Debug,
+    /// Clone"` instead of `[{"ident": "Debug"}, {"punct": {"op": ",",
+    /// "spacing": "alone"}}, {"ident": "Clone"}]`.
+    ///
+    /// The text is taken from the source code if the spans of the tokens point
+    /// to it (with the `span-locations` feature, or in a procedural macro).
+    /// Otherwise, e.g., for tokens created by `quote!`, it is the `Display`
+    /// output of the token stream, which normalizes the spacing between
+    /// tokens, e.g., `"Debug , Clone"`. The text is lexed again when
+    /// deserializing. The spans of the tokens and the groups with no
+    /// delimiters (`Delimiter::None`) are not kept.
+    #[must_use]
+    pub const fn token_text(mut self, yes: bool) -> Self {
+        self.token_text = yes;
+        self
+    }
+
+    /// Serializes the locations of nodes and tokens in the source code in a
+    /// `span` field, e.g., `{"start": {"line": 1, "column": 0}, "end": {"line":
+    /// 1, "column": 12}}`. Identifiers and literals are serialized as objects
+    /// with their text and `span`, e.g., `{"name": "main", "span": ...}`.
+    ///
+    /// Locations are only known with the `span-locations` feature, and only
+    /// for syntax trees parsed outside of procedural macros (see proc-macro2's
+    /// `span-locations` feature). Without them, `span` is not serialized.
+    #[must_use]
+    pub const fn spans(mut self, yes: bool) -> Self {
+        self.spans = yes;
+        self
+    }
+
+    /// Serializes the arguments of well-known macros of the standard library
+    /// as [`MacroArgs`](crate::MacroArgs) in an `args` field next to their
+    /// `tokens`, e.g., `{"format": {"string": "{} {x}", "args": [{"expr": ...},
+    /// {"name": "x", "expr": ...}]}}` for `println!("{} {x}", a, x = 1)`.
+    ///
+    /// The macros are `format!`, `format_args!`, `print!`, `println!`,
+    /// `eprint!`, `eprintln!`, `write!`, `writeln!`, `panic!`, `todo!`,
+    /// `unimplemented!`, `unreachable!`, the `assert!` family, and `vec!`,
+    /// optionally prefixed with `std::`, `core::`, or `alloc::`. `args` is not
+    /// serialized for other macros and for arguments that cannot be parsed.
+    #[cfg(feature = "macro-args")]
+    #[must_use]
+    pub const fn macro_args(mut self, yes: bool) -> Self {
+        self.macro_args = yes;
+        self
+    }
+
+    /// Pretty-prints the output of the `*_with_options` functions of the
+    /// [`json`](crate::json) and [`ron`](crate::ron) modules, e.g.,
+    /// `json::to_string_with_options` returns the same as
+    /// `json::to_string_pretty_with_options`.
+    ///
+    /// This does not change the data, so it has no effect on
+    /// [`Serializable`](crate::Serializable) itself, on JSON Lines, whose
+    /// values must each fit on a line, or on the other formats.
+    #[must_use]
+    pub const fn pretty(mut self, yes: bool) -> Self {
+        self.pretty = yes;
+        self
+    }
+
+    // Set by `SingletonMaps`: the variants of enums are serialized as maps with
+    // a single key, so the fields of a variant with more than one field are
+    // passed as a tuple.
+    #[cfg(any(feature = "ron", feature = "yaml"))]
+    pub(crate) const fn singleton_maps(mut self, yes: bool) -> Self {
+        self.singleton_maps = yes;
+        self
+    }
+
+    // Set by `binary::to_vec`: every field of the adapters is serialized in
+    // order, including `span` and `trivia`, and flattened fields are
+    // serialized as nested values, so the binary format, which is not
+    // self-describing, can be read back field by field.
+    #[cfg(feature = "binary")]
+    pub(crate) const fn binary(mut self, yes: bool) -> Self {
+        self.binary = yes;
+        self
+    }
+
+    pub(crate) fn has_structured_literals(self) -> bool {
+        self.structured_literals
+    }
+
+    pub(crate) fn has_literal_spelling(self) -> bool {
+        self.literal_spelling
+    }
+
+    pub(crate) fn has_explicit_fields(self) -> bool {
+        self.explicit_fields || self.binary
+    }
+
+    pub(crate) fn is_internally_tagged(self) -> bool {
+        self.internally_tagged
+    }
+
+    pub(crate) fn has_token_text(self) -> bool {
+        self.token_text
+    }
+
+    #[cfg(feature = "span-locations")]
+    pub(crate) fn has_spans(self) -> bool {
+        self.spans
+    }
+
+    #[cfg(feature = "macro-args")]
+    pub(crate) fn has_macro_args(self) -> bool {
+        self.macro_args
+    }
+
+    #[cfg(any(feature = "json", feature = "ron"))]
+    pub(crate) fn is_pretty(self) -> bool {
+        self.pretty
+    }
+
+    pub(crate) fn has_singleton_maps(self) -> bool {
+        self.singleton_maps
+    }
+
+    pub(crate) fn is_binary(self) -> bool {
+        self.binary
+    }
+}
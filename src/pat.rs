@@ -13,12 +13,28 @@ pub use crate::{
 
 ast_struct! {
     /// An adapter for [`struct@syn::PatOr`].
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
     pub struct PatOr {
+        /// Corresponds to [`syn::PatOr::attrs`].
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
-        pub(crate) attrs: Vec<Attribute>,
+        pub attrs: Vec<Attribute>,
         // TODO: can remove
+        /// Whether [`syn::PatOr::leading_vert`] is present.
         #[serde(default, skip_serializing_if = "not")]
-        pub(crate) leading_vert: bool,
-        pub(crate) cases: Punctuated<Pat>,
+        pub leading_vert: bool,
+        /// Corresponds to [`syn::PatOr::cases`].
+        pub cases: Punctuated<Pat>,
+        /// The location of this node, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub span: Option<Location>,
+    }
+}
+
+impl PatOr {
+    /// Creates an adapter from the fields that are required in the serialized
+    /// form, with the other fields set to their default values.
+    #[must_use]
+    pub fn new(cases: Punctuated<Pat>) -> Self {
+        Self { attrs: default(), leading_vert: default(), cases, span: None }
     }
 }
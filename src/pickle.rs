@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A module to provide functions for Pickle <-> Rust serialize and deserialize.
+//!
+//! The data is written with the default options of [serde-pickle] (protocol
+//! version 3), which can be read by Python 3 with `pickle.load`. The
+//! resulting Python objects have the same shape as the JSON representation,
+//! except that tuples are represented by Python tuples.
+//!
+//! [serde-pickle]: https://docs.rs/serde-pickle
+
+use alloc::vec::Vec;
+use std::io;
+
+use serde::de;
+use serde_pickle::{DeOptions, Result, SerOptions};
+
+use super::*;
+
+// Serialize [`Syn`] type into Pickle data.
+
+/// Serialize the given [`Syn`] type as Pickle into the I/O stream.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn to_writer<W>(mut writer: W, syn_file: &syn::File) -> serde_pickle::Result<()>
+/// # where
+/// #     W: io::Write,
+/// # {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file);
+/// serde_pickle::to_writer(&mut writer, &serializable, serde_pickle::SerOptions::new())
+/// # }
+/// ```
+pub fn to_writer<S, W>(mut writer: W, syn: &S) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    let serializable = Serializable::new(syn);
+    serde_pickle::to_writer(&mut writer, &serializable, SerOptions::new())
+}
+
+/// Serialize the given [`Syn`] type as a Pickle byte vector.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # fn to_vec(syn_file: &syn::File) -> Vec<u8> {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file);
+/// serde_pickle::to_vec(&serializable, serde_pickle::SerOptions::new()).unwrap()
+/// # }
+/// ```
+// All of the data structures in syn-serde are compatible with Pickle so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec<S>(syn: &S) -> Vec<u8>
+where
+    S: Syn,
+{
+    let serializable = Serializable::new(syn);
+    serde_pickle::to_vec(&serializable, SerOptions::new()).unwrap()
+}
+
+/// Serialize the given [`Syn`] type as Pickle into the I/O stream, with the
+/// given options.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn to_writer_with_options<W>(
+/// #     mut writer: W,
+/// #     syn_file: &syn::File,
+/// #     options: syn_serde::SerializeOptions,
+/// # ) -> serde_pickle::Result<()>
+/// # where
+/// #     W: io::Write,
+/// # {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file).with_options(options);
+/// serde_pickle::to_writer(&mut writer, &serializable, serde_pickle::SerOptions::new())
+/// # }
+/// ```
+pub fn to_writer_with_options<S, W>(mut writer: W, syn: &S, options: SerializeOptions) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    serde_pickle::to_writer(&mut writer, &serializable, SerOptions::new())
+}
+
+/// Serialize the given [`Syn`] type as a Pickle byte vector, with the given
+/// options.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # fn to_vec_with_options(syn_file: &syn::File, options: syn_serde::SerializeOptions) -> Vec<u8> {
+/// use syn_serde::Serializable;
+///
+/// let serializable = Serializable::new(syn_file).with_options(options);
+/// serde_pickle::to_vec(&serializable, serde_pickle::SerOptions::new()).unwrap()
+/// # }
+/// ```
+// All of the data structures in syn-serde are compatible with Pickle so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec_with_options<S>(syn: &S, options: SerializeOptions) -> Vec<u8>
+where
+    S: Syn,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    serde_pickle::to_vec(&serializable, SerOptions::new()).unwrap()
+}
+
+// Deserialize Pickle data to [`Syn`] type.
+
+/// Deserialize an instance of [`Syn`] type from an I/O stream of Pickle.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # use std::io;
+/// # fn from_reader<R: io::Read>(reader: R) -> serde_pickle::Result<syn::File> {
+/// use serde::de::Error as _;
+/// use syn_serde::Syn;
+///
+/// let adapter: <syn::File as Syn>::Adapter =
+///     serde_pickle::from_reader(reader, serde_pickle::DeOptions::new())?;
+/// let syn_file = syn::File::try_from_adapter(&adapter).map_err(serde_pickle::Error::custom)?;
+/// Ok(syn_file)
+/// # }
+/// ```
+pub fn from_reader<S, R>(reader: R) -> Result<S>
+where
+    S: Syn,
+    R: io::Read,
+{
+    let adapter: S::Adapter = serde_pickle::from_reader(reader, DeOptions::new())?;
+    S::try_from_adapter(&adapter).map_err(de::Error::custom)
+}
+
+/// Deserialize an instance of [`Syn`] type from bytes of Pickle data.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # fn from_slice(v: &[u8]) -> serde_pickle::Result<syn::File> {
+/// use serde::de::Error as _;
+/// use syn_serde::Syn;
+///
+/// let adapter: <syn::File as Syn>::Adapter =
+///     serde_pickle::from_slice(v, serde_pickle::DeOptions::new())?;
+/// let syn_file = syn::File::try_from_adapter(&adapter).map_err(serde_pickle::Error::custom)?;
+/// Ok(syn_file)
+/// # }
+/// ```
+pub fn from_slice<S>(v: &[u8]) -> Result<S>
+where
+    S: Syn,
+{
+    let adapter: S::Adapter = serde_pickle::from_slice(v, DeOptions::new())?;
+    S::try_from_adapter(&adapter).map_err(de::Error::custom)
+}
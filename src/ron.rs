@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A module to provide functions for RON <-> Rust serialize and deserialize.
+//!
+//! The data has the same shape as the JSON representation, which keeps
+//! snapshots of syntax trees readable: structs are written as RON structs,
+//! enum variants with data as maps with a single string key (many variant
+//! names, such as `"+="`, are not valid RON identifiers), and variants without
+//! data as strings.
+
+use alloc::{
+    string::{String, ToString as _},
+    vec::Vec,
+};
+use std::io;
+
+use ::ron::{
+    Error, Options,
+    error::{Position, Span, SpannedError},
+    ser::PrettyConfig,
+};
+
+use super::*;
+use crate::singleton_map::SingletonMaps;
+
+type Result<T, E = Error> = core::result::Result<T, E>;
+
+/// Returns an error that spans the whole of `s`, for errors that occur after
+/// the RON text has been parsed.
+fn error(s: &str, code: Error) -> SpannedError {
+    let (line, last) =
+        s.rsplit_once('\n').map_or((1, s), |(rest, last)| (rest.matches('\n').count() + 2, last));
+    let end = Position { line, col: last.chars().count() + 1 };
+    let span = Span { start: Position { line: 1, col: 1 }, end };
+    SpannedError { code, span }
+}
+
+// Serialize [`Syn`] type into RON data.
+
+/// Serialize the given [`Syn`] type as RON into the I/O stream.
+pub fn to_writer<S, W>(writer: W, syn: &S) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    to_writer_with_options(writer, syn, SerializeOptions::new())
+}
+
+/// Serialize the given [`Syn`] type as RON into the I/O stream, with the given
+/// options.
+pub fn to_writer_with_options<S, W>(writer: W, syn: &S, options: SerializeOptions) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    if options.is_pretty() {
+        return to_writer_pretty_with_options(writer, syn, options);
+    }
+    Options::default().to_io_writer(writer, &SingletonMaps::new(syn, options))
+}
+
+/// Serialize the given [`Syn`] type as pretty-printed RON into the I/O
+/// stream.
+pub fn to_writer_pretty<S, W>(writer: W, syn: &S) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    to_writer_pretty_with_options(writer, syn, SerializeOptions::new())
+}
+
+/// Serialize the given [`Syn`] type as pretty-printed RON into the I/O
+/// stream, with the given options.
+pub fn to_writer_pretty_with_options<S, W>(
+    writer: W,
+    syn: &S,
+    options: SerializeOptions,
+) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    Options::default().to_io_writer_pretty(
+        writer,
+        &SingletonMaps::new(syn, options),
+        PrettyConfig::default(),
+    )
+}
+
+/// Serialize the given [`Syn`] type as a RON byte vector.
+// All of the data structures in syn-serde are compatible with RON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec<S>(syn: &S) -> Vec<u8>
+where
+    S: Syn,
+{
+    to_string(syn).into_bytes()
+}
+
+/// Serialize the given [`Syn`] type as a RON byte vector, with the given
+/// options.
+// All of the data structures in syn-serde are compatible with RON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec_with_options<S>(syn: &S, options: SerializeOptions) -> Vec<u8>
+where
+    S: Syn,
+{
+    to_string_with_options(syn, options).into_bytes()
+}
+
+/// Serialize the given [`Syn`] type as a pretty-printed RON byte vector.
+// All of the data structures in syn-serde are compatible with RON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec_pretty<S>(syn: &S) -> Vec<u8>
+where
+    S: Syn,
+{
+    to_string_pretty(syn).into_bytes()
+}
+
+/// Serialize the given [`Syn`] type as a pretty-printed RON byte vector, with
+/// the given options.
+// All of the data structures in syn-serde are compatible with RON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec_pretty_with_options<S>(syn: &S, options: SerializeOptions) -> Vec<u8>
+where
+    S: Syn,
+{
+    to_string_pretty_with_options(syn, options).into_bytes()
+}
+
+/// Serialize the given [`Syn`] type as a String of RON.
+///
+/// # Examples
+///
+/// ```
+/// let item: syn::Item = syn::parse_quote! {
+///     struct Unit;
+/// };
+///
+/// assert_eq!(syn_serde::ron::to_string(&item), r#"{"struct":(ident:"Unit",fields:"unit")}"#);
+/// ```
+// All of the data structures in syn-serde are compatible with RON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_string<S>(syn: &S) -> String
+where
+    S: Syn,
+{
+    to_string_with_options(syn, SerializeOptions::new())
+}
+
+/// Serialize the given [`Syn`] type as a String of RON, with the given
+/// options.
+// All of the data structures in syn-serde are compatible with RON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_string_with_options<S>(syn: &S, options: SerializeOptions) -> String
+where
+    S: Syn,
+{
+    if options.is_pretty() {
+        return to_string_pretty_with_options(syn, options);
+    }
+    Options::default().to_string(&SingletonMaps::new(syn, options)).unwrap()
+}
+
+/// Serialize the given [`Syn`] type as a pretty-printed String of RON.
+// All of the data structures in syn-serde are compatible with RON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_string_pretty<S>(syn: &S) -> String
+where
+    S: Syn,
+{
+    to_string_pretty_with_options(syn, SerializeOptions::new())
+}
+
+/// Serialize the given [`Syn`] type as a pretty-printed String of RON, with
+/// the given options.
+// All of the data structures in syn-serde are compatible with RON so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_string_pretty_with_options<S>(syn: &S, options: SerializeOptions) -> String
+where
+    S: Syn,
+{
+    Options::default()
+        .to_string_pretty(&SingletonMaps::new(syn, options), PrettyConfig::default())
+        .unwrap()
+}
+
+// Deserialize RON data to [`Syn`] type.
+
+/// Deserialize an instance of [`Syn`] type from an I/O stream of RON.
+pub fn from_reader<S, R>(mut reader: R) -> Result<S, SpannedError>
+where
+    S: Syn,
+    R: io::Read,
+{
+    let mut s = String::new();
+    if let Err(e) = reader.read_to_string(&mut s) {
+        return Err(error(&s, Error::Io(e.to_string())));
+    }
+    from_str(&s)
+}
+
+/// Deserialize an instance of [`Syn`] type from bytes of RON text.
+pub fn from_slice<S>(v: &[u8]) -> Result<S, SpannedError>
+where
+    S: Syn,
+{
+    match core::str::from_utf8(v) {
+        Ok(s) => from_str(s),
+        Err(e) => Err(error("", Error::Utf8Error(e))),
+    }
+}
+
+/// Deserialize an instance of [`Syn`] type from a string of RON text.
+pub fn from_str<S>(s: &str) -> Result<S, SpannedError>
+where
+    S: Syn,
+{
+    // The deserializer of RON reads the variants of enums only from
+    // identifiers, so the maps and strings are read into a `Value` first.
+    let value: serde_json::Value = ::ron::from_str(s)?;
+    let adapter: S::Adapter =
+        serde_json::from_value(value).map_err(|e| error(s, Error::Message(e.to_string())))?;
+    S::try_from_adapter(&adapter).map_err(|e| error(s, Error::Message(e.to_string())))
+}
@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Serializes syntax trees in the same shape as the JSON representation into
+// formats whose own representation of enums is different, e.g., YAML tags or
+// RON identifiers (which cannot spell variant names such as `+=`).
+//
+// The variants of enums are written as maps with a single key, e.g., `{"fn":
+// {...}}`, and unit variants as strings. The fields of a variant with more
+// than one field cannot be streamed into a map, so `Ser` passes them as a
+// tuple to `serialize_newtype_variant` (see `SerializeOptions::singleton_maps`).
+// Structs without fields (e.g., `PatWild`) are written as empty maps, as RON
+// writes them as `()`, which is read back as a unit instead of an object.
+
+use core::fmt;
+
+use serde::ser::{
+    self, Impossible, SerializeSeq, SerializeStruct, SerializeTuple, SerializeTupleStruct,
+};
+
+use super::*;
+
+/// A syntax tree that serializes the variants of enums as maps with a single
+/// key.
+pub(crate) struct SingletonMaps<'a, T>(Serializable<'a, T>);
+
+impl<'a, T> SingletonMaps<'a, T>
+where
+    T: Syn,
+{
+    pub(crate) fn new(syn: &'a T, options: SerializeOptions) -> Self {
+        Self(Serializable::new(syn).with_options(options.singleton_maps(true)))
+    }
+}
+
+impl<T> Serialize for SingletonMaps<'_, T>
+where
+    T: Syn,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(Wrap(serializer))
+    }
+}
+
+/// Wraps a serializer, a value, or a compound serializer so the enums in it
+/// are serialized as maps.
+struct Wrap<T>(T);
+
+impl<T> Serialize for Wrap<&T>
+where
+    T: ?Sized + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(Wrap(serializer))
+    }
+}
+
+macro_rules! forward {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {$(
+        fn $method(self, $($arg: $ty),*) -> Result<S::Ok, S::Error> {
+            self.0.$method($($arg),*)
+        }
+    )*};
+}
+
+impl<S> Serializer for Wrap<S>
+where
+    S: Serializer,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Wrap<S::SerializeSeq>;
+    type SerializeTuple = Wrap<S::SerializeTuple>;
+    type SerializeTupleStruct = Wrap<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Impossible<S::Ok, S::Error>;
+    type SerializeMap = Wrap<S::SerializeMap>;
+    type SerializeStruct = Struct<Wrap<S::SerializeStruct>, S::SerializeMap>;
+    type SerializeStructVariant = Impossible<S::Ok, S::Error>;
+
+    forward! {
+        serialize_bool(v: bool),
+        serialize_i8(v: i8),
+        serialize_i16(v: i16),
+        serialize_i32(v: i32),
+        serialize_i64(v: i64),
+        serialize_i128(v: i128),
+        serialize_u8(v: u8),
+        serialize_u16(v: u16),
+        serialize_u32(v: u32),
+        serialize_u64(v: u64),
+        serialize_u128(v: u128),
+        serialize_f32(v: f32),
+        serialize_f64(v: f64),
+        serialize_char(v: char),
+        serialize_str(v: &str),
+        serialize_bytes(v: &[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(name: &'static str),
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.0.serialize_newtype_struct(name, &Wrap(value))
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let mut map = self.0.serialize_map(Some(1))?;
+        map.serialize_entry(variant, &Wrap(value))?;
+        map.end()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.0.serialize_some(&Wrap(value))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        self.0.serialize_seq(len).map(Wrap)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        self.0.serialize_tuple(len).map(Wrap)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        self.0.serialize_tuple_struct(name, len).map(Wrap)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        Err(ser::Error::custom(format_args!("unexpected tuple variant `{name}::{variant}`")))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        self.0.serialize_map(len).map(Wrap)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        if len == 0 {
+            self.0.serialize_map(Some(0)).map(Struct::Empty)
+        } else {
+            self.0.serialize_struct(name, len).map(|state| Struct::Fields(Wrap(state)))
+        }
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        Err(ser::Error::custom(format_args!("unexpected struct variant `{name}::{variant}`")))
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        self.0.collect_str(value)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+impl<S> SerializeSeq for Wrap<S>
+where
+    S: SerializeSeq,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), S::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.0.serialize_element(&Wrap(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S> SerializeTuple for Wrap<S>
+where
+    S: SerializeTuple,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), S::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.0.serialize_element(&Wrap(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S> SerializeTupleStruct for Wrap<S>
+where
+    S: SerializeTupleStruct,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), S::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.0.serialize_field(&Wrap(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S> SerializeMap for Wrap<S>
+where
+    S: SerializeMap,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), S::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.0.serialize_key(&Wrap(key))
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), S::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.0.serialize_value(&Wrap(value))
+    }
+
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<(), S::Error>
+    where
+        K: ?Sized + Serialize,
+        V: ?Sized + Serialize,
+    {
+        self.0.serialize_entry(&Wrap(key), &Wrap(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+impl<S> SerializeStruct for Wrap<S>
+where
+    S: SerializeStruct,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), S::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.0.serialize_field(key, &Wrap(value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), S::Error> {
+        self.0.skip_field(key)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+/// A struct, or a struct without fields that is serialized as an empty map.
+enum Struct<S, M> {
+    Fields(S),
+    Empty(M),
+}
+
+impl<S, M> SerializeStruct for Struct<S, M>
+where
+    S: SerializeStruct,
+    M: SerializeMap<Ok = S::Ok, Error = S::Error>,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), S::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        match self {
+            Self::Fields(state) => state.serialize_field(key, value),
+            Self::Empty(map) => map.serialize_entry(key, &Wrap(value)),
+        }
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), S::Error> {
+        match self {
+            Self::Fields(state) => state.skip_field(key),
+            Self::Empty(_) => Ok(()),
+        }
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Fields(state) => state.end(),
+            Self::Empty(map) => map.end(),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(feature = "span-locations")]
+use syn::spanned::Spanned;
+
+use crate::SerializeOptions;
+
+ast_struct! {
+    /// The location of a node in the source code.
+    ///
+    /// This is serialized with [`SerializeOptions::spans`] if the
+    /// `span-locations` feature is enabled. [`Syn::to_adapter`] does not
+    /// record it, but it is kept when deserializing an adapter. It is ignored
+    /// when converting an adapter back into a syntax tree.
+    ///
+    /// [`SerializeOptions::spans`]: crate::SerializeOptions::spans
+    /// [`Syn::to_adapter`]: crate::Syn::to_adapter
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Hash))]
+    pub struct Location {
+        /// The start of the node, inclusive.
+        pub start: LineColumn,
+        /// The end of the node, exclusive.
+        pub end: LineColumn,
+    }
+}
+
+ast_struct! {
+    /// An adapter for [`struct@proc_macro2::LineColumn`].
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Hash))]
+    pub struct LineColumn {
+        /// The 1-indexed line in the source file.
+        pub line: usize,
+        /// The 0-indexed column (in UTF-8 characters) in the source file.
+        pub column: usize,
+    }
+}
+
+impl Location {
+    /// Creates a location from its start and end.
+    #[must_use]
+    pub fn new(start: LineColumn, end: LineColumn) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the location of `node` if it is serialized with `options`.
+    #[cfg(feature = "span-locations")]
+    pub(crate) fn of<T>(node: &T, options: SerializeOptions) -> Option<Self>
+    where
+        T: ?Sized + Spanned,
+    {
+        Self::from_span(node.span(), options)
+    }
+    #[cfg(not(feature = "span-locations"))]
+    pub(crate) fn of<T>(_node: &T, _options: SerializeOptions) -> Option<Self>
+    where
+        T: ?Sized,
+    {
+        None
+    }
+
+    /// Returns the location of `span` if it is serialized with `options`.
+    #[cfg(feature = "span-locations")]
+    pub(crate) fn from_span(span: proc_macro2::Span, options: SerializeOptions) -> Option<Self> {
+        if !options.has_spans() {
+            return None;
+        }
+        let (start, end) = (span.start(), span.end());
+        // `Span::call_site()` (e.g., the span of an empty node or of a syntax
+        // tree that was not parsed from source) has no meaningful location.
+        if start == end {
+            return None;
+        }
+        Some(Self { start: start.into(), end: end.into() })
+    }
+    #[cfg(not(feature = "span-locations"))]
+    pub(crate) fn from_span(_span: proc_macro2::Span, _options: SerializeOptions) -> Option<Self> {
+        None
+    }
+}
+
+impl LineColumn {
+    /// Creates a line-column pair from a 1-indexed line and a 0-indexed
+    /// column.
+    #[must_use]
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+#[cfg(feature = "span-locations")]
+impl From<proc_macro2::LineColumn> for LineColumn {
+    fn from(other: proc_macro2::LineColumn) -> Self {
+        Self { line: other.line, column: other.column }
+    }
+}
@@ -3,14 +3,47 @@
 use super::*;
 pub use crate::ast_struct::{Block, Local, LocalInit, StmtMacro};
 
-ast_enum! {
-    /// An adapter for [`enum@syn::Stmt`].
-    pub enum Stmt {
-        #[serde(rename = "let")]
-        Local(Local),
-        Item(Item),
-        // TODO: Should be Expr { expr: Exor, semi: bool }?
-        Expr(Expr, bool),
-        Macro(StmtMacro),
+/// An adapter for [`enum@syn::Stmt`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+#[serde(rename_all = "snake_case")]
+pub enum Stmt {
+    #[serde(rename = "let")]
+    Local(Local),
+    Item(Item),
+    // TODO: Should be Expr { expr: Exor, semi: bool }?
+    Expr(Expr, bool),
+    Macro(StmtMacro),
+}
+
+impl<'de> Deserialize<'de> for Stmt {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+
+impl tagged::TaggedEnum for Stmt {
+    const VARIANTS: &'static [&'static str] = &["let", "item", "expr", "macro"];
+    const KINDS: &'static [&'static str] = &["StmtLocal", "StmtItem", "StmtExpr", "StmtMacro"];
+    const INLINE: &'static [bool] = &[true, false, false, true];
+    const AMBIGUOUS: &'static [bool] = &[false, false, false, false];
+
+    fn deserialize_variant<'de, D>(index: usize, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => Local::deserialize(deserializer).map(Self::Local),
+            1 => Item::deserialize(deserializer).map(Self::Item),
+            2 => {
+                <(Expr, bool)>::deserialize(deserializer).map(|(expr, semi)| Self::Expr(expr, semi))
+            }
+            3 => StmtMacro::deserialize(deserializer).map(Self::Macro),
+            _ => unreachable!(),
+        }
     }
 }
@@ -0,0 +1,542 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// The internally tagged representation of enums, in which a variant is
+// serialized as a map with a `kind` key (see
+// `SerializeOptions::internally_tagged`), e.g., `{"kind": "ItemFn", ...}`
+// instead of `{"fn": {...}}`.
+//
+// The fields of a variant whose payload is a struct are written next to
+// `kind`. The payload of other variants is written under `value`, e.g.,
+// `{"kind": "ExprVerbatim", "value": [...]}`. Unit variants are still
+// serialized as strings.
+
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, DeserializeSeed, EnumAccess, MapAccess, VariantAccess as _, Visitor},
+    ser::{self, Impossible, SerializeStruct as _},
+};
+
+use super::*;
+use crate::content::{BufferedMap, Content, ContentDeserializer};
+
+const KIND: &str = "kind";
+const VALUE: &str = "value";
+
+/// An adapter enum that can be serialized and deserialized in the internally
+/// tagged representation.
+pub(crate) trait TaggedEnum: Sized {
+    /// The names of the variants in the externally tagged representation.
+    const VARIANTS: &'static [&'static str];
+    /// The kinds of the variants, e.g., `ItemFn` for `Item::Fn`.
+    const KINDS: &'static [&'static str];
+    /// Whether the payload of each variant is a struct, whose fields are
+    /// written next to `kind` instead of under `value`.
+    const INLINE: &'static [bool];
+    /// Whether the name of each variant is also the key of a field that is
+    /// written next to `kind`, so a map whose first key is that name is
+    /// buffered until it is known which representation it is in.
+    const AMBIGUOUS: &'static [bool];
+
+    /// Deserializes the payload of the variant at `index`.
+    fn deserialize_variant<'de, D>(index: usize, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+// -----------------------------------------------------------------------------
+// Serialize
+
+/// Serializes the variants of `T` in the representation selected by the
+/// options.
+///
+/// This has the methods of `Serializer` that the `Ser` impls of enums call.
+pub(crate) struct EnumSerializer<S, T> {
+    serializer: S,
+    tagged: bool,
+    fields_as_tuple: bool,
+    _enum: PhantomData<fn() -> T>,
+}
+
+impl<S, T> EnumSerializer<S, T>
+where
+    S: Serializer,
+    T: TaggedEnum,
+{
+    pub(crate) fn new(serializer: S, options: SerializeOptions) -> Self {
+        let tagged = options.is_internally_tagged();
+        let fields_as_tuple = tagged || options.has_singleton_maps();
+        Self { serializer, tagged, fields_as_tuple, _enum: PhantomData }
+    }
+
+    /// Returns whether the fields of a variant with more than one field are
+    /// passed as a tuple to `serialize_newtype_variant` instead of to
+    /// `serialize_tuple_variant`.
+    pub(crate) fn fields_as_tuple(&self) -> bool {
+        self.fields_as_tuple
+    }
+
+    pub(crate) fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        self.serializer.serialize_unit_variant(name, index, variant)
+    }
+
+    pub(crate) fn serialize_newtype_variant<V>(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        value: &V,
+    ) -> Result<S::Ok, S::Error>
+    where
+        V: ?Sized + Serialize,
+    {
+        if !self.tagged {
+            return self.serializer.serialize_newtype_variant(name, index, variant, value);
+        }
+        let kind = T::KINDS[index as usize];
+        if T::INLINE[index as usize] {
+            value.serialize(InlineSerializer { serializer: self.serializer, kind })
+        } else {
+            let mut state = self.serializer.serialize_struct(name, 2)?;
+            state.serialize_field(KIND, kind)?;
+            state.serialize_field(VALUE, value)?;
+            state.end()
+        }
+    }
+
+    pub(crate) fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<S::SerializeTupleVariant, S::Error> {
+        // Callers pass the fields as a tuple to `serialize_newtype_variant`
+        // when `fields_as_tuple` returns true.
+        debug_assert!(!self.fields_as_tuple);
+        self.serializer.serialize_tuple_variant(name, index, variant, len)
+    }
+}
+
+/// Serializes a struct with `kind` as its first field.
+struct InlineSerializer<S> {
+    serializer: S,
+    kind: &'static str,
+}
+
+impl<S> InlineSerializer<S>
+where
+    S: Serializer,
+{
+    fn unsupported(&self) -> S::Error {
+        ser::Error::custom(format_args!("the payload of `{}` is not a struct", self.kind))
+    }
+}
+
+macro_rules! unsupported {
+    ($($method:ident($($ty:ty),*)),* $(,)?) => {$(
+        fn $method(self, $(_: $ty),*) -> Result<S::Ok, S::Error> {
+            Err(self.unsupported())
+        }
+    )*};
+}
+
+impl<S> Serializer for InlineSerializer<S>
+where
+    S: Serializer,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Impossible<S::Ok, S::Error>;
+    type SerializeTuple = Impossible<S::Ok, S::Error>;
+    type SerializeTupleStruct = Impossible<S::Ok, S::Error>;
+    type SerializeTupleVariant = Impossible<S::Ok, S::Error>;
+    type SerializeMap = S::SerializeMap;
+    type SerializeStruct = S::SerializeStruct;
+    type SerializeStructVariant = Impossible<S::Ok, S::Error>;
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        let mut state = self.serializer.serialize_struct(name, len + 1)?;
+        state.serialize_field(KIND, self.kind)?;
+        Ok(state)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        let mut map = self.serializer.serialize_map(len.map(|len| len + 1))?;
+        map.serialize_entry(KIND, self.kind)?;
+        Ok(map)
+    }
+
+    unsupported! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    }
+
+    fn serialize_some<T>(self, _: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(self.unsupported())
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, _: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(self.unsupported())
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(self.unsupported())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(self.unsupported())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Deserialize
+
+/// Deserializes `T` from either the externally tagged or the internally
+/// tagged representation, or from the index of its variant in the binary
+/// format.
+///
+/// In the internally tagged representation, `kind` may be any key of the map.
+/// The entries before it are buffered, so writing it first is faster.
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: TaggedEnum,
+    D: Deserializer<'de>,
+{
+    hint::deserialize(deserializer, hint::Hint::Enum, EnumVisitor(PhantomData))
+}
+
+struct EnumVisitor<T>(PhantomData<fn() -> T>);
+
+impl<'de, T> Visitor<'de> for EnumVisitor<T>
+where
+    T: TaggedEnum,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a variant name or a map")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let index = variant_index(v, T::VARIANTS)?;
+        T::deserialize_variant(index, de::value::UnitDeserializer::new())
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    // The binary format, which writes the index of the variant.
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (index, variant) = data.variant::<u32>()?;
+        let index = index as usize;
+        if index >= T::VARIANTS.len() {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(index as u64),
+                &"the index of a variant",
+            ));
+        }
+        variant.newtype_variant_seed(VariantSeed(index, PhantomData))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let Some(first) = map.next_key_seed(KeySeed(T::VARIANTS))? else {
+            return Err(de::Error::invalid_length(0, &"a map with a variant name or `kind`"));
+        };
+        // The entries before `kind`, which are buffered until the variant is
+        // known.
+        let mut buffered = Vec::new();
+        // The first key, if it is neither a variant name nor `kind`.
+        let mut unknown = None;
+        let mut key = match first {
+            Key::Kind => Key::Kind,
+            Key::Variant(index) if !T::AMBIGUOUS[index] => {
+                let value = map.next_value_seed(VariantSeed(index, PhantomData))?;
+                return end(&mut map, value);
+            }
+            // The name of the variant is also the key of a field written next
+            // to `kind`, so the map is externally tagged only if this is its
+            // only key.
+            Key::Variant(index) => {
+                let value: Content = map.next_value()?;
+                match map.next_key_seed(KeySeed(T::VARIANTS))? {
+                    None => {
+                        let deserializer = ContentDeserializer::new(value);
+                        return VariantSeed(index, PhantomData).deserialize(deserializer);
+                    }
+                    Some(key) => {
+                        buffered.push((Content::String(T::VARIANTS[index].into()), value));
+                        key
+                    }
+                }
+            }
+            Key::Field(field) => {
+                unknown = Some(field.clone());
+                Key::Field(field)
+            }
+        };
+        loop {
+            let field = match key {
+                Key::Kind => break,
+                Key::Variant(index) => T::VARIANTS[index].into(),
+                Key::Field(field) => field,
+            };
+            buffered.push((Content::String(field), map.next_value::<Content>()?));
+            key = match map.next_key_seed(KeySeed(T::VARIANTS))? {
+                Some(key) => key,
+                // A map with neither a variant name nor `kind`.
+                None => {
+                    return Err(match unknown {
+                        Some(key) => de::Error::unknown_variant(&key, T::VARIANTS),
+                        None => de::Error::missing_field(KIND),
+                    });
+                }
+            };
+        }
+        let index = map.next_value_seed(KindSeed(T::KINDS))?;
+        let mut map = BufferedMap::new(buffered, &mut map);
+        let payload = Payload { map: &mut map, inline: T::INLINE[index] };
+        let value = T::deserialize_variant(index, payload)?;
+        end(&mut map, value)
+    }
+}
+
+/// Returns `value` if there are no keys after the variant in `map`.
+fn end<'de, A, T>(map: &mut A, value: T) -> Result<T, A::Error>
+where
+    A: MapAccess<'de>,
+{
+    if let Some(key) = map.next_key::<String>()? {
+        return Err(de::Error::custom(format_args!("unexpected key `{key}` after the variant")));
+    }
+    Ok(value)
+}
+
+fn variant_index<E>(v: &str, variants: &'static [&'static str]) -> Result<usize, E>
+where
+    E: de::Error,
+{
+    variants.iter().position(|&variant| variant == v).ok_or_else(|| E::unknown_variant(v, variants))
+}
+
+enum Key {
+    Variant(usize),
+    Kind,
+    /// Another key, which is a field of the variant in the internally tagged
+    /// representation.
+    Field(String),
+}
+
+struct KeySeed(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for KeySeed {
+    type Value = Key;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl Visitor<'_> for KeySeed {
+    type Value = Key;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a variant name or `kind`")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(if v == KIND {
+            Key::Kind
+        } else if let Some(index) = self.0.iter().position(|&variant| variant == v) {
+            Key::Variant(index)
+        } else {
+            Key::Field(v.into())
+        })
+    }
+}
+
+struct KindSeed(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for KindSeed {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl Visitor<'_> for KindSeed {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a kind")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        variant_index(v, self.0)
+    }
+}
+
+struct VariantSeed<T>(usize, PhantomData<fn() -> T>);
+
+impl<'de, T> DeserializeSeed<'de> for VariantSeed<T>
+where
+    T: TaggedEnum,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize_variant(self.0, deserializer)
+    }
+}
+
+/// The payload of a variant in the internally tagged representation: the rest
+/// of the map after `kind`.
+struct Payload<'a, A> {
+    map: &'a mut A,
+    inline: bool,
+}
+
+impl<'de, A> Deserializer<'de> for Payload<'_, A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.inline {
+            return visitor.visit_map(self.map);
+        }
+        match self.map.next_key::<String>()? {
+            // A unit variant, e.g., `{"kind": "TypeInfer"}`.
+            None => visitor.visit_unit(),
+            Some(key) if key == VALUE => self.map.next_value_seed(AnySeed(visitor)),
+            Some(key) => Err(de::Error::unknown_field(&key, &[VALUE])),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct AnySeed<V>(V);
+
+impl<'de, V> DeserializeSeed<'de> for AnySeed<V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self.0)
+    }
+}
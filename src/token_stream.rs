@@ -6,49 +6,171 @@ use alloc::{
     string::{String, ToString as _},
     vec::Vec,
 };
-use core::fmt::{self, Write as _};
+use core::{
+    ffi::CStr,
+    fmt::{self, Write as _},
+};
 
 use super::*;
 
-ast_struct! {
-    /// An adapter for [`struct@proc_macro2::TokenStream`].
-    #[derive(Clone, Default)]
-    #[serde(transparent)]
-    pub struct TokenStream {
-        inner: Vec<TokenTree>,
-    }
+/// An adapter for [`struct@proc_macro2::TokenStream`].
+///
+/// A token stream is represented by an array of token trees. Deserialization
+/// also accepts the source text of the tokens (see
+/// [`SerializeOptions::token_text`]) and rejects text that cannot be lexed.
+#[derive(Clone, Default, Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, Hash))]
+#[serde(transparent)]
+pub struct TokenStream {
+    inner: Vec<TokenTree>,
 }
 
 impl TokenStream {
-    fn _new(inner: Vec<TokenTree>) -> Self {
-        Self { inner }
+    /// Creates a token stream from a list of token trees.
+    #[must_use]
+    pub fn new(trees: Vec<TokenTree>) -> Self {
+        Self { inner: trees }
+    }
+
+    /// Returns the token trees of this token stream.
+    #[must_use]
+    pub fn trees(&self) -> &[TokenTree] {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the token trees of this token stream.
+    pub fn trees_mut(&mut self) -> &mut Vec<TokenTree> {
+        &mut self.inner
     }
 }
 
-ast_enum! {
-    /// An adapter for [`enum@proc_macro2::TokenTree`].
-    #[derive(Clone)]
-    pub enum TokenTree {
-        Group(Group),
-        Ident(Ident),
-        Punct(Punct),
-        #[serde(rename = "lit")]
-        Literal(Literal),
+impl<'de> Deserialize<'de> for TokenStream {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct TokenStreamVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for TokenStreamVisitor {
+            type Value = TokenStream;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an array of token trees or a string")
+            }
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let tokens: proc_macro2::TokenStream = v
+                    .parse()
+                    .map_err(|e| E::custom(format!("`{v}` is not a valid token stream: {e}")))?;
+                Ok((&without_spans(tokens)).into())
+            }
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let seq = serde::de::value::SeqAccessDeserializer::new(seq);
+                Vec::deserialize(seq).map(TokenStream::new)
+            }
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_any(self)
+            }
+        }
+
+        hint::deserialize(deserializer, hint::Hint::Seq, TokenStreamVisitor)
+    }
+}
+
+/// Sets the spans of `tokens` lexed from a string to `Span::call_site()`, so
+/// their locations in that string are not recorded as locations in the source.
+fn without_spans(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            proc_macro2::TokenTree::Group(g) => {
+                proc_macro2::Group::new(g.delimiter(), without_spans(g.stream())).into()
+            }
+            mut tt => {
+                tt.set_span(Span::call_site());
+                tt
+            }
+        })
+        .collect()
+}
+
+/// An adapter for [`enum@proc_macro2::TokenTree`].
+#[derive(Clone, Serialize)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, Hash))]
+#[serde(rename_all = "snake_case")]
+pub enum TokenTree {
+    Group(Group),
+    Ident(Ident),
+    Punct(Punct),
+    #[serde(rename = "lit")]
+    Literal(Literal),
+}
+
+impl<'de> Deserialize<'de> for TokenTree {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        tagged::deserialize(deserializer)
+    }
+}
+
+impl tagged::TaggedEnum for TokenTree {
+    const VARIANTS: &'static [&'static str] = &["group", "ident", "punct", "lit"];
+    const KINDS: &'static [&'static str] =
+        &["TokenTreeGroup", "TokenTreeIdent", "TokenTreePunct", "TokenTreeLiteral"];
+    const INLINE: &'static [bool] = &[true, false, true, false];
+    const AMBIGUOUS: &'static [bool] = &[false, false, false, false];
+
+    fn deserialize_variant<'de, D>(index: usize, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => Group::deserialize(deserializer).map(Self::Group),
+            1 => Ident::deserialize(deserializer).map(Self::Ident),
+            2 => Punct::deserialize(deserializer).map(Self::Punct),
+            3 => Literal::deserialize(deserializer).map(Self::Literal),
+            _ => unreachable!(),
+        }
     }
 }
 
 ast_struct! {
     /// An adapter for [`struct@proc_macro2::Group`].
     #[derive(Clone)]
+    #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, Hash))]
     pub struct Group {
-        delimiter: Delimiter,
-        stream: TokenStream,
+        /// Corresponds to [`proc_macro2::Group::delimiter`].
+        pub delimiter: Delimiter,
+        /// Corresponds to [`proc_macro2::Group::stream`].
+        pub stream: TokenStream,
+        /// The location of this token, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub span: Option<Location>,
+    }
+}
+
+impl Group {
+    /// Creates a group with the given delimiter and tokens.
+    #[must_use]
+    pub fn new(delimiter: Delimiter, stream: TokenStream) -> Self {
+        Self { delimiter, stream, span: None }
     }
 }
 
 ast_enum! {
     /// An adapter for [`enum@proc_macro2::Delimiter`].
     #[derive(Clone, Copy)]
+    #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, Hash))]
     pub enum Delimiter {
         Parenthesis,
         Brace,
@@ -60,46 +182,231 @@ ast_enum! {
 ast_struct! {
     /// An adapter for [`struct@proc_macro2::Punct`].
     #[derive(Clone, Copy)]
+    #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, Hash))]
     pub struct Punct {
-        op: char,
-        spacing: Spacing,
+        /// Corresponds to [`proc_macro2::Punct::as_char`].
+        #[serde(deserialize_with = "deserialize_punct_char")]
+        pub op: char,
+        /// Corresponds to [`proc_macro2::Punct::spacing`].
+        pub spacing: Spacing,
+        /// The location of this token, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub span: Option<Location>,
+    }
+}
+
+impl Punct {
+    /// Creates a punctuation character with the given spacing.
+    ///
+    /// Unlike [`proc_macro2::Punct::new`], this does not check that `op` is
+    /// a valid punctuation character. It is checked when converting to a
+    /// syntax tree.
+    #[must_use]
+    pub fn new(op: char, spacing: Spacing) -> Self {
+        Self { op, spacing, span: None }
     }
 }
 
 ast_enum! {
     /// An adapter for [`enum@proc_macro2::Spacing`].
     #[derive(Clone, Copy)]
+    #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, Hash))]
     pub enum Spacing {
         Alone,
         Joint,
     }
 }
 
-ast_struct! {
-    /// An adapter for [`struct@proc_macro2::Ident`].
-    #[derive(Clone, Eq, PartialEq)]
-    #[serde(transparent)]
-    pub struct Ident {
-        inner: String,
+/// Returns whether `c` is a character accepted by `proc_macro2::Punct::new`,
+/// which panics on others.
+fn is_punct_char(c: char) -> bool {
+    matches!(
+        c,
+        '!' | '#'
+            | '$'
+            | '%'
+            | '&'
+            | '\''
+            | '*'
+            | '+'
+            | ','
+            | '-'
+            | '.'
+            | '/'
+            | ':'
+            | ';'
+            | '<'
+            | '='
+            | '>'
+            | '?'
+            | '@'
+            | '^'
+            | '|'
+            | '~'
+    )
+}
+
+fn deserialize_punct_char<'de, D>(deserializer: D) -> Result<char, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let c = char::deserialize(deserializer)?;
+    if !is_punct_char(c) {
+        return Err(serde::de::Error::custom(format!(
+            "{c:?} is not a valid punctuation character"
+        )));
     }
+    Ok(c)
 }
 
-ast_struct! {
-    /// An adapter for [`struct@proc_macro2::Literal`].
-    #[derive(Clone)]
-    #[serde(transparent)]
-    pub struct Literal {
-        pub(crate) text: String,
+/// An adapter for [`struct@proc_macro2::Ident`].
+///
+/// An identifier is represented by its string. Raw identifiers keep their
+/// `r#` prefix, e.g., `"r#type"`. If its location is known, it is represented
+/// by an object with the string in `name` and the location in `span`.
+///
+/// Deserialization rejects strings that are not identifiers.
+///
+/// As with [`struct@proc_macro2::Ident`], two identifiers are equal if their
+/// strings are equal, regardless of their locations.
+#[derive(Clone)]
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub struct Ident {
+    inner: String,
+    /// The location of this identifier, if known.
+    pub span: Option<Location>,
+}
+
+impl PartialEq for Ident {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl Eq for Ident {}
+
+#[cfg(feature = "extra-traits")]
+impl core::hash::Hash for Ident {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: core::hash::Hasher,
+    {
+        self.inner.hash(state);
+    }
+}
+
+impl Ident {
+    /// Creates an identifier from its string, e.g., `"foo"` or `"r#type"`.
+    ///
+    /// The string is not checked here: a string that is not a valid identifier
+    /// is rejected when the adapter is converted into a syntax tree.
+    #[must_use]
+    pub fn new(string: &str) -> Self {
+        Self { inner: string.to_owned(), span: None }
+    }
+
+    /// Creates a raw identifier from its string without the `r#` prefix, e.g.,
+    /// `"type"` for `r#type`.
+    ///
+    /// The string is not checked here, as with [`new`](Self::new).
+    #[must_use]
+    pub fn new_raw(string: &str) -> Self {
+        Self { inner: format!("r#{string}"), span: None }
+    }
+
+    /// Returns the string of this identifier.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Returns whether this is a raw identifier, e.g., `r#type`.
+    #[must_use]
+    pub fn is_raw(&self) -> bool {
+        self.inner.starts_with("r#")
     }
 }
 
+impl Serialize for Ident {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_text(serializer, "Ident", "name", &self.inner, self.span, SerializeOptions::new())
+    }
+}
+
+impl<'de> Deserialize<'de> for Ident {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (inner, span) = deserialize_text(deserializer, &["name", "span"])?;
+        let ident = Self { inner, span };
+        if !is_ident(&ident.inner) {
+            return Err(serde::de::Error::custom(format!(
+                "`{}` is not a valid identifier",
+                ident.inner
+            )));
+        }
+        Ok(ident)
+    }
+}
+
+/// An adapter for [`struct@proc_macro2::Literal`].
+///
+/// A literal is represented by its source text. If its location is known, it
+/// is represented by an object with the text in `text` and the location in
+/// `span`.
+///
+/// Deserialization rejects text that is not exactly one literal.
+#[derive(Clone)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, Hash))]
+pub struct Literal {
+    pub(crate) text: String,
+    /// The location of this literal, if known.
+    pub span: Option<Location>,
+}
+
 impl Literal {
     fn _new(text: String) -> Self {
-        Self { text }
+        Self { text, span: None }
     }
 
-    pub(crate) fn u8_suffixed(n: u8) -> Self {
-        Self::_new(format!(concat!("{}", stringify!(u8)), n))
+    /// Creates a literal from its source text, e.g., `"1u8"` or `"\"foo\""`.
+    ///
+    /// The text is not checked here: text that is not exactly one literal is
+    /// rejected when the adapter is converted into a syntax tree.
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        Self::_new(text.to_owned())
+    }
+
+    /// Returns the source text of this literal.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the literal token spelled by `self.text`, or `None` if the text
+    /// is not exactly one literal.
+    pub(crate) fn to_token(&self) -> Option<proc_macro2::Literal> {
+        self.text.parse().ok()
+    }
+
+    #[allow(clippy::match_overlapping_arm)]
+    pub(crate) fn byte_character(byte: u8) -> Self {
+        let escaped = match byte {
+            b'\0' => r"\0".to_owned(),
+            b'\t' => r"\t".to_owned(),
+            b'\n' => r"\n".to_owned(),
+            b'\r' => r"\r".to_owned(),
+            b'\'' => r"\'".to_owned(),
+            b'\\' => r"\\".to_owned(),
+            b'\x20'..=b'\x7E' => (byte as char).to_string(),
+            _ => format!("\\x{byte:02X}"),
+        };
+        Self::_new(format!("b'{escaped}'"))
     }
 
     pub(crate) fn string(t: &str) -> Self {
@@ -133,6 +440,59 @@ impl Literal {
         escaped.push('"');
         Self::_new(escaped)
     }
+
+    #[allow(clippy::match_overlapping_arm)]
+    pub(crate) fn c_string(t: &CStr) -> Self {
+        let mut escaped = "c\"".to_owned();
+        for b in t.to_bytes() {
+            match *b {
+                b'\t' => escaped.push_str(r"\t"),
+                b'\n' => escaped.push_str(r"\n"),
+                b'\r' => escaped.push_str(r"\r"),
+                b'"' => escaped.push_str("\\\""),
+                b'\\' => escaped.push_str("\\\\"),
+                b'\x20'..=b'\x7E' => escaped.push(*b as char),
+                _ => {
+                    let _ = write!(escaped, "\\x{b:02X}");
+                }
+            }
+        }
+        escaped.push('"');
+        Self::_new(escaped)
+    }
+}
+
+impl Serialize for Literal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_text(
+            serializer,
+            "Literal",
+            "text",
+            &self.text,
+            self.span,
+            SerializeOptions::new(),
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for Literal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (text, span) = deserialize_text(deserializer, &["text", "span"])?;
+        let literal = Self { text, span };
+        if literal.to_token().is_none() {
+            return Err(serde::de::Error::custom(format!(
+                "`{}` is not a valid literal",
+                literal.text
+            )));
+        }
+        Ok(literal)
+    }
 }
 
 // TODO(semver): when release the next breaking release, remove this.
@@ -142,6 +502,93 @@ impl fmt::Display for Literal {
     }
 }
 
+/// Serializes the text of an identifier or a literal as a string, or as an
+/// object with the text in `field` if its location is known. The binary format
+/// always writes a pair of the text and the location.
+fn serialize_text<S, T>(
+    serializer: S,
+    name: &'static str,
+    field: &'static str,
+    text: &T,
+    span: Option<Location>,
+    options: SerializeOptions,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: ?Sized + fmt::Display,
+{
+    if options.is_binary() {
+        return (text.to_string(), span).serialize(serializer);
+    }
+    let Some(span) = span else { return serializer.collect_str(text) };
+    let mut state = serializer.serialize_struct(name, 2)?;
+    state.serialize_field(field, &text.to_string())?;
+    state.serialize_field("span", &span)?;
+    state.end()
+}
+
+/// Deserializes the representation written by `serialize_text`. `fields` are
+/// the field of the text and `span`.
+fn deserialize_text<'de, D>(
+    deserializer: D,
+    fields: &'static [&'static str; 2],
+) -> Result<(String, Option<Location>), D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct TextVisitor(&'static [&'static str; 2]);
+
+    impl<'de> serde::de::Visitor<'de> for TextVisitor {
+        type Value = (String, Option<Location>);
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a string or an object with `{}` and `span`", self.0[0])
+        }
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+            Ok((v.to_owned(), None))
+        }
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+            Ok((v, None))
+        }
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            let (mut text, mut span) = (None, None);
+            while let Some(key) = map.next_key::<String>()? {
+                if key == self.0[0] {
+                    text = Some(map.next_value()?);
+                } else if key == "span" {
+                    span = map.next_value()?;
+                } else {
+                    return Err(serde::de::Error::unknown_field(&key, self.0));
+                }
+            }
+            let text = text.ok_or_else(|| serde::de::Error::missing_field(self.0[0]))?;
+            Ok((text, span))
+        }
+        // The binary format, which always writes the text and the location.
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            let text =
+                seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+            let span =
+                seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+            Ok((text, span))
+        }
+        fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+    }
+
+    hint::deserialize(deserializer, hint::Hint::Pair, TextVisitor(fields))
+}
+
 mod convert {
     use super::*;
 
@@ -149,15 +596,52 @@ mod convert {
     syn_trait_impl!(proc_macro2::TokenStream);
     impl From<&proc_macro2::TokenStream> for TokenStream {
         fn from(other: &proc_macro2::TokenStream) -> Self {
-            Self::_new(other.clone().into_iter().map::<TokenTree, _>(|x| x.ref_into()).collect())
+            Self::new(other.clone().into_iter().map::<TokenTree, _>(|x| x.ref_into()).collect())
+        }
+    }
+    impl TryFromRef<TokenStream> for proc_macro2::TokenStream {
+        fn try_from_ref(other: &TokenStream) -> Result<Self, Error> {
+            other
+                .inner
+                .iter()
+                .map::<Result<proc_macro2::TokenTree, _>, _>(TryRefInto::try_ref_into)
+                .collect()
         }
     }
-    impl From<&TokenStream> for proc_macro2::TokenStream {
-        fn from(other: &TokenStream) -> Self {
-            other.inner.iter().map::<proc_macro2::TokenTree, _>(Into::into).collect()
+    impl Serialize for Ser<'_, proc_macro2::TokenStream> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if self.1.has_token_text() {
+                return match source_text(self.0) {
+                    Some(text) => serializer.serialize_str(&text),
+                    None => serializer.collect_str(self.0),
+                };
+            }
+            let len = self.0.clone().into_iter().count();
+            let mut seq = serializer.serialize_seq(Some(len))?;
+            for tt in self.0.clone() {
+                seq.serialize_element(&Ser(&tt, self.1))?;
+            }
+            seq.end()
         }
     }
 
+    /// Returns the source text of `tokens` if their spans point to it and it
+    /// is lexed into the same tokens.
+    fn source_text(tokens: &proc_macro2::TokenStream) -> Option<String> {
+        let mut iter = tokens.clone().into_iter();
+        let first = iter.next()?.span();
+        let span = match iter.last() {
+            Some(last) => first.join(last.span())?,
+            None => first,
+        };
+        let text = span.source_text()?;
+        let lexed: proc_macro2::TokenStream = text.parse().ok()?;
+        (lexed.to_string() == tokens.to_string()).then_some(text)
+    }
+
     // TokenTree
     syn_trait_impl!(proc_macro2::TokenTree);
     impl From<&proc_macro2::TokenTree> for TokenTree {
@@ -171,14 +655,36 @@ mod convert {
             }
         }
     }
-    impl From<&TokenTree> for proc_macro2::TokenTree {
-        fn from(other: &TokenTree) -> Self {
+    impl TryFromRef<TokenTree> for proc_macro2::TokenTree {
+        fn try_from_ref(other: &TokenTree) -> Result<Self, Error> {
             use proc_macro2::TokenTree::*;
-            match other {
-                TokenTree::Group(t) => Group(t.into()),
-                TokenTree::Ident(t) => Ident(t.into()),
-                TokenTree::Punct(t) => Punct(t.into()),
-                TokenTree::Literal(t) => Literal(t.into()),
+            Ok(match other {
+                TokenTree::Group(t) => Group(t.try_ref_into()?),
+                TokenTree::Ident(t) => Ident(t.try_ref_into()?),
+                TokenTree::Punct(t) => Punct(t.try_ref_into()?),
+                TokenTree::Literal(t) => Literal(t.try_ref_into()?),
+            })
+        }
+    }
+    impl Serialize for Ser<'_, proc_macro2::TokenTree> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let serializer = tagged::EnumSerializer::<_, TokenTree>::new(serializer, self.1);
+            match self.0 {
+                proc_macro2::TokenTree::Group(t) => {
+                    serializer.serialize_newtype_variant("TokenTree", 0, "group", &Ser(t, self.1))
+                }
+                proc_macro2::TokenTree::Ident(t) => {
+                    serializer.serialize_newtype_variant("TokenTree", 1, "ident", &Ser(t, self.1))
+                }
+                proc_macro2::TokenTree::Punct(t) => {
+                    serializer.serialize_newtype_variant("TokenTree", 2, "punct", &Ser(t, self.1))
+                }
+                proc_macro2::TokenTree::Literal(t) => {
+                    serializer.serialize_newtype_variant("TokenTree", 3, "lit", &Ser(t, self.1))
+                }
             }
         }
     }
@@ -187,12 +693,34 @@ mod convert {
     syn_trait_impl!(proc_macro2::Group);
     impl From<&proc_macro2::Group> for Group {
         fn from(other: &proc_macro2::Group) -> Self {
-            Self { delimiter: other.delimiter().ref_into(), stream: other.stream().ref_into() }
+            Self {
+                delimiter: other.delimiter().ref_into(),
+                stream: other.stream().ref_into(),
+                span: None,
+            }
         }
     }
-    impl From<&Group> for proc_macro2::Group {
-        fn from(other: &Group) -> Self {
-            Self::new(other.delimiter.ref_into(), other.stream.ref_into())
+    impl TryFromRef<Group> for proc_macro2::Group {
+        fn try_from_ref(other: &Group) -> Result<Self, Error> {
+            Ok(Self::new(other.delimiter.try_ref_into()?, other.stream.try_ref_into()?))
+        }
+    }
+    impl Serialize for Ser<'_, proc_macro2::Group> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let span = Location::from_span(self.0.span(), self.1);
+            let keep_span = self.1.is_binary() || span.is_some();
+            let mut state = serializer.serialize_struct("Group", 2 + usize::from(keep_span))?;
+            state.serialize_field("delimiter", &Ser(&self.0.delimiter(), self.1))?;
+            state.serialize_field("stream", &Ser(&self.0.stream(), self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            state.end()
         }
     }
 
@@ -209,15 +737,29 @@ mod convert {
             }
         }
     }
-    impl From<&Delimiter> for proc_macro2::Delimiter {
-        fn from(other: &Delimiter) -> Self {
+    impl TryFromRef<Delimiter> for proc_macro2::Delimiter {
+        fn try_from_ref(other: &Delimiter) -> Result<Self, Error> {
             use proc_macro2::Delimiter::*;
-            match other {
+            Ok(match other {
                 Delimiter::Parenthesis => Parenthesis,
                 Delimiter::Brace => Brace,
                 Delimiter::Bracket => Bracket,
                 Delimiter::None => None,
-            }
+            })
+        }
+    }
+    impl Serialize for Ser<'_, proc_macro2::Delimiter> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let (index, variant) = match self.0 {
+                proc_macro2::Delimiter::Parenthesis => (0, "parenthesis"),
+                proc_macro2::Delimiter::Brace => (1, "brace"),
+                proc_macro2::Delimiter::Bracket => (2, "bracket"),
+                proc_macro2::Delimiter::None => (3, "none"),
+            };
+            serializer.serialize_unit_variant("Delimiter", index, variant)
         }
     }
 
@@ -225,12 +767,36 @@ mod convert {
     syn_trait_impl!(proc_macro2::Ident);
     impl From<&proc_macro2::Ident> for Ident {
         fn from(other: &proc_macro2::Ident) -> Self {
-            Self { inner: other.to_string() }
+            Self { inner: other.to_string(), span: None }
         }
     }
-    impl From<&Ident> for proc_macro2::Ident {
-        fn from(other: &Ident) -> Self {
-            Self::new(&other.inner, Span::call_site())
+    impl TryFromRef<Ident> for proc_macro2::Ident {
+        fn try_from_ref(other: &Ident) -> Result<Self, Error> {
+            if !is_ident(&other.inner) {
+                return Err(Error::new(
+                    "Ident",
+                    format!("`{}` is not a valid identifier", other.inner),
+                ));
+            }
+            Ok(match other.inner.strip_prefix("r#") {
+                Some(raw) => Self::new_raw(raw, Span::call_site()),
+                None => Self::new(&other.inner, Span::call_site()),
+            })
+        }
+    }
+    impl Serialize for Ser<'_, proc_macro2::Ident> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_text(
+                serializer,
+                "Ident",
+                "name",
+                self.0,
+                Location::from_span(self.0.span(), self.1),
+                self.1,
+            )
         }
     }
 
@@ -238,12 +804,36 @@ mod convert {
     syn_trait_impl!(proc_macro2::Punct);
     impl From<&proc_macro2::Punct> for Punct {
         fn from(other: &proc_macro2::Punct) -> Self {
-            Self { op: other.as_char(), spacing: other.spacing().ref_into() }
+            Self { op: other.as_char(), spacing: other.spacing().ref_into(), span: None }
         }
     }
-    impl From<&Punct> for proc_macro2::Punct {
-        fn from(other: &Punct) -> Self {
-            Self::new(other.op, other.spacing.ref_into())
+    impl TryFromRef<Punct> for proc_macro2::Punct {
+        fn try_from_ref(other: &Punct) -> Result<Self, Error> {
+            if !is_punct_char(other.op) {
+                return Err(Error::new(
+                    "Punct",
+                    format!("{:?} is not a valid punctuation character", other.op),
+                ));
+            }
+            Ok(Self::new(other.op, other.spacing.try_ref_into()?))
+        }
+    }
+    impl Serialize for Ser<'_, proc_macro2::Punct> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let span = Location::from_span(self.0.span(), self.1);
+            let keep_span = self.1.is_binary() || span.is_some();
+            let mut state = serializer.serialize_struct("Punct", 2 + usize::from(keep_span))?;
+            state.serialize_field("op", &self.0.as_char())?;
+            state.serialize_field("spacing", &Ser(&self.0.spacing(), self.1))?;
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+            state.end()
         }
     }
 
@@ -258,12 +848,27 @@ mod convert {
             }
         }
     }
-    impl From<&Spacing> for proc_macro2::Spacing {
-        fn from(other: &Spacing) -> Self {
+    impl TryFromRef<Spacing> for proc_macro2::Spacing {
+        fn try_from_ref(other: &Spacing) -> Result<Self, Error> {
             use proc_macro2::Spacing::*;
-            match other {
+            Ok(match other {
                 Spacing::Alone => Alone,
                 Spacing::Joint => Joint,
+            })
+        }
+    }
+    impl Serialize for Ser<'_, proc_macro2::Spacing> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self.0 {
+                proc_macro2::Spacing::Alone => {
+                    serializer.serialize_unit_variant("Spacing", 0, "alone")
+                }
+                proc_macro2::Spacing::Joint => {
+                    serializer.serialize_unit_variant("Spacing", 1, "joint")
+                }
             }
         }
     }
@@ -272,17 +877,51 @@ mod convert {
     syn_trait_impl!(proc_macro2::Literal);
     impl From<&proc_macro2::Literal> for Literal {
         fn from(other: &proc_macro2::Literal) -> Self {
-            Self { text: other.to_string() }
+            Self { text: other.to_string(), span: None }
         }
     }
-    impl From<&Literal> for proc_macro2::Literal {
-        fn from(other: &Literal) -> Self {
-            use proc_macro2::*;
-            let stream = other.text.parse::<TokenStream>().unwrap();
-            match stream.into_iter().next().unwrap() {
-                TokenTree::Literal(l) => l,
-                _ => unreachable!(),
-            }
+    impl TryFromRef<Literal> for proc_macro2::Literal {
+        fn try_from_ref(other: &Literal) -> Result<Self, Error> {
+            other.to_token().ok_or_else(|| {
+                Error::new("Literal", format!("`{}` is not a valid literal", other.text))
+            })
+        }
+    }
+    impl Serialize for Ser<'_, proc_macro2::Literal> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_text(
+                serializer,
+                "Literal",
+                "text",
+                self.0,
+                Location::from_span(self.0.span(), self.1),
+                self.1,
+            )
         }
     }
 }
+
+/// Returns whether `s` is an identifier, including raw identifiers (e.g.,
+/// `r#type`) and keywords.
+fn is_ident(s: &str) -> bool {
+    // Fast path for the common case. `Ident::new` panics on strings that are
+    // not identifiers, so let the lexer decide the others.
+    if s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && s.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+    {
+        return true;
+    }
+    matches!(parse_single_token(s), Some(proc_macro2::TokenTree::Ident(i)) if i == s)
+}
+
+/// Lexes `s` and returns its token if it consists of exactly one token.
+fn parse_single_token(s: &str) -> Option<proc_macro2::TokenTree> {
+    let mut tokens = s.parse::<proc_macro2::TokenStream>().ok()?.into_iter();
+    match (tokens.next(), tokens.next()) {
+        (Some(token), None) => Some(token),
+        _ => None,
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use super::*;
+
+ast_struct! {
+    /// The comments and whitespace of a file or an item.
+    ///
+    /// This is only recorded by `lossless::parse_file` (otherwise the `trivia`
+    /// fields of adapters are always `None`), and is used by
+    /// `lossless::print_file` to reproduce the source code. It is ignored
+    /// when converting an adapter back into a syntax tree.
+    ///
+    /// The trivia of a node is aligned with the tokens that the node prints,
+    /// not including the tokens of the items it contains, which have trivia of
+    /// their own.
+    #[derive(Default)]
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+    pub struct Trivia {
+        /// The source text before each token: whitespace, comments, and
+        /// tokens that are not printed from the syntax tree, such as trailing
+        /// commas.
+        pub leading: Vec<String>,
+        /// Tokens that are spelled differently in the source code than they
+        /// are printed, such as doc comments.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub spellings: Vec<Spelling>,
+        /// The source text after the last item of a file.
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        pub trailing: String,
+        /// Whether a file starts with a byte order mark.
+        #[serde(default, skip_serializing_if = "not")]
+        pub bom: bool,
+    }
+}
+
+ast_struct! {
+    /// Tokens that are spelled differently in the source code than they are
+    /// printed.
+    ///
+    /// For example, the doc comment `/// doc` is printed as the tokens
+    /// `# [doc = " doc"]`.
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
+    pub struct Spelling {
+        /// The index of the first token.
+        pub index: usize,
+        /// The tokens as they are printed. The spelling is only used if the
+        /// node still prints these tokens.
+        pub tokens: Vec<String>,
+        /// The source text of the tokens.
+        pub text: String,
+    }
+}
+
+impl Spelling {
+    /// Creates a spelling of the tokens starting at `index`.
+    #[must_use]
+    pub fn new(index: usize, tokens: Vec<String>, text: String) -> Self {
+        Self { index, tokens, text }
+    }
+}
@@ -14,9 +14,20 @@ pub use crate::{
 ast_struct! {
     /// An adapter for [`enum@syn::ReturnType`].
     #[derive(Default)]
+    #[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))]
     #[serde(transparent)]
     pub struct ReturnType {
-        ty: Option<Box<Type>>,
+        /// The return type, or `None` for the default return type `()`.
+        pub ty: Option<Box<Type>>,
+    }
+}
+
+impl ReturnType {
+    /// Creates an adapter from the return type, or `None` for the default
+    /// return type `()`.
+    #[must_use]
+    pub fn new(ty: Option<Box<Type>>) -> Self {
+        Self { ty }
     }
 }
 
@@ -34,12 +45,25 @@ mod convert {
             }
         }
     }
-    impl From<&ReturnType> for syn::ReturnType {
-        fn from(other: &ReturnType) -> Self {
+    impl TryFromRef<ReturnType> for syn::ReturnType {
+        fn try_from_ref(other: &ReturnType) -> Result<Self, Error> {
             use syn::ReturnType;
-            match &other.ty {
+            Ok(match &other.ty {
                 None => ReturnType::Default,
-                Some(x) => ReturnType::Type(default(), x.map_into()),
+                Some(x) => {
+                    ReturnType::Type(default(), x.try_map_into().context("ReturnType", "ty")?)
+                }
+            })
+        }
+    }
+    impl Serialize for Ser<'_, syn::ReturnType> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self.0 {
+                syn::ReturnType::Default => serializer.serialize_none(),
+                syn::ReturnType::Type(_, x) => serializer.serialize_some(&Ser(x, self.1)),
             }
         }
     }
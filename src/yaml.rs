@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A module to provide functions for YAML <-> Rust serialize and deserialize.
+//!
+//! The data has the same shape as the JSON representation, which keeps
+//! snapshots of syntax trees readable: enum variants with data are written as
+//! mappings with a single key (not as YAML tags), and variants without data
+//! as strings. YAML is always written in block style, so the `*_pretty`
+//! functions, which exist for parity with the other format modules, produce
+//! the same output as the others.
+
+use alloc::{string::String, vec::Vec};
+use std::io;
+
+use serde::de::Error as _;
+use serde_yaml_ng::{Deserializer, Error, Result, with::singleton_map_recursive};
+
+use super::*;
+use crate::singleton_map::SingletonMaps;
+
+fn from_deserializer<S>(deserializer: Deserializer<'_>) -> Result<S>
+where
+    S: Syn,
+{
+    let adapter: S::Adapter = singleton_map_recursive::deserialize(deserializer)?;
+    S::try_from_adapter(&adapter).map_err(Error::custom)
+}
+
+// Serialize [`Syn`] type into YAML data.
+
+/// Serialize the given [`Syn`] type as YAML into the I/O stream.
+pub fn to_writer<S, W>(writer: W, syn: &S) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    to_writer_with_options(writer, syn, SerializeOptions::new())
+}
+
+/// Serialize the given [`Syn`] type as YAML into the I/O stream, with the
+/// given options.
+pub fn to_writer_with_options<S, W>(writer: W, syn: &S, options: SerializeOptions) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    serde_yaml_ng::to_writer(writer, &SingletonMaps::new(syn, options))
+}
+
+/// Serialize the given [`Syn`] type as pretty-printed YAML into the I/O
+/// stream.
+///
+/// This is the same as [`to_writer`].
+pub fn to_writer_pretty<S, W>(writer: W, syn: &S) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    to_writer(writer, syn)
+}
+
+/// Serialize the given [`Syn`] type as pretty-printed YAML into the I/O
+/// stream, with the given options.
+///
+/// This is the same as [`to_writer_with_options`].
+pub fn to_writer_pretty_with_options<S, W>(
+    writer: W,
+    syn: &S,
+    options: SerializeOptions,
+) -> Result<()>
+where
+    S: Syn,
+    W: io::Write,
+{
+    to_writer_with_options(writer, syn, options)
+}
+
+/// Serialize the given [`Syn`] type as a YAML byte vector.
+// All of the data structures in syn-serde are compatible with YAML so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec<S>(syn: &S) -> Vec<u8>
+where
+    S: Syn,
+{
+    to_vec_with_options(syn, SerializeOptions::new())
+}
+
+/// Serialize the given [`Syn`] type as a YAML byte vector, with the given
+/// options.
+// All of the data structures in syn-serde are compatible with YAML so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_vec_with_options<S>(syn: &S, options: SerializeOptions) -> Vec<u8>
+where
+    S: Syn,
+{
+    let mut buf = Vec::new();
+    to_writer_with_options(&mut buf, syn, options).unwrap();
+    buf
+}
+
+/// Serialize the given [`Syn`] type as a pretty-printed YAML byte vector.
+///
+/// This is the same as [`to_vec`].
+pub fn to_vec_pretty<S>(syn: &S) -> Vec<u8>
+where
+    S: Syn,
+{
+    to_vec(syn)
+}
+
+/// Serialize the given [`Syn`] type as a pretty-printed YAML byte vector, with
+/// the given options.
+///
+/// This is the same as [`to_vec_with_options`].
+pub fn to_vec_pretty_with_options<S>(syn: &S, options: SerializeOptions) -> Vec<u8>
+where
+    S: Syn,
+{
+    to_vec_with_options(syn, options)
+}
+
+/// Serialize the given [`Syn`] type as a String of YAML.
+///
+/// # Examples
+///
+/// ```
+/// let item: syn::Item = syn::parse_quote! {
+///     struct Unit;
+/// };
+///
+/// assert_eq!(syn_serde::yaml::to_string(&item), "struct:\n  ident: Unit\n  fields: unit\n");
+/// ```
+// All of the data structures in syn-serde are compatible with YAML so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_string<S>(syn: &S) -> String
+where
+    S: Syn,
+{
+    to_string_with_options(syn, SerializeOptions::new())
+}
+
+/// Serialize the given [`Syn`] type as a String of YAML, with the given
+/// options.
+// All of the data structures in syn-serde are compatible with YAML so unwrap will never fail.
+#[allow(clippy::missing_panics_doc)]
+pub fn to_string_with_options<S>(syn: &S, options: SerializeOptions) -> String
+where
+    S: Syn,
+{
+    serde_yaml_ng::to_string(&SingletonMaps::new(syn, options)).unwrap()
+}
+
+/// Serialize the given [`Syn`] type as a pretty-printed String of YAML.
+///
+/// This is the same as [`to_string`].
+pub fn to_string_pretty<S>(syn: &S) -> String
+where
+    S: Syn,
+{
+    to_string(syn)
+}
+
+/// Serialize the given [`Syn`] type as a pretty-printed String of YAML, with
+/// the given options.
+///
+/// This is the same as [`to_string_with_options`].
+pub fn to_string_pretty_with_options<S>(syn: &S, options: SerializeOptions) -> String
+where
+    S: Syn,
+{
+    to_string_with_options(syn, options)
+}
+
+// Deserialize YAML data to [`Syn`] type.
+
+/// Deserialize an instance of [`Syn`] type from an I/O stream of YAML.
+pub fn from_reader<S, R>(reader: R) -> Result<S>
+where
+    S: Syn,
+    R: io::Read,
+{
+    from_deserializer(Deserializer::from_reader(reader))
+}
+
+/// Deserialize an instance of [`Syn`] type from bytes of YAML text.
+pub fn from_slice<S>(v: &[u8]) -> Result<S>
+where
+    S: Syn,
+{
+    from_deserializer(Deserializer::from_slice(v))
+}
+
+/// Deserialize an instance of [`Syn`] type from a string of YAML text.
+pub fn from_str<S>(s: &str) -> Result<S>
+where
+    S: Syn,
+{
+    from_deserializer(Deserializer::from_str(s))
+}
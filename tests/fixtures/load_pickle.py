@@ -0,0 +1,27 @@
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that a pickle file written by syn_serde::pickle can be loaded by
+# Python and has the same shape as the JSON representation of the syntax tree.
+#
+# Usage: python3 load_pickle.py <pickle_path> <json_path>
+
+import json
+import pickle
+import sys
+
+
+def normalize(value):
+    if isinstance(value, (list, tuple)):
+        return [normalize(v) for v in value]
+    if isinstance(value, dict):
+        return {k: normalize(v) for k, v in value.items()}
+    return value
+
+
+with open(sys.argv[1], "rb") as f:
+    ast = pickle.load(f)
+with open(sys.argv[2], "r", encoding="utf-8") as f:
+    expected = json.load(f)
+
+assert normalize(ast) == expected, "pickle and JSON representations differ"
+assert isinstance(ast["items"], list)
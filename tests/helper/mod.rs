@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(dead_code)]
+
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use syn_serde::Syn as _;
+
+/// The directories whose Rust files are used as test cases.
+///
+/// The generated files in src/gen are not included: they are large and repeat
+/// the same few kinds of items, so they make the tests slow without covering
+/// more syntax.
+pub(crate) const CORPUS_DIRS: &[&str] = &["src", "tests"];
+
+/// Returns the paths of the Rust files in `dirs` of this repository.
+pub(crate) fn source_paths(dirs: &[&str]) -> Vec<PathBuf> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut paths = vec![];
+    for dir in dirs {
+        for entry in fs::read_dir(root.join(dir)).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "rs") {
+                paths.push(path);
+            }
+        }
+    }
+    assert!(!paths.is_empty());
+    paths.sort();
+    paths
+}
+
+/// Returns the syntax trees of the Rust files in `CORPUS_DIRS`, with their
+/// paths.
+pub(crate) fn corpus() -> impl Iterator<Item = (PathBuf, syn::File)> {
+    source_paths(CORPUS_DIRS).into_iter().map(|path| {
+        let file = syn::parse_file(&fs::read_to_string(&path).unwrap()).unwrap();
+        (path, file)
+    })
+}
+
+/// Returns the source code of the test cases in tests/test_item.rs.
+pub(crate) fn item_cases() -> Vec<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/test_item.rs");
+    let file = syn::parse_file(&fs::read_to_string(path).unwrap()).unwrap();
+    let mut cases = vec![];
+    for item in file.items {
+        let syn::Item::Fn(item) = item else { continue };
+        for stmt in item.block.stmts {
+            if let syn::Stmt::Local(syn::Local {
+                pat: syn::Pat::Ident(pat),
+                init: Some(syn::LocalInit { expr, .. }),
+                ..
+            }) = stmt
+            {
+                if let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) = *expr {
+                    if pat.ident == "raw" {
+                        cases.push(lit.value());
+                    }
+                }
+            }
+        }
+    }
+    assert!(!cases.is_empty());
+    cases
+}
+
+/// Encodes `file` with `encode`, checks that `decode` reads it back, and
+/// returns the encoded data.
+///
+/// Converting a syntax tree to an adapter does not keep, e.g., trailing
+/// punctuation, so the decoded syntax tree is compared with the one that is
+/// converted back from the adapter.
+#[track_caller]
+pub(crate) fn assert_round_trip<T, E>(
+    file: &syn::File,
+    encode: impl FnOnce(&syn::File) -> T,
+    decode: impl FnOnce(&T) -> Result<syn::File, E>,
+) -> T
+where
+    E: fmt::Debug,
+{
+    let encoded = encode(file);
+    let expected = syn::File::from_adapter(&file.to_adapter());
+    assert_eq!(decode(&encoded).unwrap(), expected);
+    encoded
+}
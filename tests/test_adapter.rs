@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use syn_serde::Syn as _;
+
+#[test]
+fn test_inspect() {
+    let item: syn::Item = syn::parse_quote! {
+        fn f(x: u8) -> bool {
+            x == 1
+        }
+    };
+    let syn_serde::Item::Fn(item) = item.to_adapter() else { panic!() };
+    assert_eq!(item.sig.ident.as_str(), "f");
+    assert_eq!(item.sig.inputs.len(), 1);
+    assert!(item.sig.output.ty.is_some());
+
+    let [syn_serde::Stmt::Expr(syn_serde::Expr::Binary(expr), false)] = &*item.block.stmts else {
+        panic!()
+    };
+    let syn_serde::Expr::Lit(lit) = &*expr.right else { panic!() };
+    let syn_serde::Lit::Int(int) = &lit.lit else { panic!() };
+    assert_eq!(int.token().as_str(), "1");
+}
+
+#[test]
+fn test_build() {
+    let path = |ident: &str| {
+        let segment = syn_serde::PathSegment::new(syn_serde::Ident::new(ident));
+        let path = syn_serde::Path::new(vec![segment]);
+        syn_serde::Expr::Path(syn_serde::ExprPath::new(path))
+    };
+    let lit = syn_serde::Lit::Int(syn_serde::LitInt::new(syn_serde::Literal::new("1u8")));
+    let expr = syn_serde::Expr::Binary(syn_serde::ExprBinary::new(
+        Box::new(path("a")),
+        syn_serde::BinOp::Add,
+        Box::new(syn_serde::Expr::Lit(syn_serde::ExprLit::new(lit))),
+    ));
+
+    let expected: syn::Expr = syn::parse_quote!(a + 1u8);
+    assert_eq!(syn::Expr::from_adapter(&expr), expected);
+
+    let err = syn::Expr::try_from_adapter(&path("1a")).unwrap_err();
+    assert_eq!(err.to_string(), "PathSegment.ident: `1a` is not a valid identifier");
+}
+
+#[test]
+fn test_ident_eq() {
+    let mut ident = syn_serde::Ident::new("a");
+    ident.span = Some(syn_serde::Location::new(
+        syn_serde::LineColumn::new(1, 0),
+        syn_serde::LineColumn::new(1, 1),
+    ));
+    // The location is not compared.
+    assert!(ident == syn_serde::Ident::new("a"));
+    assert!(ident != syn_serde::Ident::new("b"));
+}
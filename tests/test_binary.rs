@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "binary")]
+
+mod helper;
+
+use quote::ToTokens as _;
+use syn_serde::{Syn as _, binary};
+
+#[test]
+fn test_round_trip() {
+    for (path, file) in helper::corpus() {
+        let buf = helper::assert_round_trip(&file, binary::to_vec, |buf| binary::from_slice(buf));
+        let json = serde_json::to_vec(&file.to_adapter()).unwrap();
+        assert!(buf.len() < json.len(), "{}", path.display());
+    }
+}
+
+#[test]
+fn test_skipped_and_flattened_fields() {
+    // `TraitItemFn` flattens its signature, and most of its fields are
+    // omitted when they have default values.
+    let item: syn::ItemTrait = syn::parse_quote! {
+        pub trait Trait {
+            fn required(&self);
+            #[inline]
+            async unsafe fn provided<T: Clone>(x: T) -> T where T: Send { x }
+        }
+    };
+    let buf = binary::to_vec(&item);
+    let actual: syn::ItemTrait = binary::from_slice(&buf).unwrap();
+    assert_eq!(actual.to_token_stream().to_string(), item.to_token_stream().to_string());
+}
+
+#[test]
+fn test_invalid() {
+    let buf = binary::to_vec(&syn::parse_str::<syn::Ident>("a").unwrap());
+
+    // Truncated data.
+    assert!(binary::from_slice::<syn::Ident>(&buf[..buf.len() - 1]).is_err());
+    assert!(binary::from_slice::<syn::Ident>(&[]).is_err());
+
+    // Trailing data.
+    let mut trailing = buf.clone();
+    trailing.push(0);
+    assert!(binary::from_slice::<syn::Ident>(&trailing).is_err());
+
+    // Data written by another version of the binary format.
+    let mut other_version = buf.clone();
+    other_version[0] = 0;
+    let err = binary::from_slice::<syn::Ident>(&other_version).unwrap_err();
+    assert_eq!(err.to_string(), "unsupported binary format version 0 (expected 1)");
+
+    // Data written with other features.
+    let mut other_features = buf.clone();
+    other_features[1] ^= 1;
+    let err = binary::from_slice::<syn::Ident>(&other_features).unwrap_err();
+    let expected = if cfg!(feature = "macro-args") {
+        "the data was written without the `macro-args` feature, but is read with it"
+    } else {
+        "the data was written with the `macro-args` feature, but is read without it"
+    };
+    assert_eq!(err.to_string(), expected);
+    other_features[1] = 0x80;
+    let err = binary::from_slice::<syn::Ident>(&other_features).unwrap_err();
+    assert_eq!(err.to_string(), "unsupported binary format features 0x80");
+
+    // Data that does not describe the requested type.
+    assert!(binary::from_slice::<syn::File>(&buf).is_err());
+}
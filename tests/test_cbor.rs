@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "cbor")]
+
+mod helper;
+
+use syn_serde::{Serializable, SerializeOptions, Syn as _, cbor};
+
+#[track_caller]
+fn assert_cbor(file: &syn::File) {
+    let buf = helper::assert_round_trip(file, cbor::to_vec, |buf| cbor::from_slice(buf));
+    let mut writer = vec![];
+    cbor::to_writer(&mut writer, file).unwrap();
+    assert_eq!(writer, buf);
+    let from_reader: syn::File = cbor::from_reader(&*buf).unwrap();
+    assert_eq!(from_reader, cbor::from_slice::<syn::File>(&buf).unwrap());
+
+    // The data has the same shape as the JSON representation.
+    let value: serde_json::Value = ciborium::from_reader(&*buf).unwrap();
+    assert_eq!(value, serde_json::to_value(file.to_adapter()).unwrap());
+}
+
+#[test]
+fn test_items() {
+    for raw in helper::item_cases() {
+        assert_cbor(&syn::parse_file(&raw).unwrap());
+    }
+}
+
+#[test]
+fn test_sources() {
+    for (_, file) in helper::corpus() {
+        assert_cbor(&file);
+    }
+}
+
+#[test]
+fn test_options() {
+    let item: syn::Item = syn::parse_quote!(
+        fn f() {
+            'a'
+        }
+    );
+    let options = SerializeOptions::new().explicit_fields(true).structured_literals(true);
+    let buf = cbor::to_vec_with_options(&item, options);
+    let mut writer = vec![];
+    cbor::to_writer_with_options(&mut writer, &item, options).unwrap();
+    assert_eq!(writer, buf);
+    let value: serde_json::Value = ciborium::from_reader(&*buf).unwrap();
+    let expected = serde_json::to_value(Serializable::new(&item).with_options(options)).unwrap();
+    assert_eq!(value, expected);
+    assert_eq!(cbor::from_slice::<syn::Item>(&buf).unwrap(), item);
+}
+
+#[test]
+fn test_invalid() {
+    let mut buf = vec![];
+    ciborium::into_writer("1abc", &mut buf).unwrap();
+    let err = cbor::from_slice::<proc_macro2::Ident>(&buf).unwrap_err();
+    assert!(err.to_string().contains("`1abc` is not a valid identifier"), "{err}");
+}
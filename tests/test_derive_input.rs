@@ -0,0 +1,347 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "json")]
+#![allow(clippy::needless_raw_string_hashes)]
+
+use syn::*;
+
+fn print_actual(actual: &impl syn_serde::Syn) {
+    println!("actual:\n```\n{}\n", syn_serde::json::to_string_pretty(actual));
+}
+
+#[test]
+fn test_unit() {
+    let raw = "struct Unit;";
+
+    let json = r#"
+    {
+      "ident": "Unit",
+      "data": {
+        "struct": "unit"
+      }
+    }
+    "#;
+
+    let actual = syn::parse_str(raw).unwrap();
+    print_actual(&actual);
+    let json: syn_serde::DeriveInput = serde_json::from_str(json).unwrap();
+    let json = DeriveInput::from(&json);
+    assert_eq!(json, actual);
+}
+
+#[test]
+fn test_struct() {
+    let raw = "
+        #[derive(Debug, Clone)]
+        pub struct Item {
+            pub ident: Ident,
+            attrs: Vec<Attribute>
+        }
+    ";
+
+    let json = r#"
+    {
+      "attrs": [
+        {
+          "style": "outer",
+          "meta": {
+            "list": {
+              "path": {
+                "segments": [
+                  {
+                    "ident": "derive"
+                  }
+                ]
+              },
+              "delimiter": "paren",
+              "tokens": [
+                {
+                  "ident": "Debug"
+                },
+                {
+                  "punct": {
+                    "op": ",",
+                    "spacing": "alone"
+                  }
+                },
+                {
+                  "ident": "Clone"
+                }
+              ]
+            }
+          }
+        }
+      ],
+      "vis": "pub",
+      "ident": "Item",
+      "data": {
+        "struct": {
+          "named": [
+            {
+              "vis": "pub",
+              "ident": "ident",
+              "colon_token": true,
+              "ty": {
+                "path": {
+                  "segments": [
+                    {
+                      "ident": "Ident"
+                    }
+                  ]
+                }
+              }
+            },
+            {
+              "ident": "attrs",
+              "colon_token": true,
+              "ty": {
+                "path": {
+                  "segments": [
+                    {
+                      "ident": "Vec",
+                      "arguments": {
+                        "angle_bracketed": {
+                          "args": [
+                            {
+                              "type": {
+                                "path": {
+                                  "segments": [
+                                    {
+                                      "ident": "Attribute"
+                                    }
+                                  ]
+                                }
+                              }
+                            }
+                          ]
+                        }
+                      }
+                    }
+                  ]
+                }
+              }
+            }
+          ]
+        }
+      }
+    }
+    "#;
+
+    let actual = syn::parse_str(raw).unwrap();
+    print_actual(&actual);
+    let json: syn_serde::DeriveInput = serde_json::from_str(json).unwrap();
+    let json = DeriveInput::from(&json);
+    assert_eq!(json, actual);
+}
+
+#[test]
+fn test_tuple_struct() {
+    let raw = "struct S<T>(T) where T: Copy;";
+
+    let json = r#"
+    {
+      "ident": "S",
+      "generics": {
+        "params": [
+          {
+            "type": {
+              "ident": "T"
+            }
+          }
+        ],
+        "where_clause": [
+          {
+            "type": {
+              "bounded_ty": {
+                "path": {
+                  "segments": [
+                    {
+                      "ident": "T"
+                    }
+                  ]
+                }
+              },
+              "bounds": [
+                {
+                  "trait": {
+                    "path": {
+                      "segments": [
+                        {
+                          "ident": "Copy"
+                        }
+                      ]
+                    }
+                  }
+                }
+              ]
+            }
+          }
+        ]
+      },
+      "data": {
+        "struct": {
+          "unnamed": [
+            {
+              "ty": {
+                "path": {
+                  "segments": [
+                    {
+                      "ident": "T"
+                    }
+                  ]
+                }
+              }
+            }
+          ]
+        }
+      }
+    }
+    "#;
+
+    let actual = syn::parse_str(raw).unwrap();
+    print_actual(&actual);
+    let json: syn_serde::DeriveInput = serde_json::from_str(json).unwrap();
+    let json = DeriveInput::from(&json);
+    assert_eq!(json, actual);
+}
+
+#[test]
+fn test_enum() {
+    let raw = "
+        enum E {
+            A,
+            B(u8),
+            C { x: u8 }
+        }
+    ";
+
+    let json = r#"
+    {
+      "ident": "E",
+      "data": {
+        "enum": [
+          {
+            "ident": "A",
+            "fields": "unit"
+          },
+          {
+            "ident": "B",
+            "fields": {
+              "unnamed": [
+                {
+                  "ty": {
+                    "path": {
+                      "segments": [
+                        {
+                          "ident": "u8"
+                        }
+                      ]
+                    }
+                  }
+                }
+              ]
+            }
+          },
+          {
+            "ident": "C",
+            "fields": {
+              "named": [
+                {
+                  "ident": "x",
+                  "colon_token": true,
+                  "ty": {
+                    "path": {
+                      "segments": [
+                        {
+                          "ident": "u8"
+                        }
+                      ]
+                    }
+                  }
+                }
+              ]
+            }
+          }
+        ]
+      }
+    }
+    "#;
+
+    let actual = syn::parse_str(raw).unwrap();
+    print_actual(&actual);
+    let json: syn_serde::DeriveInput = serde_json::from_str(json).unwrap();
+    let json = DeriveInput::from(&json);
+    assert_eq!(json, actual);
+}
+
+#[test]
+fn test_union() {
+    let raw = "
+        union MaybeUninit<T> {
+            uninit: (),
+            value: T
+        }
+    ";
+
+    let json = r#"
+    {
+      "ident": "MaybeUninit",
+      "generics": {
+        "params": [
+          {
+            "type": {
+              "ident": "T"
+            }
+          }
+        ]
+      },
+      "data": {
+        "union": [
+          {
+            "ident": "uninit",
+            "colon_token": true,
+            "ty": {
+              "tuple": {
+                "elems": []
+              }
+            }
+          },
+          {
+            "ident": "value",
+            "colon_token": true,
+            "ty": {
+              "path": {
+                "segments": [
+                  {
+                    "ident": "T"
+                  }
+                ]
+              }
+            }
+          }
+        ]
+      }
+    }
+    "#;
+
+    let actual = syn::parse_str(raw).unwrap();
+    print_actual(&actual);
+    let json: syn_serde::DeriveInput = serde_json::from_str(json).unwrap();
+    let json = DeriveInput::from(&json);
+    assert_eq!(json, actual);
+}
+
+#[test]
+fn test_round_trip() {
+    let raw = "
+        #[repr(C)]
+        pub(crate) struct S<'a, T: ?Sized> {
+            a: &'a T
+        }
+    ";
+
+    let actual: DeriveInput = syn::parse_str(raw).unwrap();
+    let json = syn_serde::json::to_string(&actual);
+    let round_trip: DeriveInput = syn_serde::json::from_str(&json).unwrap();
+    assert_eq!(round_trip, actual);
+}
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "extra-traits")]
+#![allow(clippy::needless_raw_string_hashes)]
+
+use syn_serde::Syn as _;
+
+#[test]
+fn test_invalid_ident() {
+    let item: syn::Item = syn::parse_quote!(
+        fn f() {}
+    );
+    let mut adapter = item.to_adapter();
+    let syn_serde::Item::Fn(item_fn) = &mut adapter else { unreachable!() };
+    item_fn.sig.ident = syn_serde::Ident::new("1abc");
+    let err = syn::Item::try_from_adapter(&adapter).unwrap_err();
+    assert_eq!(err.node(), "Signature");
+    assert_eq!(err.field(), Some("ident"));
+    assert_eq!(err.to_string(), "Signature.ident: `1abc` is not a valid identifier");
+
+    for ident in ["", "1abc", "foo bar", "r#self", "r#", "a-b"] {
+        let err = proc_macro2::Ident::try_from_adapter(&syn_serde::Ident::new(ident)).unwrap_err();
+        assert_eq!(err.node(), "Ident");
+        assert_eq!(err.field(), None);
+
+        let json = serde_json::to_string(ident).unwrap();
+        let err = serde_json::from_str::<syn_serde::Ident>(&json).unwrap_err();
+        assert_eq!(err.to_string(), format!("`{ident}` is not a valid identifier"));
+    }
+
+    let json = r#"{ "fn": { "ident": "foo bar", "inputs": [], "output": null, "stmts": [] } }"#;
+    let err = serde_json::from_str::<syn_serde::Item>(json).unwrap_err();
+    assert!(err.to_string().starts_with("`foo bar` is not a valid identifier"), "{err}");
+}
+
+#[test]
+fn test_raw_ident() {
+    let ident: syn_serde::Ident = serde_json::from_str(r#""r#type""#).unwrap();
+    assert!(ident.is_raw());
+    assert_eq!(ident, syn_serde::Ident::new_raw("type"));
+    let ident = proc_macro2::Ident::from_adapter(&ident);
+    assert_eq!(ident.to_string(), "r#type");
+    assert_eq!(serde_json::to_string(&ident.to_adapter()).unwrap(), r#""r#type""#);
+
+    for ident in ["_", "self", "Self", "crate", "fn", "\u{e9}t\u{e9}"] {
+        let json = format!("\"{ident}\"");
+        let adapter: syn_serde::Ident = serde_json::from_str(&json).unwrap();
+        assert!(!adapter.is_raw());
+        proc_macro2::Ident::try_from_adapter(&adapter).unwrap();
+    }
+}
+
+#[test]
+fn test_invalid_punct() {
+    let json = r#"
+    {
+      "macro": {
+        "path": {
+          "segments": [
+            {
+              "ident": "m"
+            }
+          ]
+        },
+        "delimiter": "paren",
+        "tokens": [
+          {
+            "punct": {
+              "op": "a",
+              "spacing": "alone"
+            }
+          }
+        ]
+      }
+    }
+    "#;
+
+    let err = serde_json::from_str::<syn_serde::Expr>(json).unwrap_err();
+    assert!(err.to_string().starts_with("'a' is not a valid punctuation character"), "{err}");
+
+    let punct = syn_serde::Punct::new('a', syn_serde::Spacing::Alone);
+    let tokens = syn_serde::TokenStream::new(vec![syn_serde::TokenTree::Punct(punct)]);
+    let err = proc_macro2::TokenStream::try_from_adapter(&tokens).unwrap_err();
+    assert_eq!(err.node(), "Punct");
+    assert_eq!(err.message(), "'a' is not a valid punctuation character");
+}
+
+#[test]
+fn test_invalid_literal() {
+    for text in ["", "foo bar", "1 2", "\"unterminated", " 1"] {
+        let literal = syn_serde::Literal::new(text);
+        let err = proc_macro2::Literal::try_from_adapter(&literal).unwrap_err();
+        assert_eq!(err.node(), "Literal");
+        assert_eq!(err.message(), format!("`{text}` is not a valid literal"));
+
+        let json = serde_json::to_string(text).unwrap();
+        let err = serde_json::from_str::<syn_serde::Literal>(&json).unwrap_err();
+        assert_eq!(err.to_string(), format!("`{text}` is not a valid literal"));
+    }
+
+    let lit = syn_serde::Lit::Int(syn_serde::LitInt::new(syn_serde::Literal::new("1.0")));
+    let err = syn::Lit::try_from_adapter(&lit).unwrap_err();
+    assert_eq!(err.node(), "Lit");
+    assert_eq!(err.field(), Some("Int"));
+    assert_eq!(err.message(), "expected integer literal, found `1.0`");
+}
+
+#[test]
+fn test_deserialize_invalid_literal() {
+    #[track_caller]
+    fn assert_err(json: &str, msg: &str) {
+        let err = serde_json::from_str::<syn_serde::Expr>(json).unwrap_err();
+        assert!(err.to_string().starts_with(msg), "{err}");
+    }
+
+    assert_err(r#"{ "lit": { "int": "1.0" } }"#, "LitInt: expected integer literal, found `1.0`");
+    assert_err(r#"{ "lit": { "float": "1" } }"#, "LitFloat: expected float literal, found `1`");
+    assert_err(r#"{ "lit": { "float": "1u8" } }"#, "LitFloat: expected float literal, found `1u8`");
+    for float in ["1.0", "1.", "1e3", "1E-3", "1f32", "1_f64", "1.5x"] {
+        let json = format!(r#"{{ "lit": {{ "float": "{float}" }} }}"#);
+        serde_json::from_str::<syn_serde::Expr>(&json).unwrap();
+    }
+    assert_err(r#"{ "lit": { "str": "1" } }"#, "LitStr: expected string literal, found `1`");
+    assert_err(r#"{ "lit": { "char": "'a'1" } }"#, "`'a'1` is not a valid literal");
+    assert_err(r#"{ "lit": { "char": "\"a\"" } }"#, "LitChar: expected character literal");
+    assert_err(r#"{ "lit": { "str": "foo bar" } }"#, "`foo bar` is not a valid literal");
+    assert_err(r#"{ "lit": { "verbatim": "" } }"#, "`` is not a valid literal");
+    assert_err(
+        r#"{ "macro": { "path": { "segments": [{ "ident": "m" }] }, "delimiter": "paren", "tokens": [{ "lit": "a" }] } }"#,
+        "`a` is not a valid literal",
+    );
+    assert_err(r#"{ "lit": { "int": { "value": "x" } } }"#, "LitInt: `x` is not a valid literal");
+}
+
+#[test]
+fn test_lit_byte() {
+    let expr: syn::Expr = syn::parse_quote!(b'a');
+    let adapter = expr.to_adapter();
+    let json = serde_json::to_value(&adapter).unwrap();
+    assert_eq!(json["lit"]["byte"], "b'a'");
+    assert_eq!(syn::Expr::try_from_adapter(&adapter).unwrap(), expr);
+
+    // Older versions serialized byte literals as `u8`-suffixed integers.
+    let json = r#"{ "lit": { "byte": "97u8" } }"#;
+    let adapter: syn_serde::Expr = serde_json::from_str(json).unwrap();
+    assert_eq!(syn::Expr::try_from_adapter(&adapter).unwrap(), expr);
+}
+
+#[test]
+fn test_json() {
+    let json = r#"{ "struct": { "ident": "1abc", "fields": "unit" } }"#;
+    let err = syn_serde::json::from_str::<syn::Item>(json).unwrap_err();
+    assert!(err.is_data());
+    assert_eq!(err.to_string(), "`1abc` is not a valid identifier at line 1 column 29");
+}
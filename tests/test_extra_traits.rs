@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "extra-traits")]
+
+use std::collections::HashSet;
+
+use syn_serde::Syn as _;
+
+#[test]
+fn test_extra_traits() {
+    let file: syn::File = syn::parse_quote! {
+        #[derive(Debug)]
+        struct S<'a, T: ?Sized> {
+            field: &'a T,
+        }
+
+        fn main() {
+            println!("{}", 1 + 2);
+        }
+    };
+    let adapter = file.to_adapter();
+
+    let json = serde_json::to_string(&adapter).unwrap();
+    let deserialized: syn_serde::File = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, adapter);
+    assert_eq!(adapter.clone(), adapter);
+    assert_eq!(format!("{deserialized:?}"), format!("{adapter:?}"));
+
+    let mut set = HashSet::new();
+    for item in &adapter.items {
+        assert!(set.insert(item.clone()));
+    }
+    assert!(!set.insert(adapter.items[0].clone()));
+
+    let other: syn::Item = syn::parse_quote!(
+        struct S;
+    );
+    assert_ne!(other.to_adapter(), adapter.items[0]);
+}
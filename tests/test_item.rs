@@ -579,3 +579,176 @@ fn test_static_mut() {
     let json = Item::from(&json);
     assert_eq!(json, actual);
 }
+
+#[test]
+fn test_c_str() {
+    let raw = r#"
+        static S: &CStr = c"foo\tbar";
+    "#;
+
+    let json = r#"
+    {
+      "static": {
+        "ident": "S",
+        "ty": {
+          "reference": {
+            "elem": {
+              "path": {
+                "segments": [
+                  {
+                    "ident": "CStr"
+                  }
+                ]
+              }
+            }
+          }
+        },
+        "expr": {
+          "lit": {
+            "c_str": "c\"foo\\tbar\""
+          }
+        }
+      }
+    }
+    "#;
+
+    let actual = syn::parse_str(raw).unwrap();
+    print_actual(&actual);
+    let json: syn_serde::Item = serde_json::from_str(json).unwrap();
+    let json = Item::from(&json);
+    assert_eq!(json, actual);
+}
+
+#[test]
+fn test_precise_capture() {
+    let raw = "
+        fn f<'a, T>() -> impl Sized + use<'a, T> {}
+    ";
+
+    let json = r#"
+    {
+      "fn": {
+        "ident": "f",
+        "generics": {
+          "params": [
+            {
+              "lifetime": {
+                "lifetime": "a",
+                "bounds": []
+              }
+            },
+            {
+              "type": {
+                "ident": "T"
+              }
+            }
+          ]
+        },
+        "inputs": [],
+        "output": {
+          "impl_trait": {
+            "bounds": [
+              {
+                "trait": {
+                  "path": {
+                    "segments": [
+                      {
+                        "ident": "Sized"
+                      }
+                    ]
+                  }
+                }
+              },
+              {
+                "precise_capture": [
+                  {
+                    "lifetime": "a"
+                  },
+                  {
+                    "ident": "T"
+                  }
+                ]
+              }
+            ]
+          }
+        },
+        "stmts": []
+      }
+    }
+    "#;
+
+    let actual = syn::parse_str(raw).unwrap();
+    print_actual(&actual);
+    let json: syn_serde::Item = serde_json::from_str(json).unwrap();
+    let json = Item::from(&json);
+    assert_eq!(json, actual);
+}
+
+#[test]
+fn test_raw_addr() {
+    let raw = "
+        fn f() {
+            let p = &raw mut x;
+        }
+    ";
+
+    let json = r#"
+    {
+      "fn": {
+        "ident": "f",
+        "inputs": [],
+        "output": null,
+        "stmts": [
+          {
+            "let": {
+              "pat": {
+                "ident": {
+                  "ident": "p"
+                }
+              },
+              "init": {
+                "expr": {
+                  "raw_addr": {
+                    "mutability": "mut",
+                    "expr": {
+                      "path": {
+                        "segments": [
+                          {
+                            "ident": "x"
+                          }
+                        ]
+                      }
+                    }
+                  }
+                }
+              }
+            }
+          }
+        ]
+      }
+    }
+    "#;
+
+    let actual = syn::parse_str(raw).unwrap();
+    print_actual(&actual);
+    let json: syn_serde::Item = serde_json::from_str(json).unwrap();
+    let json = Item::from(&json);
+    assert_eq!(json, actual);
+}
+
+#[test]
+fn test_round_trip_recent_syntax() {
+    let raw = r#"
+        static S: &CStr = c"a\"b\\c\td";
+        fn f<'a, T, const N: usize>() -> impl Sized + use<'a, T, N> {}
+        fn g() {
+            let a = &raw const x;
+            let b = &raw mut x;
+        }
+    "#;
+
+    let actual: File = syn::parse_str(raw).unwrap();
+    let json = syn_serde::json::to_string(&actual);
+    let round_trip: File = syn_serde::json::from_str(&json).unwrap();
+    assert_eq!(round_trip, actual);
+}
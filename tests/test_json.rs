@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "json")]
+
+mod helper;
+
+use syn_serde::{SerializeOptions, Syn, json};
+
+#[track_caller]
+fn assert_round_trip<T>(syn: &T)
+where
+    T: Syn + PartialEq + std::fmt::Debug,
+{
+    let json = json::to_string(syn);
+    let adapter: T::Adapter = serde_json::from_str(&json).unwrap();
+    let expected = T::from_adapter(&adapter);
+    assert_eq!(json::from_str::<T>(&json).unwrap(), expected);
+    assert_eq!(json::from_slice::<T>(json.as_bytes()).unwrap(), expected);
+}
+
+#[test]
+fn test_sources() {
+    for (_, file) in helper::corpus() {
+        assert_round_trip(&file);
+    }
+}
+
+#[test]
+fn test_escaped() {
+    let expr: syn::Expr = syn::parse_quote!(f("a\n\"b\"", '\\', "\u{1F980}", r#"c"#));
+    assert_round_trip(&expr);
+
+    let json = json::to_string(&expr);
+    assert!(json.contains(r#"\"a\\n\\\"b\\\"\""#), "{json}");
+}
+
+#[test]
+fn test_invalid_utf8() {
+    let err = json::from_slice::<syn::Expr>(b"\"\xFF\"").unwrap_err();
+    assert!(err.is_syntax(), "{err}");
+}
+
+#[test]
+fn test_options() {
+    let item: syn::Item = syn::parse_quote!(
+        struct S;
+    );
+    let options = SerializeOptions::new();
+    assert_eq!(json::to_string_with_options(&item, options), json::to_string(&item));
+    assert_eq!(json::to_vec_with_options(&item, options), json::to_vec(&item));
+
+    assert_eq!(json::to_string_pretty_with_options(&item, options), json::to_string_pretty(&item));
+    assert_eq!(json::to_vec_pretty_with_options(&item, options), json::to_vec_pretty(&item));
+    let mut writer = vec![];
+    json::to_writer_pretty_with_options(&mut writer, &item, options).unwrap();
+    assert_eq!(writer, json::to_vec_pretty(&item));
+
+    let pretty = options.pretty(true);
+    assert_eq!(json::to_string_with_options(&item, pretty), json::to_string_pretty(&item));
+    assert_eq!(json::to_vec_with_options(&item, pretty), json::to_vec_pretty(&item));
+    let mut writer = vec![];
+    json::to_writer_with_options(&mut writer, &item, pretty).unwrap();
+    assert_eq!(writer, json::to_vec_pretty(&item));
+
+    let options = options.explicit_fields(true);
+    let json = json::to_string_pretty_with_options(&item, options);
+    assert!(json.contains("\"vis\": \"inherited\""), "{json}");
+    assert_eq!(json::from_str::<syn::Item>(&json).unwrap(), item);
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "json")]
+
+mod helper;
+
+use syn_serde::{Serializable, SerializeOptions, Syn as _, json};
+
+#[track_caller]
+fn assert_json_lines(file: &syn::File) {
+    let buf = helper::assert_round_trip(
+        file,
+        |file| {
+            let mut buf = vec![];
+            json::items_to_writer_lines(&mut buf, file).unwrap();
+            buf
+        },
+        |buf| json::ItemsReader::new(&**buf)?.into_file(),
+    );
+    let lines: Vec<&str> = std::str::from_utf8(&buf).unwrap().lines().collect();
+    assert_eq!(lines.len(), file.items.len() + 1);
+
+    let expected = syn::File::from_adapter(&file.to_adapter());
+    let reader = json::ItemsReader::new(&*buf).unwrap();
+    assert_eq!(reader.shebang(), expected.shebang.as_deref());
+    assert_eq!(reader.attrs(), expected.attrs);
+    let items = reader.collect::<serde_json::Result<Vec<syn::Item>>>().unwrap();
+    assert_eq!(items, expected.items);
+}
+
+#[test]
+fn test_sources() {
+    for (_, file) in helper::corpus() {
+        assert_json_lines(&file);
+    }
+}
+
+#[test]
+fn test_file_level() {
+    let raw = "#!/usr/bin/env rustx\n#![allow(dead_code)]\n#![no_std]\n";
+    let file = syn::parse_file(raw).unwrap();
+    let mut buf = vec![];
+    json::items_to_writer_lines(&mut buf, &file).unwrap();
+    assert_eq!(buf.iter().filter(|&&b| b == b'\n').count(), 1);
+
+    let mut reader = json::ItemsReader::new(&*buf).unwrap();
+    assert_eq!(reader.shebang(), Some("#!/usr/bin/env rustx"));
+    assert_eq!(reader.attrs().len(), 2);
+    assert!(reader.next().is_none());
+    assert_eq!(json::ItemsReader::new(&*buf).unwrap().into_file().unwrap(), file);
+}
+
+#[test]
+fn test_options() {
+    let file: syn::File = syn::parse_quote! {
+        #![doc = "a"]
+        fn f() {}
+    };
+    let options = SerializeOptions::new().explicit_fields(true);
+    let mut buf = vec![];
+    json::items_to_writer_lines_with_options(&mut buf, &file, options).unwrap();
+    let lines: Vec<serde_json::Value> = buf
+        .split(|&b| b == b'\n')
+        .filter(|line| !line.is_empty())
+        .map(|line| serde_json::from_slice(line).unwrap())
+        .collect();
+    let header: syn::File = syn::parse_quote!(#![doc = "a"]);
+    assert_eq!(lines, [
+        serde_json::to_value(Serializable::new(&header).with_options(options)).unwrap(),
+        serde_json::to_value(Serializable::new(&file.items[0]).with_options(options)).unwrap(),
+    ]);
+    assert_eq!(lines[0]["shebang"], serde_json::Value::Null);
+    assert_eq!(json::ItemsReader::new(&*buf).unwrap().into_file().unwrap(), file);
+}
+
+#[test]
+fn test_into_file_after_next() {
+    let file: syn::File = syn::parse_quote! {
+        struct A;
+        struct B;
+    };
+    let mut buf = vec![];
+    json::items_to_writer_lines(&mut buf, &file).unwrap();
+    let mut reader = json::ItemsReader::new(&*buf).unwrap();
+    assert!(reader.next().unwrap().is_ok());
+    let err = reader.into_file().unwrap_err();
+    assert!(err.to_string().contains("some of its items have been read"), "{err}");
+}
+
+#[test]
+fn test_empty_lines() {
+    let s = "\n{\"items\":[]}\n\n{\"struct\":{\"ident\":\"A\",\"fields\":\"unit\"}}\n  \n\
+             {\"struct\":{\"ident\":\"B\",\"fields\":\"unit\"}}";
+    let file = json::ItemsReader::new(s.as_bytes()).unwrap().into_file().unwrap();
+    let expected: syn::File = syn::parse_quote! {
+        struct A;
+        struct B;
+    };
+    assert_eq!(file, expected);
+}
+
+#[test]
+fn test_invalid() {
+    let err = json::ItemsReader::new(&b""[..]).err().unwrap();
+    assert!(err.is_eof(), "{err}");
+
+    let err = json::ItemsReader::new(&b"{\"struct\":{}}\n"[..]).err().unwrap();
+    assert!(err.to_string().contains("missing field `items`"), "{err}");
+
+    let s = "{\"items\":[]}\n{\"struct\":{\"ident\":\"A\",\"fields\":\"unit\"}}\n\
+             {\"struct\":{\"ident\":\"1abc\",\"fields\":\"unit\"}}\n";
+    let mut reader = json::ItemsReader::new(s.as_bytes()).unwrap();
+    assert!(reader.next().unwrap().is_ok());
+    let err = reader.next().unwrap().unwrap_err();
+    assert!(err.to_string().contains("`1abc` is not a valid identifier"), "{err}");
+    assert!(reader.next().is_none());
+    let err = json::ItemsReader::new(s.as_bytes()).unwrap().into_file().unwrap_err();
+    assert!(err.to_string().contains("`1abc` is not a valid identifier"), "{err}");
+}
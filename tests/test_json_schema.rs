@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod helper;
+
+use std::{fs, path::Path};
+
+use serde_json::Value;
+use syn_serde::{Serializable, SerializeOptions, Syn as _};
+
+/// A validator that supports the subset of JSON Schema used by
+/// `schema/syn-serde.schema.json`.
+struct Validator {
+    root: Value,
+}
+
+impl Validator {
+    fn new() -> Self {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("schema/syn-serde.schema.json");
+        Self { root: serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap() }
+    }
+
+    fn validate(&self, value: &Value) -> Result<(), String> {
+        self.validate_schema(&self.root, value, "")
+    }
+
+    fn validate_schema(&self, schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+        let Value::Object(schema) = schema else {
+            return if *schema == Value::Bool(false) {
+                Err(format!("{path}: unexpected value"))
+            } else {
+                Ok(())
+            };
+        };
+        if let Some(reference) = schema.get("$ref") {
+            let name = reference.as_str().unwrap().strip_prefix("#/$defs/").unwrap();
+            self.validate_schema(&self.root["$defs"][name], value, path)?;
+        }
+        if let Some(ty) = schema.get("type") {
+            let ok = match ty.as_str().unwrap() {
+                "object" => value.is_object(),
+                "array" => value.is_array(),
+                "string" => value.is_string(),
+                "integer" => value.is_u64() || value.is_i64(),
+                "boolean" => value.is_boolean(),
+                "null" => value.is_null(),
+                ty => panic!("unsupported type {ty}"),
+            };
+            if !ok {
+                return Err(format!("{path}: expected {ty}, found {value}"));
+            }
+        }
+        if let Some(variants) = schema.get("enum") {
+            if !variants.as_array().unwrap().contains(value) {
+                return Err(format!("{path}: expected one of {variants}, found {value}"));
+            }
+        }
+        if let Some(expected) = schema.get("const") {
+            if value != expected {
+                return Err(format!("{path}: expected {expected}, found {value}"));
+            }
+        }
+        if let Some(minimum) = schema.get("minimum") {
+            if value.as_f64().unwrap() < minimum.as_f64().unwrap() {
+                return Err(format!("{path}: {value} is less than {minimum}"));
+            }
+        }
+        if let Value::String(s) = value {
+            let len = s.chars().count() as u64;
+            if schema.get("minLength").is_some_and(|min| len < min.as_u64().unwrap())
+                || schema.get("maxLength").is_some_and(|max| len > max.as_u64().unwrap())
+            {
+                return Err(format!("{path}: unexpected length of {value}"));
+            }
+        }
+        if let Value::Object(object) = value {
+            if let Some(required) = schema.get("required") {
+                for name in required.as_array().unwrap() {
+                    if !object.contains_key(name.as_str().unwrap()) {
+                        return Err(format!("{path}: missing property {name}"));
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (name, value) in object {
+                let path = &format!("{path}/{name}");
+                match properties.and_then(|properties| properties.get(name)) {
+                    Some(schema) => self.validate_schema(schema, value, path)?,
+                    None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                        return Err(format!("{path}: unexpected property"));
+                    }
+                    None => {}
+                }
+            }
+        }
+        if let Value::Array(array) = value {
+            let prefix_items = schema.get("prefixItems").and_then(Value::as_array);
+            let prefix_len = prefix_items.map_or(0, Vec::len);
+            if let Some(prefix_items) = prefix_items {
+                if array.len() < prefix_len {
+                    return Err(format!("{path}: expected {prefix_len} items, found {value}"));
+                }
+                for (i, (schema, value)) in prefix_items.iter().zip(array).enumerate() {
+                    self.validate_schema(schema, value, &format!("{path}/{i}"))?;
+                }
+            }
+            if let Some(items) = schema.get("items") {
+                for (i, value) in array.iter().enumerate().skip(prefix_len) {
+                    self.validate_schema(items, value, &format!("{path}/{i}"))?;
+                }
+            }
+        }
+        if let Some(all_of) = schema.get("allOf") {
+            for schema in all_of.as_array().unwrap() {
+                self.validate_schema(schema, value, path)?;
+            }
+        }
+        if let Some(any_of) = schema.get("anyOf") {
+            let any_of = any_of.as_array().unwrap();
+            if !any_of.iter().any(|schema| self.validate_schema(schema, value, path).is_ok()) {
+                return Err(format!("{path}: no schema in anyOf matches {value}"));
+            }
+        }
+        if let Some(one_of) = schema.get("oneOf") {
+            let mut errors = vec![];
+            for schema in one_of.as_array().unwrap() {
+                if let Err(e) = self.validate_schema(schema, value, path) {
+                    errors.push(e);
+                }
+            }
+            match one_of.as_array().unwrap().len() - errors.len() {
+                1 => {}
+                0 => return Err(format!("{path}: no schema in oneOf matches: {errors:#?}")),
+                n => return Err(format!("{path}: {n} schemas in oneOf match {value}")),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_examples() {
+    let validator = Validator::new();
+    for example in ["json2rust", "rust2json", "rust2pickle"] {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples").join(example);
+        let json: Value =
+            serde_json::from_str(&fs::read_to_string(path.join("main.json")).unwrap()).unwrap();
+        validator.validate(&json).unwrap_or_else(|e| panic!("{example}: {e}"));
+    }
+}
+
+#[test]
+fn test_sources() {
+    let validator = Validator::new();
+    for (path, file) in helper::corpus() {
+        let json = serde_json::to_value(file.to_adapter()).unwrap();
+        validator.validate(&json).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let options = SerializeOptions::new().structured_literals(true);
+        let json = serde_json::to_value(Serializable::new(&file).with_options(options)).unwrap();
+        validator.validate(&json).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let options = SerializeOptions::new().explicit_fields(true);
+        let json = serde_json::to_value(Serializable::new(&file).with_options(options)).unwrap();
+        validator.validate(&json).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let options = SerializeOptions::new().internally_tagged(true);
+        let json = serde_json::to_value(Serializable::new(&file).with_options(options)).unwrap();
+        validator.validate(&json).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let options = SerializeOptions::new().token_text(true);
+        let json = serde_json::to_value(Serializable::new(&file).with_options(options)).unwrap();
+        validator.validate(&json).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        #[cfg(feature = "macro-args")]
+        {
+            let options = SerializeOptions::new().macro_args(true);
+            let json =
+                serde_json::to_value(Serializable::new(&file).with_options(options)).unwrap();
+            validator.validate(&json).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        }
+    }
+}
+
+#[test]
+fn test_invalid() {
+    let validator = Validator::new();
+    for json in [
+        "{}",
+        r#"{ "items": [{ "fn": {} }] }"#,
+        r#"{ "items": [{ "unknown": {} }] }"#,
+        r#"{ "items": [{ "kind": "ItemUnknown" }] }"#,
+        r#"{ "items": [{ "kind": "ItemFn", "fn": {} }] }"#,
+        r#"{ "items": [{ "fn": { "ident": "f", "inputs": [], "stmts": [], "const": 1 } }] }"#,
+        r#"{ "items": [{ "fn": { "ident": "f", "inputs": [], "stmts": [{ "expr": [{ "path": { "segments": [] } }, 1] }] } }] }"#,
+    ] {
+        let json: Value = serde_json::from_str(json).unwrap();
+        assert!(validator.validate(&json).is_err(), "{json}");
+    }
+}
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod helper;
+
+use quote::ToTokens as _;
+use serde_json::json;
+use syn_serde::{Serializable, SerializeOptions, Syn};
+
+fn to_structured_value<T>(syn: &T) -> serde_json::Value
+where
+    T: Syn,
+{
+    let options = SerializeOptions::new().structured_literals(true);
+    serde_json::to_value(Serializable::new(syn).with_options(options)).unwrap()
+}
+
+#[track_caller]
+fn assert_round_trip<T>(syn: &T)
+where
+    T: Syn,
+{
+    // The structured representation does not keep the spelling of integers
+    // and floats (e.g., `0x_FF`), so compare the serialized output.
+    let value = to_structured_value(syn);
+    let adapter: T::Adapter = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(to_structured_value(&T::from_adapter(&adapter)), value);
+}
+
+#[test]
+fn test_structured() {
+    let expr: syn::Expr = syn::parse_quote! {
+        f("a\n\u{1F980}", r#"b"#, b"\xFF", br"c", c"d", b'e', '\'', 0x_FFu8, 1_000, 1.5e3f32)
+    };
+    let syn::Expr::Call(call) = &expr else { unreachable!() };
+    let args: Vec<_> = call.args.iter().map(to_structured_value).collect();
+    // Without `SerializeOptions::literal_spelling`, a string literal is
+    // rebuilt from its value, so it is always a cooked string.
+    assert_eq!(args, [
+        json!({ "lit": { "str": { "value": "a\n\u{1F980}", "suffix": "", "style": "cooked" } } }),
+        json!({ "lit": { "str": { "value": "b", "suffix": "", "style": "cooked" } } }),
+        json!({ "lit": { "byte_str": { "value": [255], "suffix": "", "style": "cooked" } } }),
+        json!({ "lit": { "byte_str": { "value": [99], "suffix": "", "style": "cooked" } } }),
+        json!({ "lit": { "c_str": { "value": [100], "suffix": "", "style": "cooked" } } }),
+        json!({ "lit": { "byte": { "value": 101, "suffix": "" } } }),
+        json!({ "lit": { "char": { "value": "'", "suffix": "" } } }),
+        json!({ "lit": { "int": { "value": "255", "suffix": "u8" } } }),
+        json!({ "lit": { "int": { "value": "1000", "suffix": "" } } }),
+        json!({ "lit": { "float": { "value": "1.5e3", "suffix": "f32" } } }),
+    ]);
+    assert_round_trip(&expr);
+}
+
+#[test]
+fn test_sources() {
+    for (_, file) in helper::corpus() {
+        assert_round_trip(&file);
+    }
+}
+
+#[test]
+fn test_either_form() {
+    let cases = [
+        ("str", json!("\"a\\nb\""), json!({ "value": "a\nb" })),
+        ("str", json!("r#\"a\"b\"#"), json!({ "value": "a\"b", "style": { "raw": 1 } })),
+        ("byte_str", json!("b\"\\x00a\""), json!({ "value": [0, 97] })),
+        ("byte_str", json!("br\"a\""), json!({ "value": [97], "style": { "raw": 0 } })),
+        ("c_str", json!("c\"a\""), json!({ "value": [97], "style": "cooked" })),
+        ("byte", json!("b'a'"), json!({ "value": 97 })),
+        ("char", json!("'a'"), json!({ "value": "a" })),
+        ("int", json!("1u8"), json!({ "value": "1", "suffix": "u8" })),
+        ("float", json!("1.0"), json!({ "value": "1.0" })),
+    ];
+    for (kind, token, structured) in cases {
+        let token: syn_serde::Lit = serde_json::from_value(json!({ kind: token })).unwrap();
+        let structured: syn_serde::Lit =
+            serde_json::from_value(json!({ kind: structured })).unwrap();
+        // Compare the values, because deserialization keeps the spelling of
+        // tokens.
+        assert_eq!(
+            to_structured_value(&syn::Lit::from_adapter(&structured)),
+            to_structured_value(&syn::Lit::from_adapter(&token))
+        );
+    }
+}
+
+#[test]
+fn test_invalid() {
+    #[track_caller]
+    fn assert_err<T>(value: serde_json::Value, msg: &str)
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let err = serde_json::from_value::<T>(value).err().unwrap();
+        assert!(err.to_string().contains(msg), "{err}");
+    }
+
+    assert_err::<syn_serde::LitStr>(
+        json!({ "value": "a\"#b", "style": { "raw": 1 } }),
+        "cannot be written as a raw string with 1 `#`",
+    );
+    assert_err::<syn_serde::LitByteStr>(
+        json!({ "value": [255], "style": { "raw": 0 } }),
+        "raw byte string literal must be ASCII",
+    );
+    assert_err::<syn_serde::LitCStr>(json!({ "value": [0] }), "must not contain nul");
+    assert_err::<syn_serde::LitInt>(
+        json!({ "value": "1", "style": "cooked" }),
+        "integer literal does not have a style",
+    );
+    assert_err::<syn_serde::LitChar>(json!({ "value": "a", "kind": "char" }), "unknown field");
+    assert_err::<syn_serde::LitChar>(json!(1), "a character literal or its structured");
+}
+
+#[test]
+fn test_spelling() {
+    let expr: syn::Expr = syn::parse_quote! {
+        f(r#"a"b"#, "\u{e9}\té", "c"suffix, br"d", b"\x41", c"e\n", b'\x42', '\u{1F980}', 'f'x)
+    };
+    let syn::Expr::Call(call) = &expr else { unreachable!() };
+    let options = SerializeOptions::new().literal_spelling(true);
+    let to_value = |syn: &syn::Expr, options| {
+        serde_json::to_value(Serializable::new(syn).with_options(options)).unwrap()
+    };
+    let args: Vec<_> = call.args.iter().map(|arg| to_value(arg, options)).collect();
+    assert_eq!(args, [
+        json!({ "lit": { "str": r##"r#"a"b"#"## } }),
+        json!({ "lit": { "str": r#""\u{e9}\té""# } }),
+        json!({ "lit": { "str": r#""c"suffix"# } }),
+        json!({ "lit": { "byte_str": r#"br"d""# } }),
+        json!({ "lit": { "byte_str": r#"b"\x41""# } }),
+        json!({ "lit": { "c_str": r#"c"e\n""# } }),
+        json!({ "lit": { "byte": r"b'\x42'" } }),
+        json!({ "lit": { "char": r"'\u{1F980}'" } }),
+        json!({ "lit": { "char": "'f'x" } }),
+    ]);
+    // By default, literals are rebuilt from their values.
+    assert_eq!(
+        to_value(&call.args[0], SerializeOptions::new()),
+        json!({ "lit": { "str": r#""a\"b""# } })
+    );
+    assert_eq!(
+        to_value(&call.args[0], options.structured_literals(true)),
+        json!({ "lit": { "str": { "value": "a\"b", "suffix": "", "style": { "raw": 1 } } } })
+    );
+
+    let json = serde_json::to_string(&Serializable::new(&expr).with_options(options)).unwrap();
+    let actual = syn::Expr::from_adapter(&serde_json::from_str(&json).unwrap());
+    assert_eq!(actual.to_token_stream().to_string(), expr.to_token_stream().to_string());
+    assert_eq!(actual, expr);
+}
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "lossless")]
+
+mod helper;
+
+use std::fs;
+
+use syn_serde::{
+    File, Item,
+    lossless::{parse_file, print_file},
+};
+
+#[track_caller]
+fn assert_round_trip(source: &str) {
+    let file = parse_file(source).unwrap();
+    assert_eq!(print_file(&file).unwrap(), source);
+    let json = serde_json::to_string(&file).unwrap();
+    let file: File = serde_json::from_str(&json).unwrap();
+    assert_eq!(print_file(&file).unwrap(), source);
+}
+
+/// Applies `f` to the JSON of `source` and prints the result.
+fn edit(source: &str, f: impl FnOnce(&mut serde_json::Value)) -> String {
+    let mut json = serde_json::to_value(parse_file(source).unwrap()).unwrap();
+    f(&mut json);
+    print_file(&serde_json::from_value(json).unwrap()).unwrap()
+}
+
+#[test]
+fn test_sources() {
+    let dirs =
+        [helper::CORPUS_DIRS, &["src/gen", "examples/json2rust", "examples/rust2json"]].concat();
+    for path in helper::source_paths(&dirs) {
+        let source = fs::read_to_string(&path).unwrap();
+        let file = parse_file(&source).unwrap();
+        assert!(print_file(&file).unwrap() == source, "{}", path.display());
+    }
+}
+
+#[test]
+fn test_trivia() {
+    for source in [
+        "",
+        "\n",
+        "// comment",
+        "fn f() {}",
+        "  /* a */ fn /* b */ f ( ) { } // c\n\n\n",
+        "//! inner doc\n#![allow(dead_code)]\n\n/// outer doc\n/** block doc */\nstruct S;\n",
+        "#!/usr/bin/env rust\nfn main() {}\n",
+        "#!/usr/bin/env rust\n",
+        "\u{feff}fn main() {}\n",
+        "\u{feff}#!/usr/bin/env rust\n// comment\nfn main() {}\n",
+        "fn main() {\r\n    // comment\r\n    let x = 1;\r\n}\r\n",
+        "fn f(a: u8, b: u8,) {}\nstruct S { a: u8, }\nenum E { A, B, }\n",
+        "const S: &str = r#\"raw \"string\"\"#;\nconst B: &[u8] = b\"\\x00\";\nconst C: char = '\\u{1F600}';\n",
+        "fn f() -> u8 { 0x_ff_u8 }\n",
+        "mod m {\n    // a\n    mod n {\n        fn f() {} // b\n    }\n    // c\n}\n",
+        "mod m;\nmod n {}\n",
+        "impl S {\n    /// doc\n    fn f(&self) {}\n\n    const C: u8 = 0; // c\n}\n",
+        "trait T {\n    // comment\n    fn f();\n    type A: Clone;\n}\n",
+        "macro_rules! m {\n    ($x:expr) => { $x };\n}\nm!(1);\n",
+        "fn f() { let s = \"é\"; /* ü */ }\n",
+    ] {
+        assert_round_trip(source);
+    }
+}
+
+#[test]
+fn test_rename() {
+    let source = "// a\nfn   foo() {} // b\n\n/// c\nstruct Foo;\n";
+    let out = edit(source, |json| {
+        json["items"][0]["fn"]["ident"] = "bar".into();
+        json["items"][1]["struct"]["ident"] = "Bar".into();
+    });
+    assert_eq!(out, "// a\nfn   bar() {} // b\n\n/// c\nstruct Bar;\n");
+}
+
+#[test]
+fn test_insert_remove() {
+    let source = "// a\nfn a() {}\n\n// b\nfn   b() {}\n\n// c\nfn c() {}\n";
+
+    let out = edit(source, |json| {
+        json["items"].as_array_mut().unwrap().remove(1);
+    });
+    assert_eq!(out, "// a\nfn a() {}\n\n// c\nfn c() {}\n");
+
+    let out = edit(source, |json| {
+        json["items"].as_array_mut().unwrap().swap(1, 2);
+    });
+    assert_eq!(out, "// a\nfn a() {}\n\n// c\nfn c() {}\n\n// b\nfn   b() {}\n");
+
+    // Items without trivia are printed by prettyplease.
+    let out = edit(source, |json| {
+        let item = serde_json::json!({ "struct": { "ident": "S", "fields": "unit" } });
+        json["items"].as_array_mut().unwrap().insert(1, item);
+    });
+    assert_eq!(out, "// a\nfn a() {}\n\nstruct S;\n\n// b\nfn   b() {}\n\n// c\nfn c() {}\n");
+
+    let source = "impl S {\n    // a\n    fn a() {}\n}\n";
+    let out = edit(source, |json| {
+        let item = serde_json::json!({ "const": {
+            "ident": "C", "ty": { "path": { "segments": [{ "ident": "u8" }] } }, "expr": { "lit": { "int": "0" } }
+        } });
+        json["items"][0]["impl"]["items"].as_array_mut().unwrap().push(item);
+    });
+    assert_eq!(out, "impl S {\n    // a\n    fn a() {}\n\n    const C: u8 = 0;\n}\n");
+}
+
+#[test]
+fn test_stale() {
+    // An item whose tokens changed is printed by prettyplease, keeping the
+    // comments before it.
+    let source = "// a\nfn  a() {}\n\n// b\nfn  b() {  }\n";
+    let out = edit(source, |json| {
+        json["items"][1]["fn"]["inputs"] = serde_json::json!([{
+            "typed": { "pat": { "ident": { "ident": "x" } }, "ty": { "path": { "segments": [{ "ident": "u8" }] } } }
+        }]);
+    });
+    assert_eq!(out, "// a\nfn  a() {}\n\n// b\nfn b(x: u8) {}\n");
+
+    // Files without trivia are printed by prettyplease.
+    let mut file = parse_file("fn  f() {}").unwrap();
+    file.trivia = None;
+    if let Item::Fn(item) = &mut file.items[0] {
+        item.trivia = None;
+    }
+    assert_eq!(print_file(&file).unwrap(), "fn f() {}\n");
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "msgpack")]
+
+mod helper;
+
+use syn_serde::{Serializable, SerializeOptions, Syn as _, msgpack};
+
+#[track_caller]
+fn assert_msgpack(file: &syn::File) {
+    let buf = helper::assert_round_trip(file, msgpack::to_vec, |buf| msgpack::from_slice(buf));
+    let mut writer = vec![];
+    msgpack::to_writer(&mut writer, file).unwrap();
+    assert_eq!(writer, buf);
+    let from_reader: syn::File = msgpack::from_reader(&*buf).unwrap();
+    assert_eq!(from_reader, msgpack::from_slice::<syn::File>(&buf).unwrap());
+
+    // The data has the same shape as the JSON representation.
+    let value: serde_json::Value = rmp_serde::from_slice(&buf).unwrap();
+    assert_eq!(value, serde_json::to_value(file.to_adapter()).unwrap());
+}
+
+#[test]
+fn test_items() {
+    for raw in helper::item_cases() {
+        assert_msgpack(&syn::parse_file(&raw).unwrap());
+    }
+}
+
+#[test]
+fn test_sources() {
+    for (_, file) in helper::corpus() {
+        assert_msgpack(&file);
+    }
+}
+
+#[test]
+fn test_options() {
+    let item: syn::Item = syn::parse_quote!(
+        fn f() {
+            'a'
+        }
+    );
+    let options = SerializeOptions::new().explicit_fields(true).structured_literals(true);
+    let buf = msgpack::to_vec_with_options(&item, options);
+    let mut writer = vec![];
+    msgpack::to_writer_with_options(&mut writer, &item, options).unwrap();
+    assert_eq!(writer, buf);
+    let value: serde_json::Value = rmp_serde::from_slice(&buf).unwrap();
+    let expected = serde_json::to_value(Serializable::new(&item).with_options(options)).unwrap();
+    assert_eq!(value, expected);
+    assert_eq!(msgpack::from_slice::<syn::Item>(&buf).unwrap(), item);
+}
+
+#[test]
+fn test_invalid() {
+    let buf = rmp_serde::to_vec("1abc").unwrap();
+    let err = msgpack::from_slice::<proc_macro2::Ident>(&buf).unwrap_err();
+    assert!(err.to_string().contains("`1abc` is not a valid identifier"), "{err}");
+}
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "pickle")]
+
+mod helper;
+
+use std::{env, fs, path::Path, process::Command};
+
+use syn_serde::{SerializeOptions, Syn as _, pickle};
+
+#[test]
+fn test_round_trip() {
+    for (_, file) in helper::corpus() {
+        let buf = helper::assert_round_trip(&file, pickle::to_vec, |buf| pickle::from_slice(buf));
+        let mut writer = vec![];
+        pickle::to_writer(&mut writer, &file).unwrap();
+        assert_eq!(writer, buf);
+        let from_reader: syn::File = pickle::from_reader(&*buf).unwrap();
+        assert_eq!(from_reader, pickle::from_slice::<syn::File>(&buf).unwrap());
+    }
+}
+
+#[test]
+fn test_explicit_fields() {
+    let item: syn::Item = syn::parse_quote!(
+        fn f() {}
+    );
+    let options = SerializeOptions::new().explicit_fields(true);
+    let buf = pickle::to_vec_with_options(&item, options);
+    let mut writer = vec![];
+    pickle::to_writer_with_options(&mut writer, &item, options).unwrap();
+    assert_eq!(writer, buf);
+    assert_ne!(buf, pickle::to_vec(&item));
+    assert_eq!(pickle::from_slice::<syn::Item>(&buf).unwrap(), item);
+}
+
+#[test]
+fn test_invalid() {
+    let buf = serde_pickle::to_vec(&"1abc", serde_pickle::SerOptions::new()).unwrap();
+    let err = pickle::from_slice::<proc_macro2::Ident>(&buf).unwrap_err();
+    assert!(err.to_string().contains("`1abc` is not a valid identifier"), "{err}");
+}
+
+#[test]
+fn test_python() {
+    if Command::new("python3").arg("--version").output().is_err() {
+        eprintln!("skipping test_python: python3 not found");
+        return;
+    }
+    let script = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/load_pickle.py");
+    let dir = env::temp_dir().join(format!("syn-serde-test-pickle-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let (pickle_path, json_path) = (dir.join("t.pickle"), dir.join("t.json"));
+    for (path, file) in helper::corpus() {
+        fs::write(&pickle_path, pickle::to_vec(&file)).unwrap();
+        fs::write(&json_path, serde_json::to_vec(&file.to_adapter()).unwrap()).unwrap();
+        let status = Command::new("python3")
+            .arg(&script)
+            .arg(&pickle_path)
+            .arg(&json_path)
+            .status()
+            .unwrap();
+        assert!(status.success(), "{}", path.display());
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
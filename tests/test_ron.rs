@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "ron")]
+
+mod helper;
+
+use syn_serde::{Serializable, SerializeOptions, Syn as _, ron};
+
+#[track_caller]
+fn assert_ron(file: &syn::File) {
+    let s = helper::assert_round_trip(file, ron::to_string, |s| ron::from_str(s));
+    assert_eq!(ron::to_vec(file), s.as_bytes());
+    let mut writer = vec![];
+    ron::to_writer(&mut writer, file).unwrap();
+    assert_eq!(writer, s.as_bytes());
+    let pretty = ron::to_string_pretty(file);
+    assert_eq!(ron::to_vec_pretty(file), pretty.as_bytes());
+    let mut writer = vec![];
+    ron::to_writer_pretty(&mut writer, file).unwrap();
+    assert_eq!(writer, pretty.as_bytes());
+    let from_pretty: syn::File = ron::from_str(&pretty).unwrap();
+    assert_eq!(from_pretty, ron::from_str::<syn::File>(&s).unwrap());
+
+    // The data has the same shape as the JSON representation.
+    let expected = serde_json::to_value(file.to_adapter()).unwrap();
+    assert_eq!(::ron::from_str::<serde_json::Value>(&s).unwrap(), expected);
+}
+
+#[test]
+fn test_items() {
+    for raw in helper::item_cases() {
+        let file = syn::parse_file(&raw).unwrap();
+        assert_ron(&file);
+        let s = ron::to_string(&file);
+        let expected = syn::File::from_adapter(&file.to_adapter());
+        assert_eq!(ron::from_slice::<syn::File>(s.as_bytes()).unwrap(), expected);
+        assert_eq!(ron::from_reader::<syn::File, _>(s.as_bytes()).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_snapshot() {
+    let item: syn::Item = syn::parse_quote! {
+        fn f(x: u8) -> u8 {
+            x * x
+        }
+    };
+    let expected = r#"{"fn":{"ident":"f","inputs":[{"typed":(pat:{"ident":(ident:"x")},ty:{"path":{"segments":[(ident:"u8")]}})}],"output":Some({"path":{"segments":[(ident:"u8")]}}),"stmts":[{"expr":({"binary":(left:{"path":{"segments":[(ident:"x")]}},op:"*",right:{"path":{"segments":[(ident:"x")]}})},false)}]}}"#;
+    assert_eq!(ron::to_string(&item), expected);
+    assert_eq!(ron::from_str::<syn::Item>(expected).unwrap(), item);
+    assert_eq!(ron::from_str::<syn::Item>(&ron::to_string_pretty(&item)).unwrap(), item);
+}
+
+#[test]
+fn test_empty_struct() {
+    // Structs without fields are written as empty maps, like in JSON.
+    let pat: syn::Pat = syn::parse_quote!(_);
+    assert_eq!(ron::to_string(&pat), r#"{"_":{}}"#);
+    assert_eq!(ron::from_str::<syn::Pat>(&ron::to_string(&pat)).unwrap(), pat);
+    assert_eq!(ron::from_str::<syn::Pat>(&ron::to_string_pretty(&pat)).unwrap(), pat);
+}
+
+#[test]
+fn test_sources() {
+    for (_, file) in helper::corpus() {
+        assert_ron(&file);
+    }
+}
+
+#[test]
+fn test_options() {
+    let item: syn::Item = syn::parse_quote!(
+        fn f() {
+            g!(1u8)
+        }
+    );
+    let options = SerializeOptions::new().explicit_fields(true).structured_literals(true);
+    let s = ron::to_string_with_options(&item, options);
+    assert_eq!(ron::to_vec_with_options(&item, options), s.as_bytes());
+    let mut writer = vec![];
+    ron::to_writer_with_options(&mut writer, &item, options).unwrap();
+    assert_eq!(writer, s.as_bytes());
+    let pretty = ron::to_string_pretty_with_options(&item, options);
+    assert_eq!(ron::to_vec_pretty_with_options(&item, options), pretty.as_bytes());
+    let mut writer = vec![];
+    ron::to_writer_pretty_with_options(&mut writer, &item, options).unwrap();
+    assert_eq!(writer, pretty.as_bytes());
+    assert_eq!(ron::to_string_with_options(&item, options.pretty(true)), pretty);
+    assert_eq!(ron::to_vec_with_options(&item, options.pretty(true)), pretty.as_bytes());
+    let mut writer = vec![];
+    ron::to_writer_with_options(&mut writer, &item, options.pretty(true)).unwrap();
+    assert_eq!(writer, pretty.as_bytes());
+
+    let expected = serde_json::to_value(Serializable::new(&item).with_options(options)).unwrap();
+    assert_eq!(::ron::from_str::<serde_json::Value>(&s).unwrap(), expected);
+    assert_eq!(ron::from_str::<syn::Item>(&pretty).unwrap(), item);
+}
+
+#[test]
+fn test_invalid() {
+    let err = ron::from_str::<proc_macro2::Ident>(r#""1abc""#).unwrap_err();
+    assert_eq!(err.to_string(), "1:1-1:7: `1abc` is not a valid identifier");
+    let err = ron::from_str::<syn::Item>(r#"{"struct": ["#).unwrap_err();
+    assert_eq!(err.to_string(), "1:12-1:13: Unexpected end of RON");
+    assert!(ron::from_slice::<syn::Item>(b"\xff").is_err());
+}
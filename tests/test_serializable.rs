@@ -0,0 +1,331 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "json")]
+
+mod helper;
+
+use std::fmt;
+
+use serde_json::json;
+use syn_serde::{Serializable, SerializeOptions, Syn, json};
+
+#[track_caller]
+fn assert_same_output<T>(syn: &T)
+where
+    T: Syn,
+{
+    let adapter = syn.to_adapter();
+    let serializable = Serializable::new(syn);
+
+    assert_eq!(
+        serde_json::to_string(&serializable).unwrap(),
+        serde_json::to_string(&adapter).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_string_pretty(&serializable).unwrap(),
+        serde_json::to_string_pretty(&adapter).unwrap()
+    );
+    assert_eq!(rmp_serde::to_vec(&serializable).unwrap(), rmp_serde::to_vec(&adapter).unwrap());
+    assert_eq!(
+        rmp_serde::to_vec_named(&serializable).unwrap(),
+        rmp_serde::to_vec_named(&adapter).unwrap()
+    );
+    let (mut actual, mut expected) = (vec![], vec![]);
+    ciborium::into_writer(&serializable, &mut actual).unwrap();
+    ciborium::into_writer(&adapter, &mut expected).unwrap();
+    assert_eq!(actual, expected);
+    assert_eq!(
+        serde_pickle::to_vec(&serializable, serde_pickle::SerOptions::new()).unwrap(),
+        serde_pickle::to_vec(&adapter, serde_pickle::SerOptions::new()).unwrap()
+    );
+}
+
+/// Asserts that the output of `syn` with `options` is deserialized into the
+/// same syntax tree as the default output, from JSON and MessagePack (which
+/// writes the number of fields before them).
+#[track_caller]
+fn assert_round_trip<T>(syn: &T, options: SerializeOptions)
+where
+    T: Syn + PartialEq + fmt::Debug,
+{
+    let serializable = Serializable::new(syn).with_options(options);
+    let expected: T = json::from_str(&json::to_string(syn)).unwrap();
+    let json = serde_json::to_string(&serializable).unwrap();
+    assert_eq!(json::from_str::<T>(&json).unwrap(), expected);
+    // serde_json::Value sorts the keys of maps.
+    let value = serde_json::to_value(serializable).unwrap();
+    let adapter: T::Adapter = serde_json::from_value(value).unwrap();
+    assert_eq!(T::from_adapter(&adapter), expected);
+    let msgpack = rmp_serde::to_vec_named(&serializable).unwrap();
+    let adapter: T::Adapter = rmp_serde::from_slice(&msgpack).unwrap();
+    assert_eq!(T::from_adapter(&adapter), expected);
+}
+
+#[test]
+fn test_sources() {
+    for (_, file) in helper::corpus() {
+        assert_same_output(&file);
+    }
+}
+
+#[test]
+fn test_items() {
+    let file: syn::File = syn::parse_quote! {
+        #![no_std]
+        pub(crate) unsafe mod m;
+        struct Unit;
+        struct Tuple<'a, T: ?Sized + 'a>(&'a T) where T: Clone;
+        trait Trait: Sized {
+            fn f(&'a mut self, x: Self) -> Self { x }
+            fn g(self: Box<Self>);
+        }
+        fn f() {
+            let x = match 1u8 {
+                0 | 1 if true => b'a',
+                _ => 1.5e3f32 as u8,
+            };
+            let _ = (x, "s\n", b"\xFF", c"c", 'c', true, -x, !x, x << 1);
+            m!(a, b; @c [d] {e});
+        }
+    };
+    assert_same_output(&file);
+    for item in &file.items {
+        assert_same_output(item);
+    }
+}
+
+#[test]
+fn test_token_stream() {
+    let tokens: proc_macro2::TokenStream = "a + 'b' ::c (d, [e]) {f} r#g".parse().unwrap();
+    assert_same_output(&tokens);
+    for tt in tokens {
+        assert_same_output(&tt);
+    }
+}
+
+#[test]
+fn test_derive_input() {
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[derive(Clone)]
+        enum E<T> {
+            A,
+            B(T),
+            C { x: u8 } = 1,
+        }
+    };
+    assert_same_output(&input);
+}
+
+#[test]
+fn test_explicit_fields() {
+    let item: syn::Item = syn::parse_quote!(
+        fn f() {}
+    );
+    let options = SerializeOptions::new().explicit_fields(true);
+    let json = serde_json::to_value(Serializable::new(&item).with_options(options)).unwrap();
+    assert_eq!(json["fn"]["attrs"], json!([]));
+    assert_eq!(json["fn"]["vis"], "inherited");
+    assert_eq!(json["fn"]["const"], false);
+    assert_eq!(json["fn"]["abi"], json!(null));
+    assert_eq!(json["fn"]["generics"], json!({ "params": [], "where_clause": null }));
+    assert_eq!(json["fn"]["variadic"], json!(null));
+    // The default options still omit them.
+    let json = serde_json::to_value(Serializable::new(&item)).unwrap();
+    assert!(json["fn"].get("attrs").is_none());
+
+    for (_, file) in helper::corpus() {
+        assert_round_trip(&file, options);
+    }
+    let input: syn::DeriveInput = syn::parse_quote! {
+        struct S<T>(T) where T: Clone;
+    };
+    assert_round_trip(&input, options);
+}
+
+#[test]
+fn test_internally_tagged() {
+    let options = SerializeOptions::new().internally_tagged(true);
+    let item: syn::Item = syn::parse_quote!(
+        fn f<T: 'a>() -> _ {
+            x;
+            m!(a);
+        }
+    );
+    let json = serde_json::to_value(Serializable::new(&item).with_options(options)).unwrap();
+    assert_eq!(json["kind"], "ItemFn");
+    assert_eq!(json["ident"], "f");
+    assert_eq!(json["generics"]["params"][0]["kind"], "GenericParamType");
+    // Structs are not tagged.
+    assert!(json["generics"].get("kind").is_none(), "{json}");
+    assert_eq!(
+        json["generics"]["params"][0]["bounds"][0],
+        json!({ "kind": "TypeParamBoundLifetime", "value": "a" })
+    );
+    assert_eq!(json["output"], "_");
+    assert_eq!(json["stmts"][0]["kind"], "StmtExpr");
+    assert_eq!(json["stmts"][0]["value"][1], true);
+    assert_eq!(json["stmts"][1]["kind"], "StmtMacro");
+    assert_eq!(json["stmts"][1]["tokens"][0], json!({ "kind": "TokenTreeIdent", "value": "a" }));
+    assert_round_trip(&item, options);
+
+    // `kind` may be any key of the map.
+    let ty: syn::Type = json::from_str(r#"{"kind":"TypeInfer"}"#).unwrap();
+    assert_eq!(ty, syn::parse_quote!(_));
+    let ty: syn::Type = json::from_str(r#"{"kind":"TypeSlice","elem":"_"}"#).unwrap();
+    assert_eq!(ty, syn::parse_quote!([_]));
+    let ty: syn::Type = json::from_str(r#"{"elem":"_","kind":"TypeSlice"}"#).unwrap();
+    assert_eq!(ty, syn::parse_quote!([_]));
+    let ty: syn::Type =
+        json::from_str(r#"{"elem":"_","kind":"TypeArray","len":{"lit":{"int":"1"}}}"#).unwrap();
+    assert_eq!(ty, syn::parse_quote!([_; 1]));
+    // `ident` is both a variant of `Pat` and a field of `PatIdent`.
+    let pat: syn::Pat = json::from_str(r#"{"ident":"x","kind":"PatIdent","mut":true}"#).unwrap();
+    assert_eq!(pat, syn::parse_quote!(mut x));
+    let pat: syn::Pat = json::from_str(r#"{"ident":{"ident":"x"}}"#).unwrap();
+    assert_eq!(pat, syn::parse_quote!(x));
+    assert!(json::from_str::<syn::Type>(r#"{"kind":"ExprSlice","elem":"_"}"#).is_err());
+    let err = json::from_str::<syn::Type>(r#"{"elem":"_"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("unknown variant `elem`"), "{err}");
+    let err = json::from_str::<syn::Pat>(r#"{"ident":"x","mut":true}"#).unwrap_err();
+    assert!(err.to_string().starts_with("missing field `kind`"), "{err}");
+    let bound = r#"{"kind":"TypeParamBoundLifetime","value":"a","x":1}"#;
+    assert!(json::from_str::<syn::TypeParamBound>(bound).is_err());
+
+    for (_, file) in helper::corpus() {
+        assert_round_trip(&file, options);
+    }
+    let input: syn::DeriveInput = syn::parse_quote! {
+        pub(crate) enum E<T> where T: Clone { A, B(T), C { x: u8 } }
+    };
+    assert_round_trip(&input, options);
+    // Tokens without source text are written with normalized spacing.
+    let tokens = quote::quote!(a + 'b' ::c (d, [e]) {f});
+    let json = serde_json::to_string(&Serializable::new(&tokens).with_options(options)).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value[1]["kind"], "TokenTreePunct");
+    assert_eq!(value[1]["op"], "+");
+    let actual: proc_macro2::TokenStream = json::from_str(&json).unwrap();
+    assert_eq!(actual.to_string(), tokens.to_string());
+}
+
+#[test]
+fn test_token_text() {
+    let options = SerializeOptions::new().token_text(true);
+    let input: syn::DeriveInput = syn::parse_quote! {
+        #[derive(Debug, Clone)]
+        struct S;
+    };
+    let json = serde_json::to_value(Serializable::new(&input).with_options(options)).unwrap();
+    assert_eq!(json["attrs"][0]["meta"]["list"]["tokens"], "Debug , Clone");
+    assert_round_trip(&input, options);
+    // Tokens without source text are written with normalized spacing.
+    let tokens = quote::quote!(a + 'b' ::c (d, [e]) {f});
+    let json = serde_json::to_string(&Serializable::new(&tokens).with_options(options)).unwrap();
+    assert_eq!(json, r#""a + 'b' :: c (d , [e]) { f }""#);
+    let actual: proc_macro2::TokenStream = json::from_str(&json).unwrap();
+    assert_eq!(actual.to_string(), tokens.to_string());
+
+    // The text must be lexed into tokens.
+    let err = json::from_str::<proc_macro2::TokenStream>(r#""a (b""#).unwrap_err();
+    assert!(err.to_string().contains("is not a valid token stream"), "{err}");
+    assert!(json::from_str::<proc_macro2::TokenStream>(r#""a )""#).is_err());
+
+    for (_, file) in helper::corpus() {
+        assert_round_trip(&file, options);
+    }
+}
+
+#[cfg(feature = "macro-args")]
+#[test]
+fn test_macro_args() {
+    let options = SerializeOptions::new().macro_args(true);
+    let args = |mac: syn::Macro| {
+        let json = serde_json::to_value(Serializable::new(&mac).with_options(options)).unwrap();
+        assert_round_trip(&mac, options);
+        json["args"].clone()
+    };
+
+    assert_eq!(
+        args(syn::parse_quote!(println!("{} {x}", a, x = 1))),
+        json!({
+            "format": {
+                "string": "\"{} {x}\"",
+                "args": [
+                    { "expr": { "path": { "segments": [{ "ident": "a" }] } } },
+                    { "name": "x", "expr": { "lit": { "int": "1" } } },
+                ],
+            },
+        })
+    );
+    assert_eq!(
+        args(syn::parse_quote!(write!(f, "{}", a,))),
+        json!({
+            "format": {
+                "exprs": [{ "path": { "segments": [{ "ident": "f" }] } }],
+                "string": "\"{}\"",
+                "args": [{ "expr": { "path": { "segments": [{ "ident": "a" }] } } }],
+            },
+        })
+    );
+    assert_eq!(
+        args(syn::parse_quote!(std::assert_eq!(a, b))),
+        json!({
+            "format": {
+                "exprs": [
+                    { "path": { "segments": [{ "ident": "a" }] } },
+                    { "path": { "segments": [{ "ident": "b" }] } },
+                ],
+            },
+        })
+    );
+    assert_eq!(args(syn::parse_quote!(println!())), json!({ "format": {} }));
+    assert_eq!(
+        args(syn::parse_quote!(vec![1, 2])),
+        json!({ "list": [{ "lit": { "int": "1" } }, { "lit": { "int": "2" } }] })
+    );
+    assert_eq!(
+        args(syn::parse_quote!(vec![0; n])),
+        json!({
+            "repeat": {
+                "expr": { "lit": { "int": "0" } },
+                "len": { "path": { "segments": [{ "ident": "n" }] } },
+            },
+        })
+    );
+    // Other macros and arguments that cannot be parsed.
+    assert_eq!(args(syn::parse_quote!(m!(a, b))), json!(null));
+    assert_eq!(args(syn::parse_quote!(my::println!("{}", a))), json!(null));
+    assert_eq!(args(syn::parse_quote!(println!(a b))), json!(null));
+    assert_eq!(args(syn::parse_quote!(vec![a; b; c])), json!(null));
+
+    // The tokens are rebuilt from `args` if they are empty.
+    let expr: syn::Expr = json::from_str(
+        r#"{"macro": {"path": {"segments": [{"ident": "assert"}]}, "delimiter": "paren",
+        "args": {"format": {"exprs": [{"lit": {"bool": true}}], "string": "\"{}\"",
+        "args": [{"name": "x", "expr": {"lit": {"int": "1"}}}]}}}}"#,
+    )
+    .unwrap();
+    let expected: syn::Expr = syn::parse_quote!(assert!(true, "{}", x = 1));
+    assert_eq!(expr, expected);
+    let expr: syn::Expr = json::from_str(
+        r#"{"macro": {"path": {"segments": [{"ident": "vec"}]}, "delimiter": "bracket",
+        "tokens": [], "args": {"repeat": {"expr": {"lit": {"int": "0"}}, "len": {"lit": {"int": "2"}}}}}}"#,
+    )
+    .unwrap();
+    let expected: syn::Expr = syn::parse_quote!(vec![0; 2]);
+    assert_eq!(expr, expected);
+
+    // If both are written, `tokens` and `args` must describe the same
+    // arguments.
+    let mac: syn::Macro = syn::parse_quote!(vec![1, 2,]);
+    let mut json = serde_json::to_value(Serializable::new(&mac).with_options(options)).unwrap();
+    let adapter: <syn::Macro as Syn>::Adapter = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(syn::Macro::from_adapter(&adapter), mac);
+    json["args"]["list"][1] = json!({ "lit": { "int": "3" } });
+    let e = serde_json::from_value::<<syn::Macro as Syn>::Adapter>(json).unwrap_err();
+    assert_eq!(e.to_string(), "Macro: `tokens` and `args` describe different arguments");
+
+    for (_, file) in helper::corpus() {
+        assert_round_trip(&file, options);
+    }
+}
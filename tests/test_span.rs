@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "json")]
+#![allow(clippy::needless_raw_string_hashes)]
+
+use syn::*;
+#[cfg(feature = "span-locations")]
+use syn_serde::{Serializable, SerializeOptions};
+
+#[cfg(feature = "span-locations")]
+#[test]
+fn test_span_locations() {
+    let raw = "
+fn main() {
+    println!(\"Hello, world!\");
+}
+";
+
+    let file: File = syn::parse_str(raw).unwrap();
+    let options = SerializeOptions::new().spans(true);
+    let json = serde_json::to_value(Serializable::new(&file).with_options(options)).unwrap();
+    let item = &json["items"][0]["fn"];
+    assert_eq!(
+        item["span"],
+        serde_json::json!({
+            "start": { "line": 2, "column": 0 },
+            "end": { "line": 4, "column": 1 }
+        })
+    );
+    let stmt = &item["stmts"][0]["macro"];
+    assert_eq!(
+        stmt["span"],
+        serde_json::json!({
+            "start": { "line": 3, "column": 4 },
+            "end": { "line": 3, "column": 30 }
+        })
+    );
+
+    // Identifiers and literals record their location next to their text.
+    assert_eq!(
+        item["ident"],
+        serde_json::json!({
+            "name": "main",
+            "span": { "start": { "line": 2, "column": 3 }, "end": { "line": 2, "column": 7 } }
+        })
+    );
+    assert_eq!(
+        stmt["tokens"][0]["lit"],
+        serde_json::json!({
+            "text": "\"Hello, world!\"",
+            "span": { "start": { "line": 3, "column": 13 }, "end": { "line": 3, "column": 28 } }
+        })
+    );
+    assert_eq!(syn_serde::json::from_str::<File>(&json.to_string()).unwrap(), file);
+
+    // Locations are not serialized by default.
+    let json = syn_serde::json::to_string(&file);
+    assert!(!json.contains("\"span\""), "{json}");
+    let json = serde_json::to_string(&syn_serde::Syn::to_adapter(&file)).unwrap();
+    assert!(!json.contains("\"span\""), "{json}");
+
+    // Trees that were not parsed from source have no location.
+    let file: File = syn::parse_quote!(
+        struct S;
+    );
+    let json = serde_json::to_string(&Serializable::new(&file).with_options(options)).unwrap();
+    assert!(!json.contains("\"span\""), "{json}");
+}
+
+#[cfg(feature = "span-locations")]
+#[test]
+fn test_token_text() {
+    let raw = "
+#[derive(Debug,  Clone)]
+struct S;
+
+m! { a + 'b' ::c (d, [e]) {f} }
+";
+
+    let file: File = syn::parse_str(raw).unwrap();
+    let options = SerializeOptions::new().token_text(true);
+    let json = serde_json::to_value(Serializable::new(&file).with_options(options)).unwrap();
+    // Tokens parsed from source keep their source text.
+    assert_eq!(json["items"][0]["struct"]["attrs"][0]["meta"]["list"]["tokens"], "Debug,  Clone");
+    assert_eq!(json["items"][1]["macro"]["tokens"], "a + 'b' ::c (d, [e]) {f}");
+    assert_eq!(syn_serde::json::from_str::<File>(&json.to_string()).unwrap(), file);
+}
+
+#[test]
+fn test_ignore_span() {
+    let raw = "struct Unit;";
+
+    let json = r#"
+    {
+      "struct": {
+        "ident": {
+          "name": "Unit",
+          "span": {
+            "start": { "line": 1, "column": 7 },
+            "end": { "line": 1, "column": 11 }
+          }
+        },
+        "fields": "unit",
+        "span": {
+          "start": { "line": 1, "column": 0 },
+          "end": { "line": 1, "column": 12 }
+        }
+      }
+    }
+    "#;
+
+    let actual: Item = syn::parse_str(raw).unwrap();
+    let json: Item = syn_serde::json::from_str(json).unwrap();
+    assert_eq!(json, actual);
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Checks that schema/syn-serde.d.ts is in sync with the generated adapters.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+fn read(path: &str) -> String {
+    fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join(path)).unwrap()
+}
+
+/// Returns the declarations in the .d.ts file, keyed by the type name.
+fn declarations() -> BTreeMap<String, String> {
+    let mut declarations = BTreeMap::new();
+    for block in read("schema/syn-serde.d.ts").split("\n\n") {
+        let Some(start) = block.find("export ") else { continue };
+        let declaration = &block[start..];
+        let name = declaration.split([' ', '<']).nth(2).unwrap();
+        declarations.insert(name.to_owned(), declaration.to_owned());
+    }
+    declarations
+}
+
+#[derive(Default)]
+struct SerdeAttrs {
+    rename: Option<String>,
+    default: bool,
+    flatten: bool,
+    transparent: bool,
+}
+
+fn serde_attrs(attrs: &[syn::Attribute]) -> SerdeAttrs {
+    let mut serde_attrs = SerdeAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                serde_attrs.rename = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            } else if meta.path.is_ident("default") {
+                serde_attrs.default = true;
+            } else if meta.path.is_ident("flatten") {
+                serde_attrs.flatten = true;
+            } else if meta.path.is_ident("transparent") {
+                serde_attrs.transparent = true;
+            } else if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        })
+        .unwrap();
+    }
+    serde_attrs
+}
+
+/// Converts a variant name as `#[serde(rename_all = "snake_case")]` does.
+fn snake_case(ident: &syn::Ident) -> String {
+    let mut name = String::new();
+    for (i, ch) in ident.to_string().char_indices() {
+        if i > 0 && ch.is_ascii_uppercase() {
+            name.push('_');
+        }
+        name.push(ch.to_ascii_lowercase());
+    }
+    name
+}
+
+#[test]
+fn test_structs() {
+    let declarations = declarations();
+    let file = syn::parse_file(&read("src/gen/ast_struct.rs")).unwrap();
+    for item in file.items {
+        let syn::Item::Struct(item) = item else { continue };
+        let name = item.ident.to_string();
+        let declaration = declarations.get(&name).unwrap_or_else(|| panic!("{name}: missing"));
+        if serde_attrs(&item.attrs).transparent {
+            assert!(declaration.starts_with(&format!("export type {name} = ")), "{declaration}");
+            assert!(!declaration.contains('\n'), "{declaration}");
+            continue;
+        }
+        let mut has_flatten = false;
+        let mut properties = 0;
+        for field in &item.fields {
+            let attrs = serde_attrs(&field.attrs);
+            if attrs.flatten {
+                has_flatten = true;
+                continue;
+            }
+            let field_name =
+                attrs.rename.unwrap_or_else(|| field.ident.as_ref().unwrap().to_string());
+            let optional = if attrs.default { "?" } else { "" };
+            let property = format!("\n  {field_name}{optional}: ");
+            assert!(declaration.contains(&property), "{name}: missing `{property}`: {declaration}");
+            properties += 1;
+        }
+        if name == "Macro" {
+            // `args` is only serialized with `SerializeOptions::macro_args`.
+            assert!(declaration.contains("\n  args?: MacroArgs;"), "{declaration}");
+            properties += 1;
+        }
+        if !has_flatten {
+            let count = declaration.lines().filter(|line| line.starts_with("  ")).count();
+            assert_eq!(count, properties, "{declaration}");
+        }
+    }
+}
+
+#[test]
+fn test_enums() {
+    let declarations = declarations();
+    let file = syn::parse_file(&read("src/gen/ast_enum.rs")).unwrap();
+    for item in file.items {
+        let syn::Item::Enum(item) = item else { continue };
+        let name = item.ident.to_string();
+        let declaration = declarations.get(&name).unwrap_or_else(|| panic!("{name}: missing"));
+        let members: Vec<_> = if declaration.contains("\n  | ") {
+            declaration.split("\n  | ").skip(1).map(|member| member.trim_end_matches(';')).collect()
+        } else {
+            let (_, ty) = declaration.split_once(" = ").unwrap();
+            vec![ty.trim_end_matches(';')]
+        };
+        if item.variants.is_empty() {
+            assert_eq!(members, ["never"], "{declaration}");
+            continue;
+        }
+        // The internally tagged representation, if the enum has one.
+        let (tagged, members): (Vec<_>, Vec<_>) =
+            members.into_iter().partition(|m| m.starts_with("{ kind: \""));
+        assert_eq!(members.len(), item.variants.len(), "{declaration}");
+        if !tagged.is_empty() {
+            // Unit variants are written as strings in both representations.
+            let count = item.variants.iter().filter(|v| !v.fields.is_empty()).count();
+            assert_eq!(tagged.len(), count, "{declaration}");
+        }
+        for variant in &item.variants {
+            let variant_name =
+                serde_attrs(&variant.attrs).rename.unwrap_or_else(|| snake_case(&variant.ident));
+            let member = if variant.fields.is_empty() {
+                format!("\"{variant_name}\"")
+            } else {
+                format!("{{ {variant_name}: ")
+            };
+            assert_eq!(
+                members.iter().filter(|m| m.starts_with(&member)).count(),
+                1,
+                "{name}::{}: missing `{member}`: {declaration}",
+                variant.ident
+            );
+        }
+    }
+}
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(all(feature = "visit", feature = "visit-mut", feature = "fold"))]
+
+use syn_serde::{Syn as _, fold::Fold, visit::Visit, visit_mut::VisitMut};
+
+#[derive(Default)]
+struct Idents(Vec<String>);
+
+impl<'ast> Visit<'ast> for Idents {
+    fn visit_ident(&mut self, node: &'ast syn_serde::Ident) {
+        self.0.push(proc_macro2::Ident::from_adapter(node).to_string());
+    }
+}
+
+#[test]
+fn test_visit() {
+    let item: syn::Item = syn::parse_quote! {
+        impl<T: Clone> Trait for S<T> {
+            fn f(&'a mut self, x: u8) -> Ret {
+                let y = x;
+                y
+            }
+        }
+    };
+    let mut idents = Idents::default();
+    idents.visit_item(&item.to_adapter());
+    assert_eq!(idents.0, [
+        "T", "Clone", "Trait", "S", "T", "f", "a", "a", "Self", "x", "u8", "Ret", "y", "x", "y"
+    ]);
+}
+
+struct Rename;
+
+impl Rename {
+    fn rename(node: &syn_serde::Ident) -> syn_serde::Ident {
+        let ident = proc_macro2::Ident::from_adapter(node);
+        if matches!(&*ident.to_string(), "crate" | "self" | "Self") {
+            return ident.to_adapter();
+        }
+        quote::format_ident!("{}_", ident).to_adapter()
+    }
+}
+
+impl VisitMut for Rename {
+    fn visit_ident_mut(&mut self, node: &mut syn_serde::Ident) {
+        *node = Self::rename(node);
+    }
+}
+
+impl Fold for Rename {
+    fn fold_ident(&mut self, node: syn_serde::Ident) -> syn_serde::Ident {
+        Self::rename(&node)
+    }
+}
+
+#[test]
+fn test_visit_mut() {
+    let json = r#"
+    {
+      "fn": {
+        "ident": "f",
+        "inputs": [
+          {
+            "receiver": {
+              "ref": true,
+              "mut": true,
+              "ty": { "reference": { "mut": true, "elem": { "path": { "segments": [{ "ident": "Self" }] } } } }
+            }
+          }
+        ],
+        "output": null,
+        "stmts": [{ "expr": [{ "path": { "segments": [{ "ident": "x" }] } }, true] }]
+      }
+    }
+    "#;
+    let mut item: syn_serde::Item = serde_json::from_str(json).unwrap();
+    Rename.visit_item_mut(&mut item);
+    let expected: syn::Item = syn::parse_quote! {
+        fn f_(&mut self) { x_; }
+    };
+    assert_eq!(syn::Item::from_adapter(&item), expected);
+}
+
+#[test]
+fn test_fold() {
+    let item: syn::Item = syn::parse_quote! {
+        unsafe impl<T> !Trait for S<T> where T: 'static {}
+    };
+    let item = syn::Item::from_adapter(&Rename.fold_item(item.to_adapter()));
+    let expected: syn::Item = syn::parse_quote! {
+        unsafe impl<T_> !Trait_ for S_<T_> where T_: 'static_ {}
+    };
+    assert_eq!(item, expected);
+}
+
+#[cfg(feature = "macro-args")]
+#[test]
+fn test_macro_args() {
+    let json = r#"
+    {
+      "format": {
+        "exprs": [{ "path": { "segments": [{ "ident": "f" }] } }],
+        "string": "\"{} {x}\"",
+        "args": [
+          { "expr": { "path": { "segments": [{ "ident": "a" }] } } },
+          { "name": "x", "expr": { "path": { "segments": [{ "ident": "b" }] } } }
+        ]
+      }
+    }
+    "#;
+    let args: syn_serde::MacroArgs = serde_json::from_str(json).unwrap();
+    let mut idents = Idents::default();
+    idents.visit_macro_args(&args);
+    assert_eq!(idents.0, ["f", "a", "x", "b"]);
+
+    let expected = json.replace(r#""f""#, r#""f_""#).replace(r#""a""#, r#""a_""#);
+    let expected = expected.replace(r#""x""#, r#""x_""#).replace(r#""b""#, r#""b_""#);
+    let expected: serde_json::Value = serde_json::from_str(&expected).unwrap();
+    let mut renamed: syn_serde::MacroArgs = serde_json::from_str(json).unwrap();
+    Rename.visit_macro_args_mut(&mut renamed);
+    assert_eq!(serde_json::to_value(&renamed).unwrap(), expected);
+    let folded = Rename.fold_macro_args(args);
+    assert_eq!(serde_json::to_value(&folded).unwrap(), expected);
+
+    let json = r#"
+    {
+      "repeat": {
+        "expr": { "path": { "segments": [{ "ident": "a" }] } },
+        "len": { "path": { "segments": [{ "ident": "n" }] } }
+      }
+    }
+    "#;
+    let args: syn_serde::MacroArgs = serde_json::from_str(json).unwrap();
+    let mut idents = Idents::default();
+    idents.visit_macro_args(&args);
+    assert_eq!(idents.0, ["a", "n"]);
+}
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "yaml")]
+
+mod helper;
+
+use syn_serde::{Serializable, SerializeOptions, Syn as _, yaml};
+
+#[track_caller]
+fn assert_yaml(file: &syn::File) {
+    let s = helper::assert_round_trip(file, yaml::to_string, |s| yaml::from_str(s));
+    assert_eq!(yaml::to_vec(file), s.as_bytes());
+    let mut writer = vec![];
+    yaml::to_writer(&mut writer, file).unwrap();
+    assert_eq!(writer, s.as_bytes());
+
+    // The data has the same shape as the JSON representation.
+    let value: serde_json::Value = serde_yaml_ng::from_str(&s).unwrap();
+    assert_eq!(value, serde_json::to_value(file.to_adapter()).unwrap());
+}
+
+#[test]
+fn test_items() {
+    for raw in helper::item_cases() {
+        let file = syn::parse_file(&raw).unwrap();
+        assert_yaml(&file);
+        let s = yaml::to_string(&file);
+        assert_eq!(yaml::to_string_pretty(&file), s);
+        let expected = syn::File::from_adapter(&file.to_adapter());
+        assert_eq!(yaml::from_slice::<syn::File>(s.as_bytes()).unwrap(), expected);
+        assert_eq!(yaml::from_reader::<syn::File, _>(s.as_bytes()).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_snapshot() {
+    let item: syn::Item = syn::parse_quote! {
+        fn f(x: u8) -> u8 {
+            x * x
+        }
+    };
+    let expected = "fn:
+  ident: f
+  inputs:
+  - typed:
+      pat:
+        ident:
+          ident: x
+      ty:
+        path:
+          segments:
+          - ident: u8
+  output:
+    path:
+      segments:
+      - ident: u8
+  stmts:
+  - expr:
+    - binary:
+        left:
+          path:
+            segments:
+            - ident: x
+        op: '*'
+        right:
+          path:
+            segments:
+            - ident: x
+    - false
+";
+    assert_eq!(yaml::to_string(&item), expected);
+    assert_eq!(yaml::from_str::<syn::Item>(expected).unwrap(), item);
+}
+
+#[test]
+fn test_sources() {
+    for (_, file) in helper::corpus() {
+        assert_yaml(&file);
+    }
+}
+
+#[test]
+fn test_options() {
+    let item: syn::Item = syn::parse_quote!(
+        fn f() {
+            g!(1u8)
+        }
+    );
+    let options = SerializeOptions::new().explicit_fields(true).structured_literals(true);
+    let s = yaml::to_string_with_options(&item, options);
+    assert_eq!(yaml::to_vec_with_options(&item, options), s.as_bytes());
+    let mut writer = vec![];
+    yaml::to_writer_with_options(&mut writer, &item, options).unwrap();
+    assert_eq!(writer, s.as_bytes());
+    let pretty = yaml::to_string_pretty_with_options(&item, options);
+    assert_eq!(yaml::to_vec_pretty_with_options(&item, options), pretty.as_bytes());
+    let mut writer = vec![];
+    yaml::to_writer_pretty_with_options(&mut writer, &item, options).unwrap();
+    assert_eq!(writer, pretty.as_bytes());
+
+    let expected = serde_json::to_value(Serializable::new(&item).with_options(options)).unwrap();
+    assert_eq!(serde_yaml_ng::from_str::<serde_json::Value>(&s).unwrap(), expected);
+    assert_eq!(yaml::from_str::<syn::Item>(&pretty).unwrap(), item);
+}
+
+#[test]
+fn test_invalid() {
+    let err = yaml::from_str::<proc_macro2::Ident>("1abc").unwrap_err();
+    assert!(err.to_string().contains("`1abc` is not a valid identifier"), "{err}");
+    assert!(yaml::from_str::<syn::Item>("struct: [").is_err());
+}
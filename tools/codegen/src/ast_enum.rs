@@ -1,13 +1,16 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use proc_macro2::TokenStream;
+use std::collections::BTreeSet;
+
+use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote};
 use syn_codegen::{Data, Definitions, Node, Type};
 use test_helper::{bin_name, codegen::file, function_name};
 
 use crate::{
+    ast_struct,
     convert::{EMPTY_STRUCTS, IGNORED_TYPES},
-    traverse, workspace_root,
+    ser, traverse, workspace_root,
 };
 
 const AST_ENUM_SRC: &str = "src/gen/ast_enum.rs";
@@ -33,6 +36,93 @@ fn rename(ident: &str, variant: &str) -> Option<&'static str> {
     }
 }
 
+/// Returns the name of `variant` in the serialized form, as renamed by
+/// `rename` and `#[serde(rename_all = "snake_case")]`.
+pub(crate) fn serialized_name(
+    ident: &str,
+    variant: &str,
+    fields: &[Type],
+    defs: &Definitions,
+) -> String {
+    if let Some(s) = rename(ident, variant) {
+        return s.to_owned();
+    }
+    if let [Type::Token(t) | Type::Group(t)] = fields {
+        if matches!(ident, "BinOp" | "UnOp") {
+            return defs.tokens[t].clone();
+        }
+    }
+    let mut name = String::new();
+    for (i, ch) in variant.char_indices() {
+        if i > 0 && ch.is_ascii_uppercase() {
+            name.push('_');
+        }
+        name.push(ch.to_ascii_lowercase());
+    }
+    name
+}
+
+/// Whether the adapter of `node` is an enum that has an internally tagged
+/// representation (see `tagged.rs`), i.e., an enum with a variant that has a
+/// payload and whose variants are not flattened into their parent.
+pub(crate) fn is_tagged(node: &Node, defs: &Definitions) -> bool {
+    let Data::Enum(variants) = &node.data else { return false };
+    !ser::flattened(defs).contains(&node.ident)
+        && variants.values().any(|fields| payload(fields).is_some())
+}
+
+/// Returns the type of the payload of a variant, or `None` if the variant is a
+/// unit variant in the adapter.
+pub(crate) fn payload(fields: &[Type]) -> Option<&str> {
+    match fields.first()? {
+        Type::Syn(s) if EMPTY_STRUCTS.contains(&&**s) => None,
+        Type::Syn(s) | Type::Ext(s) => Some(s),
+        _ => None,
+    }
+}
+
+/// Whether the payload of a variant is serialized as a struct, whose fields are
+/// written next to `kind` in the internally tagged representation.
+pub(crate) fn is_inline(ty: &str, defs: &Definitions) -> bool {
+    // Written by hand with `#[serde(transparent)]`.
+    const TRANSPARENT: &[&str] = &["DataStruct"];
+
+    defs.types.iter().any(|node| {
+        node.ident == ty
+            && matches!(node.data, Data::Struct(_))
+            && !ast_struct::is_transparent(node)
+            && !TRANSPARENT.contains(&ty)
+    })
+}
+
+/// Inserts the keys that are written next to `kind` for a payload of type
+/// `ty`, including the keys of its flattened fields.
+fn inline_keys(ty: &str, defs: &Definitions, keys: &mut BTreeSet<String>) {
+    let node = defs.types.iter().find(|node| node.ident == ty).unwrap();
+    match &node.data {
+        Data::Struct(fields) => {
+            for (field, ty) in fields {
+                if ast_struct::format_ty(ty).is_none() {
+                    continue;
+                }
+                if ast_struct::flatten(&node.ident, field, ty) {
+                    let Type::Syn(t) = ty else { unreachable!("flatten: {ty:?}") };
+                    inline_keys(t, defs, keys);
+                } else {
+                    keys.insert(ast_struct::serialized_name(field, ty, defs));
+                }
+            }
+        }
+        // The variants of a flattened enum are written as keys.
+        Data::Enum(variants) => {
+            for (variant, fields) in variants {
+                keys.insert(serialized_name(&node.ident, variant, fields, defs));
+            }
+        }
+        Data::Private => unreachable!("inline_keys: {ty}"),
+    }
+}
+
 fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
     if SKIPPED.contains(&&*node.ident) || IGNORED_TYPES.contains(&&*node.ident) {
         return;
@@ -40,8 +130,31 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
 
     if let Data::Enum(variants) = &node.data {
         let mut body = TokenStream::new();
+        let mut names = vec![];
+        let mut kinds = vec![];
+        let mut inline = vec![];
+        let mut arms = TokenStream::new();
+        // The keys written next to `kind` by the variants whose payload is
+        // inline.
+        let mut keys = BTreeSet::new();
+
+        for (i, (variant, fields)) in variants.iter().enumerate() {
+            names.push(serialized_name(&node.ident, variant, fields, defs));
+            kinds.push(format!("{}{variant}", node.ident));
+            let index = Literal::usize_unsuffixed(i);
+            let v = format_ident!("{variant}");
+            if let Some(ty) = payload(fields) {
+                inline.push(is_inline(ty, defs));
+                if is_inline(ty, defs) {
+                    inline_keys(ty, defs, &mut keys);
+                }
+                let ty = format_ident!("{ty}");
+                arms.extend(quote!(#index => #ty::deserialize(deserializer).map(Self::#v),));
+            } else {
+                inline.push(false);
+                arms.extend(quote!(#index => <()>::deserialize(deserializer).map(|()| Self::#v),));
+            }
 
-        for (variant, fields) in variants {
             body.extend(rename(&node.ident, variant).map(|s| quote!(#[serde(rename = #s)])));
 
             let variant = format_ident!("{variant}");
@@ -76,16 +189,60 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
             quote! { #[non_exhaustive] }
         };
 
+        let ambiguous: Vec<_> = names.iter().map(|name| keys.contains(name)).collect();
+
         let ident = format_ident!("{}", node.ident);
         let doc = format!(" An adapter for [`enum@syn::{}`].", node.ident);
+        let extra_traits = ast_struct::extra_traits(&node.ident);
+        if !is_tagged(node, defs) {
+            impls.extend(quote! {
+                #[doc = #doc]
+                #[derive(Serialize, Deserialize)]
+                #extra_traits
+                #[serde(rename_all = "snake_case")]
+                #non_exhaustive
+                pub enum #ident {
+                    #body
+                }
+            });
+            return;
+        }
+
         impls.extend(quote! {
             #[doc = #doc]
-            #[derive(Serialize, Deserialize)]
+            #[derive(Serialize)]
+            #extra_traits
             #[serde(rename_all = "snake_case")]
             #non_exhaustive
             pub enum #ident {
                 #body
             }
+            impl<'de> Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    tagged::deserialize(deserializer)
+                }
+            }
+            impl tagged::TaggedEnum for #ident {
+                const VARIANTS: &'static [&'static str] = &[#(#names),*];
+                const KINDS: &'static [&'static str] = &[#(#kinds),*];
+                const INLINE: &'static [bool] = &[#(#inline),*];
+                const AMBIGUOUS: &'static [bool] = &[#(#ambiguous),*];
+                fn deserialize_variant<'de, D>(
+                    index: usize,
+                    deserializer: D,
+                ) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    match index {
+                        #arms
+                        _ => unreachable!(),
+                    }
+                }
+            }
         });
     }
 }
@@ -95,6 +252,9 @@ pub(crate) fn generate(defs: &Definitions) {
     let impls = traverse::traverse(defs, node);
     let path = &workspace_root.join(AST_ENUM_SRC);
     file::write(function_name!(), bin_name!(), workspace_root, path, quote! {
+        // `span` fields make some variants larger.
+        #![allow(clippy::large_enum_variant)]
+
         use crate::*;
 
         #impls
@@ -1,18 +1,19 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::assert_matches;
+use std::{assert_matches, fmt::Write as _};
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn_codegen::{Data, Definitions, Node, Punctuated, Type};
 use test_helper::{bin_name, codegen::file, function_name};
 
-use crate::{convert::EMPTY_STRUCTS, traverse, workspace_root};
+use crate::{convert::EMPTY_STRUCTS, traverse, visit, workspace_root};
 
 const AST_ENUM_SRC: &str = "src/gen/ast_struct.rs";
 
 const SKIPPED: &[&str] = &[
     // data.rs
+    "DataStruct",
     "Field", // TODO
     // expr.rs
     "Arm",
@@ -34,28 +35,43 @@ fn struct_attrs(ident: &str) -> TokenStream {
     match ident {
         "Lifetime" => quote!(#[derive(Clone)]),
         "BoundLifetimes" => quote!(#[derive(Default)]),
+        "Macro" => {
+            quote!(#[cfg_attr(feature = "macro-args", serde(try_from = "mac::MacroWithArgs"))])
+        }
         _ => quote!(),
     }
 }
 
-// Some fields always have the same attributes.
-fn field_attrs(field: &str, ty: &Type, defs: &Definitions) -> TokenStream {
-    fn is_keyword(token: &str) -> bool {
-        matches!(
-            token,
-            "Mut"
-                | "Ref"
-                | "Const"
-                | "Dyn"
-                | "Unsafe"
-                | "Default"
-                | "Async"
-                | "Static"
-                | "Move"
-                | "Auto"
-        )
+pub(crate) fn extra_traits(ident: &str) -> TokenStream {
+    match ident {
+        // `Clone` is always implemented.
+        "Lifetime" => {
+            quote!(#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, Hash))])
+        }
+        _ => {
+            quote!(#[cfg_attr(feature = "extra-traits", derive(Debug, Clone, PartialEq, Eq, Hash))])
+        }
     }
+}
+
+fn is_keyword(token: &str) -> bool {
+    matches!(
+        token,
+        "Mut"
+            | "Ref"
+            | "Const"
+            | "Dyn"
+            | "Unsafe"
+            | "Default"
+            | "Async"
+            | "Static"
+            | "Move"
+            | "Auto"
+    )
+}
 
+// Some fields always have the same attributes.
+fn field_attrs(field: &str, ty: &Type, defs: &Definitions) -> TokenStream {
     match ty {
         Type::Box(ty) => return field_attrs(field, ty, defs),
         Type::Option(ty) => match &**ty {
@@ -130,6 +146,49 @@ fn field_attrs(field: &str, ty: &Type, defs: &Definitions) -> TokenStream {
     quote!()
 }
 
+/// Returns the name of `field` in the serialized form, as renamed by
+/// `field_attrs` and `rename`.
+pub(crate) fn serialized_name(field: &str, ty: &Type, defs: &Definitions) -> String {
+    match ty {
+        Type::Box(ty) => return serialized_name(field, ty, defs),
+        Type::Option(ty) => {
+            if let Type::Token(ty) | Type::Group(ty) = &**ty {
+                if is_keyword(ty) {
+                    return defs.tokens[ty].clone();
+                }
+            }
+        }
+        Type::Syn(ty) => match &**ty {
+            "StaticMutability" | "FieldMutability" => return "mut".to_owned(),
+            "Block" if field == "block" => return "stmts".to_owned(),
+            _ => {}
+        },
+        _ => {}
+    }
+    rename("", field).unwrap_or(field).to_owned()
+}
+
+/// Returns whether `field` may be omitted in the serialized form, as determined
+/// by `field_attrs` and `skip_serializing_if`.
+pub(crate) fn has_default(ident: &str, field: &str, ty: &Type) -> bool {
+    let default = match ty {
+        Type::Box(ty) => return has_default(ident, field, ty),
+        Type::Option(_) => true,
+        Type::Syn(ty) => matches!(
+            &**ty,
+            "Visibility"
+                | "StaticMutability"
+                | "FieldMutability"
+                | "Generics"
+                | "PathArguments"
+                | "TraitBoundModifier"
+                | "ReturnType"
+        ),
+        _ => false,
+    };
+    default || skip_serializing_if(ident, field, ty).is_some()
+}
+
 fn skip_serializing_if(ident: &str, field: &str, ty: &Type) -> Option<String> {
     match (ident, field) {
         (_, "attrs")
@@ -152,7 +211,7 @@ fn allow_transparent(ident: &str, field: &str, ty: &Type) -> bool {
         && !ident.starts_with("Type")
 }
 
-fn flatten(ident: &str, field: &str, ty: &Type) -> bool {
+pub(crate) fn flatten(ident: &str, field: &str, ty: &Type) -> bool {
     match (field, base_ty(ty)) {
         ("member", Some("Member")) | ("mac", Some("Macro")) | ("sig", Some("Signature")) => true,
         ("lit", Some("Lit")) => ident.ends_with("Lit"),
@@ -183,7 +242,7 @@ fn outer_ty(ty: &Type) -> &str {
     }
 }
 
-fn format_ty(ty: &Type) -> Option<TokenStream> {
+pub(crate) fn format_ty(ty: &Type) -> Option<TokenStream> {
     match ty {
         Type::Box(t)
         | Type::Vec(t)
@@ -216,6 +275,59 @@ fn format_ty(ty: &Type) -> Option<TokenStream> {
     }
 }
 
+// Nodes whose location is covered by their parent's: these are flattened into
+// the parent or have no span of their own.
+const NO_SPAN: &[&str] = &["LocalInit", "Macro", "Path", "Signature"];
+
+pub(crate) fn is_transparent(node: &Node) -> bool {
+    let Data::Struct(fields) = &node.data else { return false };
+    let mut serialized = fields.iter().filter(|(_, ty)| format_ty(ty).is_some());
+    match (serialized.next(), serialized.next()) {
+        (Some((field, ty)), None) => allow_transparent(&node.ident, field, ty),
+        _ => false,
+    }
+}
+
+/// Whether the adapter of `node` has a `span` field.
+pub(crate) fn has_span(node: &Node) -> bool {
+    matches!(node.data, Data::Struct(_))
+        && !is_transparent(node)
+        && !NO_SPAN.contains(&&*node.ident)
+        && !EMPTY_STRUCTS.contains(&&*node.ident)
+        && !visit::is_hand_written(node)
+}
+
+/// Whether the adapter of `node` has a `trivia` field, i.e., `node` is a file
+/// or an item whose comments and whitespace are kept in lossless mode.
+pub(crate) fn has_trivia(node: &Node, defs: &Definitions) -> bool {
+    node.ident == "File"
+        || defs.types.iter().any(|parent| {
+            matches!(&*parent.ident, "Item" | "ImplItem" | "TraitItem")
+                && matches!(&parent.data, Data::Enum(variants) if variants.values().any(|fields| {
+                    matches!(&**fields, [Type::Syn(ty)] if *ty == node.ident)
+                }))
+        })
+}
+
+/// Returns the documentation of the adapter of `field` of `ident`.
+fn field_doc(ident: &str, field: &str, ty: &Type, defs: &Definitions) -> String {
+    let link = format!("[`syn::{ident}::{field}`]");
+    let mut doc = match ty {
+        Type::Option(ty) if matches!(**ty, Type::Token(_) | Type::Group(_)) => {
+            format!(" Whether {link} is present.")
+        }
+        _ => format!(" Corresponds to {link}."),
+    };
+    let name = serialized_name(field, ty, defs);
+    if name != field {
+        write!(doc, " Serialized as `{name}`.").unwrap();
+    }
+    if flatten(ident, field, ty) {
+        doc.push_str(" Its fields are serialized in place of this field.");
+    }
+    doc
+}
+
 fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
     if SKIPPED.contains(&&*node.ident) || EMPTY_STRUCTS.contains(&&*node.ident) {
         return;
@@ -223,49 +335,164 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
 
     if let Data::Struct(fields) = &node.data {
         let mut body = vec![];
-        let mut last = "";
+        // The parameters and field initializers of the constructor, which
+        // takes the fields that may not be omitted in the serialized form.
+        let mut params = vec![];
+        let mut inits = vec![];
+        // The fields of the adapter without their documentation, with and
+        // without their attributes, from which adapters with flattened fields
+        // are deserialized (see flatten.rs).
+        let mut map_fields = vec![];
+        let mut seq_fields = vec![];
+        let mut has_flatten = false;
         for (field, ty) in fields {
             if let Some(t) = format_ty(ty) {
+                let doc = field_doc(&node.ident, field, ty, defs);
                 let attrs = field_attrs(field, ty, defs);
                 let rename = rename(&node.ident, field).map(|s| quote!(#[serde(rename = #s)]));
                 let skip_serializing_if = skip_serializing_if(&node.ident, field, ty)
                     .map(|s| quote!(#[serde(default, skip_serializing_if = #s)]));
                 let flatten = if flatten(&node.ident, field, ty) {
+                    has_flatten = true;
                     quote!(#[serde(flatten)])
                 } else {
                     quote!()
                 };
                 let f = format_ident!("{field}");
 
-                body.push(quote! {
+                let attrs = quote! {
                     #attrs
                     #rename
                     #skip_serializing_if
                     #flatten
-                    pub(crate) #f: #t,
+                };
+                body.push(quote! {
+                    #[doc = #doc]
+                    #attrs
+                    pub #f: #t,
+                });
+                map_fields.push(quote! {
+                    #attrs
+                    #f: #t,
                 });
-                last = &**field;
+                seq_fields.push(quote!(#f: #t,));
+                if has_default(&node.ident, field, ty) {
+                    inits.push(quote!(#f: default()));
+                } else {
+                    params.push(quote!(#f: #t));
+                    inits.push(quote!(#f));
+                }
             }
         }
+        if has_span(node) {
+            body.push(quote! {
+                /// The location of this node, if known.
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub span: Option<Location>,
+            });
+            map_fields.push(quote! {
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                span: Option<Location>,
+            });
+            seq_fields.push(quote!(span: Option<Location>,));
+            inits.push(quote!(span: None));
+        }
+        if has_trivia(node, defs) {
+            body.push(quote! {
+                /// The comments and whitespace of this node, if kept by
+                /// `lossless::parse_file`.
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub trivia: Option<Trivia>,
+            });
+            map_fields.push(quote! {
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                trivia: Option<Trivia>,
+            });
+            seq_fields.push(quote!(trivia: Option<Trivia>,));
+            inits.push(quote!(trivia: None));
+        }
 
-        let transparent = if body.len() == 1 && allow_transparent(&node.ident, last, &fields[last])
-        {
-            Some(quote!(#[serde(transparent)]))
-        } else {
-            None
-        };
+        let transparent =
+            if is_transparent(node) { Some(quote!(#[serde(transparent)])) } else { None };
 
         let attrs = struct_attrs(&node.ident);
+        let extra_traits = extra_traits(&node.ident);
         let ident = format_ident!("{}", node.ident);
         let doc = format!(" An adapter for [`struct@syn::{}`].", node.ident);
+        // All fields of the adapter may be omitted, so `Default` is its
+        // constructor.
+        let default = if params.is_empty() { Some(quote!(#[derive(Default)])) } else { None };
+        let constructor = if params.is_empty() {
+            quote!()
+        } else {
+            quote! {
+                impl #ident {
+                    /// Creates an adapter from the fields that are required in the
+                    /// serialized form, with the other fields set to their default
+                    /// values.
+                    #[must_use]
+                    pub fn new(#(#params),*) -> Self {
+                        Self {
+                            #(#inits,)*
+                        }
+                    }
+                }
+            }
+        };
+        let (derive, deserialize) = if has_flatten {
+            let name = &node.ident;
+            let deserialize = quote! {
+                impl<'de> Deserialize<'de> for #ident {
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        flatten::deserialize(deserializer)
+                    }
+                }
+                impl flatten::Flattened for #ident {
+                    fn deserialize_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        #[derive(Deserialize)]
+                        #[serde(remote = #name)]
+                        struct Map {
+                            #(#map_fields)*
+                        }
+                        Map::deserialize(deserializer)
+                    }
+                    fn deserialize_seq<'de, D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        #[derive(Deserialize)]
+                        #[serde(remote = #name)]
+                        struct Seq {
+                            #(#seq_fields)*
+                        }
+                        Seq::deserialize(deserializer)
+                    }
+                }
+            };
+            (quote!(#[derive(Serialize)]), deserialize)
+        } else {
+            (quote!(#[derive(Serialize, Deserialize)]), quote!())
+        };
         impls.extend(quote! {
             #[doc = #doc]
-            #[derive(Serialize, Deserialize)]
+            #derive
+            #default
             #attrs
+            #extra_traits
             #transparent
+            #[non_exhaustive]
             pub struct #ident {
                 #(#body)*
             }
+
+            #constructor
+            #deserialize
         });
     }
 }
@@ -5,22 +5,35 @@ use quote::{format_ident, quote};
 use syn_codegen::{Data, Definitions, Node, Type};
 use test_helper::{bin_name, codegen::file, function_name};
 
-use crate::{traverse, workspace_root};
+use crate::{ast_struct, traverse, workspace_root};
 
 const CONVERT_SRC: &str = "src/gen/convert.rs";
 
 // optimize
-pub(crate) const IGNORED_TYPES: &[&str] =
-    &["Arm", "ExprMatch", "Generics", "ItemStruct", "Receiver", "ReturnType", "TraitItemFn"];
+pub(crate) const IGNORED_TYPES: &[&str] = &[
+    "Arm",
+    "DataStruct",
+    "ExprMatch",
+    "Generics",
+    "ItemStruct",
+    "Receiver",
+    "ReturnType",
+    "TraitItemFn",
+];
 
 pub(crate) const EMPTY_STRUCTS: &[&str] =
     &["TypeInfer", "TypeNever", "UseGlob", "VisCrate", "VisPublic"];
 
-fn visit(ty: &Type, var: &TokenStream, defs: &Definitions) -> (Option<TokenStream>, TokenStream) {
+fn visit(
+    ty: &Type,
+    var: &TokenStream,
+    ctx: &TokenStream,
+    defs: &Definitions,
+) -> (Option<TokenStream>, TokenStream) {
     match ty {
         Type::Box(_) | Type::Vec(_) | Type::Punctuated(_) => {
             let from = Some(quote!(#var.map_into()));
-            let into = quote!(#var.map_into());
+            let into = quote!(#var.try_map_into().context(#ctx)?);
             (from, into)
         }
         Type::Option(t) => match &**t {
@@ -37,7 +50,7 @@ fn visit(ty: &Type, var: &TokenStream, defs: &Definitions) -> (Option<TokenStrea
 
                 for (i, t) in t.iter().enumerate() {
                     let id = format_ident!("_{i}");
-                    let (from, into) = visit(t, &quote!((*#id)), defs);
+                    let (from, into) = visit(t, &quote!((*#id)), ctx, defs);
 
                     from_pat.push(id.clone());
                     into_expr.push(into);
@@ -52,22 +65,27 @@ fn visit(ty: &Type, var: &TokenStream, defs: &Definitions) -> (Option<TokenStrea
 
                 if into_pat.len() == 1 {
                     let from = Some(quote!(#var.ref_map(|(#(#from_pat),*)| #(#from_expr),*)));
-                    let into = quote!(#var.ref_map(|#(#into_pat),*| (#(#into_expr),*)));
+                    let into = quote!(#var.try_ref_map(|#(#into_pat),*| Ok((#(#into_expr),*)))?);
                     (from, into)
                 } else {
                     let from = Some(quote!(#var.ref_map(|(#(#from_pat),*)| (#(#from_expr),*))));
-                    let into = quote!(#var.ref_map(|(#(#into_pat),*)| (#(#into_expr),*)));
+                    let into = quote!(#var.try_ref_map(|(#(#into_pat),*)| Ok((#(#into_expr),*)))?);
                     (from, into)
                 }
             }
             Type::Box(_) | Type::Vec(_) | Type::Punctuated(_) => {
                 let from = Some(quote!(#var.ref_map(MapInto::map_into)));
-                let into = quote!(#var.ref_map(MapInto::map_into));
+                let into = quote!(#var.try_ref_map(TryMapInto::try_map_into).context(#ctx)?);
+                (from, into)
+            }
+            Type::Std(_) => {
+                let from = Some(quote!(#var.map_into()));
+                let into = quote!(#var.clone());
                 (from, into)
             }
             _ => {
                 let from = Some(quote!(#var.map_into()));
-                let into = quote!(#var.map_into());
+                let into = quote!(#var.try_map_into().context(#ctx)?);
                 (from, into)
             }
         },
@@ -99,7 +117,7 @@ fn visit(ty: &Type, var: &TokenStream, defs: &Definitions) -> (Option<TokenStrea
         }
         Type::Syn(_) | Type::Ext(_) => {
             let from = Some(quote!(#var.ref_into()));
-            let into = quote!(#var.ref_into());
+            let into = quote!(#var.try_ref_into().context(#ctx)?);
             (from, into)
         }
         Type::Std(t) => {
@@ -123,6 +141,7 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
     }
 
     let ident = format_ident!("{}", &node.ident);
+    let ident_str = &node.ident;
     let mut from_impl = TokenStream::new();
     let mut into_impl = TokenStream::new();
 
@@ -132,6 +151,7 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
             let mut into_variants = TokenStream::new();
 
             for (variant, fields) in variants {
+                let ctx = quote!(#ident_str, #variant);
                 let variant = format_ident!("{variant}");
 
                 if fields.is_empty() {
@@ -139,7 +159,7 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
                         syn::#ident::#variant => #ident::#variant,
                     });
                     into_variants.extend(quote! {
-                        #ident::#variant => syn::#ident::#variant,
+                        #ident::#variant => Ok(syn::#ident::#variant),
                     });
                     continue;
                 }
@@ -151,7 +171,7 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
 
                 for (i, t) in fields.iter().enumerate() {
                     let id = format_ident!("_{i}");
-                    let (from, into) = visit(t, &quote!((*#id)), defs);
+                    let (from, into) = visit(t, &quote!((*#id)), &ctx, defs);
 
                     from_pat.push(id.clone());
                     into_expr.push(into);
@@ -166,14 +186,16 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
                         syn::#ident::#variant(..) => #ident::#variant,
                     });
                     into_variants.extend(quote! {
-                        #ident::#variant => syn::#ident::#variant(#(#into_expr),*),
+                        #ident::#variant => Ok(syn::#ident::#variant(#(#into_expr),*)),
                     });
                 } else {
                     from_variants.extend(quote! {
                         syn::#ident::#variant(#(#from_pat),*) => #ident::#variant(#(#from_expr),*),
                     });
                     into_variants.extend(quote! {
-                        #ident::#variant(#(#into_pat),*) => syn::#ident::#variant(#(#into_expr),*),
+                        #ident::#variant(#(#into_pat),*) => {
+                            Ok(syn::#ident::#variant(#(#into_expr),*))
+                        }
                     });
                 }
             }
@@ -202,10 +224,11 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
             let mut into_fields = TokenStream::new();
 
             for (field, ty) in fields {
+                let ctx = quote!(#ident_str, #field);
                 let field = format_ident!("{field}");
                 let ref_tokens = quote!(node.#field);
 
-                let (from, into) = visit(ty, &ref_tokens, defs);
+                let (from, into) = visit(ty, &ref_tokens, &ctx, defs);
 
                 if from.is_some() {
                     from_fields.extend(quote!(#field: #from,));
@@ -216,11 +239,24 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
             assert!(!fields.is_empty(), "fields.is_empty: {ident}");
             assert!(!from_fields.is_empty(), "from_fields.is_empty(): {ident}");
 
+            // Locations are only serialized with `SerializeOptions::spans`
+            // (see ser.rs), so adapters do not record them.
+            if ast_struct::has_span(node) {
+                from_fields.extend(quote! {
+                    span: None,
+                });
+            }
+            if ast_struct::has_trivia(node, defs) {
+                from_fields.extend(quote! {
+                    trivia: None,
+                });
+            }
+
             from_impl.extend(quote! {
                 Self { #from_fields }
             });
             into_impl.extend(quote! {
-                Self { #into_fields }
+                Ok(Self { #into_fields })
             });
         }
         Data::Private => return,
@@ -233,8 +269,8 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
                 #from_impl
             }
         }
-        impl From<&#ident> for syn::#ident {
-            fn from(node: &#ident) -> Self {
+        impl TryFromRef<#ident> for syn::#ident {
+            fn try_from_ref(node: &#ident) -> Result<Self, Error> {
                 #into_impl
             }
         }
@@ -251,6 +287,7 @@ pub(crate) fn generate(defs: &Definitions) {
             clippy::double_parens,
             clippy::just_underscores_and_digits,
             clippy::match_single_binding,
+            clippy::needless_question_mark,
         )]
 
         use crate::*;
@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Based on https://github.com/dtolnay/syn/blob/2.0.82/codegen/src/fold.rs.
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn_codegen::{Data, Definitions, Node, Punctuated, Type};
+use test_helper::{bin_name, codegen::file, function_name};
+
+use crate::{
+    ast_struct,
+    visit::{cfg, fields, is_present, method_ty, nodes, tuple_elements, under_name},
+    workspace_root,
+};
+
+const FOLD_SRC: &str = "src/gen/fold.rs";
+
+/// Returns an expression that folds `name`, or `None` if `ty` contains no nodes.
+fn fold(ty: &Type, name: &TokenStream) -> Option<TokenStream> {
+    match ty {
+        Type::Syn(t) | Type::Ext(t) => {
+            let method = format_ident!("fold_{}", under_name(method_ty(t)?));
+            Some(quote!(f.#method(#name)))
+        }
+        Type::Box(t) => {
+            let val = fold(t, &quote!(*#name))?;
+            Some(quote!(Box::new(#val)))
+        }
+        Type::Vec(t) | Type::Punctuated(Punctuated { element: t, .. }) => {
+            let val = fold(t, &quote!(it))?;
+            Some(quote!(#name.into_iter().map(|it| #val).collect()))
+        }
+        Type::Option(t) => {
+            let val = match &**t {
+                Type::Tuple(t) => {
+                    let t = tuple_elements(t);
+                    if t.len() == 1 {
+                        fold(t[0], &quote!(it))?
+                    } else {
+                        let mut changed = false;
+                        let mut elements = Vec::with_capacity(t.len());
+                        for (i, t) in t.into_iter().enumerate() {
+                            let i = Literal::usize_unsuffixed(i);
+                            let it = quote!(it.#i);
+                            match fold(t, &it) {
+                                Some(val) => {
+                                    changed = true;
+                                    elements.push(val);
+                                }
+                                None => elements.push(it),
+                            }
+                        }
+                        if !changed {
+                            return None;
+                        }
+                        quote!((#(#elements),*))
+                    }
+                }
+                t => fold(t, &quote!(it))?,
+            };
+            Some(quote!(#name.map(|it| #val)))
+        }
+        Type::Tuple(_) | Type::Token(_) | Type::Group(_) | Type::Std(_) => None,
+    }
+}
+
+fn node(traits: &mut TokenStream, impls: &mut TokenStream, ty: &str, node: Option<&Node>) {
+    let method = format_ident!("fold_{}", under_name(ty));
+    let ty = format_ident!("{ty}");
+    let cfg = cfg(node);
+
+    traits.extend(quote! {
+        #cfg
+        fn #method(&mut self, i: #ty) -> #ty {
+            #method(self, i)
+        }
+    });
+
+    let body = match node {
+        Some(node) => {
+            if let Some(fields) = fields(node) {
+                let mut folded = vec![];
+                let mut rest = ast_struct::has_span(node);
+                for (field, t) in &fields {
+                    let field = format_ident!("{field}");
+                    match fold(t, &quote!(node.#field)) {
+                        Some(val) => folded.push(quote!(#field: #val)),
+                        None => rest |= is_present(t),
+                    }
+                }
+                if folded.is_empty() {
+                    quote!(node)
+                } else if rest {
+                    quote!(#ty { #(#folded,)* ..node })
+                } else {
+                    quote!(#ty { #(#folded),* })
+                }
+            } else if let Data::Enum(variants) = &node.data {
+                let mut arms = TokenStream::new();
+                for (variant, fields) in variants {
+                    let variant = format_ident!("{variant}");
+                    let fields: Vec<_> = fields.iter().filter(|t| is_present(t)).collect();
+                    if fields.is_empty() {
+                        arms.extend(quote!(#ty::#variant => #ty::#variant,));
+                        continue;
+                    }
+                    let mut pats = Vec::with_capacity(fields.len());
+                    let mut exprs = Vec::with_capacity(fields.len());
+                    for (i, t) in fields.into_iter().enumerate() {
+                        let binding = format_ident!("_binding_{i}");
+                        exprs.push(fold(t, &quote!(#binding)).unwrap_or_else(|| quote!(#binding)));
+                        pats.push(binding);
+                    }
+                    arms.extend(quote! {
+                        #ty::#variant(#(#pats),*) => #ty::#variant(#(#exprs),*),
+                    });
+                }
+                quote! {
+                    match node {
+                        #arms
+                    }
+                }
+            } else {
+                quote!(node)
+            }
+        }
+        None => quote!(node),
+    };
+
+    impls.extend(quote! {
+        #cfg
+        pub fn #method<F>(f: &mut F, node: #ty) -> #ty
+        where
+            F: Fold + ?Sized,
+        {
+            #body
+        }
+    });
+}
+
+pub(crate) fn generate(defs: &Definitions) {
+    let workspace_root = workspace_root();
+    let mut traits = TokenStream::new();
+    let mut impls = TokenStream::new();
+    for (ty, n) in nodes(defs) {
+        node(&mut traits, &mut impls, &ty, n.as_ref());
+    }
+
+    let path = &workspace_root.join(FOLD_SRC);
+    file::write(function_name!(), bin_name!(), workspace_root, path, quote! {
+        #![allow(unused_variables)]
+        #![allow(
+            clippy::match_same_arms,
+            clippy::needless_match,
+            clippy::needless_pass_by_ref_mut,
+            clippy::needless_pass_by_value,
+            clippy::needless_update,
+        )]
+
+        use crate::*;
+
+        /// Syntax tree traversal to transform the nodes of an owned syntax tree.
+        ///
+        /// See the [module documentation] for details.
+        ///
+        /// [module documentation]: self
+        pub trait Fold {
+            #traits
+        }
+
+        #impls
+    });
+}
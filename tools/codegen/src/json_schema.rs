@@ -0,0 +1,501 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Generates a JSON Schema (draft 2020-12) describing the serialized form of
+// the adapters.
+
+use fs_err as fs;
+use serde_json::{Map, Value, json};
+use syn_codegen::{Data, Definitions, Node, Punctuated, Type};
+
+use crate::{ast_enum, ast_struct, convert::EMPTY_STRUCTS, traverse, visit, workspace_root};
+
+const JSON_SCHEMA_SRC: &str = "schema/syn-serde.schema.json";
+
+// Fields of syn types that hand-written adapters do not have.
+const OMITTED_FIELDS: &[(&str, &str)] = &[
+    ("Arm", "comma"),
+    ("Generics", "lt_token"),
+    ("Generics", "gt_token"),
+    ("ItemStruct", "semi_token"),
+    ("TraitItemFn", "semi_token"),
+];
+
+fn reference(ty: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{ty}") })
+}
+
+fn nullable(schema: Value) -> Value {
+    json!({ "anyOf": [schema, { "type": "null" }] })
+}
+
+fn tuple(elements: &[Type]) -> Value {
+    let elements: Vec<_> = visit::tuple_elements(elements).into_iter().map(schema).collect();
+    match <[_; 1]>::try_from(elements) {
+        Ok([element]) => element,
+        Err(elements) => json!({
+            "type": "array",
+            "prefixItems": elements,
+            "items": false,
+        }),
+    }
+}
+
+fn schema(ty: &Type) -> Value {
+    match ty {
+        Type::Box(t) => schema(t),
+        Type::Vec(t) | Type::Punctuated(Punctuated { element: t, .. }) => {
+            let items = match &**t {
+                Type::Tuple(t) => tuple(t),
+                t => schema(t),
+            };
+            json!({ "type": "array", "items": items })
+        }
+        Type::Option(t) => match &**t {
+            Type::Token(_) | Type::Group(_) => json!({ "type": "boolean" }),
+            Type::Tuple(t) => nullable(tuple(t)),
+            t => nullable(schema(t)),
+        },
+        Type::Syn(t) | Type::Ext(t) => reference(t),
+        Type::Std(t) => match &**t {
+            "String" => json!({ "type": "string" }),
+            "bool" => json!({ "type": "boolean" }),
+            "u32" | "usize" => json!({ "type": "integer", "minimum": 0 }),
+            _ => unimplemented!("schema: {ty:?}"),
+        },
+        Type::Tuple(_) | Type::Token(_) | Type::Group(_) => unreachable!("schema: {ty:?}"),
+    }
+}
+
+fn object(properties: Map<String, Value>, required: Vec<String>) -> Value {
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+/// Adds the fields of `node` to `properties`, `required`, and `all_of`, which
+/// is where the variants of flattened enums go.
+fn fields(
+    node: &Node,
+    defs: &Definitions,
+    properties: &mut Map<String, Value>,
+    required: &mut Vec<String>,
+    all_of: &mut Vec<Value>,
+) {
+    let Data::Struct(node_fields) = &node.data else { unreachable!("fields: {}", node.ident) };
+    for (field, ty) in node_fields {
+        if ast_struct::format_ty(ty).is_none() || OMITTED_FIELDS.contains(&(&node.ident, field)) {
+            continue;
+        }
+        if ast_struct::flatten(&node.ident, field, ty) {
+            let Type::Syn(t) = ty else { unreachable!("flatten: {ty:?}") };
+            let inner = defs.types.iter().find(|node| node.ident == *t).unwrap();
+            match &inner.data {
+                Data::Struct(_) => fields(inner, defs, properties, required, all_of),
+                Data::Enum(variants) => {
+                    let variants = variants
+                        .iter()
+                        .map(|(variant, variant_fields)| {
+                            let name = ast_enum::serialized_name(t, variant, variant_fields, defs);
+                            let [ty] = &**variant_fields else { unreachable!("flatten: {t}") };
+                            json!({ "properties": { name.clone(): schema(ty) }, "required": [name] })
+                        })
+                        .collect::<Vec<_>>();
+                    all_of.push(json!({ "oneOf": variants }));
+                }
+                Data::Private => unreachable!("flatten: {t}"),
+            }
+            continue;
+        }
+        let name = ast_struct::serialized_name(field, ty, defs);
+        if !ast_struct::has_default(&node.ident, field, ty) {
+            required.push(name.clone());
+        }
+        properties.insert(name, schema(ty));
+    }
+    if node.ident == "Macro" {
+        // See `SerializeOptions::macro_args`.
+        properties.insert("args".to_owned(), reference("MacroArgs"));
+    }
+}
+
+fn node(node: &Node, defs: &Definitions) -> Value {
+    match &node.data {
+        Data::Struct(fields) => {
+            if ast_struct::is_transparent(node) {
+                let ty = fields.values().find(|ty| ast_struct::format_ty(ty).is_some()).unwrap();
+                return schema(ty);
+            }
+            let mut properties = Map::new();
+            let mut required = vec![];
+            let mut all_of = vec![];
+            self::fields(node, defs, &mut properties, &mut required, &mut all_of);
+            if ast_struct::has_span(node) {
+                properties.insert("span".to_owned(), nullable(reference("Location")));
+            }
+            if ast_struct::has_trivia(node, defs) {
+                properties.insert("trivia".to_owned(), nullable(reference("Trivia")));
+            }
+            if node.ident == "Generics" {
+                // Hand-written adapter skips empty `params`.
+                required.retain(|field| field != "params");
+            }
+            let mut schema = object(properties, required);
+            if !all_of.is_empty() {
+                schema["allOf"] = all_of.into();
+            }
+            schema
+        }
+        Data::Enum(variants) => {
+            let mut units = vec![];
+            let mut one_of = vec![];
+            // The variants in the internally tagged representation (see
+            // `SerializeOptions::internally_tagged`), in which unit variants
+            // are still strings.
+            let mut tagged = vec![];
+            for (variant, fields) in variants {
+                let name = ast_enum::serialized_name(&node.ident, variant, fields, defs);
+                let kind = format!("{}{variant}", node.ident);
+                let inline =
+                    ast_enum::payload(fields).is_some_and(|ty| ast_enum::is_inline(ty, defs));
+                let fields: Vec<_> = fields.iter().filter(|ty| visit::is_present(ty)).collect();
+                let content = match &*fields {
+                    [] => {
+                        units.push(Value::from(name));
+                        continue;
+                    }
+                    [ty] => schema(ty),
+                    _ => json!({
+                        "type": "array",
+                        "prefixItems": fields.iter().map(|ty| schema(ty)).collect::<Vec<_>>(),
+                        "items": false,
+                    }),
+                };
+                tagged.push(tagged_variant(&kind, inline, &content));
+                one_of.push(json!({
+                    "type": "object",
+                    "properties": { name.clone(): content },
+                    "required": [name],
+                    "additionalProperties": false,
+                }));
+            }
+            if !units.is_empty() {
+                one_of.insert(0, json!({ "enum": units }));
+            }
+            if ast_enum::is_tagged(node, defs) {
+                one_of.extend(tagged);
+            }
+            json!({ "oneOf": one_of })
+        }
+        // Lit* types other than LitBool.
+        Data::Private => json!({ "anyOf": [{ "type": "string" }, structured_lit(&node.ident)] }),
+    }
+}
+
+/// Returns the schema of a variant with a payload in the internally tagged
+/// representation: an object with `kind` and either the fields of the payload
+/// if it is `inline`, or the payload under `value`.
+fn tagged_variant(kind: &str, inline: bool, content: &Value) -> Value {
+    let mut properties = Map::new();
+    properties.insert("kind".to_owned(), json!({ "const": kind }));
+    if inline {
+        let mut schema = object(properties, vec!["kind".to_owned()]);
+        schema["allOf"] = json!([content]);
+        schema
+    } else {
+        properties.insert("value".to_owned(), content.clone());
+        let mut schema = object(properties, vec!["kind".to_owned(), "value".to_owned()]);
+        schema["additionalProperties"] = false.into();
+        schema
+    }
+}
+
+/// Returns the schema of the structured representation of a literal (see
+/// `SerializeOptions::structured_literals`).
+fn structured_lit(ident: &str) -> Value {
+    let bytes = json!({ "type": "array", "items": { "type": "integer", "minimum": 0 } });
+    let (value, has_style) = match ident {
+        "LitStr" => (json!({ "type": "string" }), true),
+        "LitByteStr" | "LitCStr" => (bytes, true),
+        "LitByte" => (json!({ "type": "integer", "minimum": 0 }), false),
+        "LitChar" => (json!({ "type": "string", "minLength": 1, "maxLength": 1 }), false),
+        "LitInt" | "LitFloat" => (json!({ "type": "string" }), false),
+        _ => unreachable!("structured_lit: {ident}"),
+    };
+    let mut properties = Map::new();
+    properties.insert("value".to_owned(), value);
+    properties.insert("suffix".to_owned(), json!({ "type": "string" }));
+    if has_style {
+        properties.insert("style".to_owned(), reference("StrStyle"));
+    }
+    let mut schema = object(properties, vec!["value".to_owned()]);
+    schema["additionalProperties"] = false.into();
+    schema
+}
+
+/// Returns the schema of an identifier or a literal, which is an object with
+/// the text in `field` if its location is known.
+fn spanned_text(field: &str) -> Value {
+    json!({
+        "anyOf": [
+            { "type": "string" },
+            {
+                "type": "object",
+                "properties": {
+                    field: { "type": "string" },
+                    "span": nullable(reference("Location")),
+                },
+                "required": [field],
+                "additionalProperties": false,
+            },
+        ],
+    })
+}
+
+/// Adapters whose serialized form is not derived from syn.json.
+fn hand_written() -> Vec<(&'static str, &'static str, Value)> {
+    vec![
+        ("DataStruct", "An adapter for `syn::DataStruct`.", reference("Fields")),
+        (
+            "Receiver",
+            "An adapter for `syn::Receiver`.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "attrs": { "type": "array", "items": reference("Attribute") },
+                    "ref": { "type": "boolean" },
+                    "lifetime": nullable(reference("Lifetime")),
+                    "mut": { "type": "boolean" },
+                    "colon_token": { "type": "boolean" },
+                    "ty": reference("Type"),
+                    "span": nullable(reference("Location")),
+                },
+                "required": ["ty"],
+            }),
+        ),
+        ("ReturnType", "An adapter for `syn::ReturnType`.", nullable(reference("Type"))),
+        (
+            "TokenStream",
+            "An adapter for `proc_macro2::TokenStream`.",
+            // A string is the source text of the tokens (see
+            // `SerializeOptions::token_text`).
+            json!({
+                "anyOf": [
+                    { "type": "array", "items": reference("TokenTree") },
+                    { "type": "string" },
+                ],
+            }),
+        ),
+        ("TokenTree", "An adapter for `proc_macro2::TokenTree`.", {
+            let variants = [
+                ("group", "Group", true),
+                ("ident", "Ident", false),
+                ("punct", "Punct", true),
+                ("lit", "Literal", false),
+            ];
+            let mut one_of: Vec<_> = variants
+                .iter()
+                .map(|(name, ty, _)| {
+                    json!({
+                        "type": "object",
+                        "properties": { *name: reference(ty) },
+                        "required": [name],
+                        "additionalProperties": false,
+                    })
+                })
+                .collect();
+            one_of.extend(variants.iter().map(|(_, ty, inline)| {
+                tagged_variant(&format!("TokenTree{ty}"), *inline, &reference(ty))
+            }));
+            json!({ "oneOf": one_of })
+        }),
+        (
+            "Group",
+            "An adapter for `proc_macro2::Group`.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "delimiter": reference("Delimiter"),
+                    "stream": reference("TokenStream"),
+                    "span": nullable(reference("Location")),
+                },
+                "required": ["delimiter", "stream"],
+            }),
+        ),
+        (
+            "Delimiter",
+            "An adapter for `proc_macro2::Delimiter`.",
+            json!({ "enum": ["parenthesis", "brace", "bracket", "none"] }),
+        ),
+        (
+            "Punct",
+            "An adapter for `proc_macro2::Punct`.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "op": {
+                        "enum": [
+                            "!", "#", "$", "%", "&", "'", "*", "+", ",", "-", ".", "/", ":", ";",
+                            "<", "=", ">", "?", "@", "^", "|", "~",
+                        ],
+                    },
+                    "spacing": reference("Spacing"),
+                    "span": nullable(reference("Location")),
+                },
+                "required": ["op", "spacing"],
+            }),
+        ),
+        (
+            "Spacing",
+            "An adapter for `proc_macro2::Spacing`.",
+            json!({ "enum": ["alone", "joint"] }),
+        ),
+        ("Ident", "An adapter for `proc_macro2::Ident`.", spanned_text("name")),
+        ("Literal", "An adapter for `proc_macro2::Literal`.", spanned_text("text")),
+        (
+            "MacroArgs",
+            "An adapter for the arguments of a well-known macro of the standard library.",
+            json!({
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": { "format": reference("FormatArgs") },
+                        "required": ["format"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "list": { "type": "array", "items": reference("Expr") } },
+                        "required": ["list"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "repeat": reference("RepeatArgs") },
+                        "required": ["repeat"],
+                        "additionalProperties": false,
+                    },
+                ],
+            }),
+        ),
+        (
+            "FormatArgs",
+            "The arguments of a macro that takes a format string.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "exprs": { "type": "array", "items": reference("Expr") },
+                    "string": reference("LitStr"),
+                    "args": { "type": "array", "items": reference("FormatArg") },
+                },
+                "required": [],
+            }),
+        ),
+        (
+            "FormatArg",
+            "An argument after the format string.",
+            json!({
+                "type": "object",
+                "properties": { "name": reference("Ident"), "expr": reference("Expr") },
+                "required": ["expr"],
+            }),
+        ),
+        (
+            "RepeatArgs",
+            "The arguments of `vec![x; n]`.",
+            json!({
+                "type": "object",
+                "properties": { "expr": reference("Expr"), "len": reference("Expr") },
+                "required": ["expr", "len"],
+            }),
+        ),
+        (
+            "Location",
+            "The location of a node in the source code.",
+            json!({
+                "type": "object",
+                "properties": { "start": reference("LineColumn"), "end": reference("LineColumn") },
+                "required": ["start", "end"],
+            }),
+        ),
+        (
+            "Trivia",
+            "The comments and whitespace of a file or an item, recorded in lossless mode.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "leading": { "type": "array", "items": { "type": "string" } },
+                    "spellings": { "type": "array", "items": reference("Spelling") },
+                    "trailing": { "type": "string" },
+                    "bom": { "type": "boolean" },
+                },
+                "required": ["leading"],
+            }),
+        ),
+        (
+            "Spelling",
+            "Tokens that are spelled differently in the source code than they are printed.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "index": { "type": "integer", "minimum": 0 },
+                    "tokens": { "type": "array", "items": { "type": "string" } },
+                    "text": { "type": "string" },
+                },
+                "required": ["index", "tokens", "text"],
+            }),
+        ),
+        (
+            "LineColumn",
+            "An adapter for `proc_macro2::LineColumn`.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "line": { "type": "integer", "minimum": 0 },
+                    "column": { "type": "integer", "minimum": 0 },
+                },
+                "required": ["line", "column"],
+            }),
+        ),
+    ]
+}
+
+/// Returns the schemas of all adapters, keyed and sorted by the name of the
+/// adapter.
+pub(crate) fn definitions(defs: &Definitions) -> Map<String, Value> {
+    let mut schemas = Map::new();
+    let hand_written = hand_written();
+    for ty in traverse::types(defs) {
+        if EMPTY_STRUCTS.contains(&&*ty.ident)
+            || hand_written.iter().any(|(ident, ..)| *ident == ty.ident)
+        {
+            continue;
+        }
+        let mut schema = node(&ty, defs);
+        schema["description"] = format!("An adapter for `syn::{}`.", ty.ident).into();
+        schemas.insert(ty.ident.clone(), schema);
+    }
+    for (ty, description, mut schema) in hand_written {
+        schema["description"] = description.into();
+        schemas.insert(ty.to_owned(), schema);
+    }
+    schemas.sort_keys();
+    schemas
+}
+
+pub(crate) fn generate(defs: &Definitions) {
+    let schema = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$comment": "This file is @generated by syn-serde-internal-codegen (generate function at tools/codegen/src/json_schema.rs). It is not intended for manual editing.",
+        "title": "syn-serde",
+        "description": "The serialized form of a Rust source file, as produced by syn-serde. The adapter of each syntax tree node is defined in `$defs`. With `SerializeOptions::internally_tagged`, the variants of enums are objects with a `kind` field; structs are plain objects in both representations.",
+        "$ref": "#/$defs/File",
+        "$defs": definitions(defs),
+    });
+    let mut out = serde_json::to_string_pretty(&schema).unwrap();
+    out.push('\n');
+    let path = workspace_root().join(JSON_SCHEMA_SRC);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, out).unwrap();
+}
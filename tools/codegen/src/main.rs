@@ -10,7 +10,12 @@
 mod ast_enum;
 mod ast_struct;
 mod convert;
+mod fold;
+mod json_schema;
+mod ser;
 mod traverse;
+mod typescript;
+mod visit;
 
 use std::path::Path;
 
@@ -29,4 +34,9 @@ fn main() {
     ast_struct::generate(&defs);
     ast_enum::generate(&defs);
     convert::generate(&defs);
+    visit::generate(&defs);
+    fold::generate(&defs);
+    ser::generate(&defs);
+    json_schema::generate(&defs);
+    typescript::generate(&defs);
 }
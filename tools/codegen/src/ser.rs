@@ -0,0 +1,446 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::BTreeSet;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn_codegen::{Data, Definitions, Node, Type};
+use test_helper::{bin_name, codegen::file, function_name};
+
+use crate::{ast_enum, ast_struct, convert::EMPTY_STRUCTS, traverse, workspace_root};
+
+const SER_SRC: &str = "src/gen/ser.rs";
+
+// The types whose adapters are written by hand. Their `Serialize` impls are
+// written next to the adapters.
+const SKIPPED: &[&str] = &[
+    // derive.rs
+    "DataStruct",
+    // expr.rs
+    "Arm",
+    // generics.rs
+    "Generics",
+    // item.rs
+    "ItemStruct",
+    "TraitItemFn",
+    "Receiver",
+    // ty.rs
+    "ReturnType",
+];
+
+/// Returns the types whose adapters are flattened into their parent.
+pub(crate) fn flattened(defs: &Definitions) -> BTreeSet<String> {
+    let mut types = BTreeSet::new();
+    for node in &defs.types {
+        if let Data::Struct(fields) = &node.data {
+            for (field, ty) in fields {
+                if ast_struct::flatten(&node.ident, field, ty) {
+                    let (Type::Syn(ty) | Type::Ext(ty)) = ty else { unreachable!() };
+                    types.insert(ty.clone());
+                }
+            }
+        }
+    }
+    types
+}
+
+/// Returns the expression that serializes `var` (a place of type `ty`, or a
+/// reference to it if `by_ref` is true) in the same way as the corresponding
+/// field of the adapter, or `None` if the adapter does not have the field.
+fn value(ty: &Type, var: &TokenStream, by_ref: bool) -> Option<TokenStream> {
+    let var_ref = if by_ref { quote!(#var) } else { quote!(&#var) };
+    match ty {
+        Type::Option(t) => match &**t {
+            Type::Token(_) | Type::Group(_) => Some(quote!(#var.is_some())),
+            Type::Tuple(t) => {
+                let pat: Vec<_> = (0..t.len()).map(|i| format_ident!("_{i}")).collect();
+                let expr: Vec<_> =
+                    t.iter().zip(&pat).filter_map(|(t, id)| value(t, &quote!(#id), true)).collect();
+                assert_ne!(expr.len(), 0);
+                if expr.len() == 1 {
+                    Some(quote!(#var.as_ref().map(|(#(#pat),*)| #(#expr)*)))
+                } else {
+                    Some(quote!(#var.as_ref().map(|(#(#pat),*)| (#(#expr),*))))
+                }
+            }
+            _ => Some(quote!(Ser(#var_ref, self.1))),
+        },
+        Type::Token(_) | Type::Group(_) => None,
+        Type::Ext(t) if t == "Span" => None,
+        Type::Syn(t) if t == "Reserved" || EMPTY_STRUCTS.contains(&&**t) => None,
+        Type::Box(_)
+        | Type::Vec(_)
+        | Type::Punctuated(_)
+        | Type::Syn(_)
+        | Type::Ext(_)
+        | Type::Std(_) => Some(quote!(Ser(#var_ref, self.1))),
+        Type::Tuple(_) => unreachable!("value: {ty:?}"),
+    }
+}
+
+/// Returns the condition under which the adapter serializes `field`, or `None`
+/// if it is always serialized.
+///
+/// This mirrors `field_attrs` and `skip_serializing_if` in ast_struct.rs.
+fn keep_if(ident: &str, field: &str, ty: &Type) -> Option<TokenStream> {
+    let f = format_ident!("{field}");
+    if let (_, "attrs")
+    | ("Attribute", "tokens")
+    | ("TypeParam" | "LifetimeDef" | "TraitItemType", "bounds")
+    | ("ItemTrait", "supertraits") = (ident, field)
+    {
+        return Some(quote!(!node.#f.is_empty()));
+    }
+    let ty = if let Type::Box(ty) = ty { &**ty } else { ty };
+    match ty {
+        Type::Option(_) => Some(quote!(node.#f.is_some())),
+        Type::Syn(ty) => match &**ty {
+            "Visibility" => Some(quote!(!matches!(node.#f, syn::Visibility::Inherited))),
+            "StaticMutability" | "FieldMutability" | "PathArguments" | "TraitBoundModifier" => {
+                let ty = format_ident!("{ty}");
+                Some(quote!(!matches!(node.#f, syn::#ty::None)))
+            }
+            "Generics" => {
+                Some(quote!(!node.#f.params.is_empty() || node.#f.where_clause.is_some()))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
+    if SKIPPED.contains(&&*node.ident) || EMPTY_STRUCTS.contains(&&*node.ident) {
+        return;
+    }
+    let flattened = flattened(defs);
+    let ident = &node.ident;
+    let ty = format_ident!("{ident}");
+
+    let body = match &node.data {
+        Data::Struct(fields) => {
+            if ast_struct::is_transparent(node) {
+                let (field, ty) = fields
+                    .iter()
+                    .find(|(_, ty)| ast_struct::format_ty(ty).is_some())
+                    .expect("transparent struct has a field");
+                let f = format_ident!("{field}");
+                let value = value(ty, &quote!(node.#f), false).unwrap();
+                quote! {
+                    let node = self.0;
+                    #value.serialize(serializer)
+                }
+            } else {
+                serialize_struct(impls, node, fields, defs, flattened.contains(ident))
+            }
+        }
+        Data::Enum(variants) => {
+            let tagged = ast_enum::is_tagged(node, defs);
+            let mut arms = TokenStream::new();
+            let mut entries = TokenStream::new();
+            for (i, (variant, fields)) in variants.iter().enumerate() {
+                let name = ast_enum::serialized_name(ident, variant, fields, defs);
+                let index = Literal::u32_unsuffixed(u32::try_from(i).unwrap());
+                let v = format_ident!("{variant}");
+                let mut pat = vec![];
+                let mut values = vec![];
+                for (i, t) in fields.iter().enumerate() {
+                    let id = format_ident!("_{i}");
+                    if let Some(value) = value(t, &quote!(#id), true) {
+                        pat.push(quote!(#id));
+                        values.push(value);
+                    } else {
+                        pat.push(quote!(_));
+                    }
+                }
+                let pat = if fields.is_empty() { quote!() } else { quote!((#(#pat),*)) };
+                match values.len() {
+                    0 => {
+                        arms.extend(quote! {
+                            syn::#ty::#v #pat => {
+                                serializer.serialize_unit_variant(#ident, #index, #name)
+                            }
+                        });
+                    }
+                    1 => {
+                        let value = &values[0];
+                        arms.extend(quote! {
+                            syn::#ty::#v #pat => {
+                                serializer.serialize_newtype_variant(#ident, #index, #name, &#value)
+                            }
+                        });
+                        entries.extend(quote! {
+                            syn::#ty::#v #pat => map.serialize_entry(#name, &#value),
+                        });
+                    }
+                    len => {
+                        // `SingletonMaps` cannot stream the fields of tuple
+                        // variants, so it relies on `fields_as_tuple`.
+                        assert!(tagged, "tuple variant of an untagged enum: {ident}::{variant}");
+                        let len = Literal::usize_unsuffixed(len);
+                        // In the internally tagged representation, the fields
+                        // are written as a tuple under `value`, and with
+                        // singleton maps, as a tuple under the variant name.
+                        arms.extend(quote! {
+                            syn::#ty::#v #pat => {
+                                if serializer.fields_as_tuple() {
+                                    return serializer.serialize_newtype_variant(
+                                        #ident, #index, #name, &(#(#values),*),
+                                    );
+                                }
+                                let mut state = serializer
+                                    .serialize_tuple_variant(#ident, #index, #name, #len)?;
+                                #(state.serialize_field(&#values)?;)*
+                                state.end()
+                            }
+                        });
+                    }
+                }
+            }
+            let non_exhaustive =
+                if node.exhaustive { None } else { Some(quote!(_ => unreachable!())) };
+            if flattened.contains(ident) {
+                assert!(
+                    variants.values().all(|fields| fields.len() == 1),
+                    "flattened enum with non-newtype variants: {ident}"
+                );
+                impls.extend(quote! {
+                    impl SerializeEntries for Ser<'_, syn::#ty> {
+                        fn serialize_entries<M>(&self, map: &mut M) -> Result<(), M::Error>
+                        where
+                            M: SerializeMap,
+                        {
+                            match self.0 {
+                                #entries
+                                #non_exhaustive
+                            }
+                        }
+                    }
+                });
+            }
+            let serializer = tagged.then(|| {
+                quote! {
+                    let serializer = tagged::EnumSerializer::<_, #ty>::new(serializer, self.1);
+                }
+            });
+            quote! {
+                #serializer
+                match self.0 {
+                    #arms
+                    #non_exhaustive
+                }
+            }
+        }
+        // The `Lit*` types, which are written by hand in lit.rs.
+        Data::Private => return,
+    };
+
+    // For syn::ImplRestriction, which has no variants.
+    let serializer = if matches!(&node.data, Data::Enum(variants) if variants.is_empty()) {
+        format_ident!("_serializer")
+    } else {
+        format_ident!("serializer")
+    };
+    impls.extend(quote! {
+        impl Serialize for Ser<'_, syn::#ty> {
+            fn serialize<S>(&self, #serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                #body
+            }
+        }
+    });
+}
+
+fn serialize_struct(
+    impls: &mut TokenStream,
+    node: &Node,
+    fields: &syn_codegen::Fields,
+    defs: &Definitions,
+    flattened: bool,
+) -> TokenStream {
+    let ident = &node.ident;
+
+    let mut entries = TokenStream::new();
+    let mut struct_fields = TokenStream::new();
+    let mut len = vec![];
+    let mut has_flatten = false;
+    let mut has_keep = false;
+    for (field, ty) in fields {
+        let f = format_ident!("{field}");
+        let Some(value) = value(ty, &quote!(node.#f), false) else { continue };
+        let name = ast_struct::serialized_name(field, ty, defs);
+        if ast_struct::flatten(ident, field, ty) {
+            has_flatten = true;
+            entries.extend(quote!(#value.serialize_entries(map)?;));
+            // The binary format serializes the flattened field as a nested
+            // value.
+            len.push(quote!(1));
+            struct_fields.extend(quote!(state.serialize_field(#name, &#value)?;));
+        } else if let Some(keep) = keep_if(ident, field, ty) {
+            has_keep = true;
+            let keep = quote!(explicit || #keep);
+            len.push(quote!(usize::from(#keep)));
+            entries.extend(quote! {
+                if #keep {
+                    map.serialize_entry(#name, &#value)?;
+                }
+            });
+            struct_fields.extend(quote! {
+                if #keep {
+                    state.serialize_field(#name, &#value)?;
+                } else {
+                    state.skip_field(#name)?;
+                }
+            });
+        } else {
+            len.push(quote!(1));
+            entries.extend(quote!(map.serialize_entry(#name, &#value)?;));
+            struct_fields.extend(quote!(state.serialize_field(#name, &#value)?;));
+        }
+    }
+    // With `SerializeOptions::macro_args`, the arguments of well-known macros
+    // are serialized next to their tokens (see mac.rs).
+    let has_args = ident == "Macro";
+    if has_args {
+        len.push(quote!(usize::from(keep_args)));
+        entries.extend(quote! {
+            if let Some(args) = &args {
+                map.serialize_entry("args", &Ser(args, self.1))?;
+            }
+        });
+        // With `macro-args`, the binary format always reads `args` (see
+        // `MacroWithArgs`).
+        struct_fields.extend(quote! {
+            if let Some(args) = &args {
+                state.serialize_field("args", &Ser(args, self.1))?;
+            } else if keep_args {
+                state.serialize_field("args", &None::<()>)?;
+            } else {
+                state.skip_field("args")?;
+            }
+        });
+    }
+    let has_span = ast_struct::has_span(node);
+    if has_span {
+        len.push(quote!(usize::from(keep_span)));
+        entries.extend(quote! {
+            if keep_span {
+                map.serialize_entry("span", &span)?;
+            }
+        });
+        struct_fields.extend(quote! {
+            if keep_span {
+                state.serialize_field("span", &span)?;
+            } else {
+                state.skip_field("span")?;
+            }
+        });
+    }
+    if ast_struct::has_trivia(node, defs) {
+        // Syntax trees do not have trivia, but the binary format serializes
+        // every field of the adapter.
+        struct_fields.extend(quote! {
+            if self.1.is_binary() {
+                state.serialize_field("trivia", &None::<Trivia>)?;
+            } else {
+                state.skip_field("trivia")?;
+            }
+        });
+    }
+    let span = if has_span {
+        Some(quote! {
+            let span = Location::of(node, self.1);
+            let keep_span = self.1.is_binary() || span.is_some();
+        })
+    } else {
+        None
+    };
+    let args =
+        if has_args { Some(quote!(let args = mac::args::parse(node, self.1);)) } else { None };
+    let keep_args = if has_args {
+        Some(quote! {
+            let keep_args = args.is_some() || cfg!(feature = "macro-args") && self.1.is_binary();
+        })
+    } else {
+        None
+    };
+    // With `SerializeOptions::explicit_fields`, the fields that the adapter
+    // omits are serialized too.
+    let explicit =
+        if has_keep { Some(quote!(let explicit = self.1.has_explicit_fields();)) } else { None };
+
+    let ty = format_ident!("{ident}");
+    if flattened {
+        assert!(!has_flatten && !has_span, "{ident}");
+        impls.extend(quote! {
+            impl SerializeEntries for Ser<'_, syn::#ty> {
+                fn serialize_entries<M>(&self, map: &mut M) -> Result<(), M::Error>
+                where
+                    M: SerializeMap,
+                {
+                    let node = self.0;
+                    #explicit
+                    #args
+                    #entries
+                    Ok(())
+                }
+            }
+        });
+    }
+
+    if has_flatten {
+        assert!(!has_args, "{ident}");
+        quote! {
+            let node = self.0;
+            #explicit
+            #span
+            if self.1.is_binary() {
+                let mut state = serializer.serialize_struct(#ident, #(#len)+*)?;
+                #struct_fields
+                return state.end();
+            }
+            let mut map = serializer.serialize_map(None)?;
+            {
+                let map = &mut map;
+                #entries
+            }
+            map.end()
+        }
+    } else {
+        quote! {
+            let node = self.0;
+            #explicit
+            #span
+            #args
+            #keep_args
+            let mut state = serializer.serialize_struct(#ident, #(#len)+*)?;
+            #struct_fields
+            state.end()
+        }
+    }
+}
+
+pub(crate) fn generate(defs: &Definitions) {
+    let workspace_root = workspace_root();
+    let impls = traverse::traverse(defs, node);
+    let path = &workspace_root.join(SER_SRC);
+    file::write(function_name!(), bin_name!(), workspace_root, path, quote! {
+        #![allow(
+            clippy::if_not_else,
+            clippy::just_underscores_and_digits,
+            clippy::match_same_arms,
+            clippy::match_single_binding,
+            clippy::too_many_lines,
+        )]
+
+        use serde::ser::{
+            SerializeMap, SerializeStruct as _, SerializeTupleVariant as _, Serializer,
+        };
+
+        use crate::*;
+
+        #impls
+    });
+}
@@ -3,18 +3,26 @@
 use proc_macro2::TokenStream;
 use syn_codegen::{Definitions, Node};
 
+/// Returns the types we provide, sorted by name.
+pub(crate) fn types(defs: &Definitions) -> Vec<Node> {
+    let mut types = defs.types.clone();
+    types.sort_by(|a, b| a.ident.cmp(&b.ident));
+    types.retain(|ty| {
+        // We don't provide types that are not available with "full" or "derive" feature
+        (ty.features.any.is_empty()
+            || ty.features.any.contains("full")
+            || ty.features.any.contains("derive"))
+            && ty.ident != "Reserved"
+    });
+    types
+}
+
 pub(crate) fn traverse(
     defs: &Definitions,
     node: fn(&mut TokenStream, &Node, &Definitions),
 ) -> TokenStream {
-    let mut types = defs.types.clone();
-    types.sort_by(|a, b| a.ident.cmp(&b.ident));
-
     let mut impls = TokenStream::new();
-    for ty in types.iter().filter(|ty| {
-        // We don't provide types that are not available only with "full" feature
-        (ty.features.any.is_empty() || ty.features.any.contains("full")) && ty.ident != "Reserved"
-    }) {
+    for ty in &types(defs) {
         node(&mut impls, ty, defs);
     }
 
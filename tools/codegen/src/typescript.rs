@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Generates TypeScript type definitions of the serialized form of the
+// adapters from their JSON Schema.
+
+use std::fmt::Write as _;
+
+use fs_err as fs;
+use serde_json::{Map, Value};
+use syn_codegen::Definitions;
+
+use crate::{json_schema, workspace_root};
+
+const TYPESCRIPT_SRC: &str = "schema/syn-serde.d.ts";
+
+/// Returns whether the TypeScript type of `schema` needs parentheses when it
+/// is used as an element of an array or an intersection.
+fn is_compound(schema: &Value) -> bool {
+    ["anyOf", "oneOf", "allOf"].iter().any(|key| schema.get(key).is_some())
+        || schema.get("enum").is_some_and(|variants| variants.as_array().unwrap().len() > 1)
+}
+
+/// Returns the string literal types of the variants of an `enum`.
+fn literals(variants: &Value) -> impl Iterator<Item = String> + '_ {
+    variants.as_array().unwrap().iter().map(Value::to_string)
+}
+
+/// Returns the members of a union, expanding the variants of `enum`s.
+fn union(schemas: &[Value]) -> Vec<String> {
+    let mut members = vec![];
+    for schema in schemas {
+        match schema.get("enum") {
+            Some(variants) => members.extend(literals(variants)),
+            None => members.push(ty(schema)),
+        }
+    }
+    members
+}
+
+/// Returns `schema` as an operand of an intersection.
+fn intersection_operand(schema: &Value) -> String {
+    if is_compound(schema) { format!("({})", ty(schema)) } else { ty(schema) }
+}
+
+fn property(name: &str, schema: &Value, required: &[Value]) -> String {
+    let optional = if required.iter().any(|r| r == name) { "" } else { "?" };
+    format!("{name}{optional}: {}", ty(schema))
+}
+
+/// Returns the properties of an object type, one per element.
+fn properties(schema: &Map<String, Value>) -> Vec<String> {
+    let required = schema.get("required").and_then(Value::as_array).map_or(&[][..], Vec::as_slice);
+    schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .map(|(name, schema)| property(name, schema, required))
+        .collect()
+}
+
+/// Returns the TypeScript type of `schema` on a single line.
+fn ty(schema: &Value) -> String {
+    let schema = schema.as_object().unwrap();
+    if let Some(reference) = schema.get("$ref") {
+        return reference.as_str().unwrap().strip_prefix("#/$defs/").unwrap().to_owned();
+    }
+    if let Some(schemas) = schema.get("anyOf").or_else(|| schema.get("oneOf")) {
+        let members = union(schemas.as_array().unwrap());
+        return if members.is_empty() { "never".to_owned() } else { members.join(" | ") };
+    }
+    if let Some(variants) = schema.get("enum") {
+        return literals(variants).collect::<Vec<_>>().join(" | ");
+    }
+    if let Some(value) = schema.get("const") {
+        return value.to_string();
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("string") => "string".to_owned(),
+        Some("boolean") => "boolean".to_owned(),
+        Some("integer") => "number".to_owned(),
+        Some("null") => "null".to_owned(),
+        Some("array") => {
+            if let Some(elements) = schema.get("prefixItems") {
+                let elements: Vec<_> = elements.as_array().unwrap().iter().map(ty).collect();
+                format!("[{}]", elements.join(", "))
+            } else if is_compound(&schema["items"]) {
+                format!("({})[]", ty(&schema["items"]))
+            } else {
+                format!("{}[]", ty(&schema["items"]))
+            }
+        }
+        // The variants of flattened enums are objects without `type`.
+        Some("object") | None if schema.contains_key("properties") => {
+            let mut ty = format!("{{ {} }}", properties(schema).join("; "));
+            for schema in schema.get("allOf").and_then(Value::as_array).into_iter().flatten() {
+                write!(ty, " & {}", intersection_operand(schema)).unwrap();
+            }
+            ty
+        }
+        _ => unimplemented!("ty: {schema:?}"),
+    }
+}
+
+/// Returns the declaration of the adapter `name`.
+fn declaration(name: &str, schema: &Value) -> String {
+    let mut out = String::new();
+    if let Some(description) = schema.get("description") {
+        writeln!(out, "/** {} */", description.as_str().unwrap()).unwrap();
+    }
+    let object = schema.as_object().unwrap();
+    if let Some(schemas) = schema.get("oneOf") {
+        let members = union(schemas.as_array().unwrap());
+        if members.len() > 1 {
+            write!(out, "export type {name} =").unwrap();
+            for member in members {
+                write!(out, "\n  | {member}").unwrap();
+            }
+            out.push_str(";\n");
+            return out;
+        }
+    } else if schema.get("type").and_then(Value::as_str) == Some("object") {
+        let mut properties = String::new();
+        for property in self::properties(object) {
+            writeln!(properties, "  {property};").unwrap();
+        }
+        if let Some(all_of) = schema.get("allOf") {
+            write!(out, "export type {name} = {{\n{properties}}}").unwrap();
+            for schema in all_of.as_array().unwrap() {
+                write!(out, " & {}", intersection_operand(schema)).unwrap();
+            }
+            out.push_str(";\n");
+        } else {
+            write!(out, "export interface {name} {{\n{properties}}}\n").unwrap();
+        }
+        return out;
+    }
+    writeln!(out, "export type {name} = {};", ty(schema)).unwrap();
+    out
+}
+
+pub(crate) fn generate(defs: &Definitions) {
+    let mut out = String::new();
+    out.push_str("// SPDX-License-Identifier: Apache-2.0 OR MIT\n");
+    out.push_str("// This file is @generated by syn-serde-internal-codegen\n");
+    out.push_str("// (generate function at tools/codegen/src/typescript.rs).\n");
+    out.push_str("// It is not intended for manual editing.\n");
+    for (name, schema) in &json_schema::definitions(defs) {
+        out.push('\n');
+        out.push_str(&declaration(name, schema));
+    }
+
+    let path = workspace_root().join(TYPESCRIPT_SRC);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, out).unwrap();
+}
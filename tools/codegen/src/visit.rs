@@ -0,0 +1,349 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Based on https://github.com/dtolnay/syn/blob/2.0.82/codegen/src/visit.rs.
+
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+use serde_json::json;
+use syn_codegen::{Data, Definitions, Node, Type};
+use test_helper::{bin_name, codegen::file, function_name};
+
+use crate::{convert::EMPTY_STRUCTS, traverse, workspace_root};
+
+const VISIT_SRC: &str = "src/gen/visit.rs";
+const VISIT_MUT_SRC: &str = "src/gen/visit_mut.rs";
+
+/// proc-macro2 types that have a corresponding method.
+pub(crate) const EXTERNAL_TYPES: &[&str] = &["Ident", "TokenStream"];
+
+/// Adapters that are written by hand and are not syn types, described in the
+/// same format as syn.json. `features` is the feature of syn-serde that
+/// enables them.
+fn hand_written_types() -> Vec<Node> {
+    let macro_args = json!({ "any": ["macro-args"] });
+    let expr = json!({ "syn": "Expr" });
+    serde_json::from_value(json!([
+        {
+            "ident": "FormatArg",
+            "features": macro_args,
+            "fields": {
+                "name": { "option": { "proc_macro2": "Ident" } },
+                "expr": expr,
+            },
+        },
+        {
+            "ident": "FormatArgs",
+            "features": macro_args,
+            "fields": {
+                "exprs": { "vec": expr },
+                "string": { "option": { "syn": "LitStr" } },
+                "args": { "vec": { "syn": "FormatArg" } },
+            },
+        },
+        {
+            "ident": "MacroArgs",
+            "features": macro_args,
+            "variants": {
+                "Format": [{ "syn": "FormatArgs" }],
+                "List": [{ "vec": expr }],
+                "Repeat": [{ "syn": "RepeatArgs" }],
+            },
+        },
+        {
+            "ident": "RepeatArgs",
+            "features": macro_args,
+            "fields": {
+                "expr": { "box": expr },
+                "len": { "box": expr },
+            },
+        },
+    ]))
+    .unwrap()
+}
+
+/// Whether `node` is one of [`hand_written_types`].
+pub(crate) fn is_hand_written(node: &Node) -> bool {
+    node.features.any.contains("macro-args")
+}
+
+/// Returns the `cfg` attribute of the items of `node` if it is a hand-written
+/// adapter, which is only available with a feature.
+pub(crate) fn cfg(node: Option<&Node>) -> TokenStream {
+    match node {
+        Some(node) if is_hand_written(node) => quote!(#[cfg(feature = "macro-args")]),
+        _ => quote!(),
+    }
+}
+
+/// Converts a type name to the method name suffix, e.g., `ExprCall` -> `expr_call`.
+pub(crate) fn under_name(ident: &str) -> String {
+    let mut name = String::new();
+    let mut prev_lowercase = false;
+    for ch in ident.chars() {
+        if ch.is_ascii_uppercase() && prev_lowercase {
+            name.push('_');
+        }
+        prev_lowercase = ch.is_ascii_lowercase();
+        name.push(ch.to_ascii_lowercase());
+    }
+    name
+}
+
+/// Returns the fields of the adapter of `node` that may contain other nodes.
+///
+/// This is the same as the fields of the syn type, except for hand-written
+/// adapters that have a different shape.
+pub(crate) fn fields(node: &Node) -> Option<Vec<(String, Type)>> {
+    let syn = |s: &str| Box::new(Type::Syn(s.to_owned()));
+    match &*node.ident {
+        "Receiver" => Some(vec![
+            ("attrs".to_owned(), Type::Vec(syn("Attribute"))),
+            ("lifetime".to_owned(), Type::Option(syn("Lifetime"))),
+            ("ty".to_owned(), Type::Box(syn("Type"))),
+        ]),
+        "ReturnType" => {
+            Some(vec![("ty".to_owned(), Type::Option(Box::new(Type::Box(syn("Type")))))])
+        }
+        _ => match &node.data {
+            Data::Struct(fields) => {
+                Some(fields.iter().map(|(field, ty)| (field.clone(), ty.clone())).collect())
+            }
+            _ => None,
+        },
+    }
+}
+
+/// Returns whether the adapter of `ty` holds a value, i.e., `ty` is not a
+/// token or a type that is omitted from the adapter.
+pub(crate) fn is_present(ty: &Type) -> bool {
+    match ty {
+        Type::Token(_) | Type::Group(_) => false,
+        Type::Syn(t) => t != "Reserved" && !EMPTY_STRUCTS.contains(&&**t),
+        Type::Ext(t) => t != "Span",
+        _ => true,
+    }
+}
+
+/// Returns the elements of a tuple that are kept in the adapter.
+pub(crate) fn tuple_elements(elements: &[Type]) -> Vec<&Type> {
+    elements.iter().filter(|ty| is_present(ty)).collect()
+}
+
+/// Returns the name of the method for `ty` if it is a node we visit.
+pub(crate) fn method_ty(ty: &str) -> Option<&str> {
+    if ty == "Reserved" || ty == "Span" || ty == "Literal" || EMPTY_STRUCTS.contains(&ty) {
+        None
+    } else {
+        Some(ty)
+    }
+}
+
+enum Operand {
+    Borrowed(TokenStream),
+    Owned(TokenStream),
+}
+
+impl Operand {
+    fn ref_tokens(&self, mutable: bool) -> TokenStream {
+        match self {
+            Self::Borrowed(n) => n.clone(),
+            Self::Owned(n) if mutable => quote!(&mut #n),
+            Self::Owned(n) => quote!(&#n),
+        }
+    }
+}
+
+fn method(ty: &str, mutable: bool) -> Ident {
+    if mutable {
+        format_ident!("visit_{}_mut", under_name(ty))
+    } else {
+        format_ident!("visit_{}", under_name(ty))
+    }
+}
+
+fn visit(ty: &Type, name: &Operand, mutable: bool) -> Option<TokenStream> {
+    match ty {
+        Type::Syn(t) | Type::Ext(t) => {
+            let method = method(method_ty(t)?, mutable);
+            let name = name.ref_tokens(mutable);
+            Some(quote!(v.#method(#name);))
+        }
+        // `&Box<T>` is coerced to `&T`.
+        Type::Box(t) => visit(t, name, mutable),
+        Type::Vec(t) | Type::Punctuated(syn_codegen::Punctuated { element: t, .. }) => {
+            let val = visit(t, &Operand::Borrowed(quote!(it)), mutable)?;
+            let name = name.ref_tokens(mutable);
+            Some(quote! {
+                for it in #name {
+                    #val
+                }
+            })
+        }
+        Type::Option(t) => {
+            let val = match &**t {
+                Type::Tuple(t) => {
+                    let t = tuple_elements(t);
+                    if t.len() == 1 {
+                        visit(t[0], &Operand::Borrowed(quote!(it)), mutable)?
+                    } else {
+                        let mut val = TokenStream::new();
+                        for (i, t) in t.into_iter().enumerate() {
+                            let i = Literal::usize_unsuffixed(i);
+                            val.extend(visit(t, &Operand::Owned(quote!(it.#i)), mutable));
+                        }
+                        if val.is_empty() {
+                            return None;
+                        }
+                        val
+                    }
+                }
+                t => visit(t, &Operand::Borrowed(quote!(it)), mutable)?,
+            };
+            let name = name.ref_tokens(mutable);
+            Some(quote! {
+                if let Some(it) = #name {
+                    #val
+                }
+            })
+        }
+        Type::Tuple(_) | Type::Token(_) | Type::Group(_) | Type::Std(_) => None,
+    }
+}
+
+fn node(
+    traits: &mut TokenStream,
+    impls: &mut TokenStream,
+    ty: &str,
+    node: Option<&Node>,
+    mutable: bool,
+) {
+    let method = method(ty, mutable);
+    let ty = format_ident!("{ty}");
+    let cfg = cfg(node);
+    let (lifetime, reference, bound) = if mutable {
+        (quote!(), quote!(&mut), quote!(VisitMut))
+    } else {
+        (quote!('ast,), quote!(&'ast), quote!(Visit<'ast>))
+    };
+
+    traits.extend(quote! {
+        #cfg
+        fn #method(&mut self, i: #reference #ty) {
+            #method(self, i);
+        }
+    });
+
+    let mut body = TokenStream::new();
+    if let Some(node) = node {
+        if let Some(fields) = fields(node) {
+            for (field, t) in &fields {
+                let field = format_ident!("{field}");
+                body.extend(visit(t, &Operand::Owned(quote!(node.#field)), mutable));
+            }
+        } else if let Data::Enum(variants) = &node.data {
+            let mut arms = TokenStream::new();
+            for (variant, fields) in variants {
+                let variant = format_ident!("{variant}");
+                let fields: Vec<_> = fields.iter().filter(|t| is_present(t)).collect();
+                if fields.is_empty() {
+                    arms.extend(quote!(#ty::#variant => {}));
+                    continue;
+                }
+                let mut pats = Vec::with_capacity(fields.len());
+                let mut stmts = TokenStream::new();
+                for (i, t) in fields.into_iter().enumerate() {
+                    let binding = format_ident!("_binding_{i}");
+                    match visit(t, &Operand::Borrowed(quote!(#binding)), mutable) {
+                        Some(stmt) => {
+                            pats.push(quote!(#binding));
+                            stmts.extend(stmt);
+                        }
+                        None => pats.push(quote!(_)),
+                    }
+                }
+                arms.extend(quote! {
+                    #ty::#variant(#(#pats),*) => {
+                        #stmts
+                    }
+                });
+            }
+            // Empty enums such as `ImplRestriction` have nothing to visit.
+            if !arms.is_empty() {
+                body.extend(quote! {
+                    match node {
+                        #arms
+                    }
+                });
+            }
+        }
+    }
+
+    impls.extend(quote! {
+        #cfg
+        pub fn #method<#lifetime V>(v: &mut V, node: #reference #ty)
+        where
+            V: #bound + ?Sized,
+        {
+            #body
+        }
+    });
+}
+
+pub(crate) fn nodes(defs: &Definitions) -> Vec<(String, Option<Node>)> {
+    let mut nodes: Vec<_> = traverse::types(defs)
+        .into_iter()
+        .filter(|node| !EMPTY_STRUCTS.contains(&&*node.ident))
+        .chain(hand_written_types())
+        .map(|node| (node.ident.clone(), Some(node)))
+        .chain(EXTERNAL_TYPES.iter().map(|&ty| (ty.to_owned(), None)))
+        .collect();
+    nodes.sort_by(|(a, _), (b, _)| a.cmp(b));
+    nodes
+}
+
+pub(crate) fn generate(defs: &Definitions) {
+    let workspace_root = workspace_root();
+    for mutable in [false, true] {
+        let mut traits = TokenStream::new();
+        let mut impls = TokenStream::new();
+        for (ty, n) in nodes(defs) {
+            node(&mut traits, &mut impls, &ty, n.as_ref(), mutable);
+        }
+
+        let (path, item) = if mutable {
+            (VISIT_MUT_SRC, quote! {
+                /// Syntax tree traversal to mutate an exclusive borrow of a syntax tree in
+                /// place.
+                ///
+                /// See the [module documentation] for details.
+                ///
+                /// [module documentation]: self
+                pub trait VisitMut {
+                    #traits
+                }
+            })
+        } else {
+            (VISIT_SRC, quote! {
+                /// Syntax tree traversal to walk a shared borrow of a syntax tree.
+                ///
+                /// See the [module documentation] for details.
+                ///
+                /// [module documentation]: self
+                pub trait Visit<'ast> {
+                    #traits
+                }
+            })
+        };
+        let path = &workspace_root.join(path);
+        file::write(function_name!(), bin_name!(), workspace_root, path, quote! {
+            #![allow(unused_variables)]
+            #![allow(clippy::match_same_arms, clippy::needless_pass_by_ref_mut)]
+
+            use crate::*;
+
+            #item
+
+            #impls
+        });
+    }
+}